@@ -6,6 +6,18 @@
 //! 3. Configuring the build environment and linking
 //!
 //! The script requires CMake to be installed on the system for building the C++ libraries.
+//! Alternatively, an installed libSBML is used if `SBML_LIB_DIR` is set or if the
+//! `system-libsbml` feature is enabled, in which case it is discovered via pkg-config.
+//!
+//! SBML Level 3 packages are selected through Cargo features. Only the enabled packages
+//! are built into libSBML and get bindings, which keeps the build time down.
+//!
+//! The XML parser libSBML is built against is selected through one of the mutually
+//! exclusive features `xml-expat` (default), `xml-libxml2` and `xml-xerces`.
+
+use std::path::PathBuf;
+
+use miette::IntoDiagnostic;
 
 /// Name of the SBML library
 const LIBSBML_NAME: &str = "sbml";
@@ -22,49 +34,394 @@ const EXPAT_WINDOWS_LIB: &str = "libexpat.lib";
 /// Name of the zlib library file on Windows
 const ZLIB_WINDOWS_LIB: &str = "zdll.lib";
 
-/// Whether to build with libxml2 support (disabled in favor of Expat)
-const WITH_LIBXML: &str = "OFF";
+/// Name of the static libSBML library on Linux
+const LIBSBML_LINUX_STATIC: &str = "sbml-static";
+
+/// Name of the pkg-config package of libSBML
+const LIBSBML_PKG_CONFIG: &str = "libsbml";
+
+/// Minimum version of an installed libSBML
+const LIBSBML_MIN_VERSION: &str = "5.20.0";
+
+/// Environment variable pointing to the directory of an installed libSBML library
+const SBML_LIB_DIR: &str = "SBML_LIB_DIR";
+
+/// Environment variable pointing to the headers of an installed libSBML library
+const SBML_INCLUDE_DIR: &str = "SBML_INCLUDE_DIR";
+
+/// Path to the libxml2 source code
+const LIBXML2_PATH: &str = "vendors/libxml2";
+
+/// Name of the pkg-config package of Xerces-C++
+const XERCES_PKG_CONFIG: &str = "xerces-c";
+
+/// The XML parser libSBML is built against, selected through a Cargo feature
+#[derive(Clone, Copy, PartialEq)]
+enum XmlParser {
+    /// Expat, built from the vendored libSBML dependencies
+    Expat,
+    /// libxml2, built from the vendored sources
+    Libxml2,
+    /// Xerces-C++, which has to be installed on the system
+    Xerces,
+}
+
+impl XmlParser {
+    /// All parsers with the Cargo feature selecting them
+    const FEATURES: [(XmlParser, &'static str); 3] = [
+        (XmlParser::Expat, "xml-expat"),
+        (XmlParser::Libxml2, "xml-libxml2"),
+        (XmlParser::Xerces, "xml-xerces"),
+    ];
 
-/// Whether to build with Expat XML parser support
-const WITH_EXPAT: &str = "ON";
+    /// Returns the parser selected through Cargo features
+    ///
+    /// # Returns
+    /// * `miette::Result<XmlParser>` - The parser, error if not exactly one is selected
+    fn selected() -> miette::Result<Self> {
+        let selected: Vec<(XmlParser, &str)> = Self::FEATURES
+            .into_iter()
+            .filter(|(_, feature)| {
+                let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+                std::env::var_os(var).is_some()
+            })
+            .collect();
+
+        match selected.as_slice() {
+            [(parser, _)] => Ok(*parser),
+            [] => Err(miette::miette!(
+                "No XML parser selected, enable one of the features xml-expat, xml-libxml2 or xml-xerces"
+            )),
+            _ => Err(miette::miette!(
+                "The features {} are mutually exclusive, enable only one XML parser",
+                selected
+                    .iter()
+                    .map(|(_, feature)| *feature)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    /// Prefix of the CMake variables locating the parser, e.g. `EXPAT_LIBRARY`
+    fn cmake_prefix(&self) -> &'static str {
+        match self {
+            XmlParser::Expat => "EXPAT",
+            XmlParser::Libxml2 => "LIBXML",
+            XmlParser::Xerces => "XERCES",
+        }
+    }
+
+    /// CMake options of libSBML enabling this parser and disabling the others
+    fn cmake_options(&self) -> [(&'static str, &'static str); 3] {
+        let on_off = |parser: XmlParser| if *self == parser { "ON" } else { "OFF" };
+        [
+            ("WITH_EXPAT", on_off(XmlParser::Expat)),
+            ("WITH_LIBXML", on_off(XmlParser::Libxml2)),
+            ("WITH_XERCES", on_off(XmlParser::Xerces)),
+        ]
+    }
+}
+
+/// Location of the XML parser library libSBML is built against
+struct XmlParserLib {
+    /// Directory containing the headers of the parser, found by CMake if `None`
+    include_dir: Option<String>,
+    /// Path of the library file, found by CMake if `None`
+    library: Option<String>,
+    /// Arguments of `cargo:rustc-link-lib` linking the parser after libSBML
+    link: Vec<String>,
+}
+
+/// An SBML Level 3 package of libSBML that is selected through a Cargo feature
+struct SbmlPackage {
+    /// Name of the Cargo feature enabling the package
+    feature: &'static str,
+    /// CMake option enabling the package in libSBML
+    cmake_option: &'static str,
+    /// Headers declaring the classes of the package
+    headers: &'static [&'static str],
+    /// Classes and enums of the package to generate bindings for
+    types: &'static [&'static str],
+}
+
+impl SbmlPackage {
+    /// Returns whether the Cargo feature of the package is enabled.
+    fn enabled(&self) -> bool {
+        let feature = format!("CARGO_FEATURE_{}", self.feature.to_uppercase());
+        std::env::var_os(feature).is_some()
+    }
+}
+
+/// Headers of the SBML core
+const CORE_HEADERS: &[&str] = &["sbml/SBMLTypes.h"];
+
+/// Classes, enums and functions of the SBML core to generate bindings for
+const CORE_TYPES: &[&str] = &[
+    // Base types
+    "SBase",
+    "ElementFilter",
+    // Root types
+    "SBMLDocument",
+    "Model",
+    // Leaf types
+    "Species",
+    "Parameter",
+    "Compartment",
+    "UnitDefinition",
+    "Unit",
+    "UnitKind_t",
+    "Reaction",
+    "SpeciesReference",
+    "SimpleSpeciesReference",
+    "ModifierSpeciesReference",
+    "InitialAssignment",
+    "RateRule",
+    "AssignmentRule",
+    "Rule",
+    "KineticLaw",
+    "LocalParameter",
+    "Event",
+    "Trigger",
+    "Delay",
+    "EventAssignment",
+    // Package types
+    "SBasePlugin",
+    // Conversion types
+    "SBMLConverter",
+    "ConversionProperties",
+    "XMLError",
+    // Math types
+    "ASTNode",
+    "ASTNodeType_t",
+    "SBML_parseL3Formula",
+    "SBML_formulaToL3String",
+    // IO types
+    "SBMLWriter",
+    "SBMLReader",
+    // Container types
+    "ListOfParameters",
+    "ListOfUnitDefinitions",
+    "ListOfCompartments",
+    "ListOfSpecies",
+    "ListOfReactions",
+    "ListOf",
+    "List",
+];
+
+/// SBML Level 3 packages that can be built into libSBML.
+///
+/// Packages without types are only built into libSBML, so that documents using them
+/// are read and validated, but have no wrappers yet.
+const PACKAGES: &[SbmlPackage] = &[
+    // Flux balance constraints
+    SbmlPackage {
+        feature: "fbc",
+        cmake_option: "ENABLE_FBC",
+        headers: &["sbml/packages/fbc/common/FbcExtensionTypes.h"],
+        types: &[
+            "FbcModelPlugin",
+            "FbcSpeciesPlugin",
+            "FbcReactionPlugin",
+            "Objective",
+            "ObjectiveType_t",
+            "FluxObjective",
+            "FluxBound",
+            "FluxBoundOperation_t",
+            "GeneProduct",
+            "GeneProductAssociation",
+            "FbcAssociation",
+            "FbcAnd",
+            "FbcOr",
+            "GeneProductRef",
+        ],
+    },
+    // Diagrams of models
+    SbmlPackage {
+        feature: "layout",
+        cmake_option: "ENABLE_LAYOUT",
+        headers: &["sbml/packages/layout/common/LayoutExtensionTypes.h"],
+        types: &[
+            "LayoutModelPlugin",
+            "Layout",
+            "Dimensions",
+            "GraphicalObject",
+            "CompartmentGlyph",
+            "SpeciesGlyph",
+            "ReactionGlyph",
+            "SpeciesReferenceGlyph",
+            "BoundingBox",
+            "Point",
+            "Curve",
+            "LineSegment",
+            "CubicBezier",
+            "ListOfLineSegments",
+            "SBMLLayoutTypeCode_t",
+        ],
+    },
+    // Visual styles of layouts (requires layout)
+    SbmlPackage {
+        feature: "render",
+        cmake_option: "ENABLE_RENDER",
+        headers: &["sbml/packages/render/common/RenderExtensionTypes.h"],
+        types: &[
+            "RenderLayoutPlugin",
+            "RenderInformationBase",
+            "LocalRenderInformation",
+            "ColorDefinition",
+            "Style",
+            "LocalStyle",
+            "RenderGroup",
+            "GraphicalPrimitive1D",
+            "GraphicalPrimitive2D",
+        ],
+    },
+    // Hierarchical model composition
+    SbmlPackage {
+        feature: "comp",
+        cmake_option: "ENABLE_COMP",
+        headers: &[
+            "sbml/packages/comp/common/CompExtensionTypes.h",
+            "sbml/packages/comp/util/CompFlatteningConverter.h",
+        ],
+        types: &[
+            "CompSBMLDocumentPlugin",
+            "CompModelPlugin",
+            "CompSBasePlugin",
+            "ModelDefinition",
+            "ExternalModelDefinition",
+            "Submodel",
+            "Port",
+            "Deletion",
+            "SBaseRef",
+            "Replacing",
+            "ReplacedElement",
+            "ReplacedBy",
+            "CompFlatteningConverter",
+        ],
+    },
+    // Grouping of model elements
+    SbmlPackage {
+        feature: "groups",
+        cmake_option: "ENABLE_GROUPS",
+        headers: &["sbml/packages/groups/common/GroupsExtensionTypes.h"],
+        types: &["GroupsModelPlugin", "Group", "Member", "GroupKind_t"],
+    },
+    // Qualitative models
+    SbmlPackage {
+        feature: "qual",
+        cmake_option: "ENABLE_QUAL",
+        headers: &["sbml/packages/qual/common/QualExtensionTypes.h"],
+        types: &[
+            "QualModelPlugin",
+            "QualitativeSpecies",
+            "Transition",
+            "Input",
+            "Output",
+            "FunctionTerm",
+            "DefaultTerm",
+            "InputSign_t",
+            "InputTransitionEffect_t",
+            "OutputTransitionEffect_t",
+        ],
+    },
+    // Distributions and uncertainties
+    SbmlPackage {
+        feature: "distrib",
+        cmake_option: "ENABLE_DISTRIB",
+        headers: &["sbml/packages/distrib/common/DistribExtensionTypes.h"],
+        types: &[
+            "DistribSBasePlugin",
+            "Uncertainty",
+            "UncertParameter",
+            "UncertType_t",
+        ],
+    },
+    // Multistate and multicomponent species
+    SbmlPackage {
+        feature: "multi",
+        cmake_option: "ENABLE_MULTI",
+        headers: &[],
+        types: &[],
+    },
+    // Spatial processes
+    SbmlPackage {
+        feature: "spatial",
+        cmake_option: "ENABLE_SPATIAL",
+        headers: &[],
+        types: &[],
+    },
+];
+
+/// Name of the generated file holding the `include_cpp!` block of the bindings
+const BINDINGS_FILE: &str = "sbmlcxx.rs";
 
 /// Main build script function that orchestrates the build process
 ///
 /// This function:
-/// 1. Builds libSBML dependencies if on Windows
-/// 2. Builds the libSBML library
-/// 3. Generates Rust bindings using autocxx
-/// 4. Configures the build environment and linking
+/// 1. Links an installed libSBML, or builds libSBML and its dependencies from source
+///    (statically on Windows and Linux)
+/// 2. Generates Rust bindings using autocxx
+/// 3. Configures the build environment and linking
 ///
 /// # Returns
 /// * `miette::Result<()>` - Success or error result
 fn main() -> miette::Result<()> {
-    // Ensure cargo rebuilds if this build script changes
+    // Ensure cargo rebuilds if this build script or the libSBML location changes
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-env-changed={}", SBML_LIB_DIR);
+    println!("cargo:rerun-if-env-changed={}", SBML_INCLUDE_DIR);
 
-    // Build and link libSBML dependencies
-    let dep_build = if cfg!(target_os = "windows") {
-        build_and_link_sbml_deps()?
-    } else {
-        String::new()
-    };
+    let sbml_include = match link_system_libsbml()? {
+        Some(include_dirs) => {
+            check_package_headers(&include_dirs)?;
+            include_dirs
+        }
+        None => {
+            let xml_parser = XmlParser::selected()?;
 
-    // Build and link libSBML
-    let sbml_build = build_and_link_libsbml(&dep_build)?;
+            // Build libSBML dependencies
+            let dep_build = if cfg!(target_os = "windows") || cfg!(target_os = "linux") {
+                build_sbml_deps(xml_parser)?
+            } else {
+                String::new()
+            };
 
-    // Print the contents of the sbml_build directory
-    print_dir_contents(&sbml_build)?;
+            // Build or locate the XML parser
+            let xml_lib = locate_xml_parser(xml_parser, &dep_build)?;
 
-    // Configure autocxx to generate Rust bindings
-    let rs_file = "src/lib.rs";
+            // Build and link libSBML
+            let sbml_build = build_and_link_libsbml(&dep_build, xml_parser, xml_lib.as_ref())?;
 
-    // Point to the libSBML headers
-    let sbml_include = format!("{}/include", sbml_build);
-    let lib_root = ".";
+            // Static libraries must be linked after the libraries using them
+            if let Some(xml_lib) = &xml_lib {
+                for lib in &xml_lib.link {
+                    println!("cargo:rustc-link-lib={}", lib);
+                }
+            }
+            if !dep_build.is_empty() {
+                link_sbml_deps(&dep_build)?;
+            }
+            if cfg!(target_os = "linux") {
+                println!("cargo:rustc-link-lib=dylib=stdc++");
+            }
+
+            // Print the contents of the sbml_build directory
+            print_dir_contents(&sbml_build)?;
+
+            // Point to the libSBML headers
+            vec![PathBuf::from(format!("{}/include", sbml_build))]
+        }
+    };
+
+    // Configure autocxx to generate Rust bindings for the enabled packages
+    let rs_file = write_bindings_file()?;
+    let lib_root = PathBuf::from(".");
 
     // Build the C++ wrapper code and bindings
-    let mut b = autocxx_build::Builder::new(rs_file, [lib_root, &sbml_include]).build()?;
+    let include_dirs = std::iter::once(lib_root).chain(sbml_include);
+    let mut b = autocxx_build::Builder::new(&rs_file, include_dirs).build()?;
 
     // Ensure C++20 is used for compilation and disable warnings
     b.flag_if_supported("-std=c++17")
@@ -74,38 +431,118 @@ fn main() -> miette::Result<()> {
     Ok(())
 }
 
+/// Links against an installed libSBML library, if requested
+///
+/// An explicit location given by `SBML_LIB_DIR` (and optionally `SBML_INCLUDE_DIR`,
+/// which defaults to the `include` directory next to the library directory) takes
+/// precedence. Otherwise, the `system-libsbml` feature discovers libSBML via pkg-config.
+///
+/// # Returns
+/// * `miette::Result<Option<Vec<PathBuf>>>` - The include directories of the installed
+///   libSBML, None if libSBML should be built from source
+fn link_system_libsbml() -> miette::Result<Option<Vec<PathBuf>>> {
+    if let Some(lib_dir) = std::env::var_os(SBML_LIB_DIR) {
+        let lib_dir = PathBuf::from(lib_dir);
+        let include_dir = match std::env::var_os(SBML_INCLUDE_DIR) {
+            Some(include_dir) => PathBuf::from(include_dir),
+            None => lib_dir.join("..").join("include"),
+        };
+
+        if !include_dir.join("sbml").join("SBMLTypes.h").is_file() {
+            return Err(miette::miette!(
+                "libSBML headers not found in {}, set {} to the directory containing `sbml/SBMLTypes.h`",
+                include_dir.display(),
+                SBML_INCLUDE_DIR
+            ));
+        }
+
+        println!("cargo:warning=Using libSBML from {}", lib_dir.display());
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!("cargo:rustc-link-lib=dylib={}", LIBSBML_NAME);
+        if !cfg!(target_os = "windows") {
+            // Find the shared library at runtime without setting the library path
+            println!("cargo:rustc-link-arg=-Wl,-rpath,{}", lib_dir.display());
+        }
+        return Ok(Some(vec![include_dir]));
+    }
+
+    if std::env::var_os("CARGO_FEATURE_SYSTEM_LIBSBML").is_none() {
+        return Ok(None);
+    }
+
+    // pkg-config prints the link flags of the library
+    let library = pkg_config::Config::new()
+        .atleast_version(LIBSBML_MIN_VERSION)
+        .probe(LIBSBML_PKG_CONFIG)
+        .map_err(|err| {
+            miette::miette!(
+                "Failed to find libSBML via pkg-config: {}\nInstall libSBML >= {} or set {} and {}",
+                err,
+                LIBSBML_MIN_VERSION,
+                SBML_LIB_DIR,
+                SBML_INCLUDE_DIR
+            )
+        })?;
+
+    println!(
+        "cargo:warning=Using libSBML {} found via pkg-config",
+        library.version
+    );
+    Ok(Some(library.include_paths))
+}
+
+/// Checks that an installed libSBML contains the packages enabled through Cargo features
+///
+/// # Arguments
+/// * `include_dirs` - The include directories of the installed libSBML
+///
+/// # Returns
+/// * `miette::Result<()>` - Success, or an error naming the first missing package
+fn check_package_headers(include_dirs: &[PathBuf]) -> miette::Result<()> {
+    for package in PACKAGES.iter().filter(|p| p.enabled()) {
+        for header in package.headers {
+            if !include_dirs.iter().any(|dir| dir.join(header).is_file()) {
+                return Err(miette::miette!(
+                    "The installed libSBML was built without the {} package (missing {}), disable the `{}` feature or use the vendored libSBML",
+                    package.feature,
+                    header,
+                    package.feature
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Helper function to build and link the libSBML library using CMake
 ///
 /// This function handles the platform-specific build configuration:
-/// - On Windows, it configures paths to Expat and zlib dependencies
-/// - On MacOS/Linux, it uses system libraries
+/// - On Windows and Linux, it configures paths to the XML parser and zlib dependencies
+///   and links libSBML statically
+/// - On MacOS, it uses system libraries
 ///
 /// # Arguments
-/// * `dep_build` - Path to the dependency build directory (used on Windows)
+/// * `dep_build` - Path to the dependency build directory (used on Windows and Linux)
+/// * `xml_parser` - The XML parser to build libSBML against
+/// * `xml_lib` - Location of the XML parser, if not found by CMake
 ///
 /// # Returns
 /// * `miette::Result<String>` - Build directory path on success, error on failure
-fn build_and_link_libsbml(dep_build: &str) -> miette::Result<String> {
+fn build_and_link_libsbml(
+    dep_build: &str,
+    xml_parser: XmlParser,
+    xml_lib: Option<&XmlParserLib>,
+) -> miette::Result<String> {
     let dst = if cfg!(target_os = "windows") {
         println!("cargo:warning=Building libSBML for Windows");
         // In order to build for windows, we need to carefully tell CMake
-        // where to find the libraries and headers for libexpat and zlib.
+        // where to find the libraries and headers for the XML parser and zlib.
         // This is necessary because the libraries are not installed in the
         // system directories by default. Unlinke MacOS and Linux kernels
-        cmake::Config::new(LIBSBML_PATH)
+        libsbml_config(xml_parser, xml_lib)
             .static_crt(true)
             .profile("Release")
             .define("WITH_STATIC_RUNTIME", "ON")
-            .define("WITH_LIBXML", WITH_LIBXML)
-            .define("WITH_EXPAT", WITH_EXPAT)
-            //
-            // Define the paths to the libraries and headers for libexpat and zlib
-            //
-            .define("EXPAT_INCLUDE_DIR", format!("{}/include", dep_build))
-            .define(
-                "EXPAT_LIBRARY",
-                format!("{}/lib/{}", dep_build, EXPAT_WINDOWS_LIB),
-            )
             //
             // Define the path to the library and headers for zlib
             //
@@ -119,14 +556,32 @@ fn build_and_link_libsbml(dep_build: &str) -> miette::Result<String> {
             //
             .define("BUILD_SHARED_LIBS", "OFF")
             .build()
+    } else if cfg!(target_os = "linux") {
+        println!("cargo:warning=Building libSBML for Linux");
+        // On Linux, libSBML and its dependencies are linked statically, so that
+        // binaries do not depend on a libSBML or XML parser installed on the system.
+        libsbml_config(xml_parser, xml_lib)
+            .profile("Release")
+            .define("WITH_STATIC_RUNTIME", "OFF")
+            .define("ZLIB_INCLUDE_DIR", format!("{}/include", dep_build))
+            .define(
+                "ZLIB_LIBRARY",
+                format!("{}/lib/{}", dep_build, dependency_lib(dep_build, "z")?),
+            )
+            //
+            // Only build the static library, as position independent code to be
+            // linkable into Rust binaries and shared libraries
+            //
+            .define("LIBSBML_SKIP_SHARED_LIBRARY", "ON")
+            .define("CMAKE_POSITION_INDEPENDENT_CODE", "ON")
+            .define("CMAKE_INSTALL_LIBDIR", "lib")
+            .build()
     } else {
-        println!("cargo:warning=Building libSBML for MacOS/Linux");
-        // When building for MacOS and Linux, we can just use the system libraries
-        cmake::Config::new(LIBSBML_PATH)
+        println!("cargo:warning=Building libSBML for MacOS");
+        // When building for MacOS, we can just use the system libraries
+        libsbml_config(xml_parser, xml_lib)
             .profile("Release")
             .define("WITH_STATIC_RUNTIME", "OFF")
-            .define("WITH_LIBXML", WITH_LIBXML)
-            .define("WITH_EXPAT", WITH_EXPAT)
             .build()
     };
 
@@ -138,50 +593,346 @@ fn build_and_link_libsbml(dep_build: &str) -> miette::Result<String> {
         // static library is named "libsbml-static" and not "libsbml".
         // which seems to confuse the rustc linker.
         println!("cargo:rustc-link-lib=libsbml-static");
+    } else if cfg!(target_os = "linux") {
+        println!("cargo:rustc-link-lib=static={}", LIBSBML_LINUX_STATIC);
     } else {
-        // On MacOS and Linux, we can just link against the dynamic library
+        // On MacOS, we can just link against the dynamic library
         println!("cargo:rustc-link-lib=dylib={}", LIBSBML_NAME);
     }
 
     Ok(dst.display().to_string())
 }
 
-/// Builds and links the libSBML dependencies (Expat and zlib) on Windows
+/// Enables the packages selected through Cargo features in the libSBML build
+///
+/// # Arguments
+/// * `config` - The CMake configuration of libSBML
+///
+/// # Returns
+/// * `&mut cmake::Config` - The configuration for further chaining
+fn configure_packages(config: &mut cmake::Config) -> &mut cmake::Config {
+    for package in PACKAGES {
+        let value = if package.enabled() { "ON" } else { "OFF" };
+        config.define(package.cmake_option, value);
+    }
+    config
+}
+
+/// Creates the CMake configuration of libSBML with the selected packages and XML parser
+///
+/// # Arguments
+/// * `xml_parser` - The XML parser to build libSBML against
+/// * `xml_lib` - Location of the XML parser, if not found by CMake
+///
+/// # Returns
+/// * `cmake::Config` - The configuration for further chaining
+fn libsbml_config(xml_parser: XmlParser, xml_lib: Option<&XmlParserLib>) -> cmake::Config {
+    let mut config = cmake::Config::new(LIBSBML_PATH);
+    configure_packages(&mut config);
+
+    for (option, value) in xml_parser.cmake_options() {
+        config.define(option, value);
+    }
+    if let Some(xml_lib) = xml_lib {
+        let prefix = xml_parser.cmake_prefix();
+        if let Some(include_dir) = &xml_lib.include_dir {
+            config.define(format!("{}_INCLUDE_DIR", prefix), include_dir);
+        }
+        if let Some(library) = &xml_lib.library {
+            config.define(format!("{}_LIBRARY", prefix), library);
+        }
+    }
+
+    // The headers of a static libxml2 declare its functions as imported from a DLL
+    // on Windows, unless told otherwise
+    if xml_parser == XmlParser::Libxml2 && cfg!(target_os = "windows") {
+        config.cflag("-DLIBXML_STATIC").cxxflag("-DLIBXML_STATIC");
+    }
+    config
+}
+
+/// Writes the `include_cpp!` block of the bindings for the enabled packages
 ///
-/// This function is only used on Windows where we need to build these
-/// dependencies from source. On other platforms, system libraries are used.
+/// autocxx does not support conditional `generate!` directives, hence the block is
+/// generated here and included by the `sbmlcxx` module in `src/lib.rs`.
+///
+/// # Returns
+/// * `miette::Result<PathBuf>` - Path of the written file on success, error on failure
+fn write_bindings_file() -> miette::Result<PathBuf> {
+    let enabled: Vec<&SbmlPackage> = PACKAGES.iter().filter(|p| p.enabled()).collect();
+    let mut block = String::from("include_cpp! {\n");
+
+    let headers = CORE_HEADERS
+        .iter()
+        .chain(enabled.iter().flat_map(|p| p.headers.iter()));
+    for header in headers {
+        block.push_str(&format!("    #include \"{}\"\n", header));
+    }
+    block.push_str("    safety!(unsafe_ffi)\n");
+
+    let types = CORE_TYPES
+        .iter()
+        .chain(enabled.iter().flat_map(|p| p.types.iter()));
+    for name in types {
+        block.push_str(&format!("    generate!(\"{}\")\n", name));
+    }
+    block.push_str("}\n");
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").into_diagnostic()?);
+    let path = out_dir.join(BINDINGS_FILE);
+    std::fs::write(&path, block).into_diagnostic()?;
+
+    Ok(path)
+}
+
+/// Builds the libSBML dependencies (zlib, and Expat if selected) on Windows and Linux
+///
+/// This function is only used on Windows and Linux where we need to build these
+/// dependencies from source. On MacOS, system libraries are used. zlib is linked
+/// by `link_sbml_deps` after libSBML.
+///
+/// # Arguments
+/// * `xml_parser` - The selected XML parser
 ///
 /// # Returns
 /// * `miette::Result<String>` - Build directory path on success, error on failure
-fn build_and_link_sbml_deps() -> miette::Result<String> {
+fn build_sbml_deps(xml_parser: XmlParser) -> miette::Result<String> {
     println!("cargo:warning=Building libSBML dependencies");
 
-    // Build the dependencies for libSBML
-    // We hard-code to EXPAT and ZLIB for now, but in the future this should
-    // be made more flexible.
+    // Build the dependencies for libSBML. libxml2 is built from its own vendored
+    // sources and Xerces-C++ is taken from the system.
+    let with_expat = if xml_parser == XmlParser::Expat {
+        "ON"
+    } else {
+        "OFF"
+    };
     let dst = cmake::Config::new(LIBSBML_DEPENDENCY_DIR)
         .static_crt(true)
         .profile("Release")
         .define("WITH_STATIC_RUNTIME", "ON")
         .define("EXPAT_MSVC_STATIC_CRT", "ON")
-        .define("WITH_EXPAT", "ON")
+        .define("WITH_EXPAT", with_expat)
         .define("WITH_LIBXML", "OFF")
         .define("WITH_ZLIB", "ON")
         .define("WITH_BZIP2", "OFF")
         .define("WITH_CHECK", "OFF")
         .define("BUILD_SHARED_LIBS", "OFF")
+        .define("CMAKE_POSITION_INDEPENDENT_CODE", "ON")
+        .define("CMAKE_INSTALL_LIBDIR", "lib")
         .build();
 
-    // Configure cargo to link against the built libraries
     // Note: We link against the static libraries, because the dynamic libraries
     // are not working for some reason.
     println!("cargo:rustc-link-search=native={}/lib", dst.display());
-    println!("cargo:rustc-link-lib=libexpat");
-    println!("cargo:rustc-link-lib=zdll");
 
     Ok(dst.display().to_string())
 }
 
+/// Links zlib built by `build_sbml_deps`
+///
+/// # Arguments
+/// * `dep_build` - Path to the dependency build directory
+///
+/// # Returns
+/// * `miette::Result<()>` - Success or error result
+fn link_sbml_deps(dep_build: &str) -> miette::Result<()> {
+    let file = dependency_lib(dep_build, "z")?;
+    println!("cargo:rustc-link-lib={}", static_link_arg(&file));
+    Ok(())
+}
+
+/// Builds or locates the selected XML parser
+///
+/// - Expat is taken from the dependencies built by `build_sbml_deps`
+/// - libxml2 is built from the vendored sources
+/// - Xerces-C++ is discovered via pkg-config
+///
+/// On MacOS, libSBML is linked dynamically and CMake finds Expat and libxml2 on the
+/// system by itself.
+///
+/// # Arguments
+/// * `xml_parser` - The selected XML parser
+/// * `dep_build` - Path to the dependency build directory (empty on MacOS)
+///
+/// # Returns
+/// * `miette::Result<Option<XmlParserLib>>` - The location of the parser, None if it
+///   is left to CMake
+fn locate_xml_parser(
+    xml_parser: XmlParser,
+    dep_build: &str,
+) -> miette::Result<Option<XmlParserLib>> {
+    match xml_parser {
+        XmlParser::Expat if !dep_build.is_empty() => {
+            let file = dependency_lib(dep_build, "expat")?;
+            Ok(Some(XmlParserLib {
+                include_dir: Some(format!("{}/include", dep_build)),
+                library: Some(format!("{}/lib/{}", dep_build, file)),
+                link: vec![static_link_arg(&file)],
+            }))
+        }
+        XmlParser::Libxml2 if !dep_build.is_empty() => build_libxml2().map(Some),
+        XmlParser::Xerces => locate_xerces(),
+        _ => Ok(None),
+    }
+}
+
+/// Builds libxml2 as a static library from the vendored sources
+///
+/// Only the features needed to parse SBML are built, hence no dependencies on zlib,
+/// lzma, ICU or iconv are introduced.
+///
+/// # Returns
+/// * `miette::Result<XmlParserLib>` - The location of libxml2 on success, error on failure
+fn build_libxml2() -> miette::Result<XmlParserLib> {
+    println!("cargo:warning=Building libxml2");
+
+    let dst = cmake::Config::new(LIBXML2_PATH)
+        .static_crt(true)
+        .profile("Release")
+        .define("BUILD_SHARED_LIBS", "OFF")
+        .define("LIBXML2_WITH_PYTHON", "OFF")
+        .define("LIBXML2_WITH_ZLIB", "OFF")
+        .define("LIBXML2_WITH_LZMA", "OFF")
+        .define("LIBXML2_WITH_ICU", "OFF")
+        .define("LIBXML2_WITH_ICONV", "OFF")
+        .define("LIBXML2_WITH_HTTP", "OFF")
+        .define("LIBXML2_WITH_TESTS", "OFF")
+        .define("LIBXML2_WITH_PROGRAMS", "OFF")
+        .define("CMAKE_POSITION_INDEPENDENT_CODE", "ON")
+        .define("CMAKE_INSTALL_LIBDIR", "lib")
+        .build();
+
+    println!("cargo:rustc-link-search=native={}/lib", dst.display());
+
+    let dst = dst.display().to_string();
+    let lib_dir = format!("{}/lib", dst);
+    let file = find_static_lib(&lib_dir, "xml2")?;
+
+    let mut link = vec![static_link_arg(&file)];
+    if cfg!(target_os = "windows") {
+        // libxml2 uses the Windows cryptography API for its random numbers
+        link.push("bcrypt".to_string());
+    }
+
+    Ok(XmlParserLib {
+        include_dir: Some(format!("{}/include/libxml2", dst)),
+        library: Some(format!("{}/{}", lib_dir, file)),
+        link,
+    })
+}
+
+/// Locates an installed Xerces-C++ via pkg-config
+///
+/// # Returns
+/// * `miette::Result<Option<XmlParserLib>>` - The location of Xerces-C++ on Linux, None
+///   on MacOS where libSBML is linked dynamically, error if Xerces-C++ is not installed
+fn locate_xerces() -> miette::Result<Option<XmlParserLib>> {
+    if cfg!(target_os = "windows") {
+        return Err(miette::miette!(
+            "The xml-xerces feature is not supported on Windows, use xml-expat or xml-libxml2"
+        ));
+    }
+
+    let library = pkg_config::Config::new()
+        .cargo_metadata(false)
+        .probe(XERCES_PKG_CONFIG)
+        .map_err(|e| {
+            miette::miette!(
+                "The xml-xerces feature requires Xerces-C++ to be installed: {}",
+                e
+            )
+        })?;
+
+    if !cfg!(target_os = "linux") {
+        return Ok(None);
+    }
+
+    for path in &library.link_paths {
+        println!("cargo:rustc-link-search=native={}", path.display());
+    }
+
+    Ok(Some(XmlParserLib {
+        include_dir: library
+            .include_paths
+            .first()
+            .map(|path| path.display().to_string()),
+        library: library
+            .link_paths
+            .iter()
+            .map(|path| path.join("libxerces-c.so"))
+            .find(|path| path.is_file())
+            .map(|path| path.display().to_string()),
+        link: vec![format!("dylib={}", XERCES_PKG_CONFIG)],
+    }))
+}
+
+/// Returns the argument of `cargo:rustc-link-lib` linking a static library file
+///
+/// # Arguments
+/// * `file` - File name of the library, e.g. `libexpat.a` or `libexpat.lib`
+fn static_link_arg(file: &str) -> String {
+    if cfg!(target_os = "windows") {
+        return file.strip_suffix(".lib").unwrap_or(file).to_string();
+    }
+
+    let lib = file
+        .strip_prefix("lib")
+        .and_then(|file| file.strip_suffix(".a"))
+        .unwrap_or(file);
+    format!("static={}", lib)
+}
+
+/// Returns the file name of a static library built by `build_sbml_deps`
+///
+/// The names differ between platforms and versions of the dependencies, e.g. zlib is
+/// installed as `libz.a` or `libzlibstatic.a`, hence the library directory is searched.
+///
+/// # Arguments
+/// * `dep_build` - Path to the dependency build directory
+/// * `name` - Name of the library without the `lib` prefix, e.g. `expat`
+///
+/// # Returns
+/// * `miette::Result<String>` - File name of the library on success, error if not found
+fn dependency_lib(dep_build: &str, name: &str) -> miette::Result<String> {
+    if cfg!(target_os = "windows") {
+        return Ok(match name {
+            "expat" => EXPAT_WINDOWS_LIB.to_string(),
+            _ => ZLIB_WINDOWS_LIB.to_string(),
+        });
+    }
+
+    find_static_lib(&format!("{}/lib", dep_build), name)
+}
+
+/// Searches a directory for a static library
+///
+/// # Arguments
+/// * `lib_dir` - Directory to search
+/// * `name` - Name of the library without the `lib` prefix, e.g. `xml2`
+///
+/// # Returns
+/// * `miette::Result<String>` - File name of the library on success, error if not found
+fn find_static_lib(lib_dir: &str, name: &str) -> miette::Result<String> {
+    let extension = if cfg!(target_os = "windows") {
+        ".lib"
+    } else {
+        ".a"
+    };
+    let mut files: Vec<String> = std::fs::read_dir(lib_dir)
+        .into_diagnostic()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|file| file.starts_with(&format!("lib{}", name)) && file.ends_with(extension))
+        .collect();
+
+    // Prefer the exact name, e.g. `libz.a` over `libzlibstatic.a`
+    files.sort_by_key(|file| file.len());
+    files
+        .into_iter()
+        .next()
+        .ok_or_else(|| miette::miette!("Static library lib{} not found in {}", name, lib_dir))
+}
+
 /// Recursively prints the contents of a directory for debugging purposes
 ///
 /// # Arguments
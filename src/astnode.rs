@@ -0,0 +1,138 @@
+//! Internal helpers for converting between infix formulas and libSBML's ASTNode.
+//!
+//! Some SBML elements (initial assignments, event triggers, delays and event
+//! assignments) only expose their math as ASTNode trees. These helpers go through
+//! libSBML's Level 3 formula parser and formatter, such that the wrappers can offer
+//! plain formula strings like the rule and kinetic law wrappers do.
+
+use std::{
+    collections::HashSet,
+    ffi::{CStr, CString},
+    pin::Pin,
+};
+
+use cxx::UniquePtr;
+
+use crate::{pin_ptr, sbmlcxx};
+
+/// Parses an infix formula in the Level 3 syntax into an ASTNode.
+///
+/// # Arguments
+/// * `formula` - The formula to parse
+///
+/// # Returns
+/// The parsed tree or None if libSBML cannot parse the formula
+pub(crate) fn parse_formula(formula: &str) -> Option<UniquePtr<sbmlcxx::ASTNode>> {
+    let formula = CString::new(formula).ok()?;
+    let ptr = unsafe { sbmlcxx::SBML_parseL3Formula(formula.as_ptr()) };
+
+    if ptr.is_null() {
+        None
+    } else {
+        // The caller owns the returned tree
+        Some(unsafe { UniquePtr::from_raw(ptr) })
+    }
+}
+
+/// Converts an ASTNode into an infix formula in the Level 3 syntax.
+///
+/// # Arguments
+/// * `math` - The tree to convert, may be null
+///
+/// # Returns
+/// The formula as a String, empty if no math is set
+pub(crate) fn formula_to_string(math: *const sbmlcxx::ASTNode) -> String {
+    if math.is_null() {
+        return String::new();
+    }
+
+    let string_ptr = unsafe { sbmlcxx::SBML_formulaToL3String(math) };
+    if string_ptr.is_null() {
+        return String::new();
+    }
+
+    let string = unsafe { CStr::from_ptr(string_ptr) };
+    string.to_string_lossy().into_owned()
+}
+
+/// Converts an ASTNode into an infix formula in which the time csymbol can be told
+/// apart from identifiers.
+///
+/// libSBML writes a time csymbol under the name given in the document, which is often
+/// `t` and may equal an identifier of the model. Therefore, all time csymbols of a copy
+/// of the tree are renamed to a name that no other node of the formula uses.
+///
+/// # Arguments
+/// * `math` - The tree to convert, may be null
+///
+/// # Returns
+/// The formula, empty if no math is set, and the name of the time csymbol in it
+pub(crate) fn formula_with_time(math: *const sbmlcxx::ASTNode) -> (String, String) {
+    if math.is_null() {
+        return (String::new(), "time".to_string());
+    }
+
+    let mut copy = unsafe { UniquePtr::from_raw((*math).deepCopy()) };
+    let mut names = HashSet::new();
+    collect_names(&copy, &mut names);
+    let time = std::iter::once("time".to_string())
+        .chain((2..).map(|i| format!("time_{}", i)))
+        .find(|name| !names.contains(name))
+        .unwrap();
+
+    let name = CString::new(time.as_str()).unwrap();
+    rename_time(copy.pin_mut(), &name);
+    (formula_to_string(copy.as_ptr()), time)
+}
+
+/// Returns whether a node is the time csymbol.
+fn is_time(node: &sbmlcxx::ASTNode) -> bool {
+    matches!(node.getType(), sbmlcxx::ASTNodeType_t::AST_NAME_TIME)
+}
+
+/// Collects the names of all nodes of a tree except for time csymbols.
+fn collect_names(node: &sbmlcxx::ASTNode, names: &mut HashSet<String>) {
+    let name = node.getName();
+    if !is_time(node) && !name.is_null() {
+        let name = unsafe { CStr::from_ptr(name) };
+        names.insert(name.to_string_lossy().into_owned());
+    }
+
+    for i in 0..node.getNumChildren().0 {
+        let child = node.getChild(i.into());
+        if !child.is_null() {
+            collect_names(unsafe { &*child }, names);
+        }
+    }
+}
+
+/// Renames all time csymbols of a tree.
+fn rename_time(mut node: Pin<&mut sbmlcxx::ASTNode>, name: &CStr) {
+    if is_time(&node) {
+        unsafe { node.as_mut().setName(name.as_ptr()) };
+    }
+
+    for i in 0..node.getNumChildren().0 {
+        let child = node.getChild(i.into());
+        if !child.is_null() {
+            rename_time(pin_ptr!(child, sbmlcxx::ASTNode), name);
+        }
+    }
+}
+
+/// Returns whether a number in a tree carries the given units (`sbml:units`).
+///
+/// # Arguments
+/// * `math` - The tree to search, may be null
+/// * `units` - The identifier of the units
+pub(crate) fn uses_units(math: *const sbmlcxx::ASTNode, units: &str) -> bool {
+    if math.is_null() {
+        return false;
+    }
+
+    let node = unsafe { &*math };
+    if node.isSetUnits() && node.getUnits().to_str() == Ok(units) {
+        return true;
+    }
+    (0..node.getNumChildren().0).any(|i| uses_units(node.getChild(i.into()), units))
+}
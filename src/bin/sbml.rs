@@ -0,0 +1,229 @@
+//! Command-line tool to validate, inspect, compare and convert SBML files
+//!
+//! ```bash
+//! sbml validate model.xml --json
+//! sbml info model.xml
+//! sbml diff model_v1.xml model_v2.xml
+//! sbml convert --level 3 --version 2 model.xml converted.xml
+//! ```
+//!
+//! `validate` exits with a non-zero status if the document contains errors and `diff`
+//! if the models differ, so they can be used in scripts and continuous integration.
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand};
+use sbml::prelude::*;
+use serde::Serialize;
+
+#[derive(Parser)]
+#[command(
+    name = "sbml",
+    version,
+    about = "Validate, inspect, compare and convert SBML files"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Checks a document for consistency with the SBML specification
+    Validate {
+        /// The SBML file to validate
+        file: PathBuf,
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Prints the level, version, packages and content counts of a document
+    Info {
+        /// The SBML file to inspect
+        file: PathBuf,
+    },
+    /// Prints the semantic differences between the models of two documents
+    Diff {
+        /// The original SBML file
+        old: PathBuf,
+        /// The changed SBML file
+        new: PathBuf,
+    },
+    /// Converts a document to another SBML level and version
+    Convert {
+        /// The target SBML level
+        #[arg(long)]
+        level: u32,
+        /// The target SBML version
+        #[arg(long)]
+        version: u32,
+        /// The SBML file to convert
+        input: PathBuf,
+        /// The file to write the converted document to
+        output: PathBuf,
+    },
+}
+
+/// Result of validating a file, as printed by `validate --json`
+#[derive(Serialize)]
+struct ValidationReport<'a> {
+    file: &'a Path,
+    valid: bool,
+    errors: Vec<SBMLError>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Validate { file, json } => validate(&file, json),
+        Command::Info { file } => info(&file),
+        Command::Diff { old, new } => diff_files(&old, &new),
+        Command::Convert {
+            level,
+            version,
+            input,
+            output,
+        } => convert(&input, &output, level, version),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Reads a document, reporting unreadable files as errors.
+fn read(path: &Path) -> Result<SBMLDocument<'static>, String> {
+    SBMLReader::from_file(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Runs `validate`, exiting with 1 if the document contains errors.
+fn validate(path: &Path, json: bool) -> Result<ExitCode, String> {
+    let doc = read(path)?;
+    let errors = doc.check_consistency();
+    let valid = !errors.iter().any(SBMLError::is_error);
+
+    if json {
+        let report = ValidationReport {
+            file: path,
+            valid,
+            errors,
+        };
+        let output = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        println!("{}", output);
+    } else {
+        for error in &errors {
+            println!("{}: {}", path.display(), error);
+        }
+
+        let n_errors = errors.iter().filter(|error| error.is_error()).count();
+        let n_warnings = errors.len() - n_errors;
+        println!(
+            "{}: {} error(s), {} warning(s)",
+            path.display(),
+            n_errors,
+            n_warnings
+        );
+    }
+
+    Ok(if valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Runs `info`.
+fn info(path: &Path) -> Result<ExitCode, String> {
+    let doc = read(path)?;
+    check_readable(path, &doc)?;
+
+    let packages: Vec<&str> = doc.packages().iter().map(Package::name).collect();
+    println!("File:          {}", path.display());
+    println!(
+        "SBML:          Level {} Version {}",
+        doc.level(),
+        doc.version()
+    );
+    println!(
+        "Packages:      {}",
+        if packages.is_empty() {
+            "none".to_string()
+        } else {
+            packages.join(", ")
+        }
+    );
+
+    let Some(model) = doc.model() else {
+        println!("Model:         none");
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    let n_rules = model.list_of_rate_rules().len() + model.list_of_assignment_rules().len();
+    println!("Model:         {}", model.id());
+    println!("Compartments:  {}", model.list_of_compartments().len());
+    println!("Species:       {}", model.list_of_species().len());
+    println!("Reactions:     {}", model.list_of_reactions().len());
+    println!("Parameters:    {}", model.list_of_parameters().len());
+    println!("Rules:         {}", n_rules);
+    println!("Events:        {}", model.list_of_events().len());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Runs `diff`, exiting with 1 if the models differ.
+fn diff_files(old_path: &Path, new_path: &Path) -> Result<ExitCode, String> {
+    let old = read(old_path)?;
+    check_readable(old_path, &old)?;
+    let new = read(new_path)?;
+    check_readable(new_path, &new)?;
+
+    let (Some(old_model), Some(new_model)) = (old.model(), new.model()) else {
+        return Err("Both documents must contain a model".to_string());
+    };
+
+    let changes = diff(&old_model, &new_model);
+    print!("{}", changes);
+
+    Ok(if changes.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Runs `convert`.
+fn convert(input: &Path, output: &Path, level: u32, version: u32) -> Result<ExitCode, String> {
+    let doc = read(input)?;
+    check_readable(input, &doc)?;
+
+    doc.set_level_and_version(level, version)
+        .map_err(|e| format!("{}: {}", input.display(), e))?;
+    std::fs::write(output, doc.to_xml_string())
+        .map_err(|e| format!("{}: {}", output.display(), e))?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Fails if libSBML could not read the document, e.g. because it is not SBML.
+fn check_readable(path: &Path, doc: &SBMLDocument) -> Result<(), String> {
+    let fatal: Vec<String> = doc
+        .errors()
+        .into_iter()
+        .filter(|error| error.severity == Severity::Fatal)
+        .map(|error| error.to_string())
+        .collect();
+
+    if fatal.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{}: {}", path.display(), fatal.join("; ")))
+    }
+}
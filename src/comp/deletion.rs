@@ -0,0 +1,139 @@
+//! This module provides a safe Rust interface to the libSBML Deletion class.
+//!
+//! The Deletion class of the comp package removes an element of a submodel when the
+//! submodel is instantiated, e.g. a reaction that is modeled differently by the
+//! containing model. Everything that depends on the removed element is removed as
+//! well during flattening.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Deletion class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    comp::{
+        sbaseref::{read_target, write_target, ReferenceTarget},
+        submodel::Submodel,
+    },
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Deletion class.
+///
+/// This struct maintains a reference to the underlying C++ Deletion object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Deletion<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Deletion>>,
+}
+
+// Set the inner trait for the Deletion struct
+inner!(sbmlcxx::Deletion, Deletion<'a>);
+
+// Set the annotation trait for the Deletion struct
+upcast_annotation!(Deletion<'a>, sbmlcxx::Deletion, sbmlcxx::SBase);
+
+// Set the SBase trait for the Deletion struct
+sbase!(Deletion<'a>, sbmlcxx::Deletion);
+
+impl<'a> Deletion<'a> {
+    /// Creates a new Deletion instance within the given Submodel.
+    ///
+    /// # Arguments
+    /// * `submodel` - The parent Submodel that will contain this deletion
+    /// * `id` - The identifier for this deletion
+    /// * `target` - The element of the submodel to remove
+    ///
+    /// # Returns
+    /// A new Deletion instance
+    pub fn new(submodel: &Submodel<'a>, id: &str, target: &ReferenceTarget) -> Self {
+        let deletion_ptr = submodel.inner().borrow_mut().as_mut().createDeletion();
+        let deletion = pin_ptr!(deletion_ptr, sbmlcxx::Deletion);
+
+        let deletion = Self {
+            inner: RefCell::new(deletion),
+        };
+        deletion.set_id(id);
+        deletion.set_target(target);
+        deletion
+    }
+
+    /// Returns the id of the deletion.
+    ///
+    /// # Returns
+    /// The id of the deletion as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the deletion.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the deletion.
+    ///
+    /// # Returns
+    /// The name of the deletion as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the deletion.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the element of the submodel removed by the deletion.
+    ///
+    /// # Returns
+    /// The target of the deletion, None if not set
+    pub fn target(&self) -> Option<ReferenceTarget> {
+        let reference = upcast!(self, sbmlcxx::Deletion, sbmlcxx::SBaseRef);
+        read_target(reference)
+    }
+
+    /// Sets the element of the submodel removed by the deletion.
+    ///
+    /// # Arguments
+    /// * `target` - The target to set
+    pub fn set_target(&self, target: &ReferenceTarget) {
+        let reference = upcast!(self, sbmlcxx::Deletion, sbmlcxx::SBaseRef);
+        write_target(reference, target);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Deletion, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Deletion> for Deletion<'_> {
+    /// Creates a new Deletion instance from a unique pointer to a libSBML Deletion.
+    ///
+    /// This method is primarily used internally by the Submodel class to create
+    /// Deletion instances from libSBML Deletion pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Deletion
+    ///
+    /// # Returns
+    /// A new Deletion instance
+    fn from_ptr(ptr: *mut sbmlcxx::Deletion) -> Self {
+        let deletion = pin_ptr!(ptr, sbmlcxx::Deletion);
+        Self {
+            inner: RefCell::new(deletion),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! This module provides a safe Rust interface to the libSBML CompSBMLDocumentPlugin class.
+//!
+//! The CompSBMLDocumentPlugin class holds the content the comp package adds to a
+//! document: model definitions, which are complete models that are only used as
+//! submodels, and external model definitions, which make models of other documents
+//! available.
+//!
+//! Model definitions are derived from the Model class in libSBML and are therefore
+//! represented by [`Model`] (see [`ModelDefinition`](crate::comp::ModelDefinition)).
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML CompSBMLDocumentPlugin
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use crate::{
+    comp::extmodeldef::ExternalModelDefinition,
+    inner,
+    model::Model,
+    pin_ptr,
+    sbmlcxx::{self},
+    traits::fromptr::FromPtr,
+};
+
+/// A safe wrapper around the libSBML CompSBMLDocumentPlugin class.
+///
+/// This struct maintains a reference to the underlying C++ CompSBMLDocumentPlugin object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the model definitions and external model definitions.
+pub struct CompDocumentPlugin<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::CompSBMLDocumentPlugin>>,
+    model_definitions: RefCell<Vec<Rc<Model<'a>>>>,
+    external_model_definitions: RefCell<Vec<Rc<ExternalModelDefinition<'a>>>>,
+}
+
+// Set the inner trait for the CompDocumentPlugin struct
+inner!(sbmlcxx::CompSBMLDocumentPlugin, CompDocumentPlugin<'a>);
+
+impl<'a> CompDocumentPlugin<'a> {
+    /// Returns a reference to the inner RefCell containing the CompSBMLDocumentPlugin
+    /// pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::CompSBMLDocumentPlugin>> {
+        &self.inner
+    }
+
+    /// Creates a new model definition.
+    ///
+    /// The definition is a full model that can be populated like the main model of
+    /// the document.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new model definition
+    ///
+    /// # Returns
+    /// The newly created model definition wrapped in an Rc
+    pub fn create_model_definition(&self, id: &str) -> Rc<Model<'a>> {
+        let definition_ptr = self
+            .inner
+            .borrow_mut()
+            .as_mut()
+            .createModelDefinition()
+            .cast::<sbmlcxx::Model>();
+
+        let definition = Rc::new(Model::from_ptr(definition_ptr));
+        definition.set_id(id);
+        self.model_definitions
+            .borrow_mut()
+            .push(Rc::clone(&definition));
+        definition
+    }
+
+    /// Returns a vector of all model definitions of the document.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all model definitions
+    pub fn list_of_model_definitions(&self) -> Vec<Rc<Model<'a>>> {
+        self.model_definitions.borrow().to_vec()
+    }
+
+    /// Retrieves a model definition by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the model definition
+    ///
+    /// # Returns
+    /// Some(Rc<Model>) if found, None if not found
+    pub fn get_model_definition(&self, id: &str) -> Option<Rc<Model<'a>>> {
+        self.model_definitions
+            .borrow()
+            .iter()
+            .find(|definition| (*definition).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Creates a new external model definition.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new definition, used by submodels
+    /// * `source` - The URI of the document containing the model
+    ///
+    /// # Returns
+    /// The newly created ExternalModelDefinition wrapped in an Rc
+    pub fn create_external_model_definition(
+        &self,
+        id: &str,
+        source: &str,
+    ) -> Rc<ExternalModelDefinition<'a>> {
+        let definition = Rc::new(ExternalModelDefinition::new(self, id, source));
+        self.external_model_definitions
+            .borrow_mut()
+            .push(Rc::clone(&definition));
+        definition
+    }
+
+    /// Returns a vector of all external model definitions of the document.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all ExternalModelDefinitions
+    pub fn list_of_external_model_definitions(&self) -> Vec<Rc<ExternalModelDefinition<'a>>> {
+        self.external_model_definitions.borrow().to_vec()
+    }
+
+    /// Retrieves an external model definition by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the definition
+    ///
+    /// # Returns
+    /// Some(Rc<ExternalModelDefinition>) if found, None if not found
+    pub fn get_external_model_definition(
+        &self,
+        id: &str,
+    ) -> Option<Rc<ExternalModelDefinition<'a>>> {
+        self.external_model_definitions
+            .borrow()
+            .iter()
+            .find(|definition| (*definition).id() == id)
+            .map(Rc::clone)
+    }
+}
+
+impl FromPtr<sbmlcxx::CompSBMLDocumentPlugin> for CompDocumentPlugin<'_> {
+    /// Creates a new CompDocumentPlugin instance from a unique pointer to a libSBML
+    /// CompSBMLDocumentPlugin.
+    ///
+    /// This method is primarily used internally by the SBMLDocument class to create
+    /// CompDocumentPlugin instances from libSBML CompSBMLDocumentPlugin pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML CompSBMLDocumentPlugin
+    ///
+    /// # Returns
+    /// A new CompDocumentPlugin instance
+    fn from_ptr(ptr: *mut sbmlcxx::CompSBMLDocumentPlugin) -> Self {
+        let plugin = RefCell::new(pin_ptr!(ptr, sbmlcxx::CompSBMLDocumentPlugin));
+
+        // Fetch all model definitions
+        let n_definitions = plugin.borrow().getNumModelDefinitions().0;
+        let model_definitions: Vec<_> = (0..n_definitions)
+            .map(|i| {
+                let definition = plugin
+                    .borrow_mut()
+                    .as_mut()
+                    .getModelDefinition1(i.into())
+                    .cast::<sbmlcxx::Model>();
+                Rc::new(Model::from_ptr(definition))
+            })
+            .collect();
+
+        // Fetch all external model definitions
+        let n_external = plugin.borrow().getNumExternalModelDefinitions().0;
+        let external_model_definitions: Vec<_> = (0..n_external)
+            .map(|i| {
+                let definition = plugin
+                    .borrow_mut()
+                    .as_mut()
+                    .getExternalModelDefinition1(i.into());
+                Rc::new(ExternalModelDefinition::from_ptr(definition))
+            })
+            .collect();
+
+        Self {
+            inner: plugin,
+            model_definitions: RefCell::new(model_definitions),
+            external_model_definitions: RefCell::new(external_model_definitions),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! This module provides a safe Rust interface to the libSBML ExternalModelDefinition class.
+//!
+//! The ExternalModelDefinition class of the comp package makes a model stored in
+//! another SBML document available for submodels. The source is a URI of the other
+//! document, usually a path relative to the containing document, and the model
+//! reference selects a model definition of that document instead of its main model.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML ExternalModelDefinition
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    comp::docplugin::CompDocumentPlugin,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML ExternalModelDefinition class.
+///
+/// This struct maintains a reference to the underlying C++ ExternalModelDefinition object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ExternalModelDefinition<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::ExternalModelDefinition>>,
+}
+
+// Set the inner trait for the ExternalModelDefinition struct
+inner!(
+    sbmlcxx::ExternalModelDefinition,
+    ExternalModelDefinition<'a>
+);
+
+// Set the annotation trait for the ExternalModelDefinition struct
+upcast_annotation!(
+    ExternalModelDefinition<'a>,
+    sbmlcxx::ExternalModelDefinition,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the ExternalModelDefinition struct
+sbase!(
+    ExternalModelDefinition<'a>,
+    sbmlcxx::ExternalModelDefinition
+);
+
+impl<'a> ExternalModelDefinition<'a> {
+    /// Creates a new ExternalModelDefinition instance within the given comp document plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The comp plugin of the document that will contain this definition
+    /// * `id` - The identifier for this definition, used by submodels
+    /// * `source` - The URI of the document containing the model
+    ///
+    /// # Returns
+    /// A new ExternalModelDefinition instance
+    pub fn new(plugin: &CompDocumentPlugin<'a>, id: &str, source: &str) -> Self {
+        let definition_ptr = plugin
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createExternalModelDefinition();
+        let definition = pin_ptr!(definition_ptr, sbmlcxx::ExternalModelDefinition);
+
+        let definition = Self {
+            inner: RefCell::new(definition),
+        };
+        definition.set_id(id);
+        definition.set_source(source);
+        definition
+    }
+
+    /// Returns the id of the definition.
+    ///
+    /// # Returns
+    /// The id of the definition as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the definition.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the definition.
+    ///
+    /// # Returns
+    /// The name of the definition as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the definition.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the URI of the document containing the model.
+    ///
+    /// # Returns
+    /// The source URI as a String
+    pub fn source(&self) -> String {
+        self.inner
+            .borrow()
+            .getSource()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the URI of the document containing the model.
+    ///
+    /// # Arguments
+    /// * `source` - A path or URI of an SBML document
+    pub fn set_source(&self, source: &str) {
+        let_cxx_string!(source = source);
+        self.inner.borrow_mut().as_mut().setSource(&source);
+    }
+
+    /// Returns the identifier of the referenced model within the source document.
+    ///
+    /// # Returns
+    /// The model identifier, None if the main model of the document is referenced
+    pub fn model_ref(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetModelRef() {
+            return None;
+        }
+        Some(inner.getModelRef().to_str().unwrap().to_string())
+    }
+
+    /// Sets the identifier of the referenced model within the source document.
+    ///
+    /// # Arguments
+    /// * `model_ref` - The identifier of a model or model definition of the source
+    pub fn set_model_ref(&self, model_ref: &str) {
+        let_cxx_string!(model_ref = model_ref);
+        self.inner.borrow_mut().as_mut().setModelRef(&model_ref);
+    }
+
+    /// Returns the MD5 checksum of the source document.
+    ///
+    /// # Returns
+    /// The checksum in hexadecimal notation, None if not set
+    pub fn md5(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetMd5() {
+            return None;
+        }
+        Some(inner.getMd5().to_str().unwrap().to_string())
+    }
+
+    /// Sets the MD5 checksum of the source document.
+    ///
+    /// # Arguments
+    /// * `md5` - The checksum in hexadecimal notation
+    pub fn set_md5(&self, md5: &str) {
+        let_cxx_string!(md5 = md5);
+        self.inner.borrow_mut().as_mut().setMd5(&md5);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::ExternalModelDefinition, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::ExternalModelDefinition> for ExternalModelDefinition<'_> {
+    /// Creates a new ExternalModelDefinition instance from a unique pointer to a libSBML
+    /// ExternalModelDefinition.
+    ///
+    /// This method is primarily used internally by the CompDocumentPlugin class to create
+    /// ExternalModelDefinition instances from libSBML ExternalModelDefinition pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML ExternalModelDefinition
+    ///
+    /// # Returns
+    /// A new ExternalModelDefinition instance
+    fn from_ptr(ptr: *mut sbmlcxx::ExternalModelDefinition) -> Self {
+        let definition = pin_ptr!(ptr, sbmlcxx::ExternalModelDefinition);
+        Self {
+            inner: RefCell::new(definition),
+        }
+    }
+}
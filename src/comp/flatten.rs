@@ -0,0 +1,255 @@
+//! Flattening of hierarchical models.
+//!
+//! Flattening instantiates all submodels of a composed model and merges them into a
+//! single model without comp constructs. Elements of submodels are renamed by
+//! prefixing them with the submodel identifier (e.g. `A__S` for species `S` of
+//! submodel `A`), deletions are removed and replacements are resolved. The actual
+//! conversion is performed by libSBML's CompFlatteningConverter.
+//!
+//! External model definitions are resolved before the conversion starts, so that
+//! libSBML only ever reads local files: sources are passed through an optional
+//! resolver hook and interpreted relative to a base path. Sources that point to the
+//! network and are not mapped by the resolver are reported as errors instead of
+//! being downloaded.
+
+use std::{error::Error, fmt, path::PathBuf};
+
+use autocxx::WithinUniquePtr;
+use cxx::let_cxx_string;
+
+use crate::{cast::upcast, packages::Package, reader::SBMLReader, sbmlcxx, sbmldoc::SBMLDocument};
+
+/// Return code of libSBML for successful operations
+const LIBSBML_OPERATION_SUCCESS: i32 = 0;
+
+/// A resolver mapping the source URI of an external model definition to a local file.
+type Resolver<'r> = Box<dyn Fn(&str) -> Option<PathBuf> + 'r>;
+
+/// Flattens composed models into a single model.
+///
+/// # Example
+/// ```no_run
+/// use std::path::PathBuf;
+/// use sbml::{comp::Flattener, prelude::*};
+///
+/// let xml = std::fs::read_to_string("models/composed.xml").unwrap();
+/// let doc = SBMLReader::from_xml_string(&xml);
+///
+/// let flat = Flattener::new()
+///     .base_path("models")
+///     .resolver(|source| {
+///         source
+///             .strip_prefix("https://example.org/models/")
+///             .map(|name| PathBuf::from("cache").join(name))
+///     })
+///     .flatten(&doc)
+///     .unwrap();
+/// ```
+pub struct Flattener<'r> {
+    base_path: PathBuf,
+    leave_ports: bool,
+    resolver: Option<Resolver<'r>>,
+}
+
+impl<'r> Flattener<'r> {
+    /// Creates a new Flattener.
+    ///
+    /// Relative sources are resolved against the current directory and ports are
+    /// removed from the flattened model.
+    pub fn new() -> Self {
+        Self {
+            base_path: PathBuf::from("."),
+            leave_ports: false,
+            resolver: None,
+        }
+    }
+
+    /// Sets the directory relative sources of external model definitions are
+    /// resolved against, usually the directory of the composed document.
+    ///
+    /// # Arguments
+    /// * `path` - The base directory
+    ///
+    /// # Returns
+    /// The flattener instance for method chaining
+    pub fn base_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base_path = path.into();
+        self
+    }
+
+    /// Sets whether the ports of the top-level model are kept in the flattened model.
+    ///
+    /// # Arguments
+    /// * `leave_ports` - Whether to keep the ports
+    ///
+    /// # Returns
+    /// The flattener instance for method chaining
+    pub fn leave_ports(mut self, leave_ports: bool) -> Self {
+        self.leave_ports = leave_ports;
+        self
+    }
+
+    /// Sets a hook that maps source URIs of external model definitions to local files.
+    ///
+    /// The resolver is consulted first for every source. Returning None falls back to
+    /// interpreting the source as a path relative to the base path. Relative paths
+    /// returned by the resolver are interpreted relative to the base path as well.
+    ///
+    /// # Arguments
+    /// * `resolver` - A function mapping a source URI to a local path
+    ///
+    /// # Returns
+    /// The flattener instance for method chaining
+    pub fn resolver(mut self, resolver: impl Fn(&str) -> Option<PathBuf> + 'r) -> Self {
+        self.resolver = Some(Box::new(resolver));
+        self
+    }
+
+    /// Flattens a document into a new document with a single flat model.
+    ///
+    /// The given document is left unchanged. Documents without the comp package are
+    /// returned as a copy.
+    ///
+    /// # Arguments
+    /// * `document` - The composed document
+    ///
+    /// # Returns
+    /// The flattened document, or an error if a source could not be resolved or
+    /// libSBML failed to flatten the model
+    pub fn flatten<'a>(&self, document: &SBMLDocument) -> Result<SBMLDocument<'a>, FlattenError> {
+        let xml = document.to_xml_string();
+        let copy = SBMLReader::from_xml_string(&xml);
+        if !copy.is_package_enabled(Package::Comp) {
+            return Ok(SBMLDocument::from_unique_ptr(copy.into_inner()));
+        }
+
+        // Point all external model definitions to local files
+        for definition in copy.comp_plugin().list_of_external_model_definitions() {
+            let path = self.resolve(&definition.source())?;
+            definition.set_source(&path.to_string_lossy());
+        }
+
+        let mut converter = sbmlcxx::CompFlatteningConverter::new().within_unique_ptr();
+        let mut properties = converter.getDefaultProperties().within_unique_ptr();
+
+        let_cxx_string!(leave_ports = "leavePorts");
+        properties
+            .pin_mut()
+            .setBoolValue(&leave_ports, self.leave_ports);
+        let_cxx_string!(base_path_key = "basePath");
+        let_cxx_string!(base_path = self.base_path.to_string_lossy().as_ref());
+        properties.pin_mut().setValue(&base_path_key, &base_path);
+
+        let mut document_ptr = copy.inner().borrow_mut();
+        let result = unsafe {
+            let mut base = upcast::<sbmlcxx::CompFlatteningConverter, sbmlcxx::SBMLConverter>(
+                converter.as_mut_ptr(),
+            );
+            base.as_mut().setDocument1(document_ptr.as_mut_ptr());
+            base.as_mut().setProperties(properties.as_ptr());
+            converter.pin_mut().convert().0
+        };
+        drop(document_ptr);
+
+        if result != LIBSBML_OPERATION_SUCCESS {
+            let mut messages = conversion_errors(&copy);
+            if messages.is_empty() {
+                messages.push(format!("Flattening failed with code {}", result));
+            }
+            return Err(FlattenError::Conversion(messages));
+        }
+
+        // The model was replaced during the conversion, so the wrappers are rebuilt
+        Ok(SBMLDocument::from_unique_ptr(copy.into_inner()))
+    }
+
+    /// Resolves the source of an external model definition to an existing local file.
+    ///
+    /// # Arguments
+    /// * `source` - The source URI of the external model definition
+    ///
+    /// # Returns
+    /// The path of the local file
+    pub(crate) fn resolve(&self, source: &str) -> Result<PathBuf, FlattenError> {
+        let path = match self.resolver.as_ref().and_then(|resolver| resolver(source)) {
+            Some(path) => path,
+            None => {
+                if let Some(path) = source.strip_prefix("file://") {
+                    PathBuf::from(path)
+                } else if source.contains("://") || source.starts_with("urn:") {
+                    return Err(FlattenError::UnresolvedSource(source.to_string()));
+                } else {
+                    PathBuf::from(source)
+                }
+            }
+        };
+
+        let path = if path.is_relative() {
+            self.base_path.join(path)
+        } else {
+            path
+        };
+
+        if !path.is_file() {
+            return Err(FlattenError::MissingSource(path));
+        }
+        Ok(path)
+    }
+}
+
+impl Default for Flattener<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects the messages of all errors in the error log of a document.
+fn conversion_errors(document: &SBMLDocument) -> Vec<String> {
+    let mut document_ptr = document.inner().borrow_mut();
+    let Some(document) = document_ptr.as_mut() else {
+        return Vec::new();
+    };
+
+    let n_errors = document.getNumErrors().0;
+    (0..n_errors)
+        .filter_map(|i| {
+            let error_ptr = document.getError(i.into()).cast::<sbmlcxx::XMLError>();
+            let error = unsafe { &*error_ptr };
+            if error.isError() || error.isFatal() {
+                Some(error.getMessage().to_str().unwrap().trim().to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Errors that can occur while flattening a composed model.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlattenError {
+    /// The source of an external model definition is not local and was not mapped by
+    /// the resolver
+    UnresolvedSource(String),
+    /// The file of an external model definition does not exist
+    MissingSource(PathBuf),
+    /// libSBML failed to flatten the model, with the messages of its error log
+    Conversion(Vec<String>),
+}
+
+impl fmt::Display for FlattenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlattenError::UnresolvedSource(source) => {
+                write!(f, "External model source '{}' is not a local file", source)
+            }
+            FlattenError::MissingSource(path) => {
+                write!(f, "External model file '{}' not found", path.display())
+            }
+            FlattenError::Conversion(messages) => {
+                write!(f, "Failed to flatten model: {}", messages.join("; "))
+            }
+        }
+    }
+}
+
+impl Error for FlattenError {}
@@ -0,0 +1,259 @@
+//! Support for the SBML Level 3 hierarchical model composition (comp) package.
+//!
+//! The comp package builds models from other models:
+//!
+//! - Model definitions ([`CompDocumentPlugin::create_model_definition`]) and
+//!   [`ExternalModelDefinition`]s provide the models to reuse
+//! - [`Submodel`]s instantiate them within a model, optionally removing elements with
+//!   [`Deletion`]s
+//! - [`Port`]s define the interface of a model
+//! - [`ReplacedElement`] and [`ReplacedBy`] merge elements of the containing model
+//!   with elements of submodels
+//!
+//! Elements of submodels are referenced with a [`ReferenceTarget`]. The replacements
+//! are available on species, compartments, parameters and reactions. Composed
+//! models can be turned into a single model with [`SBMLDocument::flatten`] or a
+//! configured [`Flattener`].
+//!
+//! # Example
+//! ```no_run
+//! use sbml::{comp::ReferenceTarget, prelude::*};
+//!
+//! let doc = SBMLDocument::default();
+//!
+//! // A reusable model of a single enzymatic step
+//! let step = doc.comp_plugin().create_model_definition("step");
+//! step.build_compartment("cell").build();
+//! step.build_species("S").compartment("cell").build();
+//!
+//! // Two instances sharing the compartment of the main model
+//! let model = doc.create_model("pathway");
+//! let cell = model.build_compartment("cell").build();
+//! for id in ["first", "second"] {
+//!     model.comp_plugin().create_submodel(id, "step");
+//!     cell.create_replaced_element(id, &ReferenceTarget::Id("cell".to_string()));
+//! }
+//!
+//! let flat = doc.flatten().unwrap();
+//! ```
+//!
+//! [`SBMLDocument::flatten`]: crate::sbmldoc::SBMLDocument::flatten
+
+/// Module providing deletion functionality
+pub mod deletion;
+/// Module providing comp document plugin functionality
+pub mod docplugin;
+/// Module providing external model definition functionality
+pub mod extmodeldef;
+/// Module providing model flattening functionality
+pub mod flatten;
+/// Module providing comp model plugin functionality
+pub mod modelplugin;
+/// Module providing port functionality
+pub mod port;
+/// Module providing replaced by functionality
+pub mod replacedby;
+/// Module providing replaced element functionality
+pub mod replacedelement;
+/// Module providing submodel reference functionality
+pub mod sbaseref;
+/// Module providing submodel functionality
+pub mod submodel;
+
+pub use deletion::Deletion;
+pub use docplugin::CompDocumentPlugin;
+pub use extmodeldef::ExternalModelDefinition;
+pub use flatten::{FlattenError, Flattener};
+pub use modelplugin::CompModelPlugin;
+pub use port::Port;
+pub use replacedby::ReplacedBy;
+pub use replacedelement::ReplacedElement;
+pub use sbaseref::ReferenceTarget;
+pub use submodel::Submodel;
+
+/// A model definition of the comp package.
+///
+/// libSBML derives model definitions from the Model class, so they are wrapped by
+/// [`Model`](crate::model::Model) and support everything a main model does.
+pub type ModelDefinition<'a> = crate::model::Model<'a>;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::prelude::*;
+
+    /// Adds a model definition `inner` holding a compartment, a species and a
+    /// degradation reaction.
+    fn add_inner_definition(doc: &SBMLDocument) {
+        let inner = doc.comp_plugin().create_model_definition("inner");
+        inner
+            .build_compartment("c")
+            .size(1.0)
+            .constant(true)
+            .build();
+        inner
+            .build_species("S")
+            .compartment("c")
+            .initial_amount(1.0)
+            .build();
+        inner.build_reaction("deg").reactant("S", 1.0).build();
+    }
+
+    #[test]
+    fn test_comp_elements() {
+        let doc = SBMLDocument::default();
+        add_inner_definition(&doc);
+        assert!(doc.is_package_enabled(Package::Comp));
+
+        let model = doc.create_model("main");
+        let comp = model.comp_plugin();
+        let submodel = comp.create_submodel("A", "inner");
+        submodel.set_time_conversion_factor("tf");
+        submodel.create_deletion("del_deg", &ReferenceTarget::Id("deg".to_string()));
+        comp.create_port("c_port", &ReferenceTarget::Id("c".to_string()));
+
+        let c = model.build_compartment("c").build();
+        let replaced = c.create_replaced_element("A", &ReferenceTarget::Id("c".to_string()));
+        let species = model.build_species("S").compartment("c").build();
+        species.set_replaced_by("A", &ReferenceTarget::Id("S".to_string()));
+
+        assert_eq!(replaced.submodel(), "A");
+        assert_eq!(c.list_of_replaced_elements().len(), 1);
+        assert_eq!(
+            species.replaced_by().unwrap().target(),
+            Some(ReferenceTarget::Id("S".to_string()))
+        );
+        assert!(model.build_parameter("k").build().replaced_by().is_none());
+
+        // Read back from XML
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let definitions = read.comp_plugin().list_of_model_definitions();
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].id(), "inner");
+        assert_eq!(definitions[0].list_of_species().len(), 1);
+
+        let model = read.model().expect("Model not found");
+        let submodel = model.comp_plugin().get_submodel("A").unwrap();
+        assert_eq!(submodel.model_ref(), "inner");
+        assert_eq!(submodel.time_conversion_factor().as_deref(), Some("tf"));
+        assert_eq!(
+            submodel.get_deletion("del_deg").unwrap().target(),
+            Some(ReferenceTarget::Id("deg".to_string()))
+        );
+        assert_eq!(
+            model.comp_plugin().get_port("c_port").unwrap().target(),
+            Some(ReferenceTarget::Id("c".to_string()))
+        );
+        let c = model.get_compartment("c").unwrap();
+        let replaced = &c.list_of_replaced_elements()[0];
+        assert_eq!(
+            replaced.target(),
+            Some(ReferenceTarget::Id("c".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_flatten() {
+        let doc = SBMLDocument::default();
+        add_inner_definition(&doc);
+        let model = doc.create_model("main");
+        let c = model
+            .build_compartment("c")
+            .size(1.0)
+            .constant(true)
+            .build();
+        for id in ["A", "B"] {
+            model.comp_plugin().create_submodel(id, "inner");
+            c.create_replaced_element(id, &ReferenceTarget::Id("c".to_string()));
+        }
+        model
+            .comp_plugin()
+            .get_submodel("B")
+            .unwrap()
+            .create_deletion("del", &ReferenceTarget::Id("deg".to_string()));
+
+        let flat = doc.flatten().expect("Flattening failed");
+        let model = flat.model().expect("Model not found");
+        let mut species: Vec<_> = model.list_of_species().iter().map(|s| s.id()).collect();
+        species.sort();
+        assert_eq!(species, vec!["A__S", "B__S"]);
+        assert_eq!(model.list_of_compartments().len(), 1);
+        assert_eq!(model.list_of_reactions().len(), 1);
+        assert_eq!(model.get_species("A__S").unwrap().compartment(), "c");
+    }
+
+    #[test]
+    fn test_flatten_external_model() {
+        let dir = std::env::temp_dir().join(format!("sbml-comp-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let external = SBMLDocument::default();
+        let external_model = external.create_model("enzyme");
+        external_model.build_compartment("c").build();
+        external_model.build_species("E").compartment("c").build();
+        std::fs::write(dir.join("enzyme.xml"), external.to_xml_string()).unwrap();
+
+        let doc = SBMLDocument::default();
+        doc.comp_plugin()
+            .create_external_model_definition("enzyme_def", "https://example.org/enzyme.xml");
+        let model = doc.create_model("main");
+        model.comp_plugin().create_submodel("E1", "enzyme_def");
+
+        // Network sources are not fetched
+        assert_eq!(
+            doc.flatten().err(),
+            Some(FlattenError::UnresolvedSource(
+                "https://example.org/enzyme.xml".to_string()
+            ))
+        );
+
+        let flat = Flattener::new()
+            .base_path(&dir)
+            .resolver(|source| {
+                source
+                    .strip_prefix("https://example.org/")
+                    .map(PathBuf::from)
+            })
+            .flatten(&doc)
+            .expect("Flattening failed");
+        let model = flat.model().expect("Model not found");
+        assert!(model.get_species("E1__E").is_some());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_sources() {
+        let dir = std::env::temp_dir();
+        let file = dir.join(format!("sbml-resolve-{}.xml", std::process::id()));
+        std::fs::write(&file, "").unwrap();
+        let name = file.file_name().unwrap().to_str().unwrap().to_string();
+
+        let flattener = Flattener::new().base_path(&dir);
+        assert_eq!(flattener.resolve(&name), Ok(file.clone()));
+        assert_eq!(
+            flattener.resolve(&format!("file://{}", file.display())),
+            Ok(file.clone())
+        );
+        assert_eq!(
+            flattener.resolve("missing.xml"),
+            Err(FlattenError::MissingSource(dir.join("missing.xml")))
+        );
+        assert!(matches!(
+            flattener.resolve("urn:miriam:biomodels.db:BIOMD0000000001"),
+            Err(FlattenError::UnresolvedSource(_))
+        ));
+
+        let flattener = Flattener::new().base_path(&dir).resolver(|source| {
+            (source == "http://example.org/m.xml").then(|| PathBuf::from(&name))
+        });
+        assert_eq!(
+            flattener.resolve("http://example.org/m.xml"),
+            Ok(file.clone())
+        );
+
+        std::fs::remove_file(&file).unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+//! This module provides a safe Rust interface to the libSBML CompModelPlugin class.
+//!
+//! The CompModelPlugin class holds the content the comp package adds to a model: the
+//! submodels it is composed of and the ports that form its interface towards models
+//! that use it as a submodel themselves.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML CompModelPlugin class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use crate::{
+    comp::{port::Port, sbaseref::ReferenceTarget, submodel::Submodel},
+    inner, pin_ptr,
+    sbmlcxx::{self},
+    traits::fromptr::FromPtr,
+};
+
+/// A safe wrapper around the libSBML CompModelPlugin class.
+///
+/// This struct maintains a reference to the underlying C++ CompModelPlugin object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the submodels and ports of the model.
+pub struct CompModelPlugin<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::CompModelPlugin>>,
+    submodels: RefCell<Vec<Rc<Submodel<'a>>>>,
+    ports: RefCell<Vec<Rc<Port<'a>>>>,
+}
+
+// Set the inner trait for the CompModelPlugin struct
+inner!(sbmlcxx::CompModelPlugin, CompModelPlugin<'a>);
+
+impl<'a> CompModelPlugin<'a> {
+    /// Returns a reference to the inner RefCell containing the CompModelPlugin pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::CompModelPlugin>> {
+        &self.inner
+    }
+
+    /// Creates a new submodel instantiating another model.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new submodel
+    /// * `model_ref` - The identifier of a model definition or external model definition
+    ///
+    /// # Returns
+    /// The newly created Submodel wrapped in an Rc
+    pub fn create_submodel(&self, id: &str, model_ref: &str) -> Rc<Submodel<'a>> {
+        let submodel = Rc::new(Submodel::new(self, id, model_ref));
+        self.submodels.borrow_mut().push(Rc::clone(&submodel));
+        submodel
+    }
+
+    /// Returns a vector of all submodels of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Submodels of the model
+    pub fn list_of_submodels(&self) -> Vec<Rc<Submodel<'a>>> {
+        self.submodels.borrow().to_vec()
+    }
+
+    /// Retrieves a submodel by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the submodel
+    ///
+    /// # Returns
+    /// Some(Rc<Submodel>) if found, None if not found
+    pub fn get_submodel(&self, id: &str) -> Option<Rc<Submodel<'a>>> {
+        self.submodels
+            .borrow()
+            .iter()
+            .find(|submodel| (*submodel).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Creates a new port exposing an element of the model.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new port
+    /// * `target` - The element of the model exposed by the port
+    ///
+    /// # Returns
+    /// The newly created Port wrapped in an Rc
+    pub fn create_port(&self, id: &str, target: &ReferenceTarget) -> Rc<Port<'a>> {
+        let port = Rc::new(Port::new(self, id, target));
+        self.ports.borrow_mut().push(Rc::clone(&port));
+        port
+    }
+
+    /// Returns a vector of all ports of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Ports of the model
+    pub fn list_of_ports(&self) -> Vec<Rc<Port<'a>>> {
+        self.ports.borrow().to_vec()
+    }
+
+    /// Retrieves a port by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the port
+    ///
+    /// # Returns
+    /// Some(Rc<Port>) if found, None if not found
+    pub fn get_port(&self, id: &str) -> Option<Rc<Port<'a>>> {
+        self.ports
+            .borrow()
+            .iter()
+            .find(|port| (*port).id() == id)
+            .map(Rc::clone)
+    }
+}
+
+impl FromPtr<sbmlcxx::CompModelPlugin> for CompModelPlugin<'_> {
+    /// Creates a new CompModelPlugin instance from a unique pointer to a libSBML
+    /// CompModelPlugin.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// CompModelPlugin instances from libSBML CompModelPlugin pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML CompModelPlugin
+    ///
+    /// # Returns
+    /// A new CompModelPlugin instance
+    fn from_ptr(ptr: *mut sbmlcxx::CompModelPlugin) -> Self {
+        let plugin = RefCell::new(pin_ptr!(ptr, sbmlcxx::CompModelPlugin));
+
+        // Fetch all submodels
+        let n_submodels = plugin.borrow().getNumSubmodels().0;
+        let submodels: Vec<_> = (0..n_submodels)
+            .map(|i| {
+                let submodel = plugin.borrow_mut().as_mut().getSubmodel1(i.into());
+                Rc::new(Submodel::from_ptr(submodel))
+            })
+            .collect();
+
+        // Fetch all ports
+        let n_ports = plugin.borrow().getNumPorts().0;
+        let ports: Vec<_> = (0..n_ports)
+            .map(|i| {
+                let port = plugin.borrow_mut().as_mut().getPort1(i.into());
+                Rc::new(Port::from_ptr(port))
+            })
+            .collect();
+
+        Self {
+            inner: plugin,
+            submodels: RefCell::new(submodels),
+            ports: RefCell::new(ports),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! This module provides a safe Rust interface to the libSBML Port class.
+//!
+//! The Port class of the comp package is part of the interface of a model. It points
+//! to an element of the model, so that models using it as a submodel can refer to
+//! the port instead of relying on the internal identifiers of the element.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Port class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    comp::{
+        modelplugin::CompModelPlugin,
+        sbaseref::{read_target, write_target, ReferenceTarget},
+    },
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Port class.
+///
+/// This struct maintains a reference to the underlying C++ Port object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Port<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Port>>,
+}
+
+// Set the inner trait for the Port struct
+inner!(sbmlcxx::Port, Port<'a>);
+
+// Set the annotation trait for the Port struct
+upcast_annotation!(Port<'a>, sbmlcxx::Port, sbmlcxx::SBase);
+
+// Set the SBase trait for the Port struct
+sbase!(Port<'a>, sbmlcxx::Port);
+
+impl<'a> Port<'a> {
+    /// Creates a new Port instance within the given comp model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The comp plugin of the model that will contain this port
+    /// * `id` - The identifier for this port
+    /// * `target` - The element of the model exposed by the port
+    ///
+    /// # Returns
+    /// A new Port instance
+    pub fn new(plugin: &CompModelPlugin<'a>, id: &str, target: &ReferenceTarget) -> Self {
+        let port_ptr = plugin.inner().borrow_mut().as_mut().createPort();
+        let port = pin_ptr!(port_ptr, sbmlcxx::Port);
+
+        let port = Self {
+            inner: RefCell::new(port),
+        };
+        port.set_id(id);
+        port.set_target(target);
+        port
+    }
+
+    /// Returns the id of the port.
+    ///
+    /// # Returns
+    /// The id of the port as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the port.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the port.
+    ///
+    /// # Returns
+    /// The name of the port as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the port.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the element of the model exposed by the port.
+    ///
+    /// # Returns
+    /// The target of the port, None if not set
+    pub fn target(&self) -> Option<ReferenceTarget> {
+        let reference = upcast!(self, sbmlcxx::Port, sbmlcxx::SBaseRef);
+        read_target(reference)
+    }
+
+    /// Sets the element of the model exposed by the port.
+    ///
+    /// Ports cannot point to other ports, so a [`ReferenceTarget::Port`] is not valid
+    /// SBML.
+    ///
+    /// # Arguments
+    /// * `target` - The target to set
+    pub fn set_target(&self, target: &ReferenceTarget) {
+        let reference = upcast!(self, sbmlcxx::Port, sbmlcxx::SBaseRef);
+        write_target(reference, target);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Port, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Port> for Port<'_> {
+    /// Creates a new Port instance from a unique pointer to a libSBML Port.
+    ///
+    /// This method is primarily used internally by the CompModelPlugin class to create
+    /// Port instances from libSBML Port pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Port
+    ///
+    /// # Returns
+    /// A new Port instance
+    fn from_ptr(ptr: *mut sbmlcxx::Port) -> Self {
+        let port = pin_ptr!(ptr, sbmlcxx::Port);
+        Self {
+            inner: RefCell::new(port),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! This module provides a safe Rust interface to the libSBML ReplacedBy class.
+//!
+//! The ReplacedBy class of the comp package is the reverse of a replaced element: it
+//! declares that an element of the containing model is replaced by an element of a
+//! submodel. During flattening, the element of the containing model is removed and
+//! references to it are redirected to the element of the submodel.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML ReplacedBy class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    comp::sbaseref::{read_target, write_target, ReferenceTarget},
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML ReplacedBy class.
+///
+/// This struct maintains a reference to the underlying C++ ReplacedBy object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ReplacedBy<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::ReplacedBy>>,
+}
+
+// Set the inner trait for the ReplacedBy struct
+inner!(sbmlcxx::ReplacedBy, ReplacedBy<'a>);
+
+// Set the annotation trait for the ReplacedBy struct
+upcast_annotation!(ReplacedBy<'a>, sbmlcxx::ReplacedBy, sbmlcxx::SBase);
+
+// Set the SBase trait for the ReplacedBy struct
+sbase!(ReplacedBy<'a>, sbmlcxx::ReplacedBy);
+
+impl<'a> ReplacedBy<'a> {
+    /// Creates a new ReplacedBy instance within the comp plugin of an element.
+    ///
+    /// An element can only be replaced by a single element, so an existing ReplacedBy
+    /// of the element is overwritten. It is created through the `set_replaced_by`
+    /// method of the replaced element.
+    ///
+    /// # Arguments
+    /// * `plugin` - The comp plugin of the replaced element
+    /// * `submodel` - The identifier of the submodel containing the replacement
+    /// * `target` - The replacement within the submodel
+    ///
+    /// # Returns
+    /// A new ReplacedBy instance
+    pub(crate) fn new(
+        mut plugin: Pin<&'a mut sbmlcxx::CompSBasePlugin>,
+        submodel: &str,
+        target: &ReferenceTarget,
+    ) -> Self {
+        let replaced_by_ptr = plugin.as_mut().createReplacedBy();
+        let replaced_by = pin_ptr!(replaced_by_ptr, sbmlcxx::ReplacedBy);
+
+        let replaced_by = Self {
+            inner: RefCell::new(replaced_by),
+        };
+        replaced_by.set_submodel(submodel);
+        replaced_by.set_target(target);
+        replaced_by
+    }
+
+    /// Returns the identifier of the submodel containing the replacement.
+    ///
+    /// # Returns
+    /// The submodel identifier, empty if not set
+    pub fn submodel(&self) -> String {
+        let replacing = upcast!(self, sbmlcxx::ReplacedBy, sbmlcxx::Replacing);
+        replacing.getSubmodelRef().to_str().unwrap().to_string()
+    }
+
+    /// Sets the identifier of the submodel containing the replacement.
+    ///
+    /// # Arguments
+    /// * `submodel` - The submodel identifier
+    pub fn set_submodel(&self, submodel: &str) {
+        let_cxx_string!(submodel = submodel);
+        let mut replacing = upcast!(self, sbmlcxx::ReplacedBy, sbmlcxx::Replacing);
+        replacing.as_mut().setSubmodelRef(&submodel);
+    }
+
+    /// Returns the replacement within the submodel.
+    ///
+    /// # Returns
+    /// The target, None if not set
+    pub fn target(&self) -> Option<ReferenceTarget> {
+        let reference = upcast!(self, sbmlcxx::ReplacedBy, sbmlcxx::SBaseRef);
+        read_target(reference)
+    }
+
+    /// Sets the replacement within the submodel.
+    ///
+    /// # Arguments
+    /// * `target` - The target to set
+    pub fn set_target(&self, target: &ReferenceTarget) {
+        let reference = upcast!(self, sbmlcxx::ReplacedBy, sbmlcxx::SBaseRef);
+        write_target(reference, target);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::ReplacedBy, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::ReplacedBy> for ReplacedBy<'_> {
+    /// Creates a new ReplacedBy instance from a unique pointer to a libSBML ReplacedBy.
+    ///
+    /// This method is primarily used internally to create ReplacedBy instances from the
+    /// comp plugins of elements.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML ReplacedBy
+    ///
+    /// # Returns
+    /// A new ReplacedBy instance
+    fn from_ptr(ptr: *mut sbmlcxx::ReplacedBy) -> Self {
+        let replaced_by = pin_ptr!(ptr, sbmlcxx::ReplacedBy);
+        Self {
+            inner: RefCell::new(replaced_by),
+        }
+    }
+}
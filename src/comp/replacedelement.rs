@@ -0,0 +1,179 @@
+//! This module provides a safe Rust interface to the libSBML ReplacedElement class.
+//!
+//! The ReplacedElement class of the comp package declares that an element of the
+//! containing model replaces an element of a submodel. During flattening, references
+//! to the replaced element are redirected to the replacement and the replaced element
+//! is removed. Optionally, a conversion factor relates the values of both elements,
+//! and a deletion can be replaced instead of an element.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML ReplacedElement class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    comp::sbaseref::{read_target, write_target, ReferenceTarget},
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML ReplacedElement class.
+///
+/// This struct maintains a reference to the underlying C++ ReplacedElement object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ReplacedElement<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::ReplacedElement>>,
+}
+
+// Set the inner trait for the ReplacedElement struct
+inner!(sbmlcxx::ReplacedElement, ReplacedElement<'a>);
+
+// Set the annotation trait for the ReplacedElement struct
+upcast_annotation!(
+    ReplacedElement<'a>,
+    sbmlcxx::ReplacedElement,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the ReplacedElement struct
+sbase!(ReplacedElement<'a>, sbmlcxx::ReplacedElement);
+
+impl<'a> ReplacedElement<'a> {
+    /// Creates a new ReplacedElement instance within the comp plugin of an element.
+    ///
+    /// Replaced elements are created through the `create_replaced_element` method of
+    /// the replacing element.
+    ///
+    /// # Arguments
+    /// * `plugin` - The comp plugin of the replacing element
+    /// * `submodel` - The identifier of the submodel containing the replaced element
+    /// * `target` - The replaced element within the submodel
+    ///
+    /// # Returns
+    /// A new ReplacedElement instance
+    pub(crate) fn new(
+        mut plugin: Pin<&'a mut sbmlcxx::CompSBasePlugin>,
+        submodel: &str,
+        target: &ReferenceTarget,
+    ) -> Self {
+        let replaced_ptr = plugin.as_mut().createReplacedElement();
+        let replaced = pin_ptr!(replaced_ptr, sbmlcxx::ReplacedElement);
+
+        let replaced = Self {
+            inner: RefCell::new(replaced),
+        };
+        replaced.set_submodel(submodel);
+        replaced.set_target(target);
+        replaced
+    }
+
+    /// Returns the identifier of the submodel containing the replaced element.
+    ///
+    /// # Returns
+    /// The submodel identifier, empty if not set
+    pub fn submodel(&self) -> String {
+        let replacing = upcast!(self, sbmlcxx::ReplacedElement, sbmlcxx::Replacing);
+        replacing.getSubmodelRef().to_str().unwrap().to_string()
+    }
+
+    /// Sets the identifier of the submodel containing the replaced element.
+    ///
+    /// # Arguments
+    /// * `submodel` - The submodel identifier
+    pub fn set_submodel(&self, submodel: &str) {
+        let_cxx_string!(submodel = submodel);
+        let mut replacing = upcast!(self, sbmlcxx::ReplacedElement, sbmlcxx::Replacing);
+        replacing.as_mut().setSubmodelRef(&submodel);
+    }
+
+    /// Returns the replaced element within the submodel.
+    ///
+    /// # Returns
+    /// The target, None if not set (e.g. if a deletion is replaced)
+    pub fn target(&self) -> Option<ReferenceTarget> {
+        let reference = upcast!(self, sbmlcxx::ReplacedElement, sbmlcxx::SBaseRef);
+        read_target(reference)
+    }
+
+    /// Sets the replaced element within the submodel.
+    ///
+    /// # Arguments
+    /// * `target` - The target to set
+    pub fn set_target(&self, target: &ReferenceTarget) {
+        let reference = upcast!(self, sbmlcxx::ReplacedElement, sbmlcxx::SBaseRef);
+        write_target(reference, target);
+    }
+
+    /// Returns the identifier of the replaced deletion.
+    ///
+    /// # Returns
+    /// The deletion identifier, None if no deletion is replaced
+    pub fn deletion(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetDeletion() {
+            return None;
+        }
+        Some(inner.getDeletion().to_str().unwrap().to_string())
+    }
+
+    /// Replaces a deletion of the submodel instead of an element.
+    ///
+    /// # Arguments
+    /// * `deletion` - The identifier of the deletion
+    pub fn set_deletion(&self, deletion: &str) {
+        let_cxx_string!(deletion = deletion);
+        self.inner.borrow_mut().as_mut().setDeletion(&deletion);
+    }
+
+    /// Returns the conversion factor between the replaced element and its replacement.
+    ///
+    /// # Returns
+    /// The identifier of the conversion factor parameter, None if not set
+    pub fn conversion_factor(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetConversionFactor() {
+            return None;
+        }
+        Some(inner.getConversionFactor().to_str().unwrap().to_string())
+    }
+
+    /// Sets the conversion factor between the replaced element and its replacement.
+    ///
+    /// # Arguments
+    /// * `conversion_factor` - The identifier of a constant parameter
+    pub fn set_conversion_factor(&self, conversion_factor: &str) {
+        let_cxx_string!(conversion_factor = conversion_factor);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setConversionFactor(&conversion_factor);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::ReplacedElement, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::ReplacedElement> for ReplacedElement<'_> {
+    /// Creates a new ReplacedElement instance from a unique pointer to a libSBML
+    /// ReplacedElement.
+    ///
+    /// This method is primarily used internally to create ReplacedElement instances
+    /// from the comp plugins of elements.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML ReplacedElement
+    ///
+    /// # Returns
+    /// A new ReplacedElement instance
+    fn from_ptr(ptr: *mut sbmlcxx::ReplacedElement) -> Self {
+        let replaced = pin_ptr!(ptr, sbmlcxx::ReplacedElement);
+        Self {
+            inner: RefCell::new(replaced),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! References to elements of submodels.
+//!
+//! Ports, deletions and replacements of the comp package all point to an element of a
+//! submodel in the same way, through exactly one of the `idRef`, `metaIdRef`,
+//! `portRef` or `unitRef` attributes of the libSBML SBaseRef class. On the Rust side
+//! this choice is represented by [`ReferenceTarget`].
+
+use std::{fmt, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::sbmlcxx;
+
+/// The element of a submodel an SBaseRef points to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferenceTarget {
+    /// An element identified by its id (`idRef`)
+    Id(String),
+    /// An element identified by its metaid (`metaIdRef`)
+    MetaId(String),
+    /// A port of the submodel (`portRef`)
+    Port(String),
+    /// A unit definition of the submodel (`unitRef`)
+    Unit(String),
+}
+
+impl ReferenceTarget {
+    /// Returns the referenced identifier, regardless of its kind.
+    pub fn value(&self) -> &str {
+        match self {
+            ReferenceTarget::Id(value)
+            | ReferenceTarget::MetaId(value)
+            | ReferenceTarget::Port(value)
+            | ReferenceTarget::Unit(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for ReferenceTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferenceTarget::Id(value) => write!(f, "idRef={}", value),
+            ReferenceTarget::MetaId(value) => write!(f, "metaIdRef={}", value),
+            ReferenceTarget::Port(value) => write!(f, "portRef={}", value),
+            ReferenceTarget::Unit(value) => write!(f, "unitRef={}", value),
+        }
+    }
+}
+
+/// Reads the target of an SBaseRef.
+///
+/// # Returns
+/// The target, None if no reference attribute is set
+pub(crate) fn read_target(reference: Pin<&mut sbmlcxx::SBaseRef>) -> Option<ReferenceTarget> {
+    if reference.isSetIdRef() {
+        let id = reference.getIdRef().to_str().unwrap().to_string();
+        Some(ReferenceTarget::Id(id))
+    } else if reference.isSetMetaIdRef() {
+        let metaid = reference.getMetaIdRef().to_str().unwrap().to_string();
+        Some(ReferenceTarget::MetaId(metaid))
+    } else if reference.isSetPortRef() {
+        let port = reference.getPortRef().to_str().unwrap().to_string();
+        Some(ReferenceTarget::Port(port))
+    } else if reference.isSetUnitRef() {
+        let unit = reference.getUnitRef().to_str().unwrap().to_string();
+        Some(ReferenceTarget::Unit(unit))
+    } else {
+        None
+    }
+}
+
+/// Replaces the target of an SBaseRef.
+///
+/// All reference attributes are unset first, since only one of them may be set.
+pub(crate) fn write_target(mut reference: Pin<&mut sbmlcxx::SBaseRef>, target: &ReferenceTarget) {
+    reference.as_mut().unsetIdRef();
+    reference.as_mut().unsetMetaIdRef();
+    reference.as_mut().unsetPortRef();
+    reference.as_mut().unsetUnitRef();
+
+    let_cxx_string!(value = target.value());
+    match target {
+        ReferenceTarget::Id(_) => reference.as_mut().setIdRef(&value),
+        ReferenceTarget::MetaId(_) => reference.as_mut().setMetaIdRef(&value),
+        ReferenceTarget::Port(_) => reference.as_mut().setPortRef(&value),
+        ReferenceTarget::Unit(_) => reference.as_mut().setUnitRef(&value),
+    };
+}
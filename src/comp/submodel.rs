@@ -0,0 +1,258 @@
+//! This module provides a safe Rust interface to the libSBML Submodel class.
+//!
+//! The Submodel class of the comp package instantiates a model definition, an external
+//! model definition or another model of the document inside the containing model.
+//! Elements of the instance can be removed with deletions, and the time and extent
+//! units of the instance can be rescaled with conversion factors.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Submodel class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    comp::{deletion::Deletion, modelplugin::CompModelPlugin, sbaseref::ReferenceTarget},
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Submodel class.
+///
+/// This struct maintains a reference to the underlying C++ Submodel object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the deletions of the submodel.
+pub struct Submodel<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Submodel>>,
+    deletions: RefCell<Vec<Rc<Deletion<'a>>>>,
+}
+
+// Set the inner trait for the Submodel struct
+inner!(sbmlcxx::Submodel, Submodel<'a>);
+
+// Set the annotation trait for the Submodel struct
+upcast_annotation!(Submodel<'a>, sbmlcxx::Submodel, sbmlcxx::SBase);
+
+// Set the SBase trait for the Submodel struct
+sbase!(Submodel<'a>, sbmlcxx::Submodel);
+
+impl<'a> Submodel<'a> {
+    /// Creates a new Submodel instance within the given comp model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The comp plugin of the model that will contain this submodel
+    /// * `id` - The identifier for this submodel
+    /// * `model_ref` - The identifier of the instantiated model
+    ///
+    /// # Returns
+    /// A new Submodel instance
+    pub fn new(plugin: &CompModelPlugin<'a>, id: &str, model_ref: &str) -> Self {
+        let submodel_ptr = plugin.inner().borrow_mut().as_mut().createSubmodel();
+        let submodel = pin_ptr!(submodel_ptr, sbmlcxx::Submodel);
+
+        let submodel = Self {
+            inner: RefCell::new(submodel),
+            deletions: RefCell::new(Vec::new()),
+        };
+        submodel.set_id(id);
+        submodel.set_model_ref(model_ref);
+        submodel
+    }
+
+    /// Returns a reference to the inner RefCell containing the Submodel pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Submodel>> {
+        &self.inner
+    }
+
+    /// Returns the id of the submodel.
+    ///
+    /// # Returns
+    /// The id of the submodel as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the submodel.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the submodel.
+    ///
+    /// # Returns
+    /// The name of the submodel as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the submodel.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the identifier of the instantiated model.
+    ///
+    /// # Returns
+    /// The identifier of a model definition or external model definition
+    pub fn model_ref(&self) -> String {
+        self.inner
+            .borrow()
+            .getModelRef()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the instantiated model.
+    ///
+    /// # Arguments
+    /// * `model_ref` - The identifier of a model definition or external model definition
+    pub fn set_model_ref(&self, model_ref: &str) {
+        let_cxx_string!(model_ref = model_ref);
+        self.inner.borrow_mut().as_mut().setModelRef(&model_ref);
+    }
+
+    /// Returns the factor converting the time units of the submodel.
+    ///
+    /// # Returns
+    /// The identifier of the conversion factor parameter, None if not set
+    pub fn time_conversion_factor(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetTimeConversionFactor() {
+            return None;
+        }
+        Some(
+            inner
+                .getTimeConversionFactor()
+                .to_str()
+                .unwrap()
+                .to_string(),
+        )
+    }
+
+    /// Sets the factor converting the time units of the submodel.
+    ///
+    /// # Arguments
+    /// * `factor` - The identifier of a constant parameter
+    pub fn set_time_conversion_factor(&self, factor: &str) {
+        let_cxx_string!(factor = factor);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setTimeConversionFactor(&factor);
+    }
+
+    /// Returns the factor converting the extent units of the submodel.
+    ///
+    /// # Returns
+    /// The identifier of the conversion factor parameter, None if not set
+    pub fn extent_conversion_factor(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetExtentConversionFactor() {
+            return None;
+        }
+        Some(
+            inner
+                .getExtentConversionFactor()
+                .to_str()
+                .unwrap()
+                .to_string(),
+        )
+    }
+
+    /// Sets the factor converting the extent units of the submodel.
+    ///
+    /// # Arguments
+    /// * `factor` - The identifier of a constant parameter
+    pub fn set_extent_conversion_factor(&self, factor: &str) {
+        let_cxx_string!(factor = factor);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setExtentConversionFactor(&factor);
+    }
+
+    /// Creates a new deletion removing an element of the submodel.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new deletion
+    /// * `target` - The element of the submodel to remove
+    ///
+    /// # Returns
+    /// The newly created Deletion wrapped in an Rc
+    pub fn create_deletion(&self, id: &str, target: &ReferenceTarget) -> Rc<Deletion<'a>> {
+        let deletion = Rc::new(Deletion::new(self, id, target));
+        self.deletions.borrow_mut().push(Rc::clone(&deletion));
+        deletion
+    }
+
+    /// Returns a vector of all deletions of the submodel.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Deletions of the submodel
+    pub fn list_of_deletions(&self) -> Vec<Rc<Deletion<'a>>> {
+        self.deletions.borrow().to_vec()
+    }
+
+    /// Retrieves a deletion by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the deletion
+    ///
+    /// # Returns
+    /// Some(Rc<Deletion>) if found, None if not found
+    pub fn get_deletion(&self, id: &str) -> Option<Rc<Deletion<'a>>> {
+        self.deletions
+            .borrow()
+            .iter()
+            .find(|deletion| (*deletion).id() == id)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Submodel, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Submodel> for Submodel<'_> {
+    /// Creates a new Submodel instance from a unique pointer to a libSBML Submodel.
+    ///
+    /// This method is primarily used internally by the CompModelPlugin class to create
+    /// Submodel instances from libSBML Submodel pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Submodel
+    ///
+    /// # Returns
+    /// A new Submodel instance
+    fn from_ptr(ptr: *mut sbmlcxx::Submodel) -> Self {
+        let submodel = RefCell::new(pin_ptr!(ptr, sbmlcxx::Submodel));
+
+        // Fetch all deletions
+        let n_deletions = submodel.borrow().getNumDeletions().0;
+        let deletions: Vec<_> = (0..n_deletions)
+            .map(|i| {
+                let deletion = submodel.borrow_mut().as_mut().getDeletion1(i.into());
+                Rc::new(Deletion::from_ptr(deletion))
+            })
+            .collect();
+
+        Self {
+            inner: submodel,
+            deletions: RefCell::new(deletions),
+        }
+    }
+}
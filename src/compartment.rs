@@ -12,7 +12,10 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use autocxx::c_uint;
 use cxx::let_cxx_string;
 
-use crate::{inner, model::Model, pin_ptr, sbmlcxx, sbo_term, upcast_annotation};
+use crate::{
+    comp_replacements, distrib_uncertainties, inner, model::Model, pin_ptr, sbase, sbmlcxx,
+    sbo_term, upcast_annotation,
+};
 
 /// A safe wrapper around the libSBML Compartment class.
 ///
@@ -30,6 +33,9 @@ inner!(sbmlcxx::Compartment, Compartment<'a>);
 // Set the annotation trait for the Compartment struct
 upcast_annotation!(Compartment<'a>, sbmlcxx::Compartment, sbmlcxx::SBase);
 
+// Set the SBase trait for the Compartment struct
+sbase!(Compartment<'a>, sbmlcxx::Compartment);
+
 impl<'a> Compartment<'a> {
     /// Creates a new Compartment instance within the given Model.
     ///
@@ -211,6 +217,12 @@ impl<'a> Compartment<'a> {
 
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Compartment, sbmlcxx::SBase);
+
+    // Replacement Methods generated by the `comp_replacements` macro
+    comp_replacements!(sbmlcxx::Compartment);
+
+    // Uncertainty Methods generated by the `distrib_uncertainties` macro
+    distrib_uncertainties!(sbmlcxx::Compartment);
 }
 
 /// A builder for constructing Compartment instances with a fluent API.
@@ -0,0 +1,104 @@
+//! This module provides a safe Rust interface to the libSBML Delay class.
+//!
+//! The Delay class holds the time that passes between the triggering of an event
+//! and the execution of its assignments.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Delay class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use crate::{
+    astnode::{formula_to_string, parse_formula},
+    event::Event,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Delay class.
+///
+/// This struct maintains a reference to the underlying C++ Delay object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Delay<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Delay>>,
+}
+
+// Set the inner trait for the Delay struct
+inner!(sbmlcxx::Delay, Delay<'a>);
+
+// Set the annotation trait for the Delay struct
+upcast_annotation!(Delay<'a>, sbmlcxx::Delay, sbmlcxx::SBase);
+
+// Set the SBase trait for the Delay struct
+sbase!(Delay<'a>, sbmlcxx::Delay);
+
+impl<'a> Delay<'a> {
+    /// Creates a new Delay instance within the given Event.
+    ///
+    /// # Arguments
+    /// * `event` - The parent Event that will contain this delay
+    /// * `formula` - The formula defining the length of the delay
+    ///
+    /// # Returns
+    /// A new Delay instance
+    pub fn new(event: &Event<'a>, formula: &str) -> Self {
+        let delay_ptr = event.inner().borrow_mut().as_mut().createDelay();
+        let delay = pin_ptr!(delay_ptr, sbmlcxx::Delay);
+
+        let delay = Self {
+            inner: RefCell::new(delay),
+        };
+        delay.set_formula(formula);
+        delay
+    }
+
+    /// Returns the formula of the delay.
+    ///
+    /// # Returns
+    /// The formula in the SBML Level 3 infix syntax, empty if no math is set
+    pub fn formula(&self) -> String {
+        formula_to_string(self.inner.borrow().getMath())
+    }
+
+    /// Returns the math of the delay, null if no math is set.
+    pub(crate) fn math(&self) -> *const sbmlcxx::ASTNode {
+        self.inner.borrow().getMath()
+    }
+
+    /// Sets the formula of the delay.
+    ///
+    /// Formulas that cannot be parsed by libSBML leave the math unchanged.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula in the SBML Level 3 infix syntax
+    pub fn set_formula(&self, formula: &str) {
+        if let Some(math) = parse_formula(formula) {
+            unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        }
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Delay, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Delay> for Delay<'_> {
+    /// Creates a new Delay instance from a unique pointer to a libSBML Delay.
+    ///
+    /// This method is primarily used internally by the Event class to create
+    /// Delay instances from libSBML Delay pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Delay
+    ///
+    /// # Returns
+    /// A new Delay instance
+    fn from_ptr(ptr: *mut sbmlcxx::Delay) -> Self {
+        let delay = pin_ptr!(ptr, sbmlcxx::Delay);
+        Self {
+            inner: RefCell::new(delay),
+        }
+    }
+}
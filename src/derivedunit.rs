@@ -0,0 +1,542 @@
+//! Unit algebra independent of any document.
+//!
+//! A [`DerivedUnit`] is a product of powers of SBML unit kinds times a factor, e.g.
+//! "mmol / l" is 0.001 mole per litre. Derived units are created from the units of a
+//! [`UnitDefinition`] or a single [`Unit`], and combined by multiplication, division
+//! and powers. Simplifying a derived unit to SI base units allows comparing units that
+//! are written differently, and computing the factor to convert values between them.
+//!
+//! Offsets of units, e.g. of degrees Celsius, are not taken into account.
+//!
+//! # Example
+//! ```
+//! use sbml::prelude::*;
+//!
+//! let molar = DerivedUnit::from(UnitKind::Mole) / DerivedUnit::from(UnitKind::Litre);
+//! let millimolar = molar.clone().scaled(-3);
+//! let si = DerivedUnit::from(UnitKind::Mole) / DerivedUnit::from(UnitKind::Metre).pow(3);
+//!
+//! assert!(millimolar.is_identical(&si));
+//! let factor = molar.conversion_factor(&si).unwrap();
+//! assert!((factor - 1000.0).abs() < 1e-9);
+//! ```
+
+use std::{
+    fmt,
+    ops::{Div, Mul},
+};
+
+use crate::{
+    unit::{Unit, UnitKind},
+    unitdef::UnitDefinition,
+};
+
+/// The kinds of the SI base units and the item, the unit of counts of entities, in the
+/// order they appear in simplified units.
+const BASE_KINDS: [UnitKind; 8] = [
+    UnitKind::Metre,
+    UnitKind::Kilogram,
+    UnitKind::Second,
+    UnitKind::Ampere,
+    UnitKind::Kelvin,
+    UnitKind::Mole,
+    UnitKind::Candela,
+    UnitKind::Item,
+];
+
+/// Relative tolerance when comparing the factors of units.
+const TOLERANCE: f64 = 1e-9;
+
+/// A unit built from SBML unit kinds, e.g. the units of a unit definition.
+///
+/// The unit is the product of its kinds raised to their exponents, multiplied by a
+/// factor that holds the scales and multipliers of the original units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedUnit {
+    factor: f64,
+    kinds: Vec<(UnitKind, i32)>,
+}
+
+impl DerivedUnit {
+    /// Creates the dimensionless unit.
+    pub fn dimensionless() -> Self {
+        Self {
+            factor: 1.0,
+            kinds: Vec::new(),
+        }
+    }
+
+    /// Gets the factor of the unit relative to the product of its kinds.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Gets the unit kinds and their exponents.
+    ///
+    /// Each kind appears once, in the order it was first multiplied in. Dimensionless
+    /// kinds are omitted, as are kinds whose exponents cancelled out.
+    pub fn kinds(&self) -> &[(UnitKind, i32)] {
+        &self.kinds
+    }
+
+    /// Returns whether the unit has no dimension, regardless of its factor.
+    pub fn is_dimensionless(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    /// Scales the unit by a power of ten, e.g. -3 for milli.
+    ///
+    /// # Arguments
+    /// * `scale` - The exponent of the power of ten
+    pub fn scaled(self, scale: i32) -> Self {
+        self.multiplied(10f64.powi(scale))
+    }
+
+    /// Multiplies the unit by a factor, e.g. 60 to turn seconds into minutes.
+    ///
+    /// # Arguments
+    /// * `multiplier` - The factor to multiply the unit with
+    pub fn multiplied(mut self, multiplier: f64) -> Self {
+        self.factor *= multiplier;
+        self
+    }
+
+    /// Raises the unit to a power.
+    ///
+    /// # Arguments
+    /// * `exponent` - The exponent, negative for the inverse unit
+    pub fn pow(&self, exponent: i32) -> Self {
+        let kinds = self
+            .kinds
+            .iter()
+            .map(|(kind, power)| (*kind, power * exponent))
+            .filter(|(_, power)| *power != 0)
+            .collect();
+        Self {
+            factor: self.factor.powi(exponent),
+            kinds,
+        }
+    }
+
+    /// Simplifies the unit to SI base units.
+    ///
+    /// Derived SI units such as the newton or the litre are expanded into base units,
+    /// while the item is kept as a base unit of its own. The factor of the result
+    /// holds the scale of the original units, so that 1 mmol / l becomes 1 mol / m^3.
+    ///
+    /// # Returns
+    /// The same unit, expressed in the base units in a fixed order
+    pub fn to_si(&self) -> Self {
+        let mut si = self.kinds.iter().fold(
+            Self::dimensionless().multiplied(self.factor),
+            |product, (kind, exponent)| product * si_units(*kind).pow(*exponent),
+        );
+        si.kinds
+            .sort_by_key(|(kind, _)| BASE_KINDS.iter().position(|base| base == kind));
+        si
+    }
+
+    /// Returns whether two units measure the same quantity, e.g. mmol / l and mol / m^3.
+    ///
+    /// Equivalent units may differ by a factor, see [`DerivedUnit::conversion_factor`].
+    pub fn is_equivalent(&self, other: &DerivedUnit) -> bool {
+        self.to_si().kinds == other.to_si().kinds
+    }
+
+    /// Returns whether two units are the same, e.g. mmol / l and mol / m^3 but not
+    /// mol / l and mol / m^3.
+    pub fn is_identical(&self, other: &DerivedUnit) -> bool {
+        self.conversion_factor(other)
+            .is_some_and(|factor| (factor - 1.0).abs() <= TOLERANCE)
+    }
+
+    /// Computes the factor that converts values from this unit into another one.
+    ///
+    /// # Arguments
+    /// * `target` - The unit to convert values into
+    ///
+    /// # Returns
+    /// The factor to multiply values with, None if the units are not equivalent
+    pub fn conversion_factor(&self, target: &DerivedUnit) -> Option<f64> {
+        let from = self.to_si();
+        let to = target.to_si();
+        (from.kinds == to.kinds).then(|| from.factor / to.factor)
+    }
+}
+
+/// Common units of biochemical models.
+///
+/// These can be passed to [`Model::ensure_unit`](crate::model::Model::ensure_unit) to
+/// declare them in a model, e.g. for the units of rate constants.
+impl DerivedUnit {
+    /// Creates the mole.
+    pub fn mole() -> Self {
+        Self::from(UnitKind::Mole)
+    }
+
+    /// Creates the millimole.
+    pub fn millimole() -> Self {
+        Self::mole().scaled(-3)
+    }
+
+    /// Creates the micromole.
+    pub fn micromole() -> Self {
+        Self::mole().scaled(-6)
+    }
+
+    /// Creates the litre.
+    pub fn litre() -> Self {
+        Self::from(UnitKind::Litre)
+    }
+
+    /// Creates the millilitre.
+    pub fn millilitre() -> Self {
+        Self::litre().scaled(-3)
+    }
+
+    /// Creates the microlitre.
+    pub fn microlitre() -> Self {
+        Self::litre().scaled(-6)
+    }
+
+    /// Creates the molar, mol / l.
+    pub fn molar() -> Self {
+        Self::mole() / Self::litre()
+    }
+
+    /// Creates the millimolar, mmol / l.
+    pub fn millimolar() -> Self {
+        Self::molar().scaled(-3)
+    }
+
+    /// Creates the micromolar, µmol / l.
+    pub fn micromolar() -> Self {
+        Self::molar().scaled(-6)
+    }
+
+    /// Creates the nanomolar, nmol / l.
+    pub fn nanomolar() -> Self {
+        Self::molar().scaled(-9)
+    }
+
+    /// Creates the second.
+    pub fn second() -> Self {
+        Self::from(UnitKind::Second)
+    }
+
+    /// Creates the minute, 60 s.
+    pub fn minute() -> Self {
+        Self::second().multiplied(60.0)
+    }
+
+    /// Creates the hour, 3600 s.
+    pub fn hour() -> Self {
+        Self::second().multiplied(3600.0)
+    }
+
+    /// Creates the unit of first-order rate constants per second, 1 / s.
+    pub fn per_second() -> Self {
+        Self::second().pow(-1)
+    }
+
+    /// Creates the unit of first-order rate constants per minute, 1 / min.
+    pub fn per_minute() -> Self {
+        Self::minute().pow(-1)
+    }
+
+    /// Creates the unit of first-order rate constants per hour, 1 / h.
+    pub fn per_hour() -> Self {
+        Self::hour().pow(-1)
+    }
+
+    /// Creates the unit of second-order rate constants, 1 / (M * s).
+    pub fn per_molar_per_second() -> Self {
+        (Self::molar() * Self::second()).pow(-1)
+    }
+}
+
+impl Default for DerivedUnit {
+    fn default() -> Self {
+        Self::dimensionless()
+    }
+}
+
+impl From<UnitKind> for DerivedUnit {
+    fn from(kind: UnitKind) -> Self {
+        let kinds = match normalize(kind) {
+            UnitKind::Dimensionless | UnitKind::Invalid => Vec::new(),
+            kind => vec![(kind, 1)],
+        };
+        Self { factor: 1.0, kinds }
+    }
+}
+
+impl From<&Unit<'_>> for DerivedUnit {
+    fn from(unit: &Unit<'_>) -> Self {
+        DerivedUnit::from(unit.kind())
+            .multiplied(unit.multiplier())
+            .scaled(unit.scale())
+            .pow(unit.exponent())
+    }
+}
+
+impl From<&UnitDefinition<'_>> for DerivedUnit {
+    fn from(unit_definition: &UnitDefinition<'_>) -> Self {
+        unit_definition
+            .units()
+            .iter()
+            .fold(DerivedUnit::dimensionless(), |product, unit| {
+                product * DerivedUnit::from(unit.as_ref())
+            })
+    }
+}
+
+impl Mul for DerivedUnit {
+    type Output = DerivedUnit;
+
+    fn mul(mut self, other: DerivedUnit) -> DerivedUnit {
+        self.factor *= other.factor;
+        for (kind, exponent) in other.kinds {
+            match self
+                .kinds
+                .iter()
+                .position(|(existing, _)| *existing == kind)
+            {
+                Some(index) => self.kinds[index].1 += exponent,
+                None => self.kinds.push((kind, exponent)),
+            }
+        }
+        self.kinds.retain(|(_, exponent)| *exponent != 0);
+        self
+    }
+}
+
+impl Div for DerivedUnit {
+    type Output = DerivedUnit;
+
+    fn div(self, other: DerivedUnit) -> DerivedUnit {
+        self * other.pow(-1)
+    }
+}
+
+impl fmt::Display for DerivedUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (self.factor - 1.0).abs() > TOLERANCE {
+            write!(f, "{} ", self.factor)?;
+        }
+        if self.kinds.is_empty() {
+            return write!(f, "dimensionless");
+        }
+
+        let powers: Vec<String> = self
+            .kinds
+            .iter()
+            .map(|(kind, exponent)| match *exponent {
+                1 => kind_name(*kind).to_string(),
+                _ => format!("{}^{}", kind_name(*kind), exponent),
+            })
+            .collect();
+        write!(f, "{}", powers.join(" * "))
+    }
+}
+
+/// Maps the American spellings of unit kinds to the ones used by SBML Level 3.
+fn normalize(kind: UnitKind) -> UnitKind {
+    match kind {
+        UnitKind::Liter => UnitKind::Litre,
+        UnitKind::Meter => UnitKind::Metre,
+        kind => kind,
+    }
+}
+
+/// Gets the name of a unit kind as used in SBML.
+pub(crate) fn kind_name(kind: UnitKind) -> &'static str {
+    match kind {
+        UnitKind::Ampere => "ampere",
+        UnitKind::Avogadro => "avogadro",
+        UnitKind::Becquerel => "becquerel",
+        UnitKind::Candela => "candela",
+        UnitKind::Celsius => "celsius",
+        UnitKind::Coulomb => "coulomb",
+        UnitKind::Dimensionless => "dimensionless",
+        UnitKind::Farad => "farad",
+        UnitKind::Gram => "gram",
+        UnitKind::Gray => "gray",
+        UnitKind::Henry => "henry",
+        UnitKind::Hertz => "hertz",
+        UnitKind::Item => "item",
+        UnitKind::Joule => "joule",
+        UnitKind::Katal => "katal",
+        UnitKind::Kelvin => "kelvin",
+        UnitKind::Kilogram => "kilogram",
+        UnitKind::Liter => "liter",
+        UnitKind::Litre => "litre",
+        UnitKind::Lumen => "lumen",
+        UnitKind::Lux => "lux",
+        UnitKind::Meter => "meter",
+        UnitKind::Metre => "metre",
+        UnitKind::Mole => "mole",
+        UnitKind::Newton => "newton",
+        UnitKind::Ohm => "ohm",
+        UnitKind::Pascal => "pascal",
+        UnitKind::Radian => "radian",
+        UnitKind::Second => "second",
+        UnitKind::Siemens => "siemens",
+        UnitKind::Sievert => "sievert",
+        UnitKind::Steradian => "steradian",
+        UnitKind::Tesla => "tesla",
+        UnitKind::Volt => "volt",
+        UnitKind::Watt => "watt",
+        UnitKind::Weber => "weber",
+        UnitKind::Invalid => "invalid",
+    }
+}
+
+/// Expresses a unit kind in SI base units.
+fn si_units(kind: UnitKind) -> DerivedUnit {
+    let base = |powers: &[(UnitKind, i32)]| DerivedUnit {
+        factor: 1.0,
+        kinds: powers.to_vec(),
+    };
+    let (m, kg, s, a) = (
+        UnitKind::Metre,
+        UnitKind::Kilogram,
+        UnitKind::Second,
+        UnitKind::Ampere,
+    );
+
+    match kind {
+        UnitKind::Avogadro => DerivedUnit::dimensionless().multiplied(6.02214076e23),
+        UnitKind::Becquerel | UnitKind::Hertz => base(&[(s, -1)]),
+        UnitKind::Celsius => base(&[(UnitKind::Kelvin, 1)]),
+        UnitKind::Coulomb => base(&[(s, 1), (a, 1)]),
+        UnitKind::Farad => base(&[(m, -2), (kg, -1), (s, 4), (a, 2)]),
+        UnitKind::Gram => base(&[(kg, 1)]).scaled(-3),
+        UnitKind::Gray | UnitKind::Sievert => base(&[(m, 2), (s, -2)]),
+        UnitKind::Henry => base(&[(m, 2), (kg, 1), (s, -2), (a, -2)]),
+        UnitKind::Joule => base(&[(m, 2), (kg, 1), (s, -2)]),
+        UnitKind::Katal => base(&[(UnitKind::Mole, 1), (s, -1)]),
+        UnitKind::Liter | UnitKind::Litre => base(&[(m, 3)]).scaled(-3),
+        UnitKind::Lumen => base(&[(UnitKind::Candela, 1)]),
+        UnitKind::Lux => base(&[(m, -2), (UnitKind::Candela, 1)]),
+        UnitKind::Meter => base(&[(m, 1)]),
+        UnitKind::Newton => base(&[(m, 1), (kg, 1), (s, -2)]),
+        UnitKind::Ohm => base(&[(m, 2), (kg, 1), (s, -3), (a, -2)]),
+        UnitKind::Pascal => base(&[(m, -1), (kg, 1), (s, -2)]),
+        UnitKind::Siemens => base(&[(m, -2), (kg, -1), (s, 3), (a, 2)]),
+        UnitKind::Tesla => base(&[(kg, 1), (s, -2), (a, -1)]),
+        UnitKind::Volt => base(&[(m, 2), (kg, 1), (s, -3), (a, -1)]),
+        UnitKind::Watt => base(&[(m, 2), (kg, 1), (s, -3)]),
+        UnitKind::Weber => base(&[(m, 2), (kg, 1), (s, -2), (a, -1)]),
+        UnitKind::Dimensionless | UnitKind::Radian | UnitKind::Steradian | UnitKind::Invalid => {
+            DerivedUnit::dimensionless()
+        }
+        kind => base(&[(kind, 1)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_unit_definition() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let unit_definition = model
+            .build_unit_definition("mM", "mmol / l")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Liter, Some(-1), Some(0), None, None)
+            .build();
+
+        let unit = DerivedUnit::from(unit_definition.as_ref());
+        assert_eq!(unit.kinds(), &[(UnitKind::Mole, 1), (UnitKind::Litre, -1)]);
+        assert!((unit.factor() - 1e-3).abs() < 1e-15);
+        assert!(unit.is_identical(&DerivedUnit::millimolar()));
+    }
+
+    #[test]
+    fn test_algebra() {
+        let second = DerivedUnit::from(UnitKind::Second);
+        let per_second = DerivedUnit::dimensionless() / second.clone();
+        assert_eq!(per_second.kinds(), &[(UnitKind::Second, -1)]);
+        assert!((per_second.clone() * second).is_dimensionless());
+
+        let area = DerivedUnit::from(UnitKind::Metre).pow(2);
+        assert_eq!(area.kinds(), &[(UnitKind::Metre, 2)]);
+        assert_eq!(area.to_string(), "metre^2");
+        assert_eq!(
+            DerivedUnit::millimolar().to_string(),
+            "0.001 mole * litre^-1"
+        );
+        assert_eq!(
+            DerivedUnit::from(UnitKind::Radian).to_string(),
+            "dimensionless"
+        );
+    }
+
+    #[test]
+    fn test_to_si() {
+        let newton = DerivedUnit::from(UnitKind::Newton).to_si();
+        assert_eq!(
+            newton.kinds(),
+            &[
+                (UnitKind::Metre, 1),
+                (UnitKind::Kilogram, 1),
+                (UnitKind::Second, -2)
+            ]
+        );
+
+        let millimolar = DerivedUnit::millimolar().to_si();
+        assert_eq!(
+            millimolar.kinds(),
+            &[(UnitKind::Metre, -3), (UnitKind::Mole, 1)]
+        );
+        assert!((millimolar.factor() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_equivalence() {
+        let molar = DerivedUnit::from(UnitKind::Mole) / DerivedUnit::from(UnitKind::Liter);
+        assert!(molar.is_equivalent(&DerivedUnit::millimolar()));
+        assert!(!molar.is_identical(&DerivedUnit::millimolar()));
+        assert!(!molar.is_equivalent(&DerivedUnit::from(UnitKind::Mole)));
+
+        let hertz = DerivedUnit::from(UnitKind::Hertz);
+        assert!(hertz.is_identical(&DerivedUnit::from(UnitKind::Second).pow(-1)));
+    }
+
+    #[test]
+    fn test_conversion_factor() {
+        let si = DerivedUnit::from(UnitKind::Mole) / DerivedUnit::from(UnitKind::Metre).pow(3);
+        let factor = DerivedUnit::millimolar().conversion_factor(&si).unwrap();
+        assert!((factor - 1.0).abs() < 1e-12);
+
+        let microlitre = DerivedUnit::from(UnitKind::Litre).scaled(-6);
+        let millilitre = DerivedUnit::from(UnitKind::Litre).scaled(-3);
+        let factor = microlitre.conversion_factor(&millilitre).unwrap();
+        assert!((factor - 1e-3).abs() < 1e-15);
+
+        let minute = DerivedUnit::from(UnitKind::Second).multiplied(60.0);
+        assert_eq!(
+            DerivedUnit::from(UnitKind::Second).conversion_factor(&minute),
+            Some(1.0 / 60.0)
+        );
+        assert_eq!(minute.conversion_factor(&si), None);
+    }
+
+    #[test]
+    fn test_library() {
+        let parse = |units: &str| units.parse::<DerivedUnit>().unwrap();
+        assert!(DerivedUnit::millimolar().is_identical(&parse("mM")));
+        assert!(DerivedUnit::nanomolar().is_identical(&parse("nmol / l")));
+        assert!(DerivedUnit::microlitre().is_identical(&parse("µl")));
+        assert!(DerivedUnit::per_minute().is_identical(&parse("1 / min")));
+        assert!(DerivedUnit::per_molar_per_second().is_identical(&parse("l / (mol * s)")));
+        let factor = DerivedUnit::hour()
+            .conversion_factor(&DerivedUnit::minute())
+            .unwrap();
+        assert!((factor - 60.0).abs() < 1e-9);
+    }
+}
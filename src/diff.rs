@@ -0,0 +1,740 @@
+//! Semantic differences between two SBML models.
+//!
+//! Reviewing changes of a model on the raw XML is tedious: attributes move, the
+//! MathML of a small change spans dozens of lines and annotations are reformatted.
+//! This module compares two models element by element instead. Elements are matched
+//! by their identifier (rules by their variable) and compared by their attributes,
+//! stoichiometries, math and annotations.
+//!
+//! Formulas are compared structurally, i.e. after parsing them into an [`Expr`], so
+//! that differences in whitespace, parentheses or the formula syntax are ignored.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let old = SBMLReader::from_file("model_v1.xml").unwrap();
+//! let new = SBMLReader::from_file("model_v2.xml").unwrap();
+//!
+//! let changes = diff(&old.model().unwrap(), &new.model().unwrap());
+//! print!("{}", changes);
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    compartment::Compartment, formula::Expr, model::Model, parameter::Parameter,
+    reaction::Reaction, rule::Rule, species::Species, traits::annotation::Annotation,
+    unitdef::UnitDefinition,
+};
+
+/// The kind of a model element compared by [`diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElementKind {
+    /// The model itself
+    Model,
+    /// A unit definition
+    UnitDefinition,
+    /// A compartment
+    Compartment,
+    /// A species
+    Species,
+    /// A global parameter
+    Parameter,
+    /// A rate rule, identified by its variable
+    RateRule,
+    /// An assignment rule, identified by its variable
+    AssignmentRule,
+    /// A reaction
+    Reaction,
+}
+
+impl fmt::Display for ElementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ElementKind::Model => "model",
+            ElementKind::UnitDefinition => "unit definition",
+            ElementKind::Compartment => "compartment",
+            ElementKind::Species => "species",
+            ElementKind::Parameter => "parameter",
+            ElementKind::RateRule => "rate rule",
+            ElementKind::AssignmentRule => "assignment rule",
+            ElementKind::Reaction => "reaction",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The role of a species in a reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpeciesRole {
+    Reactant,
+    Product,
+}
+
+impl fmt::Display for SpeciesRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpeciesRole::Reactant => write!(f, "reactant"),
+            SpeciesRole::Product => write!(f, "product"),
+        }
+    }
+}
+
+/// A change of a single property of an element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// An attribute changed its value, an empty value means the attribute is not set
+    Attribute {
+        name: String,
+        old: String,
+        new: String,
+    },
+    /// The stoichiometry of a species in a reaction changed, None means the species
+    /// does not take part in the reaction with this role
+    Stoichiometry {
+        species: String,
+        role: SpeciesRole,
+        old: Option<f64>,
+        new: Option<f64>,
+    },
+    /// The math of a rule or kinetic law changed structurally
+    Formula { old: String, new: String },
+    /// The annotation changed
+    Annotation { old: String, new: String },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Attribute { name, old, new } => write!(f, "{}: {} -> {}", name, old, new),
+            Change::Stoichiometry {
+                species,
+                role,
+                old,
+                new,
+            } => {
+                let format = |value: &Option<f64>| match value {
+                    Some(value) => value.to_string(),
+                    None => "-".to_string(),
+                };
+                write!(
+                    f,
+                    "stoichiometry of {} {}: {} -> {}",
+                    role,
+                    species,
+                    format(old),
+                    format(new)
+                )
+            }
+            Change::Formula { old, new } => write!(f, "formula: {} -> {}", old, new),
+            Change::Annotation { .. } => write!(f, "annotation changed"),
+        }
+    }
+}
+
+/// The changes of an element present in both models.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementDiff {
+    /// The kind of the element
+    pub kind: ElementKind,
+    /// The identifier of the element
+    pub id: String,
+    /// The changed properties, in a stable order
+    pub changes: Vec<Change>,
+}
+
+/// The differences between two models, see [`diff`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModelDiff {
+    /// Elements only present in the new model
+    pub added: Vec<(ElementKind, String)>,
+    /// Elements only present in the old model
+    pub removed: Vec<(ElementKind, String)>,
+    /// Elements present in both models with changed properties
+    pub changed: Vec<ElementDiff>,
+}
+
+impl ModelDiff {
+    /// Returns whether the models are semantically equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Returns the changes of an element, or None if it did not change.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of the element
+    /// * `id` - The identifier of the element
+    pub fn changes(&self, kind: ElementKind, id: &str) -> Option<&[Change]> {
+        self.changed
+            .iter()
+            .find(|element| element.kind == kind && element.id == id)
+            .map(|element| element.changes.as_slice())
+    }
+}
+
+impl fmt::Display for ModelDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (kind, id) in &self.removed {
+            writeln!(f, "- {} {}", kind, id)?;
+        }
+        for (kind, id) in &self.added {
+            writeln!(f, "+ {} {}", kind, id)?;
+        }
+        for element in &self.changed {
+            writeln!(f, "~ {} {}", element.kind, element.id)?;
+            for change in &element.changes {
+                writeln!(f, "    {}", change)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compares two models element by element.
+///
+/// # Arguments
+/// * `old` - The original model
+/// * `new` - The changed model
+///
+/// # Returns
+/// The added, removed and changed elements of the new model
+pub fn diff(old: &Model, new: &Model) -> ModelDiff {
+    let old_elements = snapshots(old);
+    let mut new_elements = snapshots(new);
+
+    // The models are compared with each other even if their identifiers differ
+    new_elements[0].id = old_elements[0].id.clone();
+
+    let index = |elements: &[Snapshot]| -> HashMap<(ElementKind, String), usize> {
+        elements
+            .iter()
+            .enumerate()
+            .map(|(i, element)| ((element.kind, element.id.clone()), i))
+            .collect()
+    };
+    let old_index = index(&old_elements);
+    let new_index = index(&new_elements);
+
+    let mut result = ModelDiff::default();
+    for element in &old_elements {
+        match new_index.get(&(element.kind, element.id.clone())) {
+            Some(&i) => {
+                let changes = element.changes(&new_elements[i]);
+                if !changes.is_empty() {
+                    result.changed.push(ElementDiff {
+                        kind: element.kind,
+                        id: element.id.clone(),
+                        changes,
+                    });
+                }
+            }
+            None => result.removed.push((element.kind, element.id.clone())),
+        }
+    }
+    for element in &new_elements {
+        if !old_index.contains_key(&(element.kind, element.id.clone())) {
+            result.added.push((element.kind, element.id.clone()));
+        }
+    }
+
+    result
+}
+
+/// The comparable properties of an element.
+struct Snapshot {
+    kind: ElementKind,
+    id: String,
+    attributes: Vec<(String, String)>,
+    formula: Option<String>,
+    stoichiometry: Vec<(SpeciesRole, String, f64)>,
+    annotation: String,
+}
+
+impl Snapshot {
+    /// Creates a snapshot with the annotation of an element and no other properties.
+    fn new(kind: ElementKind, id: String, element: &impl Annotation) -> Self {
+        Self {
+            kind,
+            id,
+            attributes: Vec::new(),
+            formula: None,
+            stoichiometry: Vec::new(),
+            annotation: element.get_annotation().trim().to_string(),
+        }
+    }
+
+    /// Adds an attribute to the snapshot.
+    fn attribute(mut self, name: &str, value: impl ToString) -> Self {
+        self.attributes.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Returns the changes from this snapshot to another one of the same element.
+    fn changes(&self, other: &Snapshot) -> Vec<Change> {
+        let mut changes = Vec::new();
+
+        // Attributes, including those only present on one side
+        let other_attributes: HashMap<&str, &str> = other
+            .attributes
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        for (name, old) in &self.attributes {
+            let new = other_attributes.get(name.as_str()).copied().unwrap_or("");
+            if old != new {
+                changes.push(Change::Attribute {
+                    name: name.clone(),
+                    old: old.clone(),
+                    new: new.to_string(),
+                });
+            }
+        }
+        for (name, new) in &other.attributes {
+            if !self.attributes.iter().any(|(old_name, _)| old_name == name) {
+                changes.push(Change::Attribute {
+                    name: name.clone(),
+                    old: String::new(),
+                    new: new.clone(),
+                });
+            }
+        }
+
+        changes.extend(stoichiometry_changes(
+            &self.stoichiometry,
+            &other.stoichiometry,
+        ));
+
+        let old_formula = self.formula.as_deref().unwrap_or("");
+        let new_formula = other.formula.as_deref().unwrap_or("");
+        if !formulas_equal(old_formula, new_formula) {
+            changes.push(Change::Formula {
+                old: old_formula.to_string(),
+                new: new_formula.to_string(),
+            });
+        }
+
+        if self.annotation != other.annotation {
+            changes.push(Change::Annotation {
+                old: self.annotation.clone(),
+                new: other.annotation.clone(),
+            });
+        }
+
+        changes
+    }
+}
+
+/// Collects the snapshots of all compared elements of a model, in document order.
+fn snapshots(model: &Model) -> Vec<Snapshot> {
+    let model_snapshot = Snapshot::new(ElementKind::Model, model.id(), model)
+        .attribute("id", model.id())
+        .attribute("name", model.name());
+    let mut snapshots = vec![model_snapshot];
+
+    snapshots.extend(
+        model
+            .list_of_unit_definitions()
+            .iter()
+            .map(|unit_definition| unit_definition_snapshot(unit_definition)),
+    );
+    snapshots.extend(
+        model
+            .list_of_compartments()
+            .iter()
+            .map(|compartment| compartment_snapshot(compartment)),
+    );
+    snapshots.extend(
+        model
+            .list_of_species()
+            .iter()
+            .map(|species| species_snapshot(species)),
+    );
+    snapshots.extend(
+        model
+            .list_of_parameters()
+            .iter()
+            .map(|parameter| parameter_snapshot(parameter)),
+    );
+    snapshots.extend(
+        model
+            .list_of_rate_rules()
+            .iter()
+            .map(|rule| rule_snapshot(ElementKind::RateRule, rule)),
+    );
+    snapshots.extend(
+        model
+            .list_of_assignment_rules()
+            .iter()
+            .map(|rule| rule_snapshot(ElementKind::AssignmentRule, rule)),
+    );
+    snapshots.extend(
+        model
+            .list_of_reactions()
+            .iter()
+            .map(|reaction| reaction_snapshot(reaction)),
+    );
+
+    snapshots
+}
+
+fn unit_definition_snapshot(unit_definition: &UnitDefinition) -> Snapshot {
+    let units = unit_definition
+        .units()
+        .iter()
+        .map(|unit| {
+            format!(
+                "({} * 10^{} * {:?})^{}",
+                unit.multiplier(),
+                unit.scale(),
+                unit.kind(),
+                unit.exponent()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" * ");
+
+    Snapshot::new(
+        ElementKind::UnitDefinition,
+        unit_definition.id(),
+        unit_definition,
+    )
+    .attribute("name", unit_definition.name())
+    .attribute("units", units)
+}
+
+fn compartment_snapshot(compartment: &Compartment) -> Snapshot {
+    Snapshot::new(ElementKind::Compartment, compartment.id(), compartment)
+        .attribute("name", compartment.name())
+        .attribute("spatial_dimensions", compartment.spatial_dimensions())
+        .attribute("size", compartment.size())
+        .attribute("unit", compartment.unit())
+        .attribute("constant", compartment.constant())
+        .attribute("outside", compartment.outside())
+}
+
+fn species_snapshot(species: &Species) -> Snapshot {
+    let snapshot = Snapshot::new(ElementKind::Species, species.id(), species)
+        .attribute("name", species.name())
+        .attribute("compartment", species.compartment())
+        .attribute("initial_amount", species.initial_amount())
+        .attribute("initial_concentration", species.initial_concentration())
+        .attribute("unit", species.unit())
+        .attribute("boundary_condition", species.boundary_condition())
+        .attribute("constant", species.constant())
+        .attribute(
+            "has_only_substance_units",
+            species.has_only_substance_units(),
+        );
+
+    #[cfg(feature = "fbc")]
+    let snapshot = snapshot
+        .attribute("charge", format_option(species.charge()))
+        .attribute(
+            "chemical_formula",
+            format_option(species.chemical_formula()),
+        );
+
+    snapshot
+}
+
+fn parameter_snapshot(parameter: &Parameter) -> Snapshot {
+    Snapshot::new(ElementKind::Parameter, parameter.id(), parameter)
+        .attribute("name", parameter.name())
+        .attribute("value", format_option(parameter.value()))
+        .attribute("units", parameter.units())
+        .attribute("constant", parameter.constant())
+}
+
+fn rule_snapshot(kind: ElementKind, rule: &Rule) -> Snapshot {
+    let mut snapshot = Snapshot::new(kind, rule.variable(), rule);
+    snapshot.formula = Some(rule.formula());
+    snapshot
+}
+
+fn reaction_snapshot(reaction: &Reaction) -> Snapshot {
+    let modifiers = reaction
+        .modifiers()
+        .borrow()
+        .iter()
+        .map(|modifier| modifier.species())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut snapshot = Snapshot::new(ElementKind::Reaction, reaction.id(), reaction)
+        .attribute("name", reaction.name())
+        .attribute("reversible", reaction.reversible())
+        .attribute("fast", reaction.fast())
+        .attribute("compartment", reaction.compartment())
+        .attribute("modifiers", modifiers);
+
+    #[cfg(feature = "fbc")]
+    {
+        snapshot = snapshot
+            .attribute(
+                "lower_flux_bound",
+                format_option(reaction.lower_flux_bound()),
+            )
+            .attribute(
+                "upper_flux_bound",
+                format_option(reaction.upper_flux_bound()),
+            );
+    }
+
+    for (role, references) in [
+        (SpeciesRole::Reactant, reaction.reactants()),
+        (SpeciesRole::Product, reaction.products()),
+    ] {
+        for reference in references.borrow().iter() {
+            snapshot
+                .stoichiometry
+                .push((role, reference.species(), reference.stoichiometry()));
+        }
+    }
+
+    if let Some(kinetic_law) = reaction.kinetic_law() {
+        snapshot.formula = Some(kinetic_law.formula());
+        for parameter in kinetic_law.list_of_local_parameters() {
+            snapshot = snapshot.attribute(
+                &format!("local parameter {}", parameter.id()),
+                format_option(parameter.value()),
+            );
+        }
+    }
+
+    snapshot
+}
+
+/// Formats an optional value, leaving unset values empty.
+fn format_option<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Compares two formulas structurally, falling back to the text if either cannot be
+/// parsed.
+fn formulas_equal(old: &str, new: &str) -> bool {
+    match (Expr::parse(old), Expr::parse(new)) {
+        (Ok(old), Ok(new)) => old == new,
+        _ => old.split_whitespace().eq(new.split_whitespace()),
+    }
+}
+
+/// Compares the stoichiometries of two reactions, summing duplicate references.
+fn stoichiometry_changes(
+    old: &[(SpeciesRole, String, f64)],
+    new: &[(SpeciesRole, String, f64)],
+) -> Vec<Change> {
+    let totals = |references: &[(SpeciesRole, String, f64)]| {
+        let mut totals: Vec<((SpeciesRole, String), f64)> = Vec::new();
+        for (role, species, stoichiometry) in references {
+            let key = (*role, species.clone());
+            match totals.iter_mut().find(|(other, _)| *other == key) {
+                Some((_, total)) => *total += stoichiometry,
+                None => totals.push((key, *stoichiometry)),
+            }
+        }
+        totals
+    };
+    let old = totals(old);
+    let new = totals(new);
+    let lookup = |totals: &[((SpeciesRole, String), f64)], key: &(SpeciesRole, String)| {
+        totals
+            .iter()
+            .find(|(other, _)| other == key)
+            .map(|(_, total)| *total)
+    };
+
+    let mut changes = Vec::new();
+    for (key, total) in &old {
+        let new_total = lookup(&new, key);
+        if new_total != Some(*total) {
+            changes.push(Change::Stoichiometry {
+                species: key.1.clone(),
+                role: key.0,
+                old: Some(*total),
+                new: new_total,
+            });
+        }
+    }
+    for (key, total) in &new {
+        if lookup(&old, key).is_none() {
+            changes.push(Change::Stoichiometry {
+                species: key.1.clone(),
+                role: key.0,
+                old: None,
+                new: Some(*total),
+            });
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// Builds a small model shared by the tests
+    fn build_model<'a>(doc: &'a SBMLDocument<'a>) {
+        let model = doc.create_model("glycolysis");
+        model.build_compartment("cytosol").size(1.0).build();
+        model
+            .build_species("glc")
+            .compartment("cytosol")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_species("g6p")
+            .compartment("cytosol")
+            .initial_concentration(0.0)
+            .build();
+        model.build_parameter("k1").value(0.1).build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .kinetic_law("k1 * glc")
+            .build();
+    }
+
+    #[test]
+    fn test_identical_models() {
+        let old = SBMLDocument::default();
+        build_model(&old);
+        let new = SBMLDocument::default();
+        build_model(&new);
+
+        let changes = diff(&old.model().unwrap(), &new.model().unwrap());
+        assert!(changes.is_empty(), "Unexpected changes: {}", changes);
+    }
+
+    #[test]
+    fn test_added_removed_and_changed() {
+        let old = SBMLDocument::default();
+        build_model(&old);
+        old.model()
+            .unwrap()
+            .build_parameter("k2")
+            .value(1.0)
+            .build();
+
+        let new = SBMLDocument::default();
+        build_model(&new);
+        let model = new.model().unwrap();
+        model
+            .get_species("glc")
+            .unwrap()
+            .set_initial_concentration(10.0);
+        model.build_species("atp").compartment("cytosol").build();
+
+        let changes = diff(&old.model().unwrap(), &model);
+        assert_eq!(
+            changes.added,
+            vec![(ElementKind::Species, "atp".to_string())]
+        );
+        assert_eq!(
+            changes.removed,
+            vec![(ElementKind::Parameter, "k2".to_string())]
+        );
+        assert_eq!(
+            changes.changes(ElementKind::Species, "glc"),
+            Some(
+                &[Change::Attribute {
+                    name: "initial_concentration".to_string(),
+                    old: "5".to_string(),
+                    new: "10".to_string(),
+                }][..]
+            )
+        );
+    }
+
+    #[test]
+    fn test_stoichiometry_and_formula() {
+        let old = SBMLDocument::default();
+        build_model(&old);
+
+        let new = SBMLDocument::default();
+        let model = new.create_model("glycolysis");
+        model.build_compartment("cytosol").size(1.0).build();
+        model
+            .build_species("glc")
+            .compartment("cytosol")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_species("g6p")
+            .compartment("cytosol")
+            .initial_concentration(0.0)
+            .build();
+        model.build_parameter("k1").value(0.1).build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 2.0)
+            .product("g6p", 1.0)
+            .kinetic_law("k1 * glc^2")
+            .build();
+
+        let changes = diff(&old.model().unwrap(), &model);
+        let hk = changes.changes(ElementKind::Reaction, "hk").unwrap();
+        assert!(hk.contains(&Change::Stoichiometry {
+            species: "glc".to_string(),
+            role: SpeciesRole::Reactant,
+            old: Some(1.0),
+            new: Some(2.0),
+        }));
+        assert!(hk
+            .iter()
+            .any(|change| matches!(change, Change::Formula { .. })));
+    }
+
+    #[test]
+    fn test_reversibility_change() {
+        let old = SBMLDocument::default();
+        build_model(&old);
+        let new = SBMLDocument::default();
+        build_model(&new);
+        let model = new.model().unwrap();
+        model.get_reaction("hk").unwrap().set_reversible(false);
+
+        let changes = diff(&old.model().unwrap(), &model);
+        assert!(!changes.is_empty());
+        assert_eq!(
+            changes.changes(ElementKind::Reaction, "hk"),
+            Some(
+                &[Change::Attribute {
+                    name: "reversible".to_string(),
+                    old: "true".to_string(),
+                    new: "false".to_string(),
+                }][..]
+            )
+        );
+    }
+
+    #[test]
+    fn test_formulas_compared_structurally() {
+        assert!(formulas_equal("k1 * glc", "(k1*glc)"));
+        assert!(formulas_equal("gt(x, 1)", "x > 1"));
+        assert!(!formulas_equal("k1 * glc", "k2 * glc"));
+    }
+
+    #[test]
+    fn test_annotation_changes() {
+        let old = SBMLDocument::default();
+        build_model(&old);
+        let new = SBMLDocument::default();
+        build_model(&new);
+
+        let model = new.model().unwrap();
+        model
+            .get_parameter("k1")
+            .unwrap()
+            .set_annotation("<annotation><note>fitted</note></annotation>")
+            .unwrap();
+
+        let changes = diff(&old.model().unwrap(), &model);
+        let k1 = changes.changes(ElementKind::Parameter, "k1").unwrap();
+        assert!(matches!(k1, [Change::Annotation { .. }]));
+        assert!(changes.to_string().contains("~ parameter k1"));
+    }
+}
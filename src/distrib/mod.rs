@@ -0,0 +1,159 @@
+//! Support for the SBML Level 3 distributions (distrib) package.
+//!
+//! The distrib package describes the uncertainty of values in a model. Parameters,
+//! species and compartments carry [`Uncertainty`] elements, which hold
+//! [`UncertParameter`]s of different [`UncertType`]s:
+//!
+//! - statistics like the mean, the standard deviation or the variance
+//! - a full distribution given as a formula, e.g. `normal(1, 0.1)`
+//!
+//! Uncertainties are added through the `create_uncertainty` method of the element.
+//! To draw parameter sets from them, see the [`sampling`](crate::sampling) module.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("uncertain");
+//!
+//! let k1 = model.build_parameter("k1").value(1.0).build();
+//! k1.create_uncertainty().set_distribution("normal(1, 0.1)");
+//!
+//! let k2 = model.build_parameter("k2").value(0.5).build();
+//! let uncertainty = k2.create_uncertainty();
+//! uncertainty.set_statistic(UncertType::Mean, 0.5);
+//! uncertainty.set_statistic(UncertType::StandardDeviation, 0.05);
+//! ```
+
+/// Module providing uncertainty functionality
+pub mod uncertainty;
+/// Module providing uncertainty parameter functionality
+pub mod uncertparam;
+
+pub use uncertainty::Uncertainty;
+pub use uncertparam::{UncertParameter, UncertType};
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_uncertainty_statistics() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let parameter = model.build_parameter("k").value(1.0).build();
+        assert!(parameter.uncertainty().is_none());
+        assert!(!doc.is_package_enabled(Package::Distrib));
+
+        let uncertainty = parameter.create_uncertainty();
+        uncertainty.set_id("k_uncertainty");
+        uncertainty.set_statistic(UncertType::Mean, 1.0);
+        uncertainty.set_statistic(UncertType::StandardDeviation, 0.1);
+        assert!(doc.is_package_enabled(Package::Distrib));
+
+        // Setting a statistic again updates the existing parameter
+        uncertainty.set_statistic(UncertType::Mean, 1.5);
+        assert_eq!(uncertainty.list_of_uncert_parameters().len(), 2);
+        assert_eq!(uncertainty.statistic(UncertType::Mean), Some(1.5));
+        assert_eq!(
+            uncertainty.statistic(UncertType::StandardDeviation),
+            Some(0.1)
+        );
+        assert_eq!(uncertainty.statistic(UncertType::Variance), None);
+        assert_eq!(uncertainty.distribution(), None);
+
+        let uncertainty = parameter.uncertainty().expect("Uncertainty not found");
+        assert_eq!(uncertainty.id(), "k_uncertainty");
+        assert_eq!(parameter.list_of_uncertainties().len(), 1);
+    }
+
+    #[test]
+    fn test_uncertainty_distribution() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.build_species("A").initial_concentration(1.0).build();
+        let compartment = model.build_compartment("cell").build();
+
+        species
+            .create_uncertainty()
+            .set_distribution("lognormal(0, 0.5)");
+        compartment
+            .create_uncertainty()
+            .set_distribution("uniform(1, 2)");
+
+        let uncertainty = species.uncertainty().unwrap();
+        assert_eq!(
+            uncertainty.distribution().as_deref(),
+            Some("lognormal(0, 0.5)")
+        );
+        let distribution = uncertainty
+            .get_uncert_parameter(UncertType::Distribution)
+            .unwrap();
+        assert_eq!(distribution.uncert_type(), Some(UncertType::Distribution));
+
+        // Replacing the distribution keeps a single parameter
+        uncertainty.set_distribution("lognormal(0, 0.2)");
+        assert_eq!(uncertainty.list_of_uncert_parameters().len(), 1);
+        assert_eq!(
+            compartment.uncertainty().unwrap().distribution().as_deref(),
+            Some("uniform(1, 2)")
+        );
+    }
+
+    #[test]
+    fn test_uncert_parameter_attributes() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_parameter("sd_k").value(0.2).build();
+        let parameter = model.build_parameter("k").value(1.0).build();
+
+        let uncertainty = parameter.create_uncertainty();
+        let sd = uncertainty.create_uncert_parameter(UncertType::StandardDeviation);
+        sd.set_var("sd_k");
+        sd.set_units("dimensionless");
+        assert_eq!(sd.value(), None);
+        assert_eq!(sd.var().as_deref(), Some("sd_k"));
+        assert_eq!(sd.units().as_deref(), Some("dimensionless"));
+
+        let external = uncertainty.create_uncert_parameter(UncertType::ExternalParameter);
+        external.set_definition_url("http://www.probonto.org/ontology#PROB_k0000056");
+        assert_eq!(
+            external.definition_url().as_deref(),
+            Some("http://www.probonto.org/ontology#PROB_k0000056")
+        );
+        assert_eq!(
+            UncertType::StandardDeviation.to_string(),
+            "standardDeviation"
+        );
+    }
+
+    #[test]
+    fn test_uncertainty_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let parameter = model.build_parameter("k").value(1.0).build();
+        let uncertainty = parameter.create_uncertainty();
+        uncertainty.set_statistic(UncertType::Mean, 1.0);
+        uncertainty.set_statistic(UncertType::Variance, 0.04);
+        uncertainty.set_distribution("normal(1, 0.2)");
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains("distrib:uncertainty"));
+
+        let read = SBMLReader::from_xml_string(&xml);
+        let model = read.model().expect("Model not found");
+        let uncertainty = model
+            .get_parameter("k")
+            .unwrap()
+            .uncertainty()
+            .expect("Uncertainty not found");
+
+        assert_eq!(uncertainty.statistic(UncertType::Mean), Some(1.0));
+        assert_eq!(uncertainty.statistic(UncertType::Variance), Some(0.04));
+        assert_eq!(
+            uncertainty.distribution().as_deref(),
+            Some("normal(1, 0.2)")
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! This module provides a safe Rust interface to the libSBML Uncertainty class.
+//!
+//! The Uncertainty class of the distrib package describes how uncertain the value of
+//! a model element is, e.g. a kinetic constant estimated from noisy measurements. It
+//! lists statistics like the mean and standard deviation, or a full distribution, as
+//! uncertainty parameters. Uncertainties are attached to parameters, species and
+//! compartments through their `create_uncertainty` method.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Uncertainty class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    distrib::uncertparam::{UncertParameter, UncertType},
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Uncertainty class.
+///
+/// This struct maintains a reference to the underlying C++ Uncertainty object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the uncertainty parameters.
+pub struct Uncertainty<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Uncertainty>>,
+    parameters: RefCell<Vec<Rc<UncertParameter<'a>>>>,
+}
+
+// Set the inner trait for the Uncertainty struct
+inner!(sbmlcxx::Uncertainty, Uncertainty<'a>);
+
+// Set the annotation trait for the Uncertainty struct
+upcast_annotation!(Uncertainty<'a>, sbmlcxx::Uncertainty, sbmlcxx::SBase);
+
+// Set the SBase trait for the Uncertainty struct
+sbase!(Uncertainty<'a>, sbmlcxx::Uncertainty);
+
+impl<'a> Uncertainty<'a> {
+    /// Creates a new Uncertainty instance within the distrib plugin of an element.
+    ///
+    /// It is created through the `create_uncertainty` method of the element.
+    ///
+    /// # Arguments
+    /// * `plugin` - The distrib plugin of the uncertain element
+    ///
+    /// # Returns
+    /// A new Uncertainty instance
+    pub(crate) fn new(mut plugin: Pin<&'a mut sbmlcxx::DistribSBasePlugin>) -> Self {
+        let uncertainty_ptr = plugin.as_mut().createUncertainty();
+        let uncertainty = pin_ptr!(uncertainty_ptr, sbmlcxx::Uncertainty);
+
+        Self {
+            inner: RefCell::new(uncertainty),
+            parameters: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a reference to the inner RefCell containing the Uncertainty pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Uncertainty>> {
+        &self.inner
+    }
+
+    /// Returns the id of the uncertainty.
+    ///
+    /// # Returns
+    /// The id of the uncertainty as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::Uncertainty, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the uncertainty.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::Uncertainty, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the name of the uncertainty.
+    ///
+    /// # Returns
+    /// The name of the uncertainty as a String
+    pub fn name(&self) -> String {
+        let base = upcast!(self, sbmlcxx::Uncertainty, sbmlcxx::SBase);
+        base.getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the uncertainty.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        let mut base = upcast!(self, sbmlcxx::Uncertainty, sbmlcxx::SBase);
+        base.as_mut().setName(&name);
+    }
+
+    /// Adds a statistic to the uncertainty.
+    ///
+    /// # Arguments
+    /// * `uncert_type` - The statistic held by the parameter
+    ///
+    /// # Returns
+    /// The newly created UncertParameter wrapped in an Rc
+    pub fn create_uncert_parameter(&self, uncert_type: UncertType) -> Rc<UncertParameter<'a>> {
+        let parameter = Rc::new(UncertParameter::new(self, uncert_type));
+        self.parameters.borrow_mut().push(Rc::clone(&parameter));
+        parameter
+    }
+
+    /// Returns a vector of all uncertainty parameters.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all UncertParameters of the uncertainty
+    pub fn list_of_uncert_parameters(&self) -> Vec<Rc<UncertParameter<'a>>> {
+        self.parameters.borrow().to_vec()
+    }
+
+    /// Retrieves the first uncertainty parameter holding a statistic.
+    ///
+    /// # Arguments
+    /// * `uncert_type` - The statistic
+    ///
+    /// # Returns
+    /// Some(Rc<UncertParameter>) if found, None if not found
+    pub fn get_uncert_parameter(&self, uncert_type: UncertType) -> Option<Rc<UncertParameter<'a>>> {
+        self.parameters
+            .borrow()
+            .iter()
+            .find(|parameter| (*parameter).uncert_type() == Some(uncert_type))
+            .map(Rc::clone)
+    }
+
+    /// Returns the numeric value of a statistic.
+    ///
+    /// # Arguments
+    /// * `uncert_type` - The statistic, e.g. [`UncertType::Mean`]
+    ///
+    /// # Returns
+    /// The value, None if the statistic is not given as a number
+    pub fn statistic(&self, uncert_type: UncertType) -> Option<f64> {
+        self.get_uncert_parameter(uncert_type)?.value()
+    }
+
+    /// Sets the numeric value of a statistic.
+    ///
+    /// The existing parameter holding the statistic is updated, or a new one is created.
+    ///
+    /// # Arguments
+    /// * `uncert_type` - The statistic, e.g. [`UncertType::StandardDeviation`]
+    /// * `value` - The value of the statistic
+    ///
+    /// # Returns
+    /// The UncertParameter holding the statistic
+    pub fn set_statistic(&self, uncert_type: UncertType, value: f64) -> Rc<UncertParameter<'a>> {
+        let parameter = self
+            .get_uncert_parameter(uncert_type)
+            .unwrap_or_else(|| self.create_uncert_parameter(uncert_type));
+        parameter.set_value(value);
+        parameter
+    }
+
+    /// Returns the formula of the distribution of the value.
+    ///
+    /// # Returns
+    /// The formula, e.g. `normal(1, 0.1)`, None if no distribution is given
+    pub fn distribution(&self) -> Option<String> {
+        self.get_uncert_parameter(UncertType::Distribution)?
+            .formula()
+    }
+
+    /// Sets the distribution of the value.
+    ///
+    /// The existing distribution is replaced, or a new one is created.
+    ///
+    /// # Arguments
+    /// * `formula` - The distribution, e.g. `normal(1, 0.1)` or `uniform(0, 2)`
+    ///
+    /// # Returns
+    /// The UncertParameter holding the distribution
+    pub fn set_distribution(&self, formula: &str) -> Rc<UncertParameter<'a>> {
+        let parameter = self
+            .get_uncert_parameter(UncertType::Distribution)
+            .unwrap_or_else(|| self.create_uncert_parameter(UncertType::Distribution));
+        parameter.set_formula(formula);
+        parameter
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Uncertainty, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Uncertainty> for Uncertainty<'_> {
+    /// Creates a new Uncertainty instance from a unique pointer to a libSBML Uncertainty.
+    ///
+    /// This method is primarily used internally to create Uncertainty instances from
+    /// the distrib plugins of elements.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Uncertainty
+    ///
+    /// # Returns
+    /// A new Uncertainty instance
+    fn from_ptr(ptr: *mut sbmlcxx::Uncertainty) -> Self {
+        let uncertainty = RefCell::new(pin_ptr!(ptr, sbmlcxx::Uncertainty));
+
+        // Fetch all uncertainty parameters
+        let n_parameters = uncertainty.borrow().getNumUncertParameters().0;
+        let parameters: Vec<_> = (0..n_parameters)
+            .map(|i| {
+                let parameter = uncertainty
+                    .borrow_mut()
+                    .as_mut()
+                    .getUncertParameter1(i.into());
+                Rc::new(UncertParameter::from_ptr(parameter))
+            })
+            .collect();
+
+        Self {
+            inner: uncertainty,
+            parameters: RefCell::new(parameters),
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! This module provides a safe Rust interface to the libSBML UncertParameter class.
+//!
+//! The UncertParameter class of the distrib package holds a single statistic of an
+//! uncertainty, e.g. the mean or the standard deviation of a parameter value. The
+//! statistic is given either as a number or as a reference to another element of the
+//! model. Parameters of the distribution type instead hold a formula like
+//! `normal(1.0, 0.1)` describing the full distribution.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML UncertParameter class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, fmt, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    astnode::{formula_to_string, parse_formula},
+    distrib::uncertainty::Uncertainty,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// The statistic held by an uncertainty parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UncertType {
+    /// A full distribution, given as a formula
+    Distribution,
+    /// A parameter defined outside of the distrib package, identified by its URL
+    ExternalParameter,
+    /// The arithmetic mean
+    Mean,
+    /// The median
+    Median,
+    /// The most likely value
+    Mode,
+    /// The standard deviation
+    StandardDeviation,
+    /// The standard error of the mean
+    StandardError,
+    /// The variance
+    Variance,
+    /// The number of samples the statistics were estimated from
+    SampleSize,
+    /// The skewness
+    Skewness,
+    /// The kurtosis
+    Kurtosis,
+}
+
+impl UncertType {
+    /// Returns the value of the type as written to SBML.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UncertType::Distribution => "distribution",
+            UncertType::ExternalParameter => "externalParameter",
+            UncertType::Mean => "mean",
+            UncertType::Median => "median",
+            UncertType::Mode => "mode",
+            UncertType::StandardDeviation => "standardDeviation",
+            UncertType::StandardError => "standardError",
+            UncertType::Variance => "variance",
+            UncertType::SampleSize => "sampleSize",
+            UncertType::Skewness => "skewness",
+            UncertType::Kurtosis => "kurtosis",
+        }
+    }
+}
+
+impl fmt::Display for UncertType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<UncertType> for sbmlcxx::UncertType_t {
+    fn from(uncert_type: UncertType) -> Self {
+        match uncert_type {
+            UncertType::Distribution => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_DISTRIBUTION,
+            UncertType::ExternalParameter => {
+                sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_EXTERNALPARAMETER
+            }
+            UncertType::Mean => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_MEAN,
+            UncertType::Median => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_MEDIAN,
+            UncertType::Mode => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_MODE,
+            UncertType::StandardDeviation => {
+                sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_STANDARDDEVIATION
+            }
+            UncertType::StandardError => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_STANDARDERROR,
+            UncertType::Variance => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_VARIANCE,
+            UncertType::SampleSize => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_SAMPLESIZE,
+            UncertType::Skewness => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_SKEWNESS,
+            UncertType::Kurtosis => sbmlcxx::UncertType_t::DISTRIB_UNCERTTYPE_KURTOSIS,
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML UncertParameter class.
+///
+/// This struct maintains a reference to the underlying C++ UncertParameter object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct UncertParameter<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::UncertParameter>>,
+}
+
+// Set the inner trait for the UncertParameter struct
+inner!(sbmlcxx::UncertParameter, UncertParameter<'a>);
+
+// Set the annotation trait for the UncertParameter struct
+upcast_annotation!(
+    UncertParameter<'a>,
+    sbmlcxx::UncertParameter,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the UncertParameter struct
+sbase!(UncertParameter<'a>, sbmlcxx::UncertParameter);
+
+impl<'a> UncertParameter<'a> {
+    /// Creates a new UncertParameter instance within the given Uncertainty.
+    ///
+    /// # Arguments
+    /// * `uncertainty` - The parent Uncertainty that will contain this parameter
+    /// * `uncert_type` - The statistic held by the parameter
+    ///
+    /// # Returns
+    /// A new UncertParameter instance
+    pub fn new(uncertainty: &Uncertainty<'a>, uncert_type: UncertType) -> Self {
+        let parameter_ptr = uncertainty
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createUncertParameter();
+        let mut parameter = pin_ptr!(parameter_ptr, sbmlcxx::UncertParameter);
+        parameter.as_mut().setType(uncert_type.into());
+
+        Self {
+            inner: RefCell::new(parameter),
+        }
+    }
+
+    /// Returns the statistic held by the parameter.
+    ///
+    /// # Returns
+    /// The type of the parameter, None if not set or not supported
+    pub fn uncert_type(&self) -> Option<UncertType> {
+        use sbmlcxx::UncertType_t::*;
+
+        match self.inner.borrow().getType() {
+            DISTRIB_UNCERTTYPE_DISTRIBUTION => Some(UncertType::Distribution),
+            DISTRIB_UNCERTTYPE_EXTERNALPARAMETER => Some(UncertType::ExternalParameter),
+            DISTRIB_UNCERTTYPE_MEAN => Some(UncertType::Mean),
+            DISTRIB_UNCERTTYPE_MEDIAN => Some(UncertType::Median),
+            DISTRIB_UNCERTTYPE_MODE => Some(UncertType::Mode),
+            DISTRIB_UNCERTTYPE_STANDARDDEVIATION => Some(UncertType::StandardDeviation),
+            DISTRIB_UNCERTTYPE_STANDARDERROR => Some(UncertType::StandardError),
+            DISTRIB_UNCERTTYPE_VARIANCE => Some(UncertType::Variance),
+            DISTRIB_UNCERTTYPE_SAMPLESIZE => Some(UncertType::SampleSize),
+            DISTRIB_UNCERTTYPE_SKEWNESS => Some(UncertType::Skewness),
+            DISTRIB_UNCERTTYPE_KURTOSIS => Some(UncertType::Kurtosis),
+            _ => None,
+        }
+    }
+
+    /// Sets the statistic held by the parameter.
+    ///
+    /// # Arguments
+    /// * `uncert_type` - The type of the parameter
+    pub fn set_uncert_type(&self, uncert_type: UncertType) {
+        self.inner.borrow_mut().as_mut().setType(uncert_type.into());
+    }
+
+    /// Returns the numeric value of the statistic.
+    ///
+    /// # Returns
+    /// The value, None if not set
+    pub fn value(&self) -> Option<f64> {
+        let parameter = self.inner.borrow();
+        parameter.isSetValue().then(|| parameter.getValue())
+    }
+
+    /// Sets the numeric value of the statistic.
+    ///
+    /// # Arguments
+    /// * `value` - The value to set
+    pub fn set_value(&self, value: f64) {
+        self.inner.borrow_mut().as_mut().setValue(value);
+    }
+
+    /// Returns the identifier of the model element holding the statistic.
+    ///
+    /// # Returns
+    /// The identifier, None if not set
+    pub fn var(&self) -> Option<String> {
+        let parameter = self.inner.borrow();
+        parameter
+            .isSetVar()
+            .then(|| parameter.getVar().to_str().unwrap().to_string())
+    }
+
+    /// Sets the model element holding the statistic.
+    ///
+    /// # Arguments
+    /// * `var` - The identifier of a parameter or other model element
+    pub fn set_var(&self, var: &str) {
+        let_cxx_string!(var = var);
+        self.inner.borrow_mut().as_mut().setVar(&var);
+    }
+
+    /// Returns the units of the statistic.
+    ///
+    /// # Returns
+    /// The unit identifier, None if not set
+    pub fn units(&self) -> Option<String> {
+        let parameter = self.inner.borrow();
+        parameter
+            .isSetUnits()
+            .then(|| parameter.getUnits().to_str().unwrap().to_string())
+    }
+
+    /// Sets the units of the statistic.
+    ///
+    /// # Arguments
+    /// * `units` - The unit identifier
+    pub fn set_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setUnits(&units);
+    }
+
+    /// Returns the URL defining an external parameter.
+    ///
+    /// # Returns
+    /// The definition URL, None if not set
+    pub fn definition_url(&self) -> Option<String> {
+        let parameter = self.inner.borrow();
+        parameter
+            .isSetDefinitionURL()
+            .then(|| parameter.getDefinitionURL().to_str().unwrap().to_string())
+    }
+
+    /// Sets the URL defining an external parameter.
+    ///
+    /// # Arguments
+    /// * `url` - The definition URL, e.g. of a ProbOnto distribution
+    pub fn set_definition_url(&self, url: &str) {
+        let_cxx_string!(url = url);
+        self.inner.borrow_mut().as_mut().setDefinitionURL(&url);
+    }
+
+    /// Returns the formula describing the distribution.
+    ///
+    /// # Returns
+    /// The formula in the SBML Level 3 infix syntax, None if no math is set
+    pub fn formula(&self) -> Option<String> {
+        let parameter = self.inner.borrow();
+        parameter
+            .isSetMath()
+            .then(|| formula_to_string(parameter.getMath()))
+    }
+
+    /// Sets the formula describing the distribution.
+    ///
+    /// Formulas that cannot be parsed by libSBML leave the math unchanged.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula in the SBML Level 3 infix syntax, e.g. `normal(0, 1)`
+    pub fn set_formula(&self, formula: &str) {
+        if let Some(math) = parse_formula(formula) {
+            unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        }
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::UncertParameter, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::UncertParameter> for UncertParameter<'_> {
+    /// Creates a new UncertParameter instance from a unique pointer to a libSBML
+    /// UncertParameter.
+    ///
+    /// This method is primarily used internally by the Uncertainty class to create
+    /// UncertParameter instances from libSBML UncertParameter pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML UncertParameter
+    ///
+    /// # Returns
+    /// A new UncertParameter instance
+    fn from_ptr(ptr: *mut sbmlcxx::UncertParameter) -> Self {
+        let parameter = pin_ptr!(ptr, sbmlcxx::UncertParameter);
+        Self {
+            inner: RefCell::new(parameter),
+        }
+    }
+}
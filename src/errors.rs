@@ -0,0 +1,205 @@
+//! Errors reported by libSBML for SBML documents
+//!
+//! libSBML records problems found while reading, converting and validating a document
+//! in the error log of the document. This module provides owned copies of these log
+//! entries, see [`SBMLDocument::errors`] and [`SBMLDocument::check_consistency`].
+
+use std::{error::Error, fmt};
+
+use serde::Serialize;
+
+use crate::{sbmlcxx, sbmldoc::SBMLDocument};
+
+/// The severity of an entry in the error log of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// An informational message, e.g. a recommendation
+    Info,
+    /// A problem that does not make the document invalid
+    Warning,
+    /// A violation of the SBML specification
+    Error,
+    /// A problem that prevented libSBML from processing the document
+    Fatal,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+            Severity::Fatal => write!(f, "fatal"),
+        }
+    }
+}
+
+/// An entry of the error log of a document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SBMLError {
+    /// The libSBML error code, e.g. 10501 for inconsistent units
+    pub id: u32,
+    /// The severity of the error
+    pub severity: Severity,
+    /// The category of the error, e.g. "SBML unit consistency"
+    pub category: String,
+    /// The line of the document the error was found at, 0 if unknown
+    pub line: u32,
+    /// The column of the document the error was found at, 0 if unknown
+    pub column: u32,
+    /// The message describing the error
+    pub message: String,
+}
+
+impl SBMLError {
+    /// Returns whether the entry makes the document invalid.
+    pub fn is_error(&self) -> bool {
+        self.severity >= Severity::Error
+    }
+
+    /// Copies an entry of the error log of libSBML.
+    fn from_xml_error(error: &sbmlcxx::XMLError) -> Self {
+        let severity = if error.isFatal() {
+            Severity::Fatal
+        } else if error.isError() {
+            Severity::Error
+        } else if error.isWarning() {
+            Severity::Warning
+        } else {
+            Severity::Info
+        };
+
+        Self {
+            id: error.getErrorId().0,
+            severity,
+            category: error.getCategoryAsString().to_string_lossy().into_owned(),
+            line: error.getLine().0,
+            column: error.getColumn().0,
+            message: error.getMessage().to_string_lossy().trim().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SBMLError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}:{}: ", self.line, self.column)?;
+        }
+        write!(
+            f,
+            "{} {} ({}): {}",
+            self.severity, self.id, self.category, self.message
+        )
+    }
+}
+
+impl Error for SBMLError {}
+
+/// Copies all entries of the error log of a document.
+///
+/// # Arguments
+/// * `document` - The document to read the error log of
+pub(crate) fn error_log(document: &SBMLDocument) -> Vec<SBMLError> {
+    let mut document_ptr = document.inner().borrow_mut();
+    let Some(document) = document_ptr.as_mut() else {
+        return Vec::new();
+    };
+
+    let n_errors = document.getNumErrors().0;
+    (0..n_errors)
+        .map(|i| {
+            let error_ptr = document.getError(i.into()).cast::<sbmlcxx::XMLError>();
+            SBMLError::from_xml_error(unsafe { &*error_ptr })
+        })
+        .collect()
+}
+
+/// Error returned when a document could not be converted to another SBML level and
+/// version.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionError {
+    /// The requested SBML level
+    pub level: u32,
+    /// The requested SBML version
+    pub version: u32,
+    /// The errors libSBML reported for the conversion
+    pub errors: Vec<SBMLError>,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to convert document to SBML Level {} Version {}",
+            self.level, self.version
+        )?;
+        let messages: Vec<&str> = self
+            .errors
+            .iter()
+            .filter(|error| error.is_error())
+            .map(|error| error.message.as_str())
+            .collect();
+        if !messages.is_empty() {
+            write!(f, ": {}", messages.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ConversionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::SBMLReader;
+
+    #[test]
+    fn test_valid_document_has_no_errors() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = doc.create_model("test");
+        model.build_compartment("cytosol").constant(true).build();
+
+        let errors = doc.check_consistency();
+        assert!(
+            errors.iter().all(|error| !error.is_error()),
+            "Unexpected errors: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let doc = SBMLReader::from_xml_string("<sbml><model></sbml>");
+
+        let errors = doc.errors();
+        assert!(!errors.is_empty());
+        assert!(errors.iter().any(|error| error.is_error()));
+    }
+
+    #[test]
+    fn test_consistency_errors() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = doc.create_model("test");
+        model
+            .build_species("glucose")
+            .compartment("missing")
+            .build();
+
+        let errors = doc.check_consistency();
+        let error = errors
+            .iter()
+            .find(|error| error.is_error())
+            .expect("Missing compartment must be reported");
+        assert!(!error.message.is_empty());
+        assert!(error.to_string().contains("error"));
+    }
+
+    #[test]
+    fn test_severity_order() {
+        assert!(Severity::Info < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert!(Severity::Error < Severity::Fatal);
+        assert_eq!(Severity::Warning.to_string(), "warning");
+    }
+}
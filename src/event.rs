@@ -0,0 +1,497 @@
+//! This module provides a safe Rust interface to the libSBML Event class.
+//!
+//! The Event class describes discontinuous changes of a model. An event consists of a
+//! trigger condition, an optional delay and a list of event assignments that are
+//! executed when the trigger switches from false to true.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Event class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    delay::Delay,
+    eventassign::EventAssignment,
+    inner,
+    model::Model,
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    trigger::Trigger,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Event class.
+///
+/// This struct maintains a reference to the underlying C++ Event object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the trigger, delay and event assignments of the event.
+pub struct Event<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Event>>,
+    trigger: RefCell<Option<Rc<Trigger<'a>>>>,
+    delay: RefCell<Option<Rc<Delay<'a>>>>,
+    event_assignments: RefCell<Vec<Rc<EventAssignment<'a>>>>,
+}
+
+// Set the inner trait for the Event struct
+inner!(sbmlcxx::Event, Event<'a>);
+
+// Set the annotation trait for the Event struct
+upcast_annotation!(Event<'a>, sbmlcxx::Event, sbmlcxx::SBase);
+
+// Set the SBase trait for the Event struct
+sbase!(Event<'a>, sbmlcxx::Event);
+
+impl<'a> Event<'a> {
+    /// Creates a new Event instance within the given Model.
+    ///
+    /// Assignments are computed from the values at the time the event triggers,
+    /// which matches the behaviour of events in SBML Level 2.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this event
+    /// * `id` - The identifier for this event
+    ///
+    /// # Returns
+    /// A new Event instance
+    pub fn new(model: &Model<'a>, id: &str) -> Self {
+        let event_ptr = model.inner().borrow_mut().as_mut().createEvent();
+        let mut event = pin_ptr!(event_ptr, sbmlcxx::Event);
+
+        let_cxx_string!(id = id);
+        event.as_mut().setId(&id);
+        event.as_mut().setUseValuesFromTriggerTime(true);
+
+        Self {
+            inner: RefCell::new(event),
+            trigger: RefCell::new(None),
+            delay: RefCell::new(None),
+            event_assignments: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a reference to the inner RefCell containing the Event pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Event>> {
+        &self.inner
+    }
+
+    /// Returns the id of the event.
+    ///
+    /// # Returns
+    /// The id of the event as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the event.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the event.
+    ///
+    /// # Returns
+    /// The name of the event as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the event.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns whether assignments are computed when the event triggers.
+    ///
+    /// # Returns
+    /// true if assignments use the values at trigger time, false if they use the
+    /// values at execution time
+    pub fn use_values_from_trigger_time(&self) -> bool {
+        self.inner.borrow().getUseValuesFromTriggerTime()
+    }
+
+    /// Sets whether assignments are computed when the event triggers.
+    ///
+    /// # Arguments
+    /// * `use_values_from_trigger_time` - Whether to use the values at trigger time
+    pub fn set_use_values_from_trigger_time(&self, use_values_from_trigger_time: bool) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setUseValuesFromTriggerTime(use_values_from_trigger_time);
+    }
+
+    /// Returns the trigger of the event.
+    ///
+    /// # Returns
+    /// Some(Rc<Trigger>) if the event has a trigger, None otherwise
+    pub fn trigger(&self) -> Option<Rc<Trigger<'a>>> {
+        self.trigger.borrow().as_ref().map(Rc::clone)
+    }
+
+    /// Creates the trigger of the event, replacing an existing one.
+    ///
+    /// # Arguments
+    /// * `formula` - The condition of the trigger
+    ///
+    /// # Returns
+    /// A new Trigger instance wrapped in an Rc
+    pub fn create_trigger(&self, formula: &str) -> Rc<Trigger<'a>> {
+        let trigger = Rc::new(Trigger::new(self, formula));
+        self.trigger.borrow_mut().replace(Rc::clone(&trigger));
+        trigger
+    }
+
+    /// Returns the delay of the event.
+    ///
+    /// # Returns
+    /// Some(Rc<Delay>) if the event has a delay, None otherwise
+    pub fn delay(&self) -> Option<Rc<Delay<'a>>> {
+        self.delay.borrow().as_ref().map(Rc::clone)
+    }
+
+    /// Creates the delay of the event, replacing an existing one.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula defining the length of the delay
+    ///
+    /// # Returns
+    /// A new Delay instance wrapped in an Rc
+    pub fn create_delay(&self, formula: &str) -> Rc<Delay<'a>> {
+        let delay = Rc::new(Delay::new(self, formula));
+        self.delay.borrow_mut().replace(Rc::clone(&delay));
+        delay
+    }
+
+    /// Creates a new event assignment within this event.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the quantity to change
+    /// * `formula` - The formula defining the new value
+    ///
+    /// # Returns
+    /// A new EventAssignment instance wrapped in an Rc
+    pub fn create_event_assignment(
+        &self,
+        variable: &str,
+        formula: &str,
+    ) -> Rc<EventAssignment<'a>> {
+        let assignment = Rc::new(EventAssignment::new(self, variable, formula));
+        self.event_assignments
+            .borrow_mut()
+            .push(Rc::clone(&assignment));
+        assignment
+    }
+
+    /// Returns a vector of all event assignments of the event.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all EventAssignments of the event
+    pub fn list_of_event_assignments(&self) -> Vec<Rc<EventAssignment<'a>>> {
+        self.event_assignments.borrow().to_vec()
+    }
+
+    /// Retrieves an event assignment by the variable it changes.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the changed quantity
+    ///
+    /// # Returns
+    /// Some(Rc<EventAssignment>) if found, None if not found
+    pub fn get_event_assignment(&self, variable: &str) -> Option<Rc<EventAssignment<'a>>> {
+        self.event_assignments
+            .borrow()
+            .iter()
+            .find(|assignment| (*assignment).variable() == variable)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Event, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Event> for Event<'_> {
+    /// Creates a new Event instance from a unique pointer to a libSBML Event.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// Event instances from libSBML Event pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Event
+    ///
+    /// # Returns
+    /// A new Event instance
+    fn from_ptr(ptr: *mut sbmlcxx::Event) -> Self {
+        let event = RefCell::new(pin_ptr!(ptr, sbmlcxx::Event));
+
+        let trigger = if event.borrow().isSetTrigger() {
+            let trigger = event.borrow_mut().as_mut().getTrigger1();
+            Some(Rc::new(Trigger::from_ptr(trigger)))
+        } else {
+            None
+        };
+
+        let delay = if event.borrow().isSetDelay() {
+            let delay = event.borrow_mut().as_mut().getDelay1();
+            Some(Rc::new(Delay::from_ptr(delay)))
+        } else {
+            None
+        };
+
+        // Fetch all event assignments
+        let n_assignments = event.borrow().getNumEventAssignments().0;
+        let event_assignments: Vec<_> = (0..n_assignments)
+            .map(|i| {
+                let assignment = event.borrow_mut().as_mut().getEventAssignment1(i.into());
+                Rc::new(EventAssignment::from_ptr(assignment))
+            })
+            .collect();
+
+        Self {
+            inner: event,
+            trigger: RefCell::new(trigger),
+            delay: RefCell::new(delay),
+            event_assignments: RefCell::new(event_assignments),
+        }
+    }
+}
+
+/// A builder for creating Event instances with a fluent interface.
+///
+/// # Example
+/// ```no_run
+/// use sbml::prelude::*;
+///
+/// let doc = SBMLDocument::default();
+/// let model = Model::new(&doc, "test");
+/// let event = model
+///     .build_event("feed")
+///     .trigger("time >= 10")
+///     .delay("2")
+///     .assignment("glucose", "glucose + 5")
+///     .build();
+/// ```
+pub struct EventBuilder<'a> {
+    event: Rc<Event<'a>>,
+}
+
+impl<'a> EventBuilder<'a> {
+    /// Creates a new EventBuilder instance.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain the event
+    /// * `id` - The identifier for the event
+    ///
+    /// # Returns
+    /// A new EventBuilder instance
+    pub fn new(model: &Model<'a>, id: &str) -> Self {
+        let event = model.create_event(id);
+        Self { event }
+    }
+
+    /// Sets the name of the event.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.event.set_name(name);
+        self
+    }
+
+    /// Sets the trigger condition of the event.
+    ///
+    /// # Arguments
+    /// * `formula` - The condition of the trigger
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn trigger(self, formula: &str) -> Self {
+        self.event.create_trigger(formula);
+        self
+    }
+
+    /// Sets the delay of the event.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula defining the length of the delay
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn delay(self, formula: &str) -> Self {
+        self.event.create_delay(formula);
+        self
+    }
+
+    /// Adds an event assignment to the event.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the quantity to change
+    /// * `formula` - The formula defining the new value
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn assignment(self, variable: &str, formula: &str) -> Self {
+        self.event.create_event_assignment(variable, formula);
+        self
+    }
+
+    /// Sets whether assignments are computed when the event triggers.
+    ///
+    /// # Arguments
+    /// * `use_values_from_trigger_time` - Whether to use the values at trigger time
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn use_values_from_trigger_time(self, use_values_from_trigger_time: bool) -> Self {
+        self.event
+            .set_use_values_from_trigger_time(use_values_from_trigger_time);
+        self
+    }
+
+    /// Sets the annotation string for this event.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.event
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.event.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Event.
+    ///
+    /// # Returns
+    /// The constructed Event instance wrapped in an Rc
+    pub fn build(self) -> Rc<Event<'a>> {
+        self.event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_event_new() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let event = Event::new(&model, "e1");
+
+        event.set_name("Event 1");
+        assert_eq!(event.id(), "e1");
+        assert_eq!(event.name(), "Event 1");
+        assert!(event.use_values_from_trigger_time());
+        assert!(event.trigger().is_none());
+        assert!(event.delay().is_none());
+    }
+
+    #[test]
+    fn test_event_builder() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let event = EventBuilder::new(&model, "e1")
+            .trigger("x > 1")
+            .delay("2")
+            .assignment("x", "0")
+            .assignment("y", "y + 1")
+            .use_values_from_trigger_time(false)
+            .build();
+
+        let trigger = event.trigger().expect("Trigger not found");
+        assert_eq!(trigger.formula(), "x > 1");
+        assert!(trigger.initial_value());
+        assert!(trigger.persistent());
+
+        assert_eq!(event.delay().expect("Delay not found").formula(), "2");
+        assert!(!event.use_values_from_trigger_time());
+
+        assert_eq!(event.list_of_event_assignments().len(), 2);
+        let assignment = event.get_event_assignment("y").unwrap();
+        assert_eq!(assignment.formula(), "y + 1");
+        assert!(event.get_event_assignment("z").is_none());
+    }
+
+    #[test]
+    fn test_trigger_attributes() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let event = model.create_event("e1");
+        let trigger = event.create_trigger("time >= 5");
+
+        trigger.set_initial_value(false);
+        trigger.set_persistent(false);
+        trigger.set_formula("time >= 10");
+
+        assert!(!trigger.initial_value());
+        assert!(!trigger.persistent());
+        assert_eq!(event.trigger().unwrap().formula(), "time >= 10");
+    }
+
+    #[test]
+    fn test_event_read_from_xml() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model
+            .build_event("e1")
+            .trigger("x > 1")
+            .delay("2")
+            .assignment("x", "0")
+            .build();
+
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = read.model().expect("Model not found");
+        let event = model.get_event("e1").expect("Event not found");
+
+        assert_eq!(event.trigger().unwrap().formula(), "x > 1");
+        assert_eq!(event.delay().unwrap().formula(), "2");
+        assert_eq!(event.get_event_assignment("x").unwrap().formula(), "0");
+    }
+
+    #[test]
+    fn test_annotation() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let event = EventBuilder::new(&model, "e1")
+            .annotation("<test>test</test>")
+            .expect("Failed to set annotation")
+            .build();
+
+        assert_eq!(
+            event.get_annotation().replace("\n", "").replace(' ', ""),
+            "<annotation><test>test</test></annotation>"
+        );
+    }
+}
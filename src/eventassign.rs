@@ -0,0 +1,134 @@
+//! This module provides a safe Rust interface to the libSBML EventAssignment class.
+//!
+//! The EventAssignment class describes a change of a species, compartment, parameter
+//! or species reference that is carried out when its event executes.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML EventAssignment class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    astnode::{formula_to_string, parse_formula},
+    event::Event,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML EventAssignment class.
+///
+/// This struct maintains a reference to the underlying C++ EventAssignment object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct EventAssignment<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::EventAssignment>>,
+}
+
+// Set the inner trait for the EventAssignment struct
+inner!(sbmlcxx::EventAssignment, EventAssignment<'a>);
+
+// Set the annotation trait for the EventAssignment struct
+upcast_annotation!(
+    EventAssignment<'a>,
+    sbmlcxx::EventAssignment,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the EventAssignment struct
+sbase!(EventAssignment<'a>, sbmlcxx::EventAssignment);
+
+impl<'a> EventAssignment<'a> {
+    /// Creates a new EventAssignment instance within the given Event.
+    ///
+    /// # Arguments
+    /// * `event` - The parent Event that will contain this assignment
+    /// * `variable` - The identifier of the quantity to change
+    /// * `formula` - The formula defining the new value
+    ///
+    /// # Returns
+    /// A new EventAssignment instance
+    pub fn new(event: &Event<'a>, variable: &str, formula: &str) -> Self {
+        let assignment_ptr = event.inner().borrow_mut().as_mut().createEventAssignment();
+        let assignment = pin_ptr!(assignment_ptr, sbmlcxx::EventAssignment);
+
+        let event_assignment = Self {
+            inner: RefCell::new(assignment),
+        };
+        event_assignment.set_variable(variable);
+        event_assignment.set_formula(formula);
+        event_assignment
+    }
+
+    /// Returns the variable changed by this assignment.
+    ///
+    /// # Returns
+    /// The identifier of the changed quantity as a String
+    pub fn variable(&self) -> String {
+        self.inner
+            .borrow()
+            .getVariable()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the variable changed by this assignment.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the quantity to change
+    pub fn set_variable(&self, variable: &str) {
+        let_cxx_string!(variable = variable);
+        self.inner.borrow_mut().as_mut().setVariable(&variable);
+    }
+
+    /// Returns the formula of the assignment.
+    ///
+    /// # Returns
+    /// The formula in the SBML Level 3 infix syntax, empty if no math is set
+    pub fn formula(&self) -> String {
+        formula_to_string(self.inner.borrow().getMath())
+    }
+
+    /// Returns the math of the assignment, null if no math is set.
+    pub(crate) fn math(&self) -> *const sbmlcxx::ASTNode {
+        self.inner.borrow().getMath()
+    }
+
+    /// Sets the formula of the assignment.
+    ///
+    /// Formulas that cannot be parsed by libSBML leave the math unchanged.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula in the SBML Level 3 infix syntax
+    pub fn set_formula(&self, formula: &str) {
+        if let Some(math) = parse_formula(formula) {
+            unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        }
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::EventAssignment, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::EventAssignment> for EventAssignment<'_> {
+    /// Creates a new EventAssignment instance from a unique pointer to a libSBML EventAssignment.
+    ///
+    /// This method is primarily used internally by the Event class to create
+    /// EventAssignment instances from libSBML EventAssignment pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML EventAssignment
+    ///
+    /// # Returns
+    /// A new EventAssignment instance
+    fn from_ptr(ptr: *mut sbmlcxx::EventAssignment) -> Self {
+        let assignment = pin_ptr!(ptr, sbmlcxx::EventAssignment);
+        Self {
+            inner: RefCell::new(assignment),
+        }
+    }
+}
@@ -0,0 +1,728 @@
+//! Flux balance analysis of constraint-based metabolic models.
+//!
+//! Flux balance analysis (FBA) assumes a steady state of all internal species, such
+//! that the fluxes `v` of the reactions satisfy `N v = 0` for the stoichiometry matrix
+//! `N`. Within the flux bounds of each reaction, the fluxes optimizing a linear
+//! objective are found with a bundled simplex solver.
+//!
+//! Models are usually annotated with the flux balance constraints (fbc) package, which
+//! provides the flux bounds, the objectives and the gene associations of reactions.
+//! [`FbaProblem::from_document`] reads these through the wrappers of the [`fbc`](crate::fbc)
+//! module, while
+//! [`FbaProblem::from_model`] and [`FbaProblem::new`] start from unbounded fluxes and
+//! leave the constraints to [`FbaProblem::set_bounds`] and
+//! [`FbaProblem::set_objective`].
+//!
+//! Besides single optimizations, the module provides:
+//!
+//! - Flux variability analysis, i.e. the range of each flux among (near) optimal
+//!   solutions
+//! - Single reaction and gene knockout scans
+//!
+//! Species with a boundary condition are excluded from the steady-state constraints, as
+//! they act as sources and sinks of the network.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//! use sbml::fba::FbaProblem;
+//!
+//! let doc = SBMLReader::from_xml_string(&std::fs::read_to_string("e_coli_core.xml").unwrap());
+//! let problem = FbaProblem::from_document(&doc).unwrap();
+//!
+//! let solution = problem.optimize().unwrap();
+//! println!("Growth rate: {}", solution.objective_value);
+//!
+//! for result in problem.gene_knockouts() {
+//!     if result.objective.unwrap_or(0.0) < 1e-6 {
+//!         println!("{} is essential", result.id);
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::{
+    fbc::{FluxBoundOperation, GeneRule, ObjectiveSense},
+    model::Model,
+    sbmldoc::SBMLDocument,
+    stoichiometry::StoichiometryMatrix,
+};
+
+mod simplex;
+
+use simplex::{LpError, Simplex};
+
+/// Relative tolerance by which flux variability analysis may miss the optimum.
+const OPTIMUM_TOLERANCE: f64 = 1e-9;
+
+/// A flux balance problem: stoichiometry, flux bounds, objective and gene rules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FbaProblem {
+    /// The steady-state constraints
+    matrix: StoichiometryMatrix,
+    /// Lower flux bound of each reaction
+    lower: Vec<f64>,
+    /// Upper flux bound of each reaction
+    upper: Vec<f64>,
+    /// Objective coefficient of each reaction
+    objective: Vec<f64>,
+    /// Direction of the objective
+    sense: ObjectiveSense,
+    /// Gene rule of each reaction, None if it does not depend on genes
+    gene_rules: Vec<Option<GeneRule>>,
+    /// All genes in order of declaration
+    genes: Vec<String>,
+}
+
+impl FbaProblem {
+    /// Creates a problem from a stoichiometry matrix.
+    ///
+    /// All fluxes are unbounded and the objective is zero.
+    ///
+    /// # Arguments
+    /// * `matrix` - The stoichiometry matrix of the internal species
+    pub fn new(matrix: StoichiometryMatrix) -> Self {
+        let n = matrix.reactions().len();
+        Self {
+            matrix,
+            lower: vec![f64::NEG_INFINITY; n],
+            upper: vec![f64::INFINITY; n],
+            objective: vec![0.0; n],
+            sense: ObjectiveSense::default(),
+            gene_rules: vec![None; n],
+            genes: Vec::new(),
+        }
+    }
+
+    /// Creates a problem from the reactions of a model, ignoring fbc annotations.
+    ///
+    /// All fluxes are unbounded and the objective is zero.
+    ///
+    /// # Arguments
+    /// * `model` - The model to analyze
+    pub fn from_model(model: &Model) -> Self {
+        Self::new(StoichiometryMatrix::from_model(model, true))
+    }
+
+    /// Creates a problem from a document annotated with the fbc package.
+    ///
+    /// Flux bounds, the active objective and gene product associations are read from
+    /// version 1 or version 2 of the package. Reactions without bounds are limited to
+    /// non-negative fluxes if they are irreversible and unbounded otherwise.
+    ///
+    /// # Arguments
+    /// * `document` - The document containing the model
+    ///
+    /// # Returns
+    /// The problem or an error if the fbc data cannot be resolved
+    pub fn from_document(document: &SBMLDocument) -> Result<Self, FbaError> {
+        let model = document
+            .model()
+            .ok_or_else(|| FbaError::InvalidModel("Document has no model".to_string()))?;
+        let mut problem = Self::from_model(&model);
+
+        let parameter = |id: &str| -> Result<f64, FbaError> {
+            model
+                .get_parameter(id)
+                .and_then(|parameter| parameter.value())
+                .ok_or_else(|| FbaError::InvalidModel(format!("Flux bound '{}' has no value", id)))
+        };
+
+        for j in 0..problem.reactions().len() {
+            let Some(reaction) = model.get_reaction(&problem.reactions()[j]) else {
+                continue;
+            };
+            if !reaction.reversible() {
+                problem.lower[j] = 0.0;
+            }
+            if let Some(id) = reaction.lower_flux_bound() {
+                problem.lower[j] = parameter(&id)?;
+            }
+            if let Some(id) = reaction.upper_flux_bound() {
+                problem.upper[j] = parameter(&id)?;
+            }
+            problem.gene_rules[j] = reaction
+                .gene_product_association()
+                .and_then(|association| association.association());
+        }
+
+        let Some(fbc) = model.fbc_plugin_if_enabled() else {
+            return Ok(problem);
+        };
+
+        for bound in fbc.list_of_flux_bounds() {
+            let j = problem.index(&bound.reaction())?;
+            match bound.operation() {
+                Some(FluxBoundOperation::LessEqual) => problem.upper[j] = bound.value(),
+                Some(FluxBoundOperation::GreaterEqual) => problem.lower[j] = bound.value(),
+                Some(FluxBoundOperation::Equal) => {
+                    problem.lower[j] = bound.value();
+                    problem.upper[j] = bound.value();
+                }
+                None => {
+                    return Err(FbaError::InvalidModel(format!(
+                        "Flux bound of reaction '{}' has no valid operation",
+                        bound.reaction()
+                    )))
+                }
+            }
+        }
+
+        // Without an active objective, the first one is used
+        let objective = fbc
+            .get_active_objective()
+            .or_else(|| fbc.list_of_objectives().first().cloned());
+        if let Some(objective) = objective {
+            let flux_objectives: Vec<(String, f64)> = objective
+                .list_of_flux_objectives()
+                .iter()
+                .map(|flux_objective| (flux_objective.reaction(), flux_objective.coefficient()))
+                .collect();
+            let coefficients: Vec<(&str, f64)> = flux_objectives
+                .iter()
+                .map(|(reaction, coefficient)| (reaction.as_str(), *coefficient))
+                .collect();
+            problem.set_objective(&coefficients, objective.sense().unwrap_or_default())?;
+        }
+
+        problem.genes = fbc
+            .list_of_gene_products()
+            .iter()
+            .map(|gene_product| gene_product.id())
+            .collect();
+        Ok(problem)
+    }
+
+    /// Returns the reaction identifiers in the order of the flux vector.
+    pub fn reactions(&self) -> &[String] {
+        self.matrix.reactions()
+    }
+
+    /// Returns the species identifiers in the order of the steady-state constraints.
+    pub fn species(&self) -> &[String] {
+        self.matrix.species()
+    }
+
+    /// Returns the genes referenced by the gene rules.
+    pub fn genes(&self) -> &[String] {
+        &self.genes
+    }
+
+    /// Returns the direction of the objective.
+    pub fn sense(&self) -> ObjectiveSense {
+        self.sense
+    }
+
+    /// Returns the flux bounds of a reaction.
+    pub fn bounds(&self, reaction: &str) -> Option<(f64, f64)> {
+        let j = self.index(reaction).ok()?;
+        Some((self.lower[j], self.upper[j]))
+    }
+
+    /// Sets the flux bounds of a reaction.
+    ///
+    /// # Arguments
+    /// * `reaction` - The identifier of the reaction
+    /// * `lower` - The lower bound, may be negative infinity
+    /// * `upper` - The upper bound, may be infinity
+    pub fn set_bounds(&mut self, reaction: &str, lower: f64, upper: f64) -> Result<(), FbaError> {
+        let j = self.index(reaction)?;
+        self.lower[j] = lower;
+        self.upper[j] = upper;
+        Ok(())
+    }
+
+    /// Returns the gene rule of a reaction.
+    pub fn gene_rule(&self, reaction: &str) -> Option<&GeneRule> {
+        let j = self.index(reaction).ok()?;
+        self.gene_rules[j].as_ref()
+    }
+
+    /// Sets the gene rule of a reaction and registers its genes.
+    ///
+    /// # Arguments
+    /// * `reaction` - The identifier of the reaction
+    /// * `rule` - The gene rule, None if the reaction does not depend on genes
+    pub fn set_gene_rule(
+        &mut self,
+        reaction: &str,
+        rule: Option<GeneRule>,
+    ) -> Result<(), FbaError> {
+        let j = self.index(reaction)?;
+        if let Some(rule) = &rule {
+            for gene in rule.genes() {
+                if !self.genes.iter().any(|g| g == gene) {
+                    self.genes.push(gene.to_string());
+                }
+            }
+        }
+        self.gene_rules[j] = rule;
+        Ok(())
+    }
+
+    /// Replaces the objective.
+    ///
+    /// # Arguments
+    /// * `coefficients` - Reactions and their weights in the objective
+    /// * `sense` - Whether the objective is maximized or minimized
+    pub fn set_objective(
+        &mut self,
+        coefficients: &[(&str, f64)],
+        sense: ObjectiveSense,
+    ) -> Result<(), FbaError> {
+        let mut objective = vec![0.0; self.reactions().len()];
+        for (reaction, coefficient) in coefficients {
+            objective[self.index(reaction)?] += coefficient;
+        }
+        self.objective = objective;
+        self.sense = sense;
+        Ok(())
+    }
+
+    /// Finds fluxes that optimize the objective.
+    ///
+    /// # Returns
+    /// The optimal solution or the reason why there is none
+    pub fn optimize(&self) -> Result<FbaSolution, FbaError> {
+        self.optimize_with(&self.lower, &self.upper)
+    }
+
+    /// Computes the range of each flux among solutions close to the optimum.
+    ///
+    /// # Arguments
+    /// * `fraction_of_optimum` - The fraction of the optimal objective value that
+    ///   must be reached, between zero and one
+    ///
+    /// # Returns
+    /// The minimum and maximum flux of each reaction, where infinite values denote
+    /// unbounded fluxes
+    pub fn flux_variability(&self, fraction_of_optimum: f64) -> Result<Vec<FluxRange>, FbaError> {
+        if !(0.0..=1.0).contains(&fraction_of_optimum) {
+            return Err(FbaError::InvalidOptions(format!(
+                "Fraction of optimum must be between zero and one, got {}",
+                fraction_of_optimum
+            )));
+        }
+
+        // A small tolerance keeps the optimum itself feasible despite rounding
+        let optimum = self.optimize()?.objective_value;
+        let slack = (1.0 - fraction_of_optimum) * optimum.abs()
+            + OPTIMUM_TOLERANCE * optimum.abs().max(1.0);
+        let (lower, upper) = match self.sense {
+            ObjectiveSense::Maximize => (optimum - slack, f64::INFINITY),
+            ObjectiveSense::Minimize => (f64::NEG_INFINITY, optimum + slack),
+        };
+
+        // The objective becomes an extra variable z with the row c^T v - z = 0
+        let (m, n) = self.matrix.shape();
+        let cols = n + 1;
+        let mut a = vec![0.0; (m + 1) * cols];
+        for i in 0..m {
+            for j in 0..n {
+                a[i * cols + j] = self.matrix.value(i, j);
+            }
+        }
+        a[m * cols..m * cols + n].copy_from_slice(&self.objective);
+        a[m * cols + n] = -1.0;
+
+        let mut lower_bounds = self.lower.clone();
+        let mut upper_bounds = self.upper.clone();
+        lower_bounds.push(lower);
+        upper_bounds.push(upper);
+
+        let mut simplex = Simplex::new(&a, &vec![0.0; m + 1], &lower_bounds, &upper_bounds);
+        let mut cost = vec![0.0; cols];
+        let mut ranges = Vec::with_capacity(n);
+
+        for (j, reaction) in self.reactions().iter().enumerate() {
+            cost[j] = 1.0;
+            let minimum = extreme(simplex.minimize(&cost), f64::NEG_INFINITY)?;
+            cost[j] = -1.0;
+            let maximum = -extreme(simplex.minimize(&cost), f64::NEG_INFINITY)?;
+            cost[j] = 0.0;
+
+            ranges.push(FluxRange {
+                reaction: reaction.clone(),
+                minimum,
+                maximum,
+            });
+        }
+
+        Ok(ranges)
+    }
+
+    /// Optimizes the objective with each reaction knocked out in turn.
+    ///
+    /// # Returns
+    /// One result per reaction, in the order of [`FbaProblem::reactions`]
+    pub fn reaction_knockouts(&self) -> Vec<KnockoutResult> {
+        self.reactions()
+            .iter()
+            .enumerate()
+            .map(|(j, reaction)| KnockoutResult {
+                id: reaction.clone(),
+                objective: self.knockout(&[j]),
+            })
+            .collect()
+    }
+
+    /// Optimizes the objective with each gene knocked out in turn.
+    ///
+    /// A gene knockout disables all reactions whose gene rule becomes inactive.
+    ///
+    /// # Returns
+    /// One result per gene, in the order of [`FbaProblem::genes`]
+    pub fn gene_knockouts(&self) -> Vec<KnockoutResult> {
+        self.genes
+            .iter()
+            .map(|gene| {
+                let disabled: Vec<usize> = self
+                    .gene_rules
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, rule)| {
+                        rule.as_ref()
+                            .is_some_and(|rule| !rule.is_active(&|g| g == gene))
+                    })
+                    .map(|(j, _)| j)
+                    .collect();
+
+                KnockoutResult {
+                    id: gene.clone(),
+                    objective: self.knockout(&disabled),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the position of a reaction in the flux vector.
+    fn index(&self, reaction: &str) -> Result<usize, FbaError> {
+        self.reactions()
+            .iter()
+            .position(|r| r == reaction)
+            .ok_or_else(|| FbaError::UnknownReaction(reaction.to_string()))
+    }
+
+    /// Optimizes with the given reactions fixed to zero flux.
+    fn knockout(&self, disabled: &[usize]) -> Option<f64> {
+        let mut lower = self.lower.clone();
+        let mut upper = self.upper.clone();
+        for &j in disabled {
+            lower[j] = 0.0;
+            upper[j] = 0.0;
+        }
+        self.optimize_with(&lower, &upper)
+            .ok()
+            .map(|solution| solution.objective_value)
+    }
+
+    /// Optimizes the objective within the given flux bounds.
+    fn optimize_with(&self, lower: &[f64], upper: &[f64]) -> Result<FbaSolution, FbaError> {
+        let (m, n) = self.matrix.shape();
+        let a: Vec<f64> = (0..m)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .map(|(i, j)| self.matrix.value(i, j))
+            .collect();
+
+        // The solver minimizes, so maximization negates the objective and the duals
+        let scale = match self.sense {
+            ObjectiveSense::Maximize => -1.0,
+            ObjectiveSense::Minimize => 1.0,
+        };
+        let cost: Vec<f64> = self.objective.iter().map(|c| scale * c).collect();
+
+        let mut simplex = Simplex::new(&a, &vec![0.0; m], lower, upper);
+        let value = simplex.minimize(&cost)?;
+
+        Ok(FbaSolution {
+            objective_value: scale * value,
+            reactions: self.reactions().to_vec(),
+            fluxes: simplex.values().to_vec(),
+            reduced_costs: simplex.reduced_costs().iter().map(|d| scale * d).collect(),
+            species: self.species().to_vec(),
+            shadow_prices: simplex.duals().iter().map(|y| scale * y).collect(),
+        })
+    }
+}
+
+/// Maps an unbounded direction of flux variability analysis to an infinite value.
+fn extreme(result: Result<f64, LpError>, unbounded: f64) -> Result<f64, FbaError> {
+    match result {
+        Err(LpError::Unbounded) => Ok(unbounded),
+        other => Ok(other?),
+    }
+}
+
+/// The optimal fluxes of a flux balance problem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FbaSolution {
+    /// The optimal value of the objective
+    pub objective_value: f64,
+    /// The reaction identifiers, one per flux
+    pub reactions: Vec<String>,
+    /// The optimal flux of each reaction
+    pub fluxes: Vec<f64>,
+    /// The change of the objective per unit flux of each reaction
+    pub reduced_costs: Vec<f64>,
+    /// The species identifiers, one per shadow price
+    pub species: Vec<String>,
+    /// The change of the objective per unit net production of each species
+    pub shadow_prices: Vec<f64>,
+}
+
+impl FbaSolution {
+    /// Returns the flux of a reaction.
+    pub fn flux(&self, reaction: &str) -> Option<f64> {
+        let j = self.reactions.iter().position(|r| r == reaction)?;
+        Some(self.fluxes[j])
+    }
+
+    /// Returns the shadow price of a species.
+    pub fn shadow_price(&self, species: &str) -> Option<f64> {
+        let i = self.species.iter().position(|s| s == species)?;
+        Some(self.shadow_prices[i])
+    }
+}
+
+/// The range of a flux found by flux variability analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluxRange {
+    /// The identifier of the reaction
+    pub reaction: String,
+    /// The smallest possible flux
+    pub minimum: f64,
+    /// The largest possible flux
+    pub maximum: f64,
+}
+
+/// The outcome of a single knockout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnockoutResult {
+    /// The identifier of the knocked out reaction or gene
+    pub id: String,
+    /// The optimal objective value, None if the knockout makes the problem infeasible
+    /// or unbounded
+    pub objective: Option<f64>,
+}
+
+/// Errors that can occur while setting up or solving a flux balance problem.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FbaError {
+    /// The fbc annotations of the model are inconsistent
+    InvalidModel(String),
+    /// The given analysis options are invalid
+    InvalidOptions(String),
+    /// A reaction referenced by the caller or the objective does not exist
+    UnknownReaction(String),
+    /// No fluxes satisfy the steady state and the bounds
+    Infeasible,
+    /// The objective can be improved without limit
+    Unbounded,
+    /// The solver did not converge within its iteration limit
+    IterationLimit,
+}
+
+impl From<LpError> for FbaError {
+    fn from(error: LpError) -> Self {
+        match error {
+            LpError::Infeasible => FbaError::Infeasible,
+            LpError::Unbounded => FbaError::Unbounded,
+            LpError::IterationLimit => FbaError::IterationLimit,
+        }
+    }
+}
+
+impl fmt::Display for FbaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FbaError::InvalidModel(message) => write!(f, "Invalid model: {}", message),
+            FbaError::InvalidOptions(message) => write!(f, "Invalid options: {}", message),
+            FbaError::UnknownReaction(id) => write!(f, "Unknown reaction '{}'", id),
+            FbaError::Infeasible => write!(f, "No flux distribution satisfies the constraints"),
+            FbaError::Unbounded => write!(f, "The objective is unbounded"),
+            FbaError::IterationLimit => write!(f, "Iteration limit of the solver reached"),
+        }
+    }
+}
+
+impl std::error::Error for FbaError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// A toy network: uptake of A, two routes from A to B, and export of B.
+    fn toy_problem() -> FbaProblem {
+        let matrix = StoichiometryMatrix::new(
+            vec!["A".to_string(), "B".to_string()],
+            ["uptake", "route1", "route2", "export"]
+                .iter()
+                .map(|r| r.to_string())
+                .collect(),
+            vec![vec![1.0, -1.0, -1.0, 0.0], vec![0.0, 1.0, 2.0, -1.0]],
+        );
+
+        let mut problem = FbaProblem::new(matrix);
+        problem.set_bounds("uptake", 0.0, 10.0).unwrap();
+        problem.set_bounds("route1", 0.0, f64::INFINITY).unwrap();
+        problem.set_bounds("route2", 0.0, 4.0).unwrap();
+        problem.set_bounds("export", 0.0, f64::INFINITY).unwrap();
+        problem
+            .set_objective(&[("export", 1.0)], ObjectiveSense::Maximize)
+            .unwrap();
+        problem
+            .set_gene_rule(
+                "route1",
+                Some(GeneRule::Or(vec![
+                    GeneRule::Gene("g1".to_string()),
+                    GeneRule::Gene("g2".to_string()),
+                ])),
+            )
+            .unwrap();
+        problem
+            .set_gene_rule("route2", Some(GeneRule::Gene("g3".to_string())))
+            .unwrap();
+        problem
+    }
+
+    #[test]
+    fn test_optimize() {
+        let solution = toy_problem().optimize().unwrap();
+
+        // The efficient route is saturated and the rest takes the other route
+        assert!((solution.objective_value - 14.0).abs() < 1e-9);
+        assert!((solution.flux("route2").unwrap() - 4.0).abs() < 1e-9);
+        assert!((solution.flux("route1").unwrap() - 6.0).abs() < 1e-9);
+
+        // Each unit of A or B that accumulates is lost for the export
+        assert!((solution.shadow_price("A").unwrap() + 1.0).abs() < 1e-9);
+        assert!((solution.shadow_price("B").unwrap() + 1.0).abs() < 1e-9);
+        assert_eq!(solution.flux("missing"), None);
+    }
+
+    #[test]
+    fn test_flux_variability() {
+        let problem = toy_problem();
+
+        let ranges = problem.flux_variability(1.0).unwrap();
+        let route1 = ranges.iter().find(|r| r.reaction == "route1").unwrap();
+        assert!((route1.minimum - 6.0).abs() < 1e-6);
+        assert!((route1.maximum - 6.0).abs() < 1e-6);
+
+        // At half the optimum, route2 may carry no flux at all
+        let ranges = problem.flux_variability(0.5).unwrap();
+        let route2 = ranges.iter().find(|r| r.reaction == "route2").unwrap();
+        assert!(route2.minimum.abs() < 1e-9);
+        assert!((route2.maximum - 4.0).abs() < 1e-9);
+
+        assert!(matches!(
+            problem.flux_variability(1.5),
+            Err(FbaError::InvalidOptions(_))
+        ));
+    }
+
+    #[test]
+    fn test_knockouts() {
+        let problem = toy_problem();
+
+        let reactions = problem.reaction_knockouts();
+        assert_eq!(reactions[0].id, "uptake");
+        assert!(reactions[0].objective.unwrap().abs() < 1e-9);
+        assert!((reactions[1].objective.unwrap() - 8.0).abs() < 1e-9);
+        assert!((reactions[2].objective.unwrap() - 10.0).abs() < 1e-9);
+
+        // Knocking out one isozyme of route1 has no effect
+        let genes = problem.gene_knockouts();
+        assert_eq!(problem.genes(), ["g1", "g2", "g3"]);
+        assert!((genes[0].objective.unwrap() - 14.0).abs() < 1e-9);
+        assert!((genes[2].objective.unwrap() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_document() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_compartment("c").build();
+        model.build_species("A").compartment("c").build();
+        model.build_parameter("zero").value(0.0).build();
+        model.build_parameter("ten").value(10.0).build();
+        model
+            .build_reaction("uptake")
+            .product("A", 1.0)
+            .lower_flux_bound("zero")
+            .upper_flux_bound("ten")
+            .gene_product_association(&GeneRule::Gene("g1".to_string()))
+            .build();
+        model
+            .build_reaction("export")
+            .reactant("A", 1.0)
+            .reversible(false)
+            .build();
+
+        let fbc = model.fbc_plugin();
+        fbc.create_gene_product("g1", "b0001");
+        fbc.build_objective("obj", ObjectiveSense::Maximize)
+            .flux_objective("export", 1.0)
+            .build();
+
+        let problem = FbaProblem::from_document(&doc).unwrap();
+        assert_eq!(problem.bounds("uptake"), Some((0.0, 10.0)));
+        assert_eq!(problem.bounds("export"), Some((0.0, f64::INFINITY)));
+        assert_eq!(problem.genes(), ["g1"]);
+        assert_eq!(
+            problem.gene_rule("uptake"),
+            Some(&GeneRule::Gene("g1".to_string()))
+        );
+
+        let solution = problem.optimize().unwrap();
+        assert!((solution.objective_value - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_document_fbc_version_1() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level3/version1/core" xmlns:fbc="http://www.sbml.org/sbml/level3/version1/fbc/version1" level="3" version="1" fbc:required="false">
+  <model id="m">
+    <listOfCompartments>
+      <compartment id="c" constant="true"/>
+    </listOfCompartments>
+    <listOfSpecies>
+      <species id="A" compartment="c" hasOnlySubstanceUnits="false" boundaryCondition="false" constant="false"/>
+    </listOfSpecies>
+    <listOfReactions>
+      <reaction id="uptake" reversible="true" fast="false">
+        <listOfProducts>
+          <speciesReference species="A" stoichiometry="1" constant="true"/>
+        </listOfProducts>
+      </reaction>
+      <reaction id="export" reversible="true" fast="false">
+        <listOfReactants>
+          <speciesReference species="A" stoichiometry="1" constant="true"/>
+        </listOfReactants>
+      </reaction>
+    </listOfReactions>
+    <fbc:listOfFluxBounds>
+      <fbc:fluxBound fbc:id="b1" fbc:reaction="uptake" fbc:operation="greaterEqual" fbc:value="-5.5"/>
+      <fbc:fluxBound fbc:id="b2" fbc:reaction="uptake" fbc:operation="lessEqual" fbc:value="8"/>
+    </fbc:listOfFluxBounds>
+    <fbc:listOfObjectives fbc:activeObjective="obj">
+      <fbc:objective fbc:id="obj" fbc:type="minimize">
+        <fbc:listOfFluxObjectives>
+          <fbc:fluxObjective fbc:reaction="export" fbc:coefficient="1"/>
+        </fbc:listOfFluxObjectives>
+      </fbc:objective>
+    </fbc:listOfObjectives>
+  </model>
+</sbml>"#;
+        let doc = SBMLReader::from_xml_string(xml);
+
+        let problem = FbaProblem::from_document(&doc).unwrap();
+        assert_eq!(problem.bounds("uptake"), Some((-5.5, 8.0)));
+        assert_eq!(problem.sense(), ObjectiveSense::Minimize);
+
+        let solution = problem.optimize().unwrap();
+        assert!((solution.objective_value + 5.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,444 @@
+//! A dense bounded-variable primal simplex solver.
+//!
+//! The solver handles linear programs of the form
+//!
+//! ```text
+//! minimize    c^T x
+//! subject to  A x = b
+//!             l <= x <= u
+//! ```
+//!
+//! where bounds may be infinite. Nonbasic variables rest at one of their bounds (or at
+//! zero if they are free), so bounds never need to be turned into extra rows. A first
+//! phase with one artificial variable per row finds a feasible basis, which is kept
+//! between calls to [`Simplex::minimize`] such that re-optimizing with a different
+//! objective starts from the previous optimum.
+//!
+//! The tableau is stored densely, which is adequate for models with up to a few
+//! thousand reactions.
+
+/// Entries of the tableau with an absolute value below this are not used as pivots.
+const PIVOT_TOLERANCE: f64 = 1e-9;
+
+/// Reduced costs with an absolute value below this are considered optimal.
+const OPTIMALITY_TOLERANCE: f64 = 1e-9;
+
+/// Remaining infeasibility after the first phase that is still accepted.
+const FEASIBILITY_TOLERANCE: f64 = 1e-7;
+
+/// Number of consecutive degenerate pivots after which Bland's rule prevents cycling.
+const DEGENERATE_PIVOTS: usize = 50;
+
+/// Reasons why a linear program has no optimal solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LpError {
+    /// No point satisfies all constraints
+    Infeasible,
+    /// The objective can be improved without limit
+    Unbounded,
+    /// The iteration limit was reached before the optimum
+    IterationLimit,
+}
+
+/// A linear program in equality form together with its current basis.
+pub(crate) struct Simplex {
+    /// Number of rows
+    m: usize,
+    /// Number of structural variables
+    n: usize,
+    /// Right-hand side of the rows
+    b: Vec<f64>,
+    /// The tableau `B^-1 [A | diag(sign)]` in row-major order with `n + m` columns
+    tableau: Vec<f64>,
+    /// Sign of the artificial variable of each row
+    sign: Vec<f64>,
+    /// Lower bounds of all variables, including the artificial ones
+    lower: Vec<f64>,
+    /// Upper bounds of all variables, including the artificial ones
+    upper: Vec<f64>,
+    /// Current values of all variables
+    x: Vec<f64>,
+    /// Basic variable of each row
+    basis: Vec<usize>,
+    /// Whether each variable is basic
+    is_basic: Vec<bool>,
+    /// Costs of the current phase
+    cost: Vec<f64>,
+    /// Reduced costs of the current phase
+    reduced: Vec<f64>,
+    /// Whether the first phase found a feasible basis
+    feasible: bool,
+    /// Maximum number of pivots per call to `minimize`
+    max_iterations: usize,
+}
+
+impl Simplex {
+    /// Sets up a linear program with the artificial basis of the first phase.
+    ///
+    /// # Arguments
+    /// * `a` - The constraint matrix, `m * n` values in row-major order
+    /// * `b` - The right-hand side with `m` values
+    /// * `lower` - The lower bounds of the `n` variables
+    /// * `upper` - The upper bounds of the `n` variables
+    pub(crate) fn new(a: &[f64], b: &[f64], lower: &[f64], upper: &[f64]) -> Self {
+        let m = b.len();
+        let n = lower.len();
+        let cols = n + m;
+
+        // Nonbasic variables start at a finite bound, or at zero if they are free
+        let mut x: Vec<f64> = lower
+            .iter()
+            .zip(upper)
+            .map(|(&l, &u)| {
+                if l.is_finite() {
+                    l
+                } else if u.is_finite() {
+                    u
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        // Artificial variables absorb the residual of each row
+        let mut sign = Vec::with_capacity(m);
+        let mut tableau = vec![0.0; m * cols];
+        for i in 0..m {
+            let residual = b[i] - (0..n).map(|j| a[i * n + j] * x[j]).sum::<f64>();
+            let s = if residual < 0.0 { -1.0 } else { 1.0 };
+            sign.push(s);
+            x.push(residual.abs());
+
+            // Scale the row such that the artificial variable has a coefficient of one
+            for j in 0..n {
+                tableau[i * cols + j] = s * a[i * n + j];
+            }
+            tableau[i * cols + n + i] = 1.0;
+        }
+
+        let mut lower = lower.to_vec();
+        let mut upper = upper.to_vec();
+        lower.resize(cols, 0.0);
+        upper.resize(cols, f64::INFINITY);
+
+        let mut is_basic = vec![false; cols];
+        for flag in &mut is_basic[n..] {
+            *flag = true;
+        }
+
+        Self {
+            m,
+            n,
+            b: b.to_vec(),
+            tableau,
+            sign,
+            lower,
+            upper,
+            x,
+            basis: (n..cols).collect(),
+            is_basic,
+            cost: vec![0.0; cols],
+            reduced: vec![0.0; cols],
+            feasible: false,
+            max_iterations: 50 * cols + 1000,
+        }
+    }
+
+    /// Minimizes a linear objective over the constraints.
+    ///
+    /// # Arguments
+    /// * `c` - The cost of each structural variable
+    ///
+    /// # Returns
+    /// The optimal objective value or the reason why there is none
+    pub(crate) fn minimize(&mut self, c: &[f64]) -> Result<f64, LpError> {
+        if !self.feasible {
+            if (0..self.n).any(|j| self.lower[j] > self.upper[j]) {
+                return Err(LpError::Infeasible);
+            }
+
+            // First phase: minimize the sum of the artificial variables
+            let mut phase_one = vec![0.0; self.n];
+            phase_one.resize(self.n + self.m, 1.0);
+            self.set_cost(phase_one);
+            self.iterate()?;
+
+            let scale = self.b.iter().fold(1.0, |acc: f64, b| acc.max(b.abs()));
+            let infeasibility: f64 = self.x[self.n..].iter().sum();
+            if infeasibility > FEASIBILITY_TOLERANCE * scale {
+                return Err(LpError::Infeasible);
+            }
+
+            // Artificial variables must stay at zero from now on
+            for j in self.n..self.n + self.m {
+                self.upper[j] = 0.0;
+                if !self.is_basic[j] {
+                    self.x[j] = 0.0;
+                }
+            }
+            self.feasible = true;
+        }
+
+        let mut cost = c.to_vec();
+        cost.resize(self.n + self.m, 0.0);
+        self.set_cost(cost);
+        self.iterate()?;
+        self.refresh();
+
+        Ok((0..self.n).map(|j| c[j] * self.x[j]).sum())
+    }
+
+    /// Returns the values of the structural variables.
+    pub(crate) fn values(&self) -> &[f64] {
+        &self.x[..self.n]
+    }
+
+    /// Returns the reduced costs of the structural variables for the last objective.
+    pub(crate) fn reduced_costs(&self) -> &[f64] {
+        &self.reduced[..self.n]
+    }
+
+    /// Returns the dual value of each row for the last objective.
+    ///
+    /// The dual value is the change of the optimal objective per unit increase of the
+    /// right-hand side of the row.
+    pub(crate) fn duals(&self) -> Vec<f64> {
+        let cols = self.n + self.m;
+        (0..self.m)
+            .map(|k| {
+                self.sign[k]
+                    * (0..self.m)
+                        .map(|i| self.cost[self.basis[i]] * self.tableau[i * cols + self.n + k])
+                        .sum::<f64>()
+            })
+            .collect()
+    }
+
+    /// Sets the costs of the current phase and recomputes the reduced costs.
+    fn set_cost(&mut self, cost: Vec<f64>) {
+        let cols = self.n + self.m;
+        let mut reduced = cost.clone();
+        for i in 0..self.m {
+            let basic_cost = cost[self.basis[i]];
+            if basic_cost != 0.0 {
+                for (j, d) in reduced.iter_mut().enumerate() {
+                    *d -= basic_cost * self.tableau[i * cols + j];
+                }
+            }
+        }
+        self.cost = cost;
+        self.reduced = reduced;
+    }
+
+    /// Pivots until no nonbasic variable can improve the objective.
+    fn iterate(&mut self) -> Result<(), LpError> {
+        let cols = self.n + self.m;
+        let mut degenerate = 0;
+
+        for _ in 0..self.max_iterations {
+            let Some((q, direction)) = self.entering(degenerate >= DEGENERATE_PIVOTS) else {
+                return Ok(());
+            };
+
+            // Ratio test: how far can the entering variable move?
+            let mut theta = self.upper[q] - self.lower[q];
+            let mut leaving: Option<usize> = None;
+            for i in 0..self.m {
+                let alpha = direction * self.tableau[i * cols + q];
+                if alpha.abs() <= PIVOT_TOLERANCE {
+                    continue;
+                }
+
+                let basic = self.basis[i];
+                let limit = if alpha > 0.0 {
+                    (self.x[basic] - self.lower[basic]) / alpha
+                } else {
+                    (self.upper[basic] - self.x[basic]) / -alpha
+                };
+                let limit = limit.max(0.0);
+
+                let better = match leaving {
+                    None => limit < theta,
+                    Some(r) => {
+                        limit < theta
+                            || (limit == theta && alpha.abs() > self.tableau[r * cols + q].abs())
+                    }
+                };
+                if better {
+                    theta = limit;
+                    leaving = Some(i);
+                }
+            }
+
+            if theta.is_infinite() {
+                return Err(LpError::Unbounded);
+            }
+
+            // Move along the edge
+            self.x[q] += direction * theta;
+            for i in 0..self.m {
+                let basic = self.basis[i];
+                self.x[basic] -= direction * theta * self.tableau[i * cols + q];
+            }
+
+            if let Some(r) = leaving {
+                let basic = self.basis[r];
+                self.x[basic] = if direction * self.tableau[r * cols + q] > 0.0 {
+                    self.lower[basic]
+                } else {
+                    self.upper[basic]
+                };
+                self.pivot(r, q);
+            }
+
+            if theta <= PIVOT_TOLERANCE {
+                degenerate += 1;
+            } else {
+                degenerate = 0;
+            }
+        }
+
+        Err(LpError::IterationLimit)
+    }
+
+    /// Chooses the nonbasic variable that enters the basis and its direction.
+    ///
+    /// Uses the largest reduced cost, or the smallest eligible index to prevent
+    /// cycling if `bland` is set.
+    fn entering(&self, bland: bool) -> Option<(usize, f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        let mut best_score = 0.0;
+
+        for j in 0..self.n + self.m {
+            if self.is_basic[j] || self.lower[j] == self.upper[j] {
+                continue;
+            }
+
+            let d = self.reduced[j];
+            let direction = if d < -OPTIMALITY_TOLERANCE && self.x[j] < self.upper[j] {
+                1.0
+            } else if d > OPTIMALITY_TOLERANCE && self.x[j] > self.lower[j] {
+                -1.0
+            } else {
+                continue;
+            };
+
+            if bland {
+                return Some((j, direction));
+            }
+            if d.abs() > best_score {
+                best_score = d.abs();
+                best = Some((j, direction));
+            }
+        }
+
+        best
+    }
+
+    /// Makes variable `q` basic in row `r`.
+    fn pivot(&mut self, r: usize, q: usize) {
+        let cols = self.n + self.m;
+        let pivot = self.tableau[r * cols + q];
+        for j in 0..cols {
+            self.tableau[r * cols + j] /= pivot;
+        }
+
+        let row: Vec<f64> = self.tableau[r * cols..(r + 1) * cols].to_vec();
+        for i in 0..self.m {
+            let factor = self.tableau[i * cols + q];
+            if i == r || factor == 0.0 {
+                continue;
+            }
+            for (j, value) in row.iter().enumerate() {
+                self.tableau[i * cols + j] -= factor * value;
+            }
+        }
+
+        let factor = self.reduced[q];
+        for (j, value) in row.iter().enumerate() {
+            self.reduced[j] -= factor * value;
+        }
+
+        self.is_basic[self.basis[r]] = false;
+        self.is_basic[q] = true;
+        self.basis[r] = q;
+    }
+
+    /// Recomputes the basic variables from the nonbasic ones to remove drift.
+    fn refresh(&mut self) {
+        let cols = self.n + self.m;
+        for i in 0..self.m {
+            let mut value: f64 = (0..self.m)
+                .map(|k| self.sign[k] * self.tableau[i * cols + self.n + k] * self.b[k])
+                .sum();
+            for j in 0..cols {
+                if !self.is_basic[j] {
+                    value -= self.tableau[i * cols + j] * self.x[j];
+                }
+            }
+            self.x[self.basis[i]] = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_lp() {
+        // minimize -x - y with x + y + s = 4, x <= 3, y <= 2, s >= 0
+        let mut simplex = Simplex::new(
+            &[1.0, 1.0, 1.0],
+            &[4.0],
+            &[0.0, 0.0, 0.0],
+            &[3.0, 2.0, f64::INFINITY],
+        );
+        let objective = simplex.minimize(&[-1.0, -1.0, 0.0]).unwrap();
+        assert!((objective + 4.0).abs() < 1e-9);
+
+        // Re-optimizing starts from the previous basis
+        let objective = simplex.minimize(&[-1.0, 0.0, 0.0]).unwrap();
+        assert!((objective + 3.0).abs() < 1e-9);
+        assert!((simplex.values()[0] - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_duals() {
+        // minimize x + 2y with x + y = 3, x <= 1
+        let mut simplex = Simplex::new(&[1.0, 1.0], &[3.0], &[0.0, 0.0], &[1.0, f64::INFINITY]);
+        let objective = simplex.minimize(&[1.0, 2.0]).unwrap();
+        assert!((objective - 5.0).abs() < 1e-9);
+
+        // One more unit on the right-hand side must be carried by y
+        assert!((simplex.duals()[0] - 2.0).abs() < 1e-9);
+        assert!((simplex.reduced_costs()[0] + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_free_variables_and_negative_rhs() {
+        // minimize x with x - y = -2, y in [0, 5], x free
+        let mut simplex = Simplex::new(
+            &[1.0, -1.0],
+            &[-2.0],
+            &[f64::NEG_INFINITY, 0.0],
+            &[f64::INFINITY, 5.0],
+        );
+        let objective = simplex.minimize(&[1.0, 0.0]).unwrap();
+        assert!((objective + 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_infeasible_and_unbounded() {
+        let mut simplex = Simplex::new(&[1.0, 1.0], &[5.0], &[0.0, 0.0], &[1.0, 1.0]);
+        assert_eq!(simplex.minimize(&[1.0, 1.0]), Err(LpError::Infeasible));
+
+        let mut simplex = Simplex::new(
+            &[1.0, -1.0],
+            &[0.0],
+            &[0.0, 0.0],
+            &[f64::INFINITY, f64::INFINITY],
+        );
+        assert_eq!(simplex.minimize(&[-1.0, 0.0]), Err(LpError::Unbounded));
+    }
+}
@@ -0,0 +1,312 @@
+//! This module provides a safe Rust interface to the libSBML GeneProductAssociation class.
+//!
+//! The GeneProductAssociation class of the fbc package describes which gene products
+//! are needed for a reaction to carry flux. The association is a tree of `and` nodes
+//! (complexes), `or` nodes (isozymes) and references to gene products, which is
+//! represented by [`GeneRule`] on the Rust side.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML GeneProductAssociation
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, fmt, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    reaction::Reaction,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A boolean association between genes and a reaction.
+///
+/// A reaction can carry flux as long as its gene rule is active, i.e. as long as the
+/// gene products catalyzing it are present.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeneRule {
+    /// A single gene product
+    Gene(String),
+    /// A complex requiring all of its parts
+    And(Vec<GeneRule>),
+    /// Isozymes, any of which suffices
+    Or(Vec<GeneRule>),
+}
+
+impl GeneRule {
+    /// Evaluates the rule for a set of deleted genes.
+    ///
+    /// # Arguments
+    /// * `deleted` - Returns true for genes that are knocked out
+    ///
+    /// # Returns
+    /// Whether the reaction can still carry flux
+    pub fn is_active(&self, deleted: &dyn Fn(&str) -> bool) -> bool {
+        match self {
+            GeneRule::Gene(gene) => !deleted(gene),
+            GeneRule::And(rules) => rules.iter().all(|rule| rule.is_active(deleted)),
+            GeneRule::Or(rules) => rules.iter().any(|rule| rule.is_active(deleted)),
+        }
+    }
+
+    /// Returns all genes referenced by the rule.
+    pub fn genes(&self) -> Vec<&str> {
+        match self {
+            GeneRule::Gene(gene) => vec![gene.as_str()],
+            GeneRule::And(rules) | GeneRule::Or(rules) => {
+                rules.iter().flat_map(|rule| rule.genes()).collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for GeneRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rules, operator) = match self {
+            GeneRule::Gene(gene) => return write!(f, "{}", gene),
+            GeneRule::And(rules) => (rules, " and "),
+            GeneRule::Or(rules) => (rules, " or "),
+        };
+
+        for (i, rule) in rules.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", operator)?;
+            }
+            match rule {
+                GeneRule::Gene(_) => write!(f, "{}", rule)?,
+                _ => write!(f, "({})", rule)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A safe wrapper around the libSBML GeneProductAssociation class.
+///
+/// This struct maintains a reference to the underlying C++ GeneProductAssociation object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct GeneProductAssociation<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::GeneProductAssociation>>,
+}
+
+// Set the inner trait for the GeneProductAssociation struct
+inner!(sbmlcxx::GeneProductAssociation, GeneProductAssociation<'a>);
+
+// Set the annotation trait for the GeneProductAssociation struct
+upcast_annotation!(
+    GeneProductAssociation<'a>,
+    sbmlcxx::GeneProductAssociation,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the GeneProductAssociation struct
+sbase!(GeneProductAssociation<'a>, sbmlcxx::GeneProductAssociation);
+
+impl<'a> GeneProductAssociation<'a> {
+    /// Creates a new GeneProductAssociation instance for the given Reaction.
+    ///
+    /// An existing association of the reaction is replaced. The fbc package is enabled
+    /// on the document if needed.
+    ///
+    /// # Arguments
+    /// * `reaction` - The Reaction that will contain this association
+    /// * `rule` - The gene products required by the reaction
+    ///
+    /// # Returns
+    /// A new GeneProductAssociation instance
+    ///
+    /// # Panics
+    /// If the reaction is not part of a document
+    pub fn new(reaction: &Reaction<'a>, rule: &GeneRule) -> Self {
+        let mut plugin = reaction
+            .fbc_plugin(true)
+            .expect("Reaction must be part of a document to use the fbc package");
+        let association_ptr = plugin.as_mut().createGeneProductAssociation();
+        let association = pin_ptr!(association_ptr, sbmlcxx::GeneProductAssociation);
+
+        let association = Self {
+            inner: RefCell::new(association),
+        };
+        association.set_association(rule);
+        association
+    }
+
+    /// Returns the id of the association.
+    ///
+    /// # Returns
+    /// The id of the association as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the association.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the association.
+    ///
+    /// # Returns
+    /// The name of the association as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the association.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the tree of gene products required by the reaction.
+    ///
+    /// Gene products are identified by the ids of their GeneProduct elements.
+    ///
+    /// # Returns
+    /// The gene rule, None if no association is set
+    pub fn association(&self) -> Option<GeneRule> {
+        let association = self.inner.borrow_mut().as_mut().getAssociation1();
+        read_association(association)
+    }
+
+    /// Replaces the tree of gene products required by the reaction.
+    ///
+    /// # Arguments
+    /// * `rule` - The gene rule, referencing the ids of GeneProduct elements
+    pub fn set_association(&self, rule: &GeneRule) {
+        let mut inner = self.inner.borrow_mut();
+        inner.as_mut().unsetAssociation();
+        write_association(&mut Parent::Association(inner.as_mut()), rule);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::GeneProductAssociation, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::GeneProductAssociation> for GeneProductAssociation<'_> {
+    /// Creates a new GeneProductAssociation instance from a unique pointer to a libSBML
+    /// GeneProductAssociation.
+    ///
+    /// This method is primarily used internally by the Reaction class to create
+    /// GeneProductAssociation instances from libSBML GeneProductAssociation pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML GeneProductAssociation
+    ///
+    /// # Returns
+    /// A new GeneProductAssociation instance
+    fn from_ptr(ptr: *mut sbmlcxx::GeneProductAssociation) -> Self {
+        let association = pin_ptr!(ptr, sbmlcxx::GeneProductAssociation);
+        Self {
+            inner: RefCell::new(association),
+        }
+    }
+}
+
+/// Converts a libSBML association tree into a gene rule.
+///
+/// The concrete class of each node is determined with the `is*` methods of
+/// FbcAssociation, after which the pointer is cast to the derived class.
+fn read_association(ptr: *mut sbmlcxx::FbcAssociation) -> Option<GeneRule> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    let association = pin_ptr!(ptr, sbmlcxx::FbcAssociation);
+    if association.isGeneProductRef() {
+        let ptr = ptr.cast::<sbmlcxx::GeneProductRef>();
+        let reference = pin_ptr!(ptr, sbmlcxx::GeneProductRef);
+        let gene = reference.getGeneProduct().to_str().unwrap().to_string();
+        Some(GeneRule::Gene(gene))
+    } else if association.isFbcAnd() {
+        let ptr = ptr.cast::<sbmlcxx::FbcAnd>();
+        let mut and = pin_ptr!(ptr, sbmlcxx::FbcAnd);
+        let n_associations = and.getNumAssociations().0;
+        let rules = (0..n_associations)
+            .filter_map(|i| read_association(and.as_mut().getAssociation1(i.into())))
+            .collect();
+        Some(GeneRule::And(rules))
+    } else if association.isFbcOr() {
+        let ptr = ptr.cast::<sbmlcxx::FbcOr>();
+        let mut or = pin_ptr!(ptr, sbmlcxx::FbcOr);
+        let n_associations = or.getNumAssociations().0;
+        let rules = (0..n_associations)
+            .filter_map(|i| read_association(or.as_mut().getAssociation1(i.into())))
+            .collect();
+        Some(GeneRule::Or(rules))
+    } else {
+        None
+    }
+}
+
+/// An element of the association tree that new nodes can be added to.
+enum Parent<'b> {
+    Association(Pin<&'b mut sbmlcxx::GeneProductAssociation>),
+    And(Pin<&'b mut sbmlcxx::FbcAnd>),
+    Or(Pin<&'b mut sbmlcxx::FbcOr>),
+}
+
+impl Parent<'_> {
+    /// Adds a reference to a gene product.
+    fn create_gene_product_ref(&mut self) -> *mut sbmlcxx::GeneProductRef {
+        match self {
+            Parent::Association(parent) => parent.as_mut().createGeneProductRef(),
+            Parent::And(parent) => parent.as_mut().createGeneProductRef(),
+            Parent::Or(parent) => parent.as_mut().createGeneProductRef(),
+        }
+    }
+
+    /// Adds an `and` node.
+    fn create_and(&mut self) -> *mut sbmlcxx::FbcAnd {
+        match self {
+            Parent::Association(parent) => parent.as_mut().createAnd(),
+            Parent::And(parent) => parent.as_mut().createAnd(),
+            Parent::Or(parent) => parent.as_mut().createAnd(),
+        }
+    }
+
+    /// Adds an `or` node.
+    fn create_or(&mut self) -> *mut sbmlcxx::FbcOr {
+        match self {
+            Parent::Association(parent) => parent.as_mut().createOr(),
+            Parent::And(parent) => parent.as_mut().createOr(),
+            Parent::Or(parent) => parent.as_mut().createOr(),
+        }
+    }
+}
+
+/// Adds a gene rule below an element of the association tree.
+fn write_association(parent: &mut Parent, rule: &GeneRule) {
+    match rule {
+        GeneRule::Gene(gene) => {
+            let reference_ptr = parent.create_gene_product_ref();
+            let mut reference = pin_ptr!(reference_ptr, sbmlcxx::GeneProductRef);
+            let_cxx_string!(gene = gene.as_str());
+            reference.as_mut().setGeneProduct(&gene);
+        }
+        GeneRule::And(rules) => {
+            let and_ptr = parent.create_and();
+            let mut and = Parent::And(pin_ptr!(and_ptr, sbmlcxx::FbcAnd));
+            for rule in rules {
+                write_association(&mut and, rule);
+            }
+        }
+        GeneRule::Or(rules) => {
+            let or_ptr = parent.create_or();
+            let mut or = Parent::Or(pin_ptr!(or_ptr, sbmlcxx::FbcOr));
+            for rule in rules {
+                write_association(&mut or, rule);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! This module provides a safe Rust interface to the libSBML FluxBound class.
+//!
+//! The FluxBound class belongs to version 1 of the fbc package, which bounds the flux
+//! of a reaction by a value and a comparison operation. Version 2 replaced flux bounds
+//! by parameters referenced from the reactions, see
+//! [`Reaction::lower_flux_bound`](crate::reaction::Reaction::lower_flux_bound). Flux
+//! bounds are therefore only read from existing documents.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML FluxBound class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use crate::{
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// The comparison by which a flux bound limits the flux of its reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FluxBoundOperation {
+    /// The flux is at most the value of the bound
+    LessEqual,
+    /// The flux is at least the value of the bound
+    GreaterEqual,
+    /// The flux equals the value of the bound
+    Equal,
+}
+
+/// A safe wrapper around the libSBML FluxBound class.
+///
+/// This struct maintains a reference to the underlying C++ FluxBound object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct FluxBound<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::FluxBound>>,
+}
+
+// Set the inner trait for the FluxBound struct
+inner!(sbmlcxx::FluxBound, FluxBound<'a>);
+
+// Set the annotation trait for the FluxBound struct
+upcast_annotation!(FluxBound<'a>, sbmlcxx::FluxBound, sbmlcxx::SBase);
+
+// Set the SBase trait for the FluxBound struct
+sbase!(FluxBound<'a>, sbmlcxx::FluxBound);
+
+impl<'a> FluxBound<'a> {
+    /// Returns the identifier of the bounded reaction.
+    ///
+    /// # Returns
+    /// The reaction identifier as a String
+    pub fn reaction(&self) -> String {
+        self.inner
+            .borrow()
+            .getReaction()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Returns how the bound limits the flux.
+    ///
+    /// Strict comparisons are treated like their non-strict counterparts, as fluxes are
+    /// continuous.
+    ///
+    /// # Returns
+    /// The operation of the bound, None if it is not set or unknown
+    pub fn operation(&self) -> Option<FluxBoundOperation> {
+        match self.inner.borrow().getFluxBoundOperation() {
+            sbmlcxx::FluxBoundOperation_t::FLUXBOUND_OPERATION_LESS_EQUAL
+            | sbmlcxx::FluxBoundOperation_t::FLUXBOUND_OPERATION_LESS => {
+                Some(FluxBoundOperation::LessEqual)
+            }
+            sbmlcxx::FluxBoundOperation_t::FLUXBOUND_OPERATION_GREATER_EQUAL
+            | sbmlcxx::FluxBoundOperation_t::FLUXBOUND_OPERATION_GREATER => {
+                Some(FluxBoundOperation::GreaterEqual)
+            }
+            sbmlcxx::FluxBoundOperation_t::FLUXBOUND_OPERATION_EQUAL => {
+                Some(FluxBoundOperation::Equal)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the bound.
+    ///
+    /// # Returns
+    /// The value as a f64, which may be infinite
+    pub fn value(&self) -> f64 {
+        self.inner.borrow().getValue()
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::FluxBound, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::FluxBound> for FluxBound<'_> {
+    /// Creates a new FluxBound instance from a unique pointer to a libSBML FluxBound.
+    ///
+    /// This method is primarily used internally by the FbcModelPlugin class to create
+    /// FluxBound instances from libSBML FluxBound pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML FluxBound
+    ///
+    /// # Returns
+    /// A new FluxBound instance
+    fn from_ptr(ptr: *mut sbmlcxx::FluxBound) -> Self {
+        let flux_bound = pin_ptr!(ptr, sbmlcxx::FluxBound);
+        Self {
+            inner: RefCell::new(flux_bound),
+        }
+    }
+}
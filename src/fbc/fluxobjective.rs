@@ -0,0 +1,124 @@
+//! This module provides a safe Rust interface to the libSBML FluxObjective class.
+//!
+//! The FluxObjective class of the fbc package contributes the flux of a single
+//! reaction, weighted by a coefficient, to an objective.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML FluxObjective class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    fbc::objective::Objective,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML FluxObjective class.
+///
+/// This struct maintains a reference to the underlying C++ FluxObjective object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct FluxObjective<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::FluxObjective>>,
+}
+
+// Set the inner trait for the FluxObjective struct
+inner!(sbmlcxx::FluxObjective, FluxObjective<'a>);
+
+// Set the annotation trait for the FluxObjective struct
+upcast_annotation!(FluxObjective<'a>, sbmlcxx::FluxObjective, sbmlcxx::SBase);
+
+// Set the SBase trait for the FluxObjective struct
+sbase!(FluxObjective<'a>, sbmlcxx::FluxObjective);
+
+impl<'a> FluxObjective<'a> {
+    /// Creates a new FluxObjective instance within the given Objective.
+    ///
+    /// # Arguments
+    /// * `objective` - The parent Objective that will contain this flux objective
+    /// * `reaction` - The identifier of the reaction whose flux is weighted
+    /// * `coefficient` - The weight of the flux in the objective
+    ///
+    /// # Returns
+    /// A new FluxObjective instance
+    pub fn new(objective: &Objective<'a>, reaction: &str, coefficient: f64) -> Self {
+        let flux_objective_ptr = objective
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createFluxObjective();
+        let flux_objective = pin_ptr!(flux_objective_ptr, sbmlcxx::FluxObjective);
+
+        let flux_objective = Self {
+            inner: RefCell::new(flux_objective),
+        };
+        flux_objective.set_reaction(reaction);
+        flux_objective.set_coefficient(coefficient);
+        flux_objective
+    }
+
+    /// Returns the identifier of the weighted reaction.
+    ///
+    /// # Returns
+    /// The reaction identifier as a String
+    pub fn reaction(&self) -> String {
+        self.inner
+            .borrow()
+            .getReaction()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the weighted reaction.
+    ///
+    /// # Arguments
+    /// * `reaction` - The reaction identifier
+    pub fn set_reaction(&self, reaction: &str) {
+        let_cxx_string!(reaction = reaction);
+        self.inner.borrow_mut().as_mut().setReaction(&reaction);
+    }
+
+    /// Returns the weight of the reaction's flux in the objective.
+    ///
+    /// # Returns
+    /// The coefficient as a f64
+    pub fn coefficient(&self) -> f64 {
+        self.inner.borrow().getCoefficient()
+    }
+
+    /// Sets the weight of the reaction's flux in the objective.
+    ///
+    /// # Arguments
+    /// * `coefficient` - The coefficient to set
+    pub fn set_coefficient(&self, coefficient: f64) {
+        self.inner.borrow_mut().as_mut().setCoefficient(coefficient);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::FluxObjective, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::FluxObjective> for FluxObjective<'_> {
+    /// Creates a new FluxObjective instance from a unique pointer to a libSBML FluxObjective.
+    ///
+    /// This method is primarily used internally by the Objective class to create
+    /// FluxObjective instances from libSBML FluxObjective pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML FluxObjective
+    ///
+    /// # Returns
+    /// A new FluxObjective instance
+    fn from_ptr(ptr: *mut sbmlcxx::FluxObjective) -> Self {
+        let flux_objective = pin_ptr!(ptr, sbmlcxx::FluxObjective);
+        Self {
+            inner: RefCell::new(flux_objective),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! This module provides a safe Rust interface to the libSBML GeneProduct class.
+//!
+//! The GeneProduct class of the fbc package declares a gene, or the protein it encodes,
+//! that can be referenced by the gene product associations of reactions. The label is
+//! usually the identifier of the gene in an external database, such as a locus tag.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML GeneProduct class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    fbc::plugin::FbcModelPlugin,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML GeneProduct class.
+///
+/// This struct maintains a reference to the underlying C++ GeneProduct object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct GeneProduct<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::GeneProduct>>,
+}
+
+// Set the inner trait for the GeneProduct struct
+inner!(sbmlcxx::GeneProduct, GeneProduct<'a>);
+
+// Set the annotation trait for the GeneProduct struct
+upcast_annotation!(GeneProduct<'a>, sbmlcxx::GeneProduct, sbmlcxx::SBase);
+
+// Set the SBase trait for the GeneProduct struct
+sbase!(GeneProduct<'a>, sbmlcxx::GeneProduct);
+
+impl<'a> GeneProduct<'a> {
+    /// Creates a new GeneProduct instance within the given fbc model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The fbc plugin of the model that will contain this gene product
+    /// * `id` - The identifier for this gene product
+    /// * `label` - The label of the gene product, e.g. a locus tag
+    ///
+    /// # Returns
+    /// A new GeneProduct instance
+    pub fn new(plugin: &FbcModelPlugin<'a>, id: &str, label: &str) -> Self {
+        let gene_product_ptr = plugin.inner().borrow_mut().as_mut().createGeneProduct();
+        let mut gene_product = pin_ptr!(gene_product_ptr, sbmlcxx::GeneProduct);
+
+        let_cxx_string!(id = id);
+        gene_product.as_mut().setId(&id);
+        let_cxx_string!(label = label);
+        gene_product.as_mut().setLabel(&label);
+
+        Self {
+            inner: RefCell::new(gene_product),
+        }
+    }
+
+    /// Returns the id of the gene product.
+    ///
+    /// # Returns
+    /// The id of the gene product as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the gene product.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the gene product.
+    ///
+    /// # Returns
+    /// The name of the gene product as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the gene product.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the label of the gene product.
+    ///
+    /// # Returns
+    /// The label of the gene product as a String
+    pub fn label(&self) -> String {
+        self.inner.borrow().getLabel().to_str().unwrap().to_string()
+    }
+
+    /// Sets the label of the gene product.
+    ///
+    /// # Arguments
+    /// * `label` - The label to set
+    pub fn set_label(&self, label: &str) {
+        let_cxx_string!(label = label);
+        self.inner.borrow_mut().as_mut().setLabel(&label);
+    }
+
+    /// Returns the species that represents this gene product in the model.
+    ///
+    /// # Returns
+    /// The identifier of the species, empty if not set
+    pub fn associated_species(&self) -> String {
+        self.inner
+            .borrow()
+            .getAssociatedSpecies()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the species that represents this gene product in the model.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the species
+    pub fn set_associated_species(&self, species: &str) {
+        let_cxx_string!(species = species);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setAssociatedSpecies(&species);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::GeneProduct, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::GeneProduct> for GeneProduct<'_> {
+    /// Creates a new GeneProduct instance from a unique pointer to a libSBML GeneProduct.
+    ///
+    /// This method is primarily used internally by the FbcModelPlugin class to create
+    /// GeneProduct instances from libSBML GeneProduct pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML GeneProduct
+    ///
+    /// # Returns
+    /// A new GeneProduct instance
+    fn from_ptr(ptr: *mut sbmlcxx::GeneProduct) -> Self {
+        let gene_product = pin_ptr!(ptr, sbmlcxx::GeneProduct);
+        Self {
+            inner: RefCell::new(gene_product),
+        }
+    }
+}
+
+/// A builder for creating GeneProduct instances with a fluent interface.
+pub struct GeneProductBuilder<'a> {
+    gene_product: Rc<GeneProduct<'a>>,
+}
+
+impl<'a> GeneProductBuilder<'a> {
+    /// Creates a new GeneProductBuilder instance.
+    ///
+    /// # Arguments
+    /// * `plugin` - The fbc plugin of the model that will contain the gene product
+    /// * `id` - The identifier for the gene product
+    /// * `label` - The label of the gene product
+    ///
+    /// # Returns
+    /// A new GeneProductBuilder instance
+    pub fn new(plugin: &FbcModelPlugin<'a>, id: &str, label: &str) -> Self {
+        let gene_product = plugin.create_gene_product(id, label);
+        Self { gene_product }
+    }
+
+    /// Sets the name of the gene product.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.gene_product.set_name(name);
+        self
+    }
+
+    /// Sets the species that represents the gene product in the model.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the species
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn associated_species(self, species: &str) -> Self {
+        self.gene_product.set_associated_species(species);
+        self
+    }
+
+    /// Sets the annotation string for this gene product.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.gene_product
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.gene_product.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured GeneProduct.
+    ///
+    /// # Returns
+    /// The constructed GeneProduct instance wrapped in an Rc
+    pub fn build(self) -> Rc<GeneProduct<'a>> {
+        self.gene_product
+    }
+}
@@ -0,0 +1,219 @@
+//! Support for the SBML Level 3 flux balance constraints (fbc) package.
+//!
+//! The fbc package adds the information needed for constraint-based analysis of
+//! metabolic networks:
+//!
+//! - Flux bounds of reactions, given as references to constant parameters, or as
+//!   separate flux bound elements in version 1 of the package
+//! - Objectives as weighted sums of reaction fluxes
+//! - Gene products and the associations that tie reactions to them
+//! - Chemical formulas and charges of species
+//!
+//! The model-level content is accessed through [`Model::fbc_plugin`], while species and
+//! reactions expose their fbc attributes directly. Creating fbc content enables the
+//! package on the document. For the analysis itself, see the [`fba`](crate::fba)
+//! module.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("core");
+//! model.build_parameter("zero").value(0.0).constant(true).build();
+//! model.build_parameter("max_uptake").value(10.0).constant(true).build();
+//!
+//! let fbc = model.fbc_plugin();
+//! fbc.build_gene_product("g_pts", "b2417").build();
+//! fbc.build_objective("growth", ObjectiveSense::Maximize)
+//!     .flux_objective("biomass", 1.0)
+//!     .build();
+//!
+//! model
+//!     .build_reaction("uptake")
+//!     .product("glc", 1.0)
+//!     .lower_flux_bound("zero")
+//!     .upper_flux_bound("max_uptake")
+//!     .gene_product_association(&GeneRule::Gene("g_pts".to_string()))
+//!     .build();
+//! ```
+//!
+//! [`Model::fbc_plugin`]: crate::model::Model::fbc_plugin
+
+/// Module providing gene product association functionality
+pub mod association;
+/// Module providing flux bound functionality (fbc version 1)
+pub mod fluxbound;
+/// Module providing flux objective functionality
+pub mod fluxobjective;
+/// Module providing gene product functionality
+pub mod geneproduct;
+/// Module providing objective functionality
+pub mod objective;
+/// Module providing fbc model plugin functionality
+pub mod plugin;
+
+pub use association::{GeneProductAssociation, GeneRule};
+pub use fluxbound::{FluxBound, FluxBoundOperation};
+pub use fluxobjective::FluxObjective;
+pub use geneproduct::{GeneProduct, GeneProductBuilder};
+pub use objective::{Objective, ObjectiveBuilder, ObjectiveSense};
+pub use plugin::FbcModelPlugin;
+
+#[cfg(test)]
+mod tests {
+    use crate::{fba::FbaProblem, prelude::*};
+
+    #[test]
+    fn test_fbc_model_plugin() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        assert!(!doc.is_package_enabled(Package::Fbc));
+
+        let fbc = model.fbc_plugin();
+        assert!(doc.is_package_enabled(Package::Fbc));
+
+        fbc.set_strict(true);
+        fbc.build_gene_product("g1", "b0001").name("thrL").build();
+        fbc.build_objective("obj1", ObjectiveSense::Maximize)
+            .flux_objective("r1", 1.0)
+            .flux_objective("r2", 0.5)
+            .build();
+        fbc.create_objective("obj2", ObjectiveSense::Minimize);
+
+        assert!(fbc.strict());
+        assert_eq!(fbc.active_objective(), "obj1");
+        assert_eq!(fbc.list_of_objectives().len(), 2);
+        assert_eq!(
+            fbc.get_objective("obj2").unwrap().sense(),
+            Some(ObjectiveSense::Minimize)
+        );
+
+        let objective = fbc.get_active_objective().unwrap();
+        assert_eq!(objective.list_of_flux_objectives().len(), 2);
+        assert_eq!(
+            objective.get_flux_objective("r2").unwrap().coefficient(),
+            0.5
+        );
+
+        let gene_product = fbc.get_gene_product("g1").unwrap();
+        assert_eq!(gene_product.label(), "b0001");
+        assert_eq!(gene_product.name(), "thrL");
+    }
+
+    #[test]
+    fn test_fbc_species_and_reaction() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model
+            .build_species("atp")
+            .chemical_formula("C10H12N5O13P3")
+            .charge(-4)
+            .build();
+        assert_eq!(species.chemical_formula().as_deref(), Some("C10H12N5O13P3"));
+        assert_eq!(species.charge(), Some(-4));
+
+        let rule = GeneRule::Or(vec![
+            GeneRule::Gene("g1".to_string()),
+            GeneRule::And(vec![
+                GeneRule::Gene("g2".to_string()),
+                GeneRule::Gene("g3".to_string()),
+            ]),
+        ]);
+        let reaction = model
+            .build_reaction("r1")
+            .lower_flux_bound("lb")
+            .upper_flux_bound("ub")
+            .gene_product_association(&rule)
+            .build();
+
+        assert_eq!(reaction.lower_flux_bound().as_deref(), Some("lb"));
+        assert_eq!(reaction.upper_flux_bound().as_deref(), Some("ub"));
+        let association = reaction.gene_product_association().unwrap();
+        assert_eq!(association.association(), Some(rule));
+    }
+
+    #[test]
+    fn test_gene_rule_display() {
+        let rule = GeneRule::Or(vec![
+            GeneRule::Gene("g1".to_string()),
+            GeneRule::And(vec![
+                GeneRule::Gene("g2".to_string()),
+                GeneRule::Gene("g3".to_string()),
+            ]),
+        ]);
+
+        assert_eq!(rule.to_string(), "g1 or (g2 and g3)");
+        assert!(rule.is_active(&|g| g == "g2"));
+        assert!(!rule.is_active(&|g| g == "g1" || g == "g3"));
+    }
+
+    #[test]
+    fn test_fbc_without_package() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.build_species("atp").build();
+        let reaction = model.build_reaction("r1").build();
+
+        assert_eq!(species.chemical_formula(), None);
+        assert_eq!(species.charge(), None);
+        assert_eq!(reaction.lower_flux_bound(), None);
+        assert!(reaction.gene_product_association().is_none());
+    }
+
+    #[test]
+    fn test_fbc_read_from_xml() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_compartment("c").build();
+        model.build_species("A").compartment("c").build();
+        model
+            .build_parameter("zero")
+            .value(0.0)
+            .constant(true)
+            .build();
+        model
+            .build_parameter("ten")
+            .value(10.0)
+            .constant(true)
+            .build();
+        model
+            .build_reaction("uptake")
+            .product("A", 1.0)
+            .lower_flux_bound("zero")
+            .upper_flux_bound("ten")
+            .gene_product_association(&GeneRule::Gene("g1".to_string()))
+            .build();
+        model
+            .build_reaction("export")
+            .reactant("A", 1.0)
+            .lower_flux_bound("zero")
+            .build();
+
+        let fbc = model.fbc_plugin();
+        fbc.create_gene_product("g1", "b0001");
+        fbc.build_objective("obj", ObjectiveSense::Maximize)
+            .flux_objective("export", 1.0)
+            .build();
+
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = read.model().expect("Model not found");
+        let fbc = model.fbc_plugin();
+
+        assert_eq!(fbc.list_of_gene_products().len(), 1);
+        assert_eq!(fbc.active_objective(), "obj");
+        let reaction = model.get_reaction("uptake").unwrap();
+        assert_eq!(reaction.upper_flux_bound().as_deref(), Some("ten"));
+        assert_eq!(
+            reaction.gene_product_association().unwrap().association(),
+            Some(GeneRule::Gene("g1".to_string()))
+        );
+
+        // The written document can be analyzed directly
+        let solution = FbaProblem::from_document(&read)
+            .unwrap()
+            .optimize()
+            .unwrap();
+        assert!((solution.objective_value - 10.0).abs() < 1e-9);
+    }
+}
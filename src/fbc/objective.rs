@@ -0,0 +1,297 @@
+//! This module provides a safe Rust interface to the libSBML Objective class.
+//!
+//! The Objective class of the fbc package defines a linear objective for flux balance
+//! analysis. It is the weighted sum of reaction fluxes given by its flux objectives,
+//! which is either maximized or minimized.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Objective class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    fbc::{fluxobjective::FluxObjective, plugin::FbcModelPlugin},
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// Whether an objective is maximized or minimized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ObjectiveSense {
+    /// Find the largest value of the objective
+    #[default]
+    Maximize,
+    /// Find the smallest value of the objective
+    Minimize,
+}
+
+impl From<ObjectiveSense> for sbmlcxx::ObjectiveType_t {
+    fn from(sense: ObjectiveSense) -> Self {
+        match sense {
+            ObjectiveSense::Maximize => sbmlcxx::ObjectiveType_t::OBJECTIVE_TYPE_MAXIMIZE,
+            ObjectiveSense::Minimize => sbmlcxx::ObjectiveType_t::OBJECTIVE_TYPE_MINIMIZE,
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML Objective class.
+///
+/// This struct maintains a reference to the underlying C++ Objective object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the flux objectives of the objective.
+pub struct Objective<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Objective>>,
+    flux_objectives: RefCell<Vec<Rc<FluxObjective<'a>>>>,
+}
+
+// Set the inner trait for the Objective struct
+inner!(sbmlcxx::Objective, Objective<'a>);
+
+// Set the annotation trait for the Objective struct
+upcast_annotation!(Objective<'a>, sbmlcxx::Objective, sbmlcxx::SBase);
+
+// Set the SBase trait for the Objective struct
+sbase!(Objective<'a>, sbmlcxx::Objective);
+
+impl<'a> Objective<'a> {
+    /// Creates a new Objective instance within the given fbc model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The fbc plugin of the model that will contain this objective
+    /// * `id` - The identifier for this objective
+    /// * `sense` - Whether the objective is maximized or minimized
+    ///
+    /// # Returns
+    /// A new Objective instance
+    pub fn new(plugin: &FbcModelPlugin<'a>, id: &str, sense: ObjectiveSense) -> Self {
+        let objective_ptr = plugin.inner().borrow_mut().as_mut().createObjective();
+        let mut objective = pin_ptr!(objective_ptr, sbmlcxx::Objective);
+
+        let_cxx_string!(id = id);
+        objective.as_mut().setId(&id);
+        objective.as_mut().setType(sense.into());
+
+        Self {
+            inner: RefCell::new(objective),
+            flux_objectives: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a reference to the inner RefCell containing the Objective pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Objective>> {
+        &self.inner
+    }
+
+    /// Returns the id of the objective.
+    ///
+    /// # Returns
+    /// The id of the objective as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the objective.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the objective.
+    ///
+    /// # Returns
+    /// The name of the objective as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the objective.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns whether the objective is maximized or minimized.
+    ///
+    /// # Returns
+    /// The direction of the objective, None if the type is not set or unknown
+    pub fn sense(&self) -> Option<ObjectiveSense> {
+        match self.inner.borrow().getType() {
+            sbmlcxx::ObjectiveType_t::OBJECTIVE_TYPE_MAXIMIZE => Some(ObjectiveSense::Maximize),
+            sbmlcxx::ObjectiveType_t::OBJECTIVE_TYPE_MINIMIZE => Some(ObjectiveSense::Minimize),
+            _ => None,
+        }
+    }
+
+    /// Sets whether the objective is maximized or minimized.
+    ///
+    /// # Arguments
+    /// * `sense` - The direction of the objective
+    pub fn set_sense(&self, sense: ObjectiveSense) {
+        self.inner.borrow_mut().as_mut().setType(sense.into());
+    }
+
+    /// Adds a reaction's flux to the objective.
+    ///
+    /// # Arguments
+    /// * `reaction` - The identifier of the reaction
+    /// * `coefficient` - The weight of the reaction's flux
+    ///
+    /// # Returns
+    /// The newly created FluxObjective wrapped in an Rc
+    pub fn create_flux_objective(&self, reaction: &str, coefficient: f64) -> Rc<FluxObjective<'a>> {
+        let flux_objective = Rc::new(FluxObjective::new(self, reaction, coefficient));
+        self.flux_objectives
+            .borrow_mut()
+            .push(Rc::clone(&flux_objective));
+        flux_objective
+    }
+
+    /// Returns a vector of all flux objectives of the objective.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all FluxObjectives of the objective
+    pub fn list_of_flux_objectives(&self) -> Vec<Rc<FluxObjective<'a>>> {
+        self.flux_objectives.borrow().to_vec()
+    }
+
+    /// Retrieves a flux objective by the reaction it refers to.
+    ///
+    /// # Arguments
+    /// * `reaction` - The identifier of the reaction
+    ///
+    /// # Returns
+    /// Some(Rc<FluxObjective>) if found, None if not found
+    pub fn get_flux_objective(&self, reaction: &str) -> Option<Rc<FluxObjective<'a>>> {
+        self.flux_objectives
+            .borrow()
+            .iter()
+            .find(|flux_objective| (*flux_objective).reaction() == reaction)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Objective, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Objective> for Objective<'_> {
+    /// Creates a new Objective instance from a unique pointer to a libSBML Objective.
+    ///
+    /// This method is primarily used internally by the FbcModelPlugin class to create
+    /// Objective instances from libSBML Objective pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Objective
+    ///
+    /// # Returns
+    /// A new Objective instance
+    fn from_ptr(ptr: *mut sbmlcxx::Objective) -> Self {
+        let objective = RefCell::new(pin_ptr!(ptr, sbmlcxx::Objective));
+
+        // Fetch all flux objectives
+        let n_flux_objectives = objective.borrow().getNumFluxObjectives().0;
+        let flux_objectives: Vec<_> = (0..n_flux_objectives)
+            .map(|i| {
+                let flux_objective = objective.borrow_mut().as_mut().getFluxObjective1(i.into());
+                Rc::new(FluxObjective::from_ptr(flux_objective))
+            })
+            .collect();
+
+        Self {
+            inner: objective,
+            flux_objectives: RefCell::new(flux_objectives),
+        }
+    }
+}
+
+/// A builder for creating Objective instances with a fluent interface.
+pub struct ObjectiveBuilder<'a> {
+    objective: Rc<Objective<'a>>,
+}
+
+impl<'a> ObjectiveBuilder<'a> {
+    /// Creates a new ObjectiveBuilder instance.
+    ///
+    /// # Arguments
+    /// * `plugin` - The fbc plugin of the model that will contain the objective
+    /// * `id` - The identifier for the objective
+    /// * `sense` - Whether the objective is maximized or minimized
+    ///
+    /// # Returns
+    /// A new ObjectiveBuilder instance
+    pub fn new(plugin: &FbcModelPlugin<'a>, id: &str, sense: ObjectiveSense) -> Self {
+        let objective = plugin.create_objective(id, sense);
+        Self { objective }
+    }
+
+    /// Sets the name of the objective.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.objective.set_name(name);
+        self
+    }
+
+    /// Adds a reaction's flux to the objective.
+    ///
+    /// # Arguments
+    /// * `reaction` - The identifier of the reaction
+    /// * `coefficient` - The weight of the reaction's flux
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn flux_objective(self, reaction: &str, coefficient: f64) -> Self {
+        self.objective.create_flux_objective(reaction, coefficient);
+        self
+    }
+
+    /// Sets the annotation string for this objective.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.objective
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.objective.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Objective.
+    ///
+    /// # Returns
+    /// The constructed Objective instance wrapped in an Rc
+    pub fn build(self) -> Rc<Objective<'a>> {
+        self.objective
+    }
+}
@@ -0,0 +1,266 @@
+//! This module provides a safe Rust interface to the libSBML FbcModelPlugin class.
+//!
+//! The FbcModelPlugin class holds the content the fbc package adds to a model: the list
+//! of objectives with the active one among them, the list of gene products and the
+//! `strict` flag, which declares that the model satisfies the restrictions needed for
+//! unambiguous flux balance analysis.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML FbcModelPlugin class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    fbc::{
+        fluxbound::FluxBound,
+        geneproduct::{GeneProduct, GeneProductBuilder},
+        objective::{Objective, ObjectiveBuilder, ObjectiveSense},
+    },
+    inner, pin_ptr,
+    sbmlcxx::{self},
+    traits::fromptr::FromPtr,
+};
+
+/// A safe wrapper around the libSBML FbcModelPlugin class.
+///
+/// This struct maintains a reference to the underlying C++ FbcModelPlugin object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the objectives, gene products and flux bounds of the model.
+pub struct FbcModelPlugin<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::FbcModelPlugin>>,
+    objectives: RefCell<Vec<Rc<Objective<'a>>>>,
+    gene_products: RefCell<Vec<Rc<GeneProduct<'a>>>>,
+    flux_bounds: Vec<Rc<FluxBound<'a>>>,
+}
+
+// Set the inner trait for the FbcModelPlugin struct
+inner!(sbmlcxx::FbcModelPlugin, FbcModelPlugin<'a>);
+
+impl<'a> FbcModelPlugin<'a> {
+    /// Returns a reference to the inner RefCell containing the FbcModelPlugin pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::FbcModelPlugin>> {
+        &self.inner
+    }
+
+    /// Returns whether the model is declared to be strict.
+    ///
+    /// A strict model has finite flux bounds given by constant parameters, linear
+    /// objectives and no kinetic information relevant for the analysis.
+    ///
+    /// # Returns
+    /// true if the model is strict, false otherwise
+    pub fn strict(&self) -> bool {
+        self.inner.borrow().getStrict()
+    }
+
+    /// Sets whether the model is declared to be strict.
+    ///
+    /// # Arguments
+    /// * `strict` - Whether the model is strict
+    pub fn set_strict(&self, strict: bool) {
+        self.inner.borrow_mut().as_mut().setStrict(strict);
+    }
+
+    /// Returns the identifier of the active objective.
+    ///
+    /// # Returns
+    /// The id of the active objective, empty if not set
+    pub fn active_objective(&self) -> String {
+        self.inner
+            .borrow()
+            .getActiveObjectiveId()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the active objective.
+    ///
+    /// # Arguments
+    /// * `id` - The id of one of the model's objectives
+    pub fn set_active_objective(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setActiveObjectiveId(&id);
+    }
+
+    /// Creates a new objective.
+    ///
+    /// The first objective of a model becomes the active one.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new objective
+    /// * `sense` - Whether the objective is maximized or minimized
+    ///
+    /// # Returns
+    /// The newly created Objective wrapped in an Rc
+    pub fn create_objective(&self, id: &str, sense: ObjectiveSense) -> Rc<Objective<'a>> {
+        let objective = Rc::new(Objective::new(self, id, sense));
+        self.objectives.borrow_mut().push(Rc::clone(&objective));
+
+        if self.active_objective().is_empty() {
+            self.set_active_objective(id);
+        }
+
+        objective
+    }
+
+    /// Creates a new ObjectiveBuilder for constructing an objective.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new objective
+    /// * `sense` - Whether the objective is maximized or minimized
+    ///
+    /// # Returns
+    /// An ObjectiveBuilder instance
+    pub fn build_objective(&self, id: &str, sense: ObjectiveSense) -> ObjectiveBuilder<'a> {
+        ObjectiveBuilder::new(self, id, sense)
+    }
+
+    /// Returns a vector of all objectives of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Objectives of the model
+    pub fn list_of_objectives(&self) -> Vec<Rc<Objective<'a>>> {
+        self.objectives.borrow().to_vec()
+    }
+
+    /// Retrieves an objective by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the objective
+    ///
+    /// # Returns
+    /// Some(Rc<Objective>) if found, None if not found
+    pub fn get_objective(&self, id: &str) -> Option<Rc<Objective<'a>>> {
+        self.objectives
+            .borrow()
+            .iter()
+            .find(|objective| (*objective).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Returns the active objective.
+    ///
+    /// # Returns
+    /// Some(Rc<Objective>) if the active objective exists, None otherwise
+    pub fn get_active_objective(&self) -> Option<Rc<Objective<'a>>> {
+        self.get_objective(&self.active_objective())
+    }
+
+    /// Creates a new gene product.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new gene product
+    /// * `label` - The label of the gene product, e.g. a locus tag
+    ///
+    /// # Returns
+    /// The newly created GeneProduct wrapped in an Rc
+    pub fn create_gene_product(&self, id: &str, label: &str) -> Rc<GeneProduct<'a>> {
+        let gene_product = Rc::new(GeneProduct::new(self, id, label));
+        self.gene_products
+            .borrow_mut()
+            .push(Rc::clone(&gene_product));
+        gene_product
+    }
+
+    /// Creates a new GeneProductBuilder for constructing a gene product.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new gene product
+    /// * `label` - The label of the gene product
+    ///
+    /// # Returns
+    /// A GeneProductBuilder instance
+    pub fn build_gene_product(&self, id: &str, label: &str) -> GeneProductBuilder<'a> {
+        GeneProductBuilder::new(self, id, label)
+    }
+
+    /// Returns a vector of all gene products of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all GeneProducts of the model
+    pub fn list_of_gene_products(&self) -> Vec<Rc<GeneProduct<'a>>> {
+        self.gene_products.borrow().to_vec()
+    }
+
+    /// Retrieves a gene product by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the gene product
+    ///
+    /// # Returns
+    /// Some(Rc<GeneProduct>) if found, None if not found
+    pub fn get_gene_product(&self, id: &str) -> Option<Rc<GeneProduct<'a>>> {
+        self.gene_products
+            .borrow()
+            .iter()
+            .find(|gene_product| (*gene_product).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Returns the flux bounds of the model (fbc version 1).
+    ///
+    /// Flux bounds only occur in documents using version 1 of the package. Later
+    /// versions reference the bounds from the reactions instead.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all FluxBounds of the model
+    pub fn list_of_flux_bounds(&self) -> Vec<Rc<FluxBound<'a>>> {
+        self.flux_bounds.to_vec()
+    }
+}
+
+impl FromPtr<sbmlcxx::FbcModelPlugin> for FbcModelPlugin<'_> {
+    /// Creates a new FbcModelPlugin instance from a unique pointer to a libSBML
+    /// FbcModelPlugin.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// FbcModelPlugin instances from libSBML FbcModelPlugin pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML FbcModelPlugin
+    ///
+    /// # Returns
+    /// A new FbcModelPlugin instance
+    fn from_ptr(ptr: *mut sbmlcxx::FbcModelPlugin) -> Self {
+        let plugin = RefCell::new(pin_ptr!(ptr, sbmlcxx::FbcModelPlugin));
+
+        // Fetch all objectives
+        let n_objectives = plugin.borrow().getNumObjectives().0;
+        let objectives: Vec<_> = (0..n_objectives)
+            .map(|i| {
+                let objective = plugin.borrow_mut().as_mut().getObjective1(i.into());
+                Rc::new(Objective::from_ptr(objective))
+            })
+            .collect();
+
+        // Fetch all gene products
+        let n_gene_products = plugin.borrow().getNumGeneProducts().0;
+        let gene_products: Vec<_> = (0..n_gene_products)
+            .map(|i| {
+                let gene_product = plugin.borrow_mut().as_mut().getGeneProduct1(i.into());
+                Rc::new(GeneProduct::from_ptr(gene_product))
+            })
+            .collect();
+
+        // Fetch all flux bounds
+        let n_flux_bounds = plugin.borrow().getNumFluxBounds().0;
+        let flux_bounds: Vec<_> = (0..n_flux_bounds)
+            .map(|i| {
+                let flux_bound = plugin.borrow_mut().as_mut().getFluxBound1(i.into());
+                Rc::new(FluxBound::from_ptr(flux_bound))
+            })
+            .collect();
+
+        Self {
+            inner: plugin,
+            objectives: RefCell::new(objectives),
+            gene_products: RefCell::new(gene_products),
+            flux_bounds,
+        }
+    }
+}
@@ -0,0 +1,948 @@
+//! Parsing and evaluation of SBML infix formulas.
+//!
+//! libSBML exposes the math of kinetic laws, rules, initial assignments and events
+//! as infix formula strings (either in the Level 1 syntax returned by `getFormula`
+//! or the Level 3 syntax of `SBML_formulaToL3String`). This module parses both
+//! flavours into a small expression tree that can be inspected, compared
+//! structurally and evaluated numerically from Rust.
+//!
+//! Function-style operators of the Level 1 syntax (e.g. `gt(a, b)`, `and(a, b)`,
+//! `pow(a, b)`) are normalized into the same nodes as their infix counterparts,
+//! so that `gt(x, 1)` and `x > 1` yield identical trees.
+//!
+//! # Example
+//! ```
+//! use sbml::formula::Expr;
+//!
+//! let expr = Expr::parse("k1 * S / (Km + S)").unwrap();
+//! let value = expr
+//!     .eval(&|name: &str| match name {
+//!         "k1" => Some(2.0),
+//!         "S" => Some(1.0),
+//!         "Km" => Some(1.0),
+//!         _ => None,
+//!     })
+//!     .unwrap();
+//! assert_eq!(value, 1.0);
+//! ```
+
+use std::{collections::BTreeSet, fmt};
+
+/// Unary operators of an SBML formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    /// Arithmetic negation (`-x`)
+    Neg,
+    /// Logical negation (`!x` or `not(x)`)
+    Not,
+}
+
+/// Binary operators of an SBML formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Eq,
+    Neq,
+    Lt,
+    Leq,
+    Gt,
+    Geq,
+    And,
+    Or,
+}
+
+impl BinaryOp {
+    /// Returns the binding strength of the operator in the infix syntax.
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Or => 1,
+            BinaryOp::And => 2,
+            BinaryOp::Eq | BinaryOp::Neq => 3,
+            BinaryOp::Lt | BinaryOp::Leq | BinaryOp::Gt | BinaryOp::Geq => 4,
+            BinaryOp::Add | BinaryOp::Sub => 5,
+            BinaryOp::Mul | BinaryOp::Div => 6,
+            BinaryOp::Pow => 8,
+        }
+    }
+
+    /// Returns the infix symbol of the operator.
+    fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Pow => "^",
+            BinaryOp::Eq => "==",
+            BinaryOp::Neq => "!=",
+            BinaryOp::Lt => "<",
+            BinaryOp::Leq => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Geq => ">=",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+        }
+    }
+}
+
+/// A parsed SBML formula.
+///
+/// Identifiers are kept as [`Expr::Symbol`] nodes. Built-in constants such as `pi`,
+/// `exponentiale` or `avogadro` are also stored as symbols and only resolved during
+/// evaluation, so that a model element with the same identifier takes precedence.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// A numeric literal
+    Number(f64),
+    /// A boolean literal (`true` or `false`)
+    Boolean(bool),
+    /// A reference to a model identifier or built-in constant
+    Symbol(String),
+    /// A unary operation
+    Unary(UnaryOp, Box<Expr>),
+    /// A binary operation
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    /// A call of a built-in or user-defined function
+    Call(String, Vec<Expr>),
+}
+
+/// Errors that can occur while parsing or evaluating a formula.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormulaError {
+    /// The formula could not be parsed
+    Parse { position: usize, message: String },
+    /// The formula references an identifier without a value
+    UnknownSymbol(String),
+    /// The formula calls a function that is not known
+    UnknownFunction(String),
+    /// A function was called with the wrong number of arguments
+    InvalidArity { function: String, arguments: usize },
+}
+
+impl fmt::Display for FormulaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormulaError::Parse { position, message } => {
+                write!(
+                    f,
+                    "Failed to parse formula at position {}: {}",
+                    position, message
+                )
+            }
+            FormulaError::UnknownSymbol(name) => write!(f, "Unknown symbol '{}'", name),
+            FormulaError::UnknownFunction(name) => write!(f, "Unknown function '{}'", name),
+            FormulaError::InvalidArity {
+                function,
+                arguments,
+            } => write!(
+                f,
+                "Function '{}' cannot be called with {} argument(s)",
+                function, arguments
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FormulaError {}
+
+/// Value of Avogadro's constant as defined by SBML Level 3.
+const AVOGADRO: f64 = 6.02214076e23;
+
+impl Expr {
+    /// Parses an infix formula in either the SBML Level 1 or Level 3 syntax.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula string to parse
+    ///
+    /// # Returns
+    /// The parsed expression or a parse error
+    pub fn parse(formula: &str) -> Result<Expr, FormulaError> {
+        let tokens = tokenize(formula)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+
+        match parser.peek() {
+            Token::End => Ok(expr),
+            token => Err(parser.error(&format!("unexpected token {:?}", token))),
+        }
+    }
+
+    /// Evaluates the expression numerically.
+    ///
+    /// Booleans are represented as `1.0` (true) and `0.0` (false). Identifiers are
+    /// resolved through `lookup` first and fall back to the built-in constants.
+    ///
+    /// # Arguments
+    /// * `lookup` - Resolves an identifier to its current value
+    ///
+    /// # Returns
+    /// The value of the expression or an error if a symbol or function is unknown
+    pub fn eval(&self, lookup: &dyn Fn(&str) -> Option<f64>) -> Result<f64, FormulaError> {
+        match self {
+            Expr::Number(value) => Ok(*value),
+            Expr::Boolean(value) => Ok(bool_to_f64(*value)),
+            Expr::Symbol(name) => lookup(name)
+                .or_else(|| constant(name))
+                .ok_or_else(|| FormulaError::UnknownSymbol(name.clone())),
+            Expr::Unary(op, operand) => {
+                let value = operand.eval(lookup)?;
+                Ok(match op {
+                    UnaryOp::Neg => -value,
+                    UnaryOp::Not => bool_to_f64(value == 0.0),
+                })
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                // Short-circuit the logical operators
+                match op {
+                    BinaryOp::And => {
+                        return Ok(bool_to_f64(
+                            lhs.eval(lookup)? != 0.0 && rhs.eval(lookup)? != 0.0,
+                        ))
+                    }
+                    BinaryOp::Or => {
+                        return Ok(bool_to_f64(
+                            lhs.eval(lookup)? != 0.0 || rhs.eval(lookup)? != 0.0,
+                        ))
+                    }
+                    _ => {}
+                }
+
+                let a = lhs.eval(lookup)?;
+                let b = rhs.eval(lookup)?;
+                Ok(match op {
+                    BinaryOp::Add => a + b,
+                    BinaryOp::Sub => a - b,
+                    BinaryOp::Mul => a * b,
+                    BinaryOp::Div => a / b,
+                    BinaryOp::Pow => a.powf(b),
+                    BinaryOp::Eq => bool_to_f64(a == b),
+                    BinaryOp::Neq => bool_to_f64(a != b),
+                    BinaryOp::Lt => bool_to_f64(a < b),
+                    BinaryOp::Leq => bool_to_f64(a <= b),
+                    BinaryOp::Gt => bool_to_f64(a > b),
+                    BinaryOp::Geq => bool_to_f64(a >= b),
+                    BinaryOp::And | BinaryOp::Or => unreachable!(),
+                })
+            }
+            Expr::Call(name, args) => eval_call(name, args, lookup),
+        }
+    }
+
+    /// Returns all identifiers referenced by the expression.
+    ///
+    /// Function names are not included, built-in constants are.
+    pub fn symbols(&self) -> BTreeSet<String> {
+        let mut symbols = BTreeSet::new();
+        self.collect_symbols(&mut symbols);
+        symbols
+    }
+
+    fn collect_symbols(&self, symbols: &mut BTreeSet<String>) {
+        match self {
+            Expr::Symbol(name) => {
+                symbols.insert(name.clone());
+            }
+            Expr::Unary(_, operand) => operand.collect_symbols(symbols),
+            Expr::Binary(_, lhs, rhs) => {
+                lhs.collect_symbols(symbols);
+                rhs.collect_symbols(symbols);
+            }
+            Expr::Call(_, args) => args.iter().for_each(|arg| arg.collect_symbols(symbols)),
+            Expr::Number(_) | Expr::Boolean(_) => {}
+        }
+    }
+
+    /// Returns whether the expression references the given identifier.
+    pub fn references(&self, symbol: &str) -> bool {
+        match self {
+            Expr::Symbol(name) => name == symbol,
+            Expr::Unary(_, operand) => operand.references(symbol),
+            Expr::Binary(_, lhs, rhs) => lhs.references(symbol) || rhs.references(symbol),
+            Expr::Call(_, args) => args.iter().any(|arg| arg.references(symbol)),
+            Expr::Number(_) | Expr::Boolean(_) => false,
+        }
+    }
+
+    /// Replaces every occurrence of a symbol by another expression.
+    ///
+    /// # Arguments
+    /// * `symbol` - The identifier to replace
+    /// * `replacement` - The expression inserted in place of the identifier
+    pub fn substitute(&self, symbol: &str, replacement: &Expr) -> Expr {
+        match self {
+            Expr::Symbol(name) if name == symbol => replacement.clone(),
+            Expr::Unary(op, operand) => {
+                Expr::Unary(*op, Box::new(operand.substitute(symbol, replacement)))
+            }
+            Expr::Binary(op, lhs, rhs) => Expr::Binary(
+                *op,
+                Box::new(lhs.substitute(symbol, replacement)),
+                Box::new(rhs.substitute(symbol, replacement)),
+            ),
+            Expr::Call(name, args) => Expr::Call(
+                name.clone(),
+                args.iter()
+                    .map(|arg| arg.substitute(symbol, replacement))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// Returns the binding strength used to decide on parentheses when formatting.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Binary(op, _, _) => op.precedence(),
+            Expr::Unary(_, _) => 7,
+            _ => 9,
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    /// Formats the expression in the SBML Level 3 infix syntax.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(value) => write!(f, "{}", value),
+            Expr::Boolean(value) => write!(f, "{}", value),
+            Expr::Symbol(name) => write!(f, "{}", name),
+            Expr::Unary(op, operand) => {
+                let symbol = match op {
+                    UnaryOp::Neg => "-",
+                    UnaryOp::Not => "!",
+                };
+                if operand.precedence() < self.precedence() {
+                    write!(f, "{}({})", symbol, operand)
+                } else {
+                    write!(f, "{}{}", symbol, operand)
+                }
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                let precedence = op.precedence();
+
+                // Power is right-associative, all other operators are left-associative
+                let (lhs_parens, rhs_parens) = if *op == BinaryOp::Pow {
+                    (
+                        lhs.precedence() <= precedence,
+                        rhs.precedence() < precedence,
+                    )
+                } else {
+                    (
+                        lhs.precedence() < precedence,
+                        rhs.precedence() <= precedence,
+                    )
+                };
+
+                write_operand(f, lhs, lhs_parens)?;
+                write!(f, " {} ", op.symbol())?;
+                write_operand(f, rhs, rhs_parens)
+            }
+            Expr::Call(name, args) => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn write_operand(f: &mut fmt::Formatter<'_>, expr: &Expr, parens: bool) -> fmt::Result {
+    if parens {
+        write!(f, "({})", expr)
+    } else {
+        write!(f, "{}", expr)
+    }
+}
+
+fn bool_to_f64(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Resolves the built-in constants of the SBML math subset.
+fn constant(name: &str) -> Option<f64> {
+    match name {
+        "pi" => Some(std::f64::consts::PI),
+        "exponentiale" => Some(std::f64::consts::E),
+        "avogadro" => Some(AVOGADRO),
+        "INF" | "inf" | "infinity" => Some(f64::INFINITY),
+        "NaN" | "nan" | "notanumber" => Some(f64::NAN),
+        _ => None,
+    }
+}
+
+/// Evaluates a call of one of the built-in functions.
+fn eval_call(
+    name: &str,
+    args: &[Expr],
+    lookup: &dyn Fn(&str) -> Option<f64>,
+) -> Result<f64, FormulaError> {
+    let arity_error = || FormulaError::InvalidArity {
+        function: name.to_string(),
+        arguments: args.len(),
+    };
+
+    // Piecewise is evaluated lazily, so that only the selected branch is computed
+    if name == "piecewise" {
+        let mut pairs = args.chunks_exact(2);
+        for pair in pairs.by_ref() {
+            if pair[1].eval(lookup)? != 0.0 {
+                return pair[0].eval(lookup);
+            }
+        }
+        return match pairs.remainder() {
+            [otherwise] => otherwise.eval(lookup),
+            _ => Ok(f64::NAN),
+        };
+    }
+
+    let values = args
+        .iter()
+        .map(|arg| arg.eval(lookup))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let unary = |function: fn(f64) -> f64| match values.as_slice() {
+        [x] => Ok(function(*x)),
+        _ => Err(arity_error()),
+    };
+
+    match name {
+        "abs" => unary(f64::abs),
+        "ceil" | "ceiling" => unary(f64::ceil),
+        "floor" => unary(f64::floor),
+        "exp" => unary(f64::exp),
+        "ln" => unary(f64::ln),
+        "log10" => unary(f64::log10),
+        "sqrt" => unary(f64::sqrt),
+        "sin" => unary(f64::sin),
+        "cos" => unary(f64::cos),
+        "tan" => unary(f64::tan),
+        "sec" => unary(|x| 1.0 / x.cos()),
+        "csc" => unary(|x| 1.0 / x.sin()),
+        "cot" => unary(|x| 1.0 / x.tan()),
+        "sinh" => unary(f64::sinh),
+        "cosh" => unary(f64::cosh),
+        "tanh" => unary(f64::tanh),
+        "sech" => unary(|x| 1.0 / x.cosh()),
+        "csch" => unary(|x| 1.0 / x.sinh()),
+        "coth" => unary(|x| 1.0 / x.tanh()),
+        "asin" | "arcsin" => unary(f64::asin),
+        "acos" | "arccos" => unary(f64::acos),
+        "atan" | "arctan" => unary(f64::atan),
+        "asinh" | "arcsinh" => unary(f64::asinh),
+        "acosh" | "arccosh" => unary(f64::acosh),
+        "atanh" | "arctanh" => unary(f64::atanh),
+        "factorial" => unary(|x| (1..=(x as u64)).map(|i| i as f64).product()),
+        "log" => match values.as_slice() {
+            // The Level 1 syntax uses `log(x)` for the natural logarithm
+            [x] => Ok(x.ln()),
+            [base, x] => Ok(x.log(*base)),
+            _ => Err(arity_error()),
+        },
+        "root" => match values.as_slice() {
+            [x] => Ok(x.sqrt()),
+            [degree, x] => Ok(x.powf(1.0 / degree)),
+            _ => Err(arity_error()),
+        },
+        "rem" => match values.as_slice() {
+            [a, b] => Ok(a % b),
+            _ => Err(arity_error()),
+        },
+        "quotient" => match values.as_slice() {
+            [a, b] => Ok((a / b).trunc()),
+            _ => Err(arity_error()),
+        },
+        "max" => values
+            .iter()
+            .cloned()
+            .reduce(f64::max)
+            .ok_or_else(arity_error),
+        "min" => values
+            .iter()
+            .cloned()
+            .reduce(f64::min)
+            .ok_or_else(arity_error),
+        "xor" => Ok(bool_to_f64(
+            values.iter().filter(|value| **value != 0.0).count() % 2 == 1,
+        )),
+        "implies" => match values.as_slice() {
+            [a, b] => Ok(bool_to_f64(*a == 0.0 || *b != 0.0)),
+            _ => Err(arity_error()),
+        },
+        _ => Err(FormulaError::UnknownFunction(name.to_string())),
+    }
+}
+
+/// Tokens of the infix syntax.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+    End,
+}
+
+/// Splits a formula into tokens, remembering the byte offset of each token.
+fn tokenize(formula: &str) -> Result<Vec<(usize, Token)>, FormulaError> {
+    const OPERATORS: &[&str] = &[
+        "&&", "||", "==", "!=", "<=", ">=", "+", "-", "*", "/", "^", "%", "<", ">", "!",
+    ];
+
+    let chars: Vec<(usize, char)> = formula.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || (c == '.' && next_is_digit(&chars, i)) {
+            let start = i;
+            while i < chars.len() && (chars[i].1.is_ascii_digit() || chars[i].1 == '.') {
+                i += 1;
+            }
+            // Optional exponent, e.g. 1e-3 or 2.5E+4
+            if i < chars.len() && (chars[i].1 == 'e' || chars[i].1 == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j].1 == '+' || chars[j].1 == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].1.is_ascii_digit() {
+                    i = j;
+                    while i < chars.len() && chars[i].1.is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let end = chars.get(i).map(|(p, _)| *p).unwrap_or(formula.len());
+            let literal = &formula[chars[start].0..end];
+            let value = literal.parse::<f64>().map_err(|_| FormulaError::Parse {
+                position: pos,
+                message: format!("invalid number '{}'", literal),
+            })?;
+            tokens.push((pos, Token::Number(value)));
+
+            // Level 3 formulas may annotate numbers with units (e.g. `3 mole`).
+            // SBML has no implicit multiplication, so an identifier following a
+            // number can only be such a unit and is skipped.
+            let mut j = i;
+            while j < chars.len() && chars[j].1.is_whitespace() {
+                j += 1;
+            }
+            if j > i && j < chars.len() && is_ident_start(chars[j].1) {
+                let mut k = j;
+                while k < chars.len() && is_ident_char(chars[k].1) {
+                    k += 1;
+                }
+                let next = chars[k..].iter().find(|(_, c)| !c.is_whitespace());
+                if !matches!(next, Some((_, '('))) {
+                    i = k;
+                }
+            }
+        } else if is_ident_start(c) {
+            let start = i;
+            while i < chars.len() && is_ident_char(chars[i].1) {
+                i += 1;
+            }
+            let end = chars.get(i).map(|(p, _)| *p).unwrap_or(formula.len());
+            tokens.push((pos, Token::Ident(formula[chars[start].0..end].to_string())));
+        } else if c == '(' {
+            tokens.push((pos, Token::LParen));
+            i += 1;
+        } else if c == ')' {
+            tokens.push((pos, Token::RParen));
+            i += 1;
+        } else if c == ',' {
+            tokens.push((pos, Token::Comma));
+            i += 1;
+        } else {
+            let rest = &formula[pos..];
+            match OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+                Some(op) => {
+                    tokens.push((pos, Token::Op(op)));
+                    i += op.chars().count();
+                }
+                None => {
+                    return Err(FormulaError::Parse {
+                        position: pos,
+                        message: format!("unexpected character '{}'", c),
+                    })
+                }
+            }
+        }
+    }
+
+    tokens.push((formula.len(), Token::End));
+    Ok(tokens)
+}
+
+fn next_is_digit(chars: &[(usize, char)], i: usize) -> bool {
+    chars
+        .get(i + 1)
+        .map(|(_, c)| c.is_ascii_digit())
+        .unwrap_or(false)
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// A recursive descent parser over the token stream.
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].1
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].1.clone();
+        if token != Token::End {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn error(&self, message: &str) -> FormulaError {
+        FormulaError::Parse {
+            position: self.tokens[self.pos].0,
+            message: message.to_string(),
+        }
+    }
+
+    fn eat_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Token::Op(op) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, FormulaError> {
+        let mut lhs = self.parse_and()?;
+        while self.eat_op(&["||"]).is_some() {
+            let rhs = self.parse_and()?;
+            lhs = Expr::Binary(BinaryOp::Or, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, FormulaError> {
+        let mut lhs = self.parse_equality()?;
+        while self.eat_op(&["&&"]).is_some() {
+            let rhs = self.parse_equality()?;
+            lhs = Expr::Binary(BinaryOp::And, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_equality(&mut self) -> Result<Expr, FormulaError> {
+        let mut lhs = self.parse_relational()?;
+        while let Some(op) = self.eat_op(&["==", "!="]) {
+            let rhs = self.parse_relational()?;
+            let op = if op == "==" {
+                BinaryOp::Eq
+            } else {
+                BinaryOp::Neq
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_relational(&mut self) -> Result<Expr, FormulaError> {
+        let mut lhs = self.parse_additive()?;
+        while let Some(op) = self.eat_op(&["<", "<=", ">", ">="]) {
+            let rhs = self.parse_additive()?;
+            let op = match op {
+                "<" => BinaryOp::Lt,
+                "<=" => BinaryOp::Leq,
+                ">" => BinaryOp::Gt,
+                _ => BinaryOp::Geq,
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, FormulaError> {
+        let mut lhs = self.parse_multiplicative()?;
+        while let Some(op) = self.eat_op(&["+", "-"]) {
+            let rhs = self.parse_multiplicative()?;
+            let op = if op == "+" {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, FormulaError> {
+        let mut lhs = self.parse_unary()?;
+        while let Some(op) = self.eat_op(&["*", "/", "%"]) {
+            let rhs = self.parse_unary()?;
+            lhs = match op {
+                "*" => Expr::Binary(BinaryOp::Mul, Box::new(lhs), Box::new(rhs)),
+                "/" => Expr::Binary(BinaryOp::Div, Box::new(lhs), Box::new(rhs)),
+                _ => Expr::Call("rem".to_string(), vec![lhs, rhs]),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, FormulaError> {
+        match self.eat_op(&["-", "+", "!"]) {
+            Some("-") => {
+                let operand = self.parse_unary()?;
+                Ok(match operand {
+                    Expr::Number(value) => Expr::Number(-value),
+                    operand => Expr::Unary(UnaryOp::Neg, Box::new(operand)),
+                })
+            }
+            Some("!") => Ok(Expr::Unary(UnaryOp::Not, Box::new(self.parse_unary()?))),
+            Some(_) => self.parse_unary(),
+            None => self.parse_power(),
+        }
+    }
+
+    fn parse_power(&mut self) -> Result<Expr, FormulaError> {
+        let base = self.parse_primary()?;
+        if self.eat_op(&["^"]).is_some() {
+            // Right-associative and binds tighter than unary minus on the left
+            let exponent = self.parse_unary()?;
+            return Ok(Expr::Binary(
+                BinaryOp::Pow,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, FormulaError> {
+        match self.next() {
+            Token::Number(value) => Ok(Expr::Number(value)),
+            Token::LParen => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Token::RParen => Ok(expr),
+                    _ => Err(self.error("expected ')'")),
+                }
+            }
+            Token::Ident(name) => {
+                if *self.peek() == Token::LParen {
+                    self.pos += 1;
+                    let args = self.parse_arguments()?;
+                    return normalize_call(name, args);
+                }
+
+                Ok(match name.as_str() {
+                    "true" | "True" | "TRUE" => Expr::Boolean(true),
+                    "false" | "False" | "FALSE" => Expr::Boolean(false),
+                    _ => Expr::Symbol(name),
+                })
+            }
+            token => {
+                self.pos = self.pos.saturating_sub(1);
+                Err(self.error(&format!("unexpected token {:?}", token)))
+            }
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<Expr>, FormulaError> {
+        let mut args = Vec::new();
+        if *self.peek() == Token::RParen {
+            self.pos += 1;
+            return Ok(args);
+        }
+
+        loop {
+            args.push(self.parse_or()?);
+            match self.next() {
+                Token::Comma => continue,
+                Token::RParen => return Ok(args),
+                _ => return Err(self.error("expected ',' or ')'")),
+            }
+        }
+    }
+}
+
+/// Maps the function-style operators of the Level 1 syntax onto operator nodes.
+fn normalize_call(name: String, args: Vec<Expr>) -> Result<Expr, FormulaError> {
+    let binary = |op: BinaryOp, args: Vec<Expr>| -> Result<Expr, FormulaError> {
+        let arguments = args.len();
+        let mut iter = args.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(lhs), Some(rhs), None) => Ok(Expr::Binary(op, Box::new(lhs), Box::new(rhs))),
+            _ => Err(FormulaError::InvalidArity {
+                function: name.clone(),
+                arguments,
+            }),
+        }
+    };
+
+    // Folds n-ary functions such as `and(a, b, c)` into nested binary nodes
+    let fold = |op: BinaryOp, args: Vec<Expr>, empty: Expr| -> Expr {
+        args.into_iter()
+            .reduce(|lhs, rhs| Expr::Binary(op, Box::new(lhs), Box::new(rhs)))
+            .unwrap_or(empty)
+    };
+
+    match name.as_str() {
+        "pow" | "power" => binary(BinaryOp::Pow, args),
+        "eq" => binary(BinaryOp::Eq, args),
+        "neq" => binary(BinaryOp::Neq, args),
+        "lt" => binary(BinaryOp::Lt, args),
+        "leq" => binary(BinaryOp::Leq, args),
+        "gt" => binary(BinaryOp::Gt, args),
+        "geq" => binary(BinaryOp::Geq, args),
+        "divide" => binary(BinaryOp::Div, args),
+        "minus" if args.len() == 2 => binary(BinaryOp::Sub, args),
+        "and" => Ok(fold(BinaryOp::And, args, Expr::Boolean(true))),
+        "or" => Ok(fold(BinaryOp::Or, args, Expr::Boolean(false))),
+        "plus" => Ok(fold(BinaryOp::Add, args, Expr::Number(0.0))),
+        "times" => Ok(fold(BinaryOp::Mul, args, Expr::Number(1.0))),
+        "not" if args.len() == 1 => Ok(Expr::Unary(
+            UnaryOp::Not,
+            Box::new(args.into_iter().next().unwrap()),
+        )),
+        _ => Ok(Expr::Call(name, args)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(formula: &str, vars: &[(&str, f64)]) -> f64 {
+        Expr::parse(formula)
+            .expect("Failed to parse formula")
+            .eval(&|name: &str| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| *value)
+            })
+            .expect("Failed to evaluate formula")
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        assert_eq!(eval("1 + 2 * 3", &[]), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", &[]), 9.0);
+        assert_eq!(eval("2 ^ 3 ^ 2", &[]), 512.0);
+        assert_eq!(eval("-2 ^ 2", &[]), -4.0);
+        assert_eq!(eval("8 / 4 / 2", &[]), 1.0);
+        assert_eq!(eval("1e-3 * 1E3", &[]), 1.0);
+    }
+
+    #[test]
+    fn test_parse_symbols_and_functions() {
+        assert_eq!(
+            eval("k1 * S / (Km + S)", &[("k1", 2.0), ("S", 1.0), ("Km", 1.0)]),
+            1.0
+        );
+        assert_eq!(eval("pow(x, 2)", &[("x", 3.0)]), 9.0);
+        assert_eq!(eval("exp(0) + ln(1)", &[]), 1.0);
+        assert_eq!(eval("log(exponentiale)", &[]), 1.0);
+        assert_eq!(eval("log10(100)", &[]), 2.0);
+    }
+
+    #[test]
+    fn test_relational_and_logical() {
+        assert_eq!(eval("x > 1 && x < 3", &[("x", 2.0)]), 1.0);
+        assert_eq!(eval("and(gt(x, 1), lt(x, 3))", &[("x", 4.0)]), 0.0);
+        assert_eq!(eval("!(x == 2) || false", &[("x", 2.0)]), 0.0);
+    }
+
+    #[test]
+    fn test_piecewise() {
+        let formula = "piecewise(1, lt(t, 5), 2)";
+        assert_eq!(eval(formula, &[("t", 1.0)]), 1.0);
+        assert_eq!(eval(formula, &[("t", 6.0)]), 2.0);
+    }
+
+    #[test]
+    fn test_level1_and_level3_syntax_are_equal() {
+        let l1 = Expr::parse("and(gt(x, 1), pow(y, 2))").unwrap();
+        let l3 = Expr::parse("x > 1 && y ^ 2").unwrap();
+        assert_eq!(l1, l3);
+    }
+
+    #[test]
+    fn test_number_with_units() {
+        assert_eq!(eval("3 mole * 2", &[]), 6.0);
+    }
+
+    #[test]
+    fn test_unknown_symbol() {
+        let expr = Expr::parse("a + b").unwrap();
+        let result = expr.eval(&|name: &str| (name == "a").then_some(1.0));
+        assert_eq!(result, Err(FormulaError::UnknownSymbol("b".to_string())));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Expr::parse("1 + ").is_err());
+        assert!(Expr::parse("(1 + 2").is_err());
+        assert!(Expr::parse("1 $ 2").is_err());
+    }
+
+    #[test]
+    fn test_symbols() {
+        let expr = Expr::parse("k1 * S * piecewise(1, gt(time, 2), 0)").unwrap();
+        let symbols: Vec<_> = expr.symbols().into_iter().collect();
+        assert_eq!(symbols, vec!["S", "k1", "time"]);
+        assert!(expr.references("S"));
+        assert!(!expr.references("P"));
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for formula in [
+            "a - (b - c)",
+            "a / (b * c)",
+            "-(a + b)",
+            "(a ^ b) ^ c",
+            "f(a, b) + 1",
+        ] {
+            let expr = Expr::parse(formula).unwrap();
+            assert_eq!(expr.to_string(), formula);
+            assert_eq!(Expr::parse(&expr.to_string()).unwrap(), expr);
+        }
+    }
+
+    #[test]
+    fn test_substitute() {
+        let expr = Expr::parse("k * S").unwrap();
+        let substituted = expr.substitute("k", &Expr::Number(2.0));
+        assert_eq!(substituted, Expr::parse("2 * S").unwrap());
+    }
+}
@@ -0,0 +1,369 @@
+//! This module provides a safe Rust interface to the libSBML Group class.
+//!
+//! The Group class of the groups package collects model elements that belong
+//! together, e.g. the reactions of a pathway or the species forming an enzyme complex.
+//! The kind of a group states how its members relate to it: as instances of a
+//! class, as parts of a whole or as an arbitrary collection.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Group class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, fmt, pin::Pin, rc::Rc, str::FromStr};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    groups::{
+        member::{Member, MemberRef},
+        plugin::GroupsModelPlugin,
+    },
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// The relation between a group and its members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupKind {
+    /// The members are instances of the class represented by the group
+    Classification,
+    /// The members are parts of the whole represented by the group
+    Partonomy,
+    /// The members belong together for any other reason
+    Collection,
+}
+
+impl GroupKind {
+    /// Returns the value of the kind as written to SBML.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupKind::Classification => "classification",
+            GroupKind::Partonomy => "partonomy",
+            GroupKind::Collection => "collection",
+        }
+    }
+}
+
+impl fmt::Display for GroupKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for GroupKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classification" => Ok(GroupKind::Classification),
+            "partonomy" => Ok(GroupKind::Partonomy),
+            "collection" => Ok(GroupKind::Collection),
+            _ => Err(format!("Invalid group kind: {}", s)),
+        }
+    }
+}
+
+impl From<GroupKind> for sbmlcxx::GroupKind_t {
+    fn from(kind: GroupKind) -> Self {
+        match kind {
+            GroupKind::Classification => sbmlcxx::GroupKind_t::GROUP_KIND_CLASSIFICATION,
+            GroupKind::Partonomy => sbmlcxx::GroupKind_t::GROUP_KIND_PARTONOMY,
+            GroupKind::Collection => sbmlcxx::GroupKind_t::GROUP_KIND_COLLECTION,
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML Group class.
+///
+/// This struct maintains a reference to the underlying C++ Group object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the members of the group.
+pub struct Group<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Group>>,
+    members: RefCell<Vec<Rc<Member<'a>>>>,
+}
+
+// Set the inner trait for the Group struct
+inner!(sbmlcxx::Group, Group<'a>);
+
+// Set the annotation trait for the Group struct
+upcast_annotation!(Group<'a>, sbmlcxx::Group, sbmlcxx::SBase);
+
+// Set the SBase trait for the Group struct
+sbase!(Group<'a>, sbmlcxx::Group);
+
+impl<'a> Group<'a> {
+    /// Creates a new Group instance within the given groups model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The groups plugin of the model that will contain this group
+    /// * `id` - The identifier for this group
+    /// * `kind` - The relation between the group and its members
+    ///
+    /// # Returns
+    /// A new Group instance
+    pub fn new(plugin: &GroupsModelPlugin<'a>, id: &str, kind: GroupKind) -> Self {
+        let group_ptr = plugin.inner().borrow_mut().as_mut().createGroup();
+        let mut group = pin_ptr!(group_ptr, sbmlcxx::Group);
+
+        let_cxx_string!(id = id);
+        group.as_mut().setId(&id);
+        group.as_mut().setKind(kind.into());
+
+        Self {
+            inner: RefCell::new(group),
+            members: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a reference to the inner RefCell containing the Group pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Group>> {
+        &self.inner
+    }
+
+    /// Returns the id of the group.
+    ///
+    /// # Returns
+    /// The id of the group as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the group.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the group.
+    ///
+    /// # Returns
+    /// The name of the group as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the group.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the relation between the group and its members.
+    ///
+    /// # Returns
+    /// The kind of the group, None if the kind is not set or unknown
+    pub fn kind(&self) -> Option<GroupKind> {
+        match self.inner.borrow().getKind() {
+            sbmlcxx::GroupKind_t::GROUP_KIND_CLASSIFICATION => Some(GroupKind::Classification),
+            sbmlcxx::GroupKind_t::GROUP_KIND_PARTONOMY => Some(GroupKind::Partonomy),
+            sbmlcxx::GroupKind_t::GROUP_KIND_COLLECTION => Some(GroupKind::Collection),
+            _ => None,
+        }
+    }
+
+    /// Sets the relation between the group and its members.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of the group
+    pub fn set_kind(&self, kind: GroupKind) {
+        self.inner.borrow_mut().as_mut().setKind(kind.into());
+    }
+
+    /// Adds an element to the group.
+    ///
+    /// # Arguments
+    /// * `reference` - The id or meta id of the element
+    ///
+    /// # Returns
+    /// The newly created Member wrapped in an Rc
+    pub fn create_member(&self, reference: &MemberRef) -> Rc<Member<'a>> {
+        let member = Rc::new(Member::new(self, reference));
+        self.members.borrow_mut().push(Rc::clone(&member));
+        member
+    }
+
+    /// Returns a vector of all members of the group.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Members of the group
+    pub fn list_of_members(&self) -> Vec<Rc<Member<'a>>> {
+        self.members.borrow().to_vec()
+    }
+
+    /// Retrieves a member by the element it refers to.
+    ///
+    /// # Arguments
+    /// * `reference` - The id or meta id of the element
+    ///
+    /// # Returns
+    /// Some(Rc<Member>) if found, None if not found
+    pub fn get_member(&self, reference: &MemberRef) -> Option<Rc<Member<'a>>> {
+        self.members
+            .borrow()
+            .iter()
+            .find(|member| member.reference().as_ref() == Some(reference))
+            .map(Rc::clone)
+    }
+
+    /// Returns the elements the members of the group refer to.
+    ///
+    /// Members of nested groups are not included, see
+    /// [`GroupsModelPlugin::resolve_members`] for that.
+    ///
+    /// # Returns
+    /// The references of all members, in document order
+    pub fn member_refs(&self) -> Vec<MemberRef> {
+        self.members
+            .borrow()
+            .iter()
+            .filter_map(|member| member.reference())
+            .collect()
+    }
+
+    /// Returns whether the group directly contains the given element.
+    ///
+    /// # Arguments
+    /// * `reference` - The id or meta id of the element
+    ///
+    /// # Returns
+    /// true if one of the members refers to the element, false otherwise
+    pub fn contains(&self, reference: &MemberRef) -> bool {
+        self.get_member(reference).is_some()
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Group, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Group> for Group<'_> {
+    /// Creates a new Group instance from a unique pointer to a libSBML Group.
+    ///
+    /// This method is primarily used internally by the GroupsModelPlugin class to create
+    /// Group instances from libSBML Group pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Group
+    ///
+    /// # Returns
+    /// A new Group instance
+    fn from_ptr(ptr: *mut sbmlcxx::Group) -> Self {
+        let group = RefCell::new(pin_ptr!(ptr, sbmlcxx::Group));
+
+        // Fetch all members
+        let n_members = group.borrow().getNumMembers().0;
+        let members: Vec<_> = (0..n_members)
+            .map(|i| {
+                let member = group.borrow_mut().as_mut().getMember1(i.into());
+                Rc::new(Member::from_ptr(member))
+            })
+            .collect();
+
+        Self {
+            inner: group,
+            members: RefCell::new(members),
+        }
+    }
+}
+
+/// A builder for creating Group instances with a fluent interface.
+pub struct GroupBuilder<'a> {
+    group: Rc<Group<'a>>,
+}
+
+impl<'a> GroupBuilder<'a> {
+    /// Creates a new GroupBuilder instance.
+    ///
+    /// # Arguments
+    /// * `plugin` - The groups plugin of the model that will contain the group
+    /// * `id` - The identifier for the group
+    /// * `kind` - The relation between the group and its members
+    ///
+    /// # Returns
+    /// A new GroupBuilder instance
+    pub fn new(plugin: &GroupsModelPlugin<'a>, id: &str, kind: GroupKind) -> Self {
+        let group = plugin.create_group(id, kind);
+        Self { group }
+    }
+
+    /// Sets the name of the group.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.group.set_name(name);
+        self
+    }
+
+    /// Adds an element referenced by its identifier to the group.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the element
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn member(self, id: &str) -> Self {
+        self.group.create_member(&MemberRef::Id(id.to_string()));
+        self
+    }
+
+    /// Adds an element referenced by its meta identifier to the group.
+    ///
+    /// # Arguments
+    /// * `metaid` - The meta identifier of the element
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn member_metaid(self, metaid: &str) -> Self {
+        self.group
+            .create_member(&MemberRef::MetaId(metaid.to_string()));
+        self
+    }
+
+    /// Sets the annotation string for this group.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.group
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.group.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Group.
+    ///
+    /// # Returns
+    /// The constructed Group instance wrapped in an Rc
+    pub fn build(self) -> Rc<Group<'a>> {
+        self.group
+    }
+}
@@ -0,0 +1,182 @@
+//! This module provides a safe Rust interface to the libSBML Member class.
+//!
+//! The Member class of the groups package adds a single element to a group. The
+//! element is referenced either by its identifier or by its meta identifier, so that
+//! elements without an id, such as rules or species references, can be grouped too.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Member class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, fmt, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    groups::group::Group,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// The element a member of a group refers to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MemberRef {
+    /// An element referenced by its identifier (`idRef`)
+    Id(String),
+    /// An element referenced by its meta identifier (`metaIdRef`)
+    MetaId(String),
+}
+
+impl MemberRef {
+    /// Returns the referenced identifier or meta identifier.
+    pub fn value(&self) -> &str {
+        match self {
+            MemberRef::Id(value) | MemberRef::MetaId(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for MemberRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemberRef::Id(id) => write!(f, "{id}"),
+            MemberRef::MetaId(metaid) => write!(f, "metaid:{metaid}"),
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML Member class.
+///
+/// This struct maintains a reference to the underlying C++ Member object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Member<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Member>>,
+}
+
+// Set the inner trait for the Member struct
+inner!(sbmlcxx::Member, Member<'a>);
+
+// Set the annotation trait for the Member struct
+upcast_annotation!(Member<'a>, sbmlcxx::Member, sbmlcxx::SBase);
+
+// Set the SBase trait for the Member struct
+sbase!(Member<'a>, sbmlcxx::Member);
+
+impl<'a> Member<'a> {
+    /// Creates a new Member instance within the given Group.
+    ///
+    /// # Arguments
+    /// * `group` - The parent Group that will contain this member
+    /// * `reference` - The element the member refers to
+    ///
+    /// # Returns
+    /// A new Member instance
+    pub fn new(group: &Group<'a>, reference: &MemberRef) -> Self {
+        let member_ptr = group.inner().borrow_mut().as_mut().createMember();
+        let member = Self {
+            inner: RefCell::new(pin_ptr!(member_ptr, sbmlcxx::Member)),
+        };
+        member.set_reference(reference);
+        member
+    }
+
+    /// Returns the id of the member.
+    ///
+    /// # Returns
+    /// The id of the member as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the member.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the member.
+    ///
+    /// # Returns
+    /// The name of the member as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the member.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the element the member refers to.
+    ///
+    /// # Returns
+    /// The reference of the member, None if neither an id nor a meta id is referenced
+    pub fn reference(&self) -> Option<MemberRef> {
+        let member = self.inner.borrow();
+        if member.isSetIdRef() {
+            Some(MemberRef::Id(
+                member.getIdRef().to_str().unwrap().to_string(),
+            ))
+        } else if member.isSetMetaIdRef() {
+            Some(MemberRef::MetaId(
+                member.getMetaIdRef().to_str().unwrap().to_string(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the element the member refers to.
+    ///
+    /// A member references exactly one element, so the previous reference is removed.
+    ///
+    /// # Arguments
+    /// * `reference` - The element the member refers to
+    pub fn set_reference(&self, reference: &MemberRef) {
+        let mut member = self.inner.borrow_mut();
+        member.as_mut().unsetIdRef();
+        member.as_mut().unsetMetaIdRef();
+
+        match reference {
+            MemberRef::Id(id) => {
+                let_cxx_string!(id = id);
+                member.as_mut().setIdRef(&id);
+            }
+            MemberRef::MetaId(metaid) => {
+                let_cxx_string!(metaid = metaid);
+                member.as_mut().setMetaIdRef(&metaid);
+            }
+        }
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Member, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Member> for Member<'_> {
+    /// Creates a new Member instance from a unique pointer to a libSBML Member.
+    ///
+    /// This method is primarily used internally by the Group class to create
+    /// Member instances from libSBML Member pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Member
+    ///
+    /// # Returns
+    /// A new Member instance
+    fn from_ptr(ptr: *mut sbmlcxx::Member) -> Self {
+        let member = pin_ptr!(ptr, sbmlcxx::Member);
+        Self {
+            inner: RefCell::new(member),
+        }
+    }
+}
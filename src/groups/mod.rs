@@ -0,0 +1,180 @@
+//! Support for the SBML Level 3 groups package.
+//!
+//! The groups package organizes model elements without changing the mathematical
+//! meaning of the model. A [`Group`] has a [`GroupKind`] and a list of [`Member`]s,
+//! each referring to an element by its id or meta id ([`MemberRef`]):
+//!
+//! - Classification: the members are instances of a class, e.g. all kinases
+//! - Partonomy: the members are parts of a whole, e.g. the subunits of a complex
+//! - Collection: the members belong together for any other reason, e.g. a pathway
+//!
+//! Groups are accessed through [`Model::groups_plugin`]. Groups may contain other
+//! groups, which [`GroupsModelPlugin::resolve_members`] takes into account.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("glycolysis");
+//!
+//! let groups = model.groups_plugin();
+//! groups
+//!     .build_group("pfk_complex", GroupKind::Partonomy)
+//!     .name("Phosphofructokinase")
+//!     .member("PfkA")
+//!     .member("PfkB")
+//!     .build();
+//! groups
+//!     .build_group("upper_glycolysis", GroupKind::Collection)
+//!     .member("PGI")
+//!     .member("PFK")
+//!     .member("pfk_complex")
+//!     .build();
+//! ```
+//!
+//! [`Model::groups_plugin`]: crate::model::Model::groups_plugin
+
+/// Module providing group functionality
+pub mod group;
+/// Module providing group member functionality
+pub mod member;
+/// Module providing groups model plugin functionality
+pub mod plugin;
+
+pub use group::{Group, GroupBuilder, GroupKind};
+pub use member::{Member, MemberRef};
+pub use plugin::GroupsModelPlugin;
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn id(id: &str) -> MemberRef {
+        MemberRef::Id(id.to_string())
+    }
+
+    #[test]
+    fn test_groups_model_plugin() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        assert!(!doc.is_package_enabled(Package::Groups));
+
+        let groups = model.groups_plugin();
+        assert!(doc.is_package_enabled(Package::Groups));
+
+        let group = groups
+            .build_group("complex", GroupKind::Partonomy)
+            .name("Enzyme complex")
+            .member("A")
+            .member_metaid("meta_B")
+            .build();
+
+        assert_eq!(group.id(), "complex");
+        assert_eq!(group.name(), "Enzyme complex");
+        assert_eq!(group.kind(), Some(GroupKind::Partonomy));
+        assert_eq!(
+            group.member_refs(),
+            vec![id("A"), MemberRef::MetaId("meta_B".to_string())]
+        );
+        assert!(group.contains(&id("A")));
+        assert!(!group.contains(&id("meta_B")));
+
+        group.set_kind(GroupKind::Classification);
+        assert_eq!(group.kind(), Some(GroupKind::Classification));
+        assert!(groups.get_group("complex").is_some());
+        assert!(groups.get_group("missing").is_none());
+    }
+
+    #[test]
+    fn test_member_reference() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let group = model
+            .groups_plugin()
+            .create_group("g", GroupKind::Collection);
+
+        let member = group.create_member(&id("S1"));
+        member.set_id("m1");
+        assert_eq!(member.reference(), Some(id("S1")));
+
+        // Setting a meta id reference replaces the id reference
+        member.set_reference(&MemberRef::MetaId("meta_S1".to_string()));
+        assert_eq!(
+            member.reference(),
+            Some(MemberRef::MetaId("meta_S1".to_string()))
+        );
+        assert_eq!(member.id(), "m1");
+        assert!(group
+            .get_member(&MemberRef::MetaId("meta_S1".to_string()))
+            .is_some());
+        assert!(group.get_member(&id("S1")).is_none());
+    }
+
+    #[test]
+    fn test_resolve_members() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let groups = model.groups_plugin();
+
+        groups
+            .build_group("upper", GroupKind::Collection)
+            .member("R1")
+            .member("R2")
+            .member("complex")
+            .build();
+        groups
+            .build_group("complex", GroupKind::Partonomy)
+            .member("E1")
+            .member("E2")
+            .member("upper")
+            .build();
+
+        assert_eq!(
+            groups.resolve_members("upper"),
+            Some(vec![
+                id("R1"),
+                id("R2"),
+                id("complex"),
+                id("E1"),
+                id("E2"),
+                id("upper"),
+            ])
+        );
+        assert_eq!(groups.resolve_members("missing"), None);
+
+        let containing: Vec<_> = groups
+            .groups_of(&id("complex"))
+            .iter()
+            .map(|group| group.id())
+            .collect();
+        assert_eq!(containing, vec!["upper"]);
+    }
+
+    #[test]
+    fn test_groups_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model
+            .groups_plugin()
+            .build_group("kinases", GroupKind::Classification)
+            .member("HK")
+            .member("PFK")
+            .build();
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains("groups:group"));
+        assert!(xml.contains("groups:kind=\"classification\""));
+
+        let read = SBMLReader::from_xml_string(&xml);
+        let model = read.model().expect("Model not found");
+        let group = model
+            .groups_plugin()
+            .get_group("kinases")
+            .expect("Group not found");
+        assert_eq!(group.kind(), Some(GroupKind::Classification));
+        assert_eq!(group.member_refs(), vec![id("HK"), id("PFK")]);
+        assert_eq!("partonomy".parse::<GroupKind>(), Ok(GroupKind::Partonomy));
+        assert!("pathway".parse::<GroupKind>().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! This module provides a safe Rust interface to the libSBML GroupsModelPlugin class.
+//!
+//! The GroupsModelPlugin class holds the content the groups package adds to a model:
+//! the list of groups. Groups can refer to other groups, in which case the members of
+//! the nested group belong to the referencing group as well.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML GroupsModelPlugin class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, collections::HashSet, pin::Pin, rc::Rc};
+
+use crate::{
+    groups::{
+        group::{Group, GroupBuilder, GroupKind},
+        member::MemberRef,
+    },
+    inner, pin_ptr,
+    sbmlcxx::{self},
+    traits::fromptr::FromPtr,
+};
+
+/// A safe wrapper around the libSBML GroupsModelPlugin class.
+///
+/// This struct maintains a reference to the underlying C++ GroupsModelPlugin object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the groups of the model.
+pub struct GroupsModelPlugin<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::GroupsModelPlugin>>,
+    groups: RefCell<Vec<Rc<Group<'a>>>>,
+}
+
+// Set the inner trait for the GroupsModelPlugin struct
+inner!(sbmlcxx::GroupsModelPlugin, GroupsModelPlugin<'a>);
+
+impl<'a> GroupsModelPlugin<'a> {
+    /// Returns a reference to the inner RefCell containing the GroupsModelPlugin pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::GroupsModelPlugin>> {
+        &self.inner
+    }
+
+    /// Creates a new group.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new group
+    /// * `kind` - The relation between the group and its members
+    ///
+    /// # Returns
+    /// The newly created Group wrapped in an Rc
+    pub fn create_group(&self, id: &str, kind: GroupKind) -> Rc<Group<'a>> {
+        let group = Rc::new(Group::new(self, id, kind));
+        self.groups.borrow_mut().push(Rc::clone(&group));
+        group
+    }
+
+    /// Creates a new GroupBuilder for constructing a group.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new group
+    /// * `kind` - The relation between the group and its members
+    ///
+    /// # Returns
+    /// A GroupBuilder instance
+    pub fn build_group(&self, id: &str, kind: GroupKind) -> GroupBuilder<'a> {
+        GroupBuilder::new(self, id, kind)
+    }
+
+    /// Returns a vector of all groups of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Groups of the model
+    pub fn list_of_groups(&self) -> Vec<Rc<Group<'a>>> {
+        self.groups.borrow().to_vec()
+    }
+
+    /// Retrieves a group by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the group
+    ///
+    /// # Returns
+    /// Some(Rc<Group>) if found, None if not found
+    pub fn get_group(&self, id: &str) -> Option<Rc<Group<'a>>> {
+        self.groups
+            .borrow()
+            .iter()
+            .find(|group| (*group).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Returns the groups that directly contain the given element.
+    ///
+    /// # Arguments
+    /// * `reference` - The id or meta id of the element
+    ///
+    /// # Returns
+    /// A vector containing Rc references to the Groups with a member referring to the
+    /// element
+    pub fn groups_of(&self, reference: &MemberRef) -> Vec<Rc<Group<'a>>> {
+        self.groups
+            .borrow()
+            .iter()
+            .filter(|group| group.contains(reference))
+            .map(Rc::clone)
+            .collect()
+    }
+
+    /// Returns all elements belonging to a group, including those of nested groups.
+    ///
+    /// A member referring to another group of the model makes that group and all of its
+    /// members part of the referencing group. Each element is reported once, in the order
+    /// it is first reached, and cyclic references between groups are followed only once.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the group
+    ///
+    /// # Returns
+    /// The references of all elements of the group, None if the group does not exist
+    pub fn resolve_members(&self, id: &str) -> Option<Vec<MemberRef>> {
+        let group = self.get_group(id)?;
+
+        let mut visited = HashSet::from([id.to_string()]);
+        let mut seen = HashSet::new();
+        let mut members = Vec::new();
+        let mut stack = vec![group];
+
+        while let Some(group) = stack.pop() {
+            for reference in group.member_refs() {
+                if !seen.insert(reference.clone()) {
+                    continue;
+                }
+
+                if let MemberRef::Id(member_id) = &reference {
+                    if visited.insert(member_id.clone()) {
+                        if let Some(nested) = self.get_group(member_id) {
+                            stack.push(nested);
+                        }
+                    }
+                }
+
+                members.push(reference);
+            }
+        }
+
+        Some(members)
+    }
+}
+
+impl FromPtr<sbmlcxx::GroupsModelPlugin> for GroupsModelPlugin<'_> {
+    /// Creates a new GroupsModelPlugin instance from a unique pointer to a libSBML
+    /// GroupsModelPlugin.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// GroupsModelPlugin instances from libSBML GroupsModelPlugin pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML GroupsModelPlugin
+    ///
+    /// # Returns
+    /// A new GroupsModelPlugin instance
+    fn from_ptr(ptr: *mut sbmlcxx::GroupsModelPlugin) -> Self {
+        let plugin = RefCell::new(pin_ptr!(ptr, sbmlcxx::GroupsModelPlugin));
+
+        // Fetch all groups
+        let n_groups = plugin.borrow().getNumGroups().0;
+        let groups: Vec<_> = (0..n_groups)
+            .map(|i| {
+                let group = plugin.borrow_mut().as_mut().getGroup1(i.into());
+                Rc::new(Group::from_ptr(group))
+            })
+            .collect();
+
+        Self {
+            inner: plugin,
+            groups: RefCell::new(groups),
+        }
+    }
+}
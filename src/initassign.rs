@@ -0,0 +1,238 @@
+//! This module provides a safe Rust interface to the libSBML InitialAssignment class.
+//!
+//! The InitialAssignment class sets the value of a species, compartment, parameter or
+//! species reference at the start of a simulation, using a mathematical formula that
+//! may refer to other quantities of the model.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML InitialAssignment class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    astnode::{formula_to_string, parse_formula},
+    inner,
+    model::Model,
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML InitialAssignment class.
+///
+/// This struct maintains a reference to the underlying C++ InitialAssignment object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct InitialAssignment<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::InitialAssignment>>,
+}
+
+// Set the inner trait for the InitialAssignment struct
+inner!(sbmlcxx::InitialAssignment, InitialAssignment<'a>);
+
+// Set the annotation trait for the InitialAssignment struct
+upcast_annotation!(
+    InitialAssignment<'a>,
+    sbmlcxx::InitialAssignment,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the InitialAssignment struct
+sbase!(InitialAssignment<'a>, sbmlcxx::InitialAssignment);
+
+impl<'a> InitialAssignment<'a> {
+    /// Creates a new InitialAssignment instance within the given Model.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this initial assignment
+    /// * `symbol` - The identifier of the quantity to assign
+    /// * `formula` - The formula defining the initial value
+    ///
+    /// # Returns
+    /// A new InitialAssignment instance
+    pub fn new(model: &Model<'a>, symbol: &str, formula: &str) -> Self {
+        let assignment_ptr = model
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createInitialAssignment();
+        let assignment = pin_ptr!(assignment_ptr, sbmlcxx::InitialAssignment);
+
+        let initial_assignment = Self {
+            inner: RefCell::new(assignment),
+        };
+        initial_assignment.set_symbol(symbol);
+        initial_assignment.set_formula(formula);
+        initial_assignment
+    }
+
+    /// Returns the symbol assigned by this initial assignment.
+    ///
+    /// # Returns
+    /// The identifier of the assigned quantity as a String
+    pub fn symbol(&self) -> String {
+        self.inner
+            .borrow()
+            .getSymbol()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the symbol assigned by this initial assignment.
+    ///
+    /// # Arguments
+    /// * `symbol` - The identifier of the quantity to assign
+    pub fn set_symbol(&self, symbol: &str) {
+        let_cxx_string!(symbol = symbol);
+        self.inner.borrow_mut().as_mut().setSymbol(&symbol);
+    }
+
+    /// Returns the formula of the initial assignment.
+    ///
+    /// # Returns
+    /// The formula in the SBML Level 3 infix syntax, empty if no math is set
+    pub fn formula(&self) -> String {
+        formula_to_string(self.inner.borrow().getMath())
+    }
+
+    /// Returns the math of the initial assignment, null if no math is set.
+    pub(crate) fn math(&self) -> *const sbmlcxx::ASTNode {
+        self.inner.borrow().getMath()
+    }
+
+    /// Sets the formula of the initial assignment.
+    ///
+    /// Formulas that cannot be parsed by libSBML leave the math unchanged.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula in the SBML Level 3 infix syntax
+    pub fn set_formula(&self, formula: &str) {
+        if let Some(math) = parse_formula(formula) {
+            unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        }
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::InitialAssignment, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::InitialAssignment> for InitialAssignment<'_> {
+    /// Creates a new InitialAssignment instance from a unique pointer to a libSBML InitialAssignment.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// InitialAssignment instances from libSBML InitialAssignment pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML InitialAssignment
+    ///
+    /// # Returns
+    /// A new InitialAssignment instance
+    fn from_ptr(ptr: *mut sbmlcxx::InitialAssignment) -> Self {
+        let assignment = pin_ptr!(ptr, sbmlcxx::InitialAssignment);
+        Self {
+            inner: RefCell::new(assignment),
+        }
+    }
+}
+
+/// A builder for creating InitialAssignment instances.
+///
+/// This struct provides a fluent interface for configuring and building
+/// InitialAssignment instances.
+pub struct InitialAssignmentBuilder<'a> {
+    initial_assignment: Rc<InitialAssignment<'a>>,
+}
+
+impl<'a> InitialAssignmentBuilder<'a> {
+    /// Creates a new InitialAssignmentBuilder instance.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this initial assignment
+    /// * `symbol` - The identifier of the quantity to assign
+    /// * `formula` - The formula defining the initial value
+    ///
+    /// # Returns
+    /// A new InitialAssignmentBuilder instance
+    pub fn new(model: &Model<'a>, symbol: &str, formula: &str) -> Self {
+        let initial_assignment = model.create_initial_assignment(symbol, formula);
+        Self { initial_assignment }
+    }
+
+    /// Sets the annotation string for this initial assignment.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.initial_assignment
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.initial_assignment.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured InitialAssignment.
+    ///
+    /// # Returns
+    /// The constructed InitialAssignment instance wrapped in an Rc
+    pub fn build(self) -> Rc<InitialAssignment<'a>> {
+        self.initial_assignment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SBMLDocument;
+
+    #[test]
+    fn test_initial_assignment_new() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let assignment = InitialAssignment::new(&model, "x", "k * 2");
+
+        assert_eq!(assignment.symbol(), "x");
+        assert_eq!(assignment.formula(), "k * 2");
+
+        assignment.set_symbol("y");
+        assignment.set_formula("pow(k, 2)");
+        assert_eq!(assignment.symbol(), "y");
+        assert_eq!(assignment.formula(), "k^2");
+    }
+
+    #[test]
+    fn test_initial_assignment_builder() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let assignment = InitialAssignmentBuilder::new(&model, "x", "k * 2")
+            .annotation("<test>test</test>")
+            .expect("Failed to set annotation")
+            .build();
+
+        assert_eq!(assignment.symbol(), "x");
+        assert_eq!(
+            assignment
+                .get_annotation()
+                .replace("\n", "")
+                .replace(' ', ""),
+            "<annotation><test>test</test></annotation>"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! This module provides a safe Rust interface to the libSBML KineticLaw class.
+//!
+//! The KineticLaw class describes the rate of a reaction as a mathematical formula.
+//! The rate is expressed in units of substance per time and may refer to local
+//! parameters that are only visible within the kinetic law.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML KineticLaw class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    localparam::{LocalParameter, LocalParameterBuilder},
+    pin_ptr,
+    reaction::Reaction,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML KineticLaw class.
+///
+/// This struct maintains a reference to the underlying C++ KineticLaw object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains a vector of the local parameters defined by the kinetic law.
+pub struct KineticLaw<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::KineticLaw>>,
+    local_parameters: RefCell<Vec<Rc<LocalParameter<'a>>>>,
+}
+
+// Set the inner trait for the KineticLaw struct
+inner!(sbmlcxx::KineticLaw, KineticLaw<'a>);
+
+// Set the annotation trait for the KineticLaw struct
+upcast_annotation!(KineticLaw<'a>, sbmlcxx::KineticLaw, sbmlcxx::SBase);
+
+// Set the SBase trait for the KineticLaw struct
+sbase!(KineticLaw<'a>, sbmlcxx::KineticLaw);
+
+impl<'a> KineticLaw<'a> {
+    /// Creates a new KineticLaw instance within the given Reaction.
+    ///
+    /// # Arguments
+    /// * `reaction` - The parent Reaction that will contain this kinetic law
+    /// * `formula` - The formula describing the rate of the reaction
+    ///
+    /// # Returns
+    /// A new KineticLaw instance
+    pub fn new(reaction: &Reaction<'a>, formula: &str) -> Self {
+        let kinetic_law_ptr = reaction.inner().borrow_mut().as_mut().createKineticLaw();
+        let mut kinetic_law = pin_ptr!(kinetic_law_ptr, sbmlcxx::KineticLaw);
+
+        let_cxx_string!(formula = formula);
+        kinetic_law.as_mut().setFormula(&formula);
+
+        Self {
+            inner: RefCell::new(kinetic_law),
+            local_parameters: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a reference to the inner RefCell containing the KineticLaw pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::KineticLaw>> {
+        &self.inner
+    }
+
+    /// Returns the formula of the kinetic law.
+    ///
+    /// # Returns
+    /// The formula of the kinetic law as a String
+    pub fn formula(&self) -> String {
+        self.inner
+            .borrow()
+            .getFormula()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Returns the math of the kinetic law, null if no math is set.
+    pub(crate) fn math(&self) -> *const sbmlcxx::ASTNode {
+        self.inner.borrow().getMath()
+    }
+
+    /// Sets the formula of the kinetic law.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula to set
+    pub fn set_formula(&self, formula: &str) {
+        let_cxx_string!(formula = formula);
+        self.inner.borrow_mut().as_mut().setFormula(&formula);
+    }
+
+    /// Creates a new local parameter within this kinetic law.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new parameter
+    ///
+    /// # Returns
+    /// A new LocalParameter instance wrapped in an Rc
+    pub fn create_local_parameter(&self, id: &str) -> Rc<LocalParameter<'a>> {
+        let parameter = Rc::new(LocalParameter::new(self, id));
+        self.local_parameters
+            .borrow_mut()
+            .push(Rc::clone(&parameter));
+        parameter
+    }
+
+    /// Creates a new LocalParameterBuilder for constructing a LocalParameter with a fluent API.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new parameter
+    ///
+    /// # Returns
+    /// A LocalParameterBuilder instance that can be used to configure and create the parameter
+    pub fn build_local_parameter(&self, id: &str) -> LocalParameterBuilder<'a> {
+        LocalParameterBuilder::new(self, id)
+    }
+
+    /// Returns a vector of all local parameters of the kinetic law.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all LocalParameters of the kinetic law
+    pub fn list_of_local_parameters(&self) -> Vec<Rc<LocalParameter<'a>>> {
+        self.local_parameters.borrow().to_vec()
+    }
+
+    /// Retrieves a local parameter by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the parameter to retrieve
+    ///
+    /// # Returns
+    /// Some(Rc<LocalParameter>) if found, None if not found
+    pub fn get_local_parameter(&self, id: &str) -> Option<Rc<LocalParameter<'a>>> {
+        self.local_parameters
+            .borrow()
+            .iter()
+            .find(|parameter| (*parameter).id() == id)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::KineticLaw, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::KineticLaw> for KineticLaw<'_> {
+    /// Creates a new KineticLaw instance from a unique pointer to a libSBML KineticLaw.
+    ///
+    /// This method is primarily used internally by the Reaction class to create
+    /// KineticLaw instances from libSBML KineticLaw pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML KineticLaw
+    ///
+    /// # Returns
+    /// A new KineticLaw instance
+    fn from_ptr(ptr: *mut sbmlcxx::KineticLaw) -> Self {
+        let kinetic_law = RefCell::new(pin_ptr!(ptr, sbmlcxx::KineticLaw));
+
+        // Fetch all local parameters
+        let n_parameters = kinetic_law.borrow().getNumLocalParameters().0;
+        let local_parameters: Vec<_> = (0..n_parameters)
+            .map(|i| {
+                let parameter = kinetic_law
+                    .borrow_mut()
+                    .as_mut()
+                    .getLocalParameter1(i.into());
+                Rc::new(LocalParameter::from_ptr(parameter))
+            })
+            .collect();
+
+        Self {
+            inner: kinetic_law,
+            local_parameters: RefCell::new(local_parameters),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_kinetic_law_formula() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = model.create_reaction("r");
+        let kinetic_law = reaction.create_kinetic_law("k1 * A");
+
+        assert_eq!(kinetic_law.formula(), "k1 * A");
+        kinetic_law.set_formula("k2 * B");
+        assert_eq!(kinetic_law.formula(), "k2 * B");
+    }
+
+    #[test]
+    fn test_kinetic_law_local_parameters() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = model.create_reaction("r");
+        let kinetic_law = reaction.create_kinetic_law("k1 * A");
+        kinetic_law.create_local_parameter("k1").set_value(1.5);
+
+        assert_eq!(kinetic_law.list_of_local_parameters().len(), 1);
+        let parameter = kinetic_law
+            .get_local_parameter("k1")
+            .expect("Local parameter not found");
+        assert_eq!(parameter.value(), Some(1.5));
+        assert!(kinetic_law.get_local_parameter("k2").is_none());
+    }
+
+    #[test]
+    fn test_kinetic_law_read_from_xml() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = model.create_reaction("r");
+        let kinetic_law = reaction.create_kinetic_law("k1 * A");
+        kinetic_law.create_local_parameter("k1").set_value(1.5);
+
+        let xml = doc.to_xml_string();
+        let read = SBMLReader::from_xml_string(&xml);
+        let model = read.model().expect("Model not found");
+        let reaction = model.get_reaction("r").expect("Reaction not found");
+        let kinetic_law = reaction.kinetic_law().expect("Kinetic law not found");
+
+        assert_eq!(kinetic_law.formula(), "k1 * A");
+        assert_eq!(
+            kinetic_law.get_local_parameter("k1").unwrap().value(),
+            Some(1.5)
+        );
+    }
+}
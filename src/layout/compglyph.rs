@@ -0,0 +1,163 @@
+//! This module provides a safe Rust interface to the libSBML CompartmentGlyph class.
+//!
+//! The CompartmentGlyph class of the layout package places a compartment of the model
+//! in a diagram, usually as a rectangle enclosing the glyphs of its species.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML CompartmentGlyph class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    layout::{
+        diagram::Layout,
+        geometry::{read_bounding_box, write_bounding_box, BoundingBox},
+    },
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML CompartmentGlyph class.
+///
+/// This struct maintains a reference to the underlying C++ CompartmentGlyph object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct CompartmentGlyph<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::CompartmentGlyph>>,
+}
+
+// Set the inner trait for the CompartmentGlyph struct
+inner!(sbmlcxx::CompartmentGlyph, CompartmentGlyph<'a>);
+
+// Set the annotation trait for the CompartmentGlyph struct
+upcast_annotation!(
+    CompartmentGlyph<'a>,
+    sbmlcxx::CompartmentGlyph,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the CompartmentGlyph struct
+sbase!(CompartmentGlyph<'a>, sbmlcxx::CompartmentGlyph);
+
+impl<'a> CompartmentGlyph<'a> {
+    /// Creates a new CompartmentGlyph instance within the given Layout.
+    ///
+    /// # Arguments
+    /// * `layout` - The parent Layout that will contain this glyph
+    /// * `id` - The identifier for this glyph
+    /// * `compartment` - The identifier of the compartment drawn by the glyph
+    /// * `bounding_box` - The position and size of the glyph
+    ///
+    /// # Returns
+    /// A new CompartmentGlyph instance
+    pub fn new(
+        layout: &Layout<'a>,
+        id: &str,
+        compartment: &str,
+        bounding_box: &BoundingBox,
+    ) -> Self {
+        let glyph_ptr = layout
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createCompartmentGlyph();
+        let glyph = pin_ptr!(glyph_ptr, sbmlcxx::CompartmentGlyph);
+
+        let glyph = Self {
+            inner: RefCell::new(glyph),
+        };
+        glyph.set_id(id);
+        glyph.set_compartment(compartment);
+        glyph.set_bounding_box(bounding_box);
+        glyph
+    }
+
+    /// Returns the id of the glyph.
+    ///
+    /// # Returns
+    /// The id of the glyph as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::CompartmentGlyph, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::CompartmentGlyph, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the identifier of the compartment drawn by the glyph.
+    ///
+    /// # Returns
+    /// The compartment identifier, empty if not set
+    pub fn compartment(&self) -> String {
+        self.inner
+            .borrow()
+            .getCompartmentId()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the compartment drawn by the glyph.
+    ///
+    /// # Arguments
+    /// * `compartment` - The compartment identifier
+    pub fn set_compartment(&self, compartment: &str) {
+        let_cxx_string!(compartment = compartment);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setCompartmentId(&compartment);
+    }
+
+    /// Returns the position and size of the glyph.
+    ///
+    /// # Returns
+    /// The bounding box of the glyph
+    pub fn bounding_box(&self) -> BoundingBox {
+        let object = upcast!(self, sbmlcxx::CompartmentGlyph, sbmlcxx::GraphicalObject);
+        read_bounding_box(object)
+    }
+
+    /// Sets the position and size of the glyph.
+    ///
+    /// # Arguments
+    /// * `bounding_box` - The bounding box to set
+    pub fn set_bounding_box(&self, bounding_box: &BoundingBox) {
+        let object = upcast!(self, sbmlcxx::CompartmentGlyph, sbmlcxx::GraphicalObject);
+        write_bounding_box(object, bounding_box);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::CompartmentGlyph, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::CompartmentGlyph> for CompartmentGlyph<'_> {
+    /// Creates a new CompartmentGlyph instance from a unique pointer to a libSBML CompartmentGlyph.
+    ///
+    /// This method is primarily used internally by the Layout class to create
+    /// CompartmentGlyph instances from libSBML CompartmentGlyph pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML CompartmentGlyph
+    ///
+    /// # Returns
+    /// A new CompartmentGlyph instance
+    fn from_ptr(ptr: *mut sbmlcxx::CompartmentGlyph) -> Self {
+        let glyph = pin_ptr!(ptr, sbmlcxx::CompartmentGlyph);
+        Self {
+            inner: RefCell::new(glyph),
+        }
+    }
+}
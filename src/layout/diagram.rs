@@ -0,0 +1,423 @@
+//! This module provides a safe Rust interface to the libSBML Layout class.
+//!
+//! The Layout class of the layout package is a single diagram of a model. It has a
+//! size and contains glyphs for compartments, species and reactions, which refer to
+//! the model elements they draw by identifier. A model can have several layouts, e.g.
+//! an overview and a detailed view of a pathway.
+//!
+//! If the render package is enabled, a layout can also carry render information that
+//! describes the colors and styles of its glyphs.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Layout class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    layout::{
+        compglyph::CompartmentGlyph, geometry::BoundingBox, plugin::LayoutModelPlugin,
+        reactionglyph::ReactionGlyph, speciesglyph::SpeciesGlyph,
+    },
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+#[cfg(feature = "render")]
+use crate::{
+    packages::{get_plugin, Package},
+    render::info::RenderInformation,
+    upcast,
+};
+
+/// A safe wrapper around the libSBML Layout class.
+///
+/// This struct maintains a reference to the underlying C++ Layout object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the glyphs and render information of the layout.
+pub struct Layout<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Layout>>,
+    compartment_glyphs: RefCell<Vec<Rc<CompartmentGlyph<'a>>>>,
+    species_glyphs: RefCell<Vec<Rc<SpeciesGlyph<'a>>>>,
+    reaction_glyphs: RefCell<Vec<Rc<ReactionGlyph<'a>>>>,
+    #[cfg(feature = "render")]
+    render_information: RefCell<Vec<Rc<RenderInformation<'a>>>>,
+}
+
+// Set the inner trait for the Layout struct
+inner!(sbmlcxx::Layout, Layout<'a>);
+
+// Set the annotation trait for the Layout struct
+upcast_annotation!(Layout<'a>, sbmlcxx::Layout, sbmlcxx::SBase);
+
+// Set the SBase trait for the Layout struct
+sbase!(Layout<'a>, sbmlcxx::Layout);
+
+impl<'a> Layout<'a> {
+    /// Creates a new Layout instance within the given layout model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The layout plugin of the model that will contain this layout
+    /// * `id` - The identifier for this layout
+    /// * `width` - The width of the diagram
+    /// * `height` - The height of the diagram
+    ///
+    /// # Returns
+    /// A new Layout instance
+    pub fn new(plugin: &LayoutModelPlugin<'a>, id: &str, width: f64, height: f64) -> Self {
+        let layout_ptr = plugin.inner().borrow_mut().as_mut().createLayout();
+        let layout = pin_ptr!(layout_ptr, sbmlcxx::Layout);
+
+        let layout = Self {
+            inner: RefCell::new(layout),
+            compartment_glyphs: RefCell::new(Vec::new()),
+            species_glyphs: RefCell::new(Vec::new()),
+            reaction_glyphs: RefCell::new(Vec::new()),
+            #[cfg(feature = "render")]
+            render_information: RefCell::new(Vec::new()),
+        };
+        layout.set_id(id);
+        layout.set_dimensions(width, height);
+        layout
+    }
+
+    /// Returns a reference to the inner RefCell containing the Layout pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Layout>> {
+        &self.inner
+    }
+
+    /// Returns the id of the layout.
+    ///
+    /// # Returns
+    /// The id of the layout as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the layout.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the layout.
+    ///
+    /// # Returns
+    /// The name of the layout as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the layout.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the width of the diagram.
+    pub fn width(&self) -> f64 {
+        let dimensions_ptr = self.inner.borrow_mut().as_mut().getDimensions1();
+        let dimensions = pin_ptr!(dimensions_ptr, sbmlcxx::Dimensions);
+        dimensions.getWidth()
+    }
+
+    /// Returns the height of the diagram.
+    pub fn height(&self) -> f64 {
+        let dimensions_ptr = self.inner.borrow_mut().as_mut().getDimensions1();
+        let dimensions = pin_ptr!(dimensions_ptr, sbmlcxx::Dimensions);
+        dimensions.getHeight()
+    }
+
+    /// Sets the size of the diagram.
+    ///
+    /// # Arguments
+    /// * `width` - The width of the diagram
+    /// * `height` - The height of the diagram
+    pub fn set_dimensions(&self, width: f64, height: f64) {
+        let dimensions_ptr = self.inner.borrow_mut().as_mut().getDimensions1();
+        let mut dimensions = pin_ptr!(dimensions_ptr, sbmlcxx::Dimensions);
+        dimensions.as_mut().setWidth(width);
+        dimensions.as_mut().setHeight(height);
+    }
+
+    /// Creates a new compartment glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new glyph
+    /// * `compartment` - The identifier of the compartment drawn by the glyph
+    /// * `bounding_box` - The position and size of the glyph
+    ///
+    /// # Returns
+    /// The newly created CompartmentGlyph wrapped in an Rc
+    pub fn create_compartment_glyph(
+        &self,
+        id: &str,
+        compartment: &str,
+        bounding_box: &BoundingBox,
+    ) -> Rc<CompartmentGlyph<'a>> {
+        let glyph = Rc::new(CompartmentGlyph::new(self, id, compartment, bounding_box));
+        self.compartment_glyphs.borrow_mut().push(Rc::clone(&glyph));
+        glyph
+    }
+
+    /// Returns a vector of all compartment glyphs of the layout.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all CompartmentGlyphs
+    pub fn list_of_compartment_glyphs(&self) -> Vec<Rc<CompartmentGlyph<'a>>> {
+        self.compartment_glyphs.borrow().to_vec()
+    }
+
+    /// Retrieves a compartment glyph by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    ///
+    /// # Returns
+    /// Some(Rc<CompartmentGlyph>) if found, None if not found
+    pub fn get_compartment_glyph(&self, id: &str) -> Option<Rc<CompartmentGlyph<'a>>> {
+        self.compartment_glyphs
+            .borrow()
+            .iter()
+            .find(|glyph| (*glyph).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Creates a new species glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new glyph
+    /// * `species` - The identifier of the species drawn by the glyph
+    /// * `bounding_box` - The position and size of the glyph
+    ///
+    /// # Returns
+    /// The newly created SpeciesGlyph wrapped in an Rc
+    pub fn create_species_glyph(
+        &self,
+        id: &str,
+        species: &str,
+        bounding_box: &BoundingBox,
+    ) -> Rc<SpeciesGlyph<'a>> {
+        let glyph = Rc::new(SpeciesGlyph::new(self, id, species, bounding_box));
+        self.species_glyphs.borrow_mut().push(Rc::clone(&glyph));
+        glyph
+    }
+
+    /// Returns a vector of all species glyphs of the layout.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all SpeciesGlyphs
+    pub fn list_of_species_glyphs(&self) -> Vec<Rc<SpeciesGlyph<'a>>> {
+        self.species_glyphs.borrow().to_vec()
+    }
+
+    /// Retrieves a species glyph by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    ///
+    /// # Returns
+    /// Some(Rc<SpeciesGlyph>) if found, None if not found
+    pub fn get_species_glyph(&self, id: &str) -> Option<Rc<SpeciesGlyph<'a>>> {
+        self.species_glyphs
+            .borrow()
+            .iter()
+            .find(|glyph| (*glyph).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Returns all glyphs drawing the given species.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the species
+    ///
+    /// # Returns
+    /// A vector containing Rc references to the matching SpeciesGlyphs
+    pub fn species_glyphs_of(&self, species: &str) -> Vec<Rc<SpeciesGlyph<'a>>> {
+        self.species_glyphs
+            .borrow()
+            .iter()
+            .filter(|glyph| glyph.species() == species)
+            .map(Rc::clone)
+            .collect()
+    }
+
+    /// Creates a new reaction glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new glyph
+    /// * `reaction` - The identifier of the reaction drawn by the glyph
+    ///
+    /// # Returns
+    /// The newly created ReactionGlyph wrapped in an Rc
+    pub fn create_reaction_glyph(&self, id: &str, reaction: &str) -> Rc<ReactionGlyph<'a>> {
+        let glyph = Rc::new(ReactionGlyph::new(self, id, reaction));
+        self.reaction_glyphs.borrow_mut().push(Rc::clone(&glyph));
+        glyph
+    }
+
+    /// Returns a vector of all reaction glyphs of the layout.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all ReactionGlyphs
+    pub fn list_of_reaction_glyphs(&self) -> Vec<Rc<ReactionGlyph<'a>>> {
+        self.reaction_glyphs.borrow().to_vec()
+    }
+
+    /// Retrieves a reaction glyph by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    ///
+    /// # Returns
+    /// Some(Rc<ReactionGlyph>) if found, None if not found
+    pub fn get_reaction_glyph(&self, id: &str) -> Option<Rc<ReactionGlyph<'a>>> {
+        self.reaction_glyphs
+            .borrow()
+            .iter()
+            .find(|glyph| (*glyph).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Returns the render plugin of the layout.
+    ///
+    /// # Arguments
+    /// * `enable` - Whether to enable the render package on the document if needed
+    #[cfg(feature = "render")]
+    pub(crate) fn render_plugin(
+        &self,
+        enable: bool,
+    ) -> Option<Pin<&'a mut sbmlcxx::RenderLayoutPlugin>> {
+        let base = upcast!(self, sbmlcxx::Layout, sbmlcxx::SBase);
+        get_plugin(base, Package::Render, enable)
+    }
+
+    /// Creates new render information for the layout.
+    ///
+    /// The render package is enabled on the document if needed.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new render information
+    ///
+    /// # Returns
+    /// The newly created RenderInformation wrapped in an Rc
+    ///
+    /// # Panics
+    /// If the layout is not part of a document
+    #[cfg(feature = "render")]
+    pub fn create_render_information(&self, id: &str) -> Rc<RenderInformation<'a>> {
+        let information = Rc::new(RenderInformation::new(self, id));
+        self.render_information
+            .borrow_mut()
+            .push(Rc::clone(&information));
+        information
+    }
+
+    /// Returns a vector of all render information of the layout.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all RenderInformation of the layout
+    #[cfg(feature = "render")]
+    pub fn list_of_render_information(&self) -> Vec<Rc<RenderInformation<'a>>> {
+        self.render_information.borrow().to_vec()
+    }
+
+    /// Retrieves render information by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the render information
+    ///
+    /// # Returns
+    /// Some(Rc<RenderInformation>) if found, None if not found
+    #[cfg(feature = "render")]
+    pub fn get_render_information(&self, id: &str) -> Option<Rc<RenderInformation<'a>>> {
+        self.render_information
+            .borrow()
+            .iter()
+            .find(|information| (*information).id() == id)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Layout, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Layout> for Layout<'_> {
+    /// Creates a new Layout instance from a unique pointer to a libSBML Layout.
+    ///
+    /// This method is primarily used internally by the LayoutModelPlugin class to create
+    /// Layout instances from libSBML Layout pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Layout
+    ///
+    /// # Returns
+    /// A new Layout instance
+    fn from_ptr(ptr: *mut sbmlcxx::Layout) -> Self {
+        let layout = RefCell::new(pin_ptr!(ptr, sbmlcxx::Layout));
+
+        // Fetch all compartment glyphs
+        let n_compartment_glyphs = layout.borrow().getNumCompartmentGlyphs().0;
+        let compartment_glyphs: Vec<_> = (0..n_compartment_glyphs)
+            .map(|i| {
+                let glyph = layout.borrow_mut().as_mut().getCompartmentGlyph1(i.into());
+                Rc::new(CompartmentGlyph::from_ptr(glyph))
+            })
+            .collect();
+
+        // Fetch all species glyphs
+        let n_species_glyphs = layout.borrow().getNumSpeciesGlyphs().0;
+        let species_glyphs: Vec<_> = (0..n_species_glyphs)
+            .map(|i| {
+                let glyph = layout.borrow_mut().as_mut().getSpeciesGlyph1(i.into());
+                Rc::new(SpeciesGlyph::from_ptr(glyph))
+            })
+            .collect();
+
+        // Fetch all reaction glyphs
+        let n_reaction_glyphs = layout.borrow().getNumReactionGlyphs().0;
+        let reaction_glyphs: Vec<_> = (0..n_reaction_glyphs)
+            .map(|i| {
+                let glyph = layout.borrow_mut().as_mut().getReactionGlyph1(i.into());
+                Rc::new(ReactionGlyph::from_ptr(glyph))
+            })
+            .collect();
+
+        let layout = Self {
+            inner: layout,
+            compartment_glyphs: RefCell::new(compartment_glyphs),
+            species_glyphs: RefCell::new(species_glyphs),
+            reaction_glyphs: RefCell::new(reaction_glyphs),
+            #[cfg(feature = "render")]
+            render_information: RefCell::new(Vec::new()),
+        };
+
+        // Fetch all render information, if the render package is enabled
+        #[cfg(feature = "render")]
+        if let Some(mut plugin) = layout.render_plugin(false) {
+            let n_information = plugin.getNumLocalRenderInformationObjects().0;
+            let information: Vec<_> = (0..n_information)
+                .map(|i| {
+                    let information = plugin.as_mut().getRenderInformation1(i.into());
+                    Rc::new(RenderInformation::from_ptr(information))
+                })
+                .collect();
+            layout.render_information.replace(information);
+        }
+
+        layout
+    }
+}
@@ -0,0 +1,214 @@
+//! Geometric value types of the layout package.
+//!
+//! Positions, bounding boxes and curves of glyphs are small records that are read and
+//! written as a whole, so they are represented by plain Rust values instead of
+//! wrappers around the libSBML objects. This module also provides the internal
+//! helpers that copy these values from and to libSBML.
+
+use std::pin::Pin;
+
+use crate::{pin_ptr, sbmlcxx, upcast_pin};
+
+/// A point in the two-dimensional coordinate system of a layout.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    /// The horizontal position
+    pub x: f64,
+    /// The vertical position
+    pub y: f64,
+}
+
+impl Point {
+    /// Creates a new point.
+    ///
+    /// # Arguments
+    /// * `x` - The horizontal position
+    /// * `y` - The vertical position
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The rectangle occupied by a glyph, given by its upper left corner and its size.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BoundingBox {
+    /// The horizontal position of the upper left corner
+    pub x: f64,
+    /// The vertical position of the upper left corner
+    pub y: f64,
+    /// The width of the rectangle
+    pub width: f64,
+    /// The height of the rectangle
+    pub height: f64,
+}
+
+impl BoundingBox {
+    /// Creates a new bounding box.
+    ///
+    /// # Arguments
+    /// * `x` - The horizontal position of the upper left corner
+    /// * `y` - The vertical position of the upper left corner
+    /// * `width` - The width of the rectangle
+    /// * `height` - The height of the rectangle
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the center of the bounding box.
+    pub fn center(&self) -> Point {
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
+/// A segment of a curve, either a straight line or a cubic Bézier curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurveSegment {
+    /// A straight line from `start` to `end`
+    Line {
+        /// The start of the line
+        start: Point,
+        /// The end of the line
+        end: Point,
+    },
+    /// A cubic Bézier curve from `start` to `end`, shaped by two control points
+    CubicBezier {
+        /// The start of the curve
+        start: Point,
+        /// The control point next to the start
+        base_point1: Point,
+        /// The control point next to the end
+        base_point2: Point,
+        /// The end of the curve
+        end: Point,
+    },
+}
+
+impl CurveSegment {
+    /// Returns the start of the segment.
+    pub fn start(&self) -> Point {
+        match self {
+            CurveSegment::Line { start, .. } | CurveSegment::CubicBezier { start, .. } => *start,
+        }
+    }
+
+    /// Returns the end of the segment.
+    pub fn end(&self) -> Point {
+        match self {
+            CurveSegment::Line { end, .. } | CurveSegment::CubicBezier { end, .. } => *end,
+        }
+    }
+}
+
+/// Reads the bounding box of a graphical object.
+pub(crate) fn read_bounding_box(mut object: Pin<&mut sbmlcxx::GraphicalObject>) -> BoundingBox {
+    let bounding_box_ptr = object.as_mut().getBoundingBox1();
+    let bounding_box = pin_ptr!(bounding_box_ptr, sbmlcxx::BoundingBox);
+    BoundingBox {
+        x: bounding_box.x(),
+        y: bounding_box.y(),
+        width: bounding_box.width(),
+        height: bounding_box.height(),
+    }
+}
+
+/// Replaces the bounding box of a graphical object.
+pub(crate) fn write_bounding_box(
+    mut object: Pin<&mut sbmlcxx::GraphicalObject>,
+    value: &BoundingBox,
+) {
+    let bounding_box_ptr = object.as_mut().getBoundingBox1();
+    let mut bounding_box = pin_ptr!(bounding_box_ptr, sbmlcxx::BoundingBox);
+    bounding_box.as_mut().setX(value.x);
+    bounding_box.as_mut().setY(value.y);
+    bounding_box.as_mut().setWidth(value.width);
+    bounding_box.as_mut().setHeight(value.height);
+}
+
+/// Reads the segments of a curve.
+///
+/// The concrete class of each segment is determined by its type code, after which
+/// the pointer is cast to CubicBezier if needed.
+pub(crate) fn read_curve(ptr: *mut sbmlcxx::Curve) -> Vec<CurveSegment> {
+    if ptr.is_null() {
+        return Vec::new();
+    }
+
+    let mut curve = pin_ptr!(ptr, sbmlcxx::Curve);
+    let n_segments = curve.getNumCurveSegments().0;
+    (0..n_segments)
+        .map(|i| {
+            let segment_ptr = curve.as_mut().getCurveSegment1(i.into());
+            let mut segment = pin_ptr!(segment_ptr, sbmlcxx::LineSegment);
+            let start = read_point(segment.as_mut().getStart1());
+            let end = read_point(segment.as_mut().getEnd1());
+
+            let base = upcast_pin!(segment, sbmlcxx::LineSegment, sbmlcxx::SBase);
+            let bezier_code = sbmlcxx::SBMLLayoutTypeCode_t::SBML_LAYOUT_CUBICBEZIER as i32;
+            if base.getTypeCode().0 == bezier_code {
+                let bezier_ptr = segment_ptr.cast::<sbmlcxx::CubicBezier>();
+                let mut bezier = pin_ptr!(bezier_ptr, sbmlcxx::CubicBezier);
+                CurveSegment::CubicBezier {
+                    start,
+                    base_point1: read_point(bezier.as_mut().getBasePoint11()),
+                    base_point2: read_point(bezier.as_mut().getBasePoint21()),
+                    end,
+                }
+            } else {
+                CurveSegment::Line { start, end }
+            }
+        })
+        .collect()
+}
+
+/// Replaces the segments of a curve.
+pub(crate) fn write_curve(mut curve: Pin<&mut sbmlcxx::Curve>, segments: &[CurveSegment]) {
+    let list_ptr = curve.as_mut().getListOfCurveSegments1();
+    let mut list = pin_ptr!(list_ptr, sbmlcxx::ListOfLineSegments);
+    let mut list = upcast_pin!(list, sbmlcxx::ListOfLineSegments, sbmlcxx::ListOf);
+    list.as_mut().clear(true);
+
+    for segment in segments {
+        match segment {
+            CurveSegment::Line { start, end } => {
+                let line_ptr = curve.as_mut().createLineSegment();
+                let mut line = pin_ptr!(line_ptr, sbmlcxx::LineSegment);
+                write_point(line.as_mut().getStart1(), start);
+                write_point(line.as_mut().getEnd1(), end);
+            }
+            CurveSegment::CubicBezier {
+                start,
+                base_point1,
+                base_point2,
+                end,
+            } => {
+                let bezier_ptr = curve.as_mut().createCubicBezier();
+                let mut bezier = pin_ptr!(bezier_ptr, sbmlcxx::CubicBezier);
+                write_point(bezier.as_mut().getBasePoint11(), base_point1);
+                write_point(bezier.as_mut().getBasePoint21(), base_point2);
+
+                let line_ptr = bezier_ptr.cast::<sbmlcxx::LineSegment>();
+                let mut line = pin_ptr!(line_ptr, sbmlcxx::LineSegment);
+                write_point(line.as_mut().getStart1(), start);
+                write_point(line.as_mut().getEnd1(), end);
+            }
+        }
+    }
+}
+
+/// Reads a libSBML point.
+fn read_point(ptr: *mut sbmlcxx::Point) -> Point {
+    let point = pin_ptr!(ptr, sbmlcxx::Point);
+    Point::new(point.x(), point.y())
+}
+
+/// Overwrites a libSBML point.
+fn write_point(ptr: *mut sbmlcxx::Point, value: &Point) {
+    let mut point = pin_ptr!(ptr, sbmlcxx::Point);
+    point.as_mut().setX(value.x);
+    point.as_mut().setY(value.y);
+}
@@ -0,0 +1,171 @@
+//! Support for the SBML Level 3 layout package.
+//!
+//! The layout package stores diagrams of a model, as drawn by tools such as
+//! CellDesigner or Newt. A [`Layout`] has a size and contains glyphs that place model
+//! elements in the diagram:
+//!
+//! - [`CompartmentGlyph`] and [`SpeciesGlyph`] have a [`BoundingBox`]
+//! - [`ReactionGlyph`] has a curve and is connected to species glyphs by
+//!   [`SpeciesReferenceGlyph`]s, whose [`SpeciesReferenceRole`] tells how to decorate
+//!   the connection
+//!
+//! Curves are sequences of [`CurveSegment`]s, which are either straight lines or cubic
+//! Bézier curves. Layouts are accessed through [`Model::layout_plugin`]; creating one
+//! enables the package on the document. Colors and styles of a layout are provided
+//! by the [`render`](crate::render) package.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("example");
+//!
+//! let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+//! layout.create_species_glyph("sg_A", "A", &BoundingBox::new(20.0, 80.0, 60.0, 40.0));
+//! layout.create_species_glyph("sg_B", "B", &BoundingBox::new(320.0, 80.0, 60.0, 40.0));
+//!
+//! let glyph = layout.create_reaction_glyph("rg_r1", "r1");
+//! glyph.set_curve(&[CurveSegment::Line {
+//!     start: Point::new(190.0, 100.0),
+//!     end: Point::new(210.0, 100.0),
+//! }]);
+//! glyph.create_species_reference_glyph("srg_A", "sg_A", SpeciesReferenceRole::Substrate);
+//! glyph.create_species_reference_glyph("srg_B", "sg_B", SpeciesReferenceRole::Product);
+//! ```
+//!
+//! [`Model::layout_plugin`]: crate::model::Model::layout_plugin
+
+/// Module providing compartment glyph functionality
+pub mod compglyph;
+/// Module providing layout functionality
+pub mod diagram;
+/// Module providing bounding box, point and curve types
+pub mod geometry;
+/// Module providing layout model plugin functionality
+pub mod plugin;
+/// Module providing reaction glyph functionality
+pub mod reactionglyph;
+/// Module providing species glyph functionality
+pub mod speciesglyph;
+/// Module providing species reference glyph functionality
+pub mod speciesrefglyph;
+
+pub use compglyph::CompartmentGlyph;
+pub use diagram::Layout;
+pub use geometry::{BoundingBox, CurveSegment, Point};
+pub use plugin::LayoutModelPlugin;
+pub use reactionglyph::ReactionGlyph;
+pub use speciesglyph::SpeciesGlyph;
+pub use speciesrefglyph::{SpeciesReferenceGlyph, SpeciesReferenceRole};
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_layout_glyphs() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        assert!(!doc.is_package_enabled(Package::Layout));
+
+        let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+        assert!(doc.is_package_enabled(Package::Layout));
+        assert_eq!(layout.width(), 400.0);
+        assert_eq!(layout.height(), 200.0);
+
+        let cell = BoundingBox::new(0.0, 0.0, 400.0, 200.0);
+        layout.create_compartment_glyph("cg_c", "c", &cell);
+        let glyph =
+            layout.create_species_glyph("sg_A", "A", &BoundingBox::new(20.0, 80.0, 60.0, 40.0));
+        layout.create_species_glyph("sg_A2", "A", &BoundingBox::new(20.0, 10.0, 60.0, 40.0));
+
+        assert_eq!(glyph.species(), "A");
+        assert_eq!(glyph.bounding_box().center(), Point::new(50.0, 100.0));
+        assert_eq!(layout.species_glyphs_of("A").len(), 2);
+        assert_eq!(
+            layout.get_compartment_glyph("cg_c").unwrap().bounding_box(),
+            cell
+        );
+    }
+
+    #[test]
+    fn test_reaction_glyph_curves() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+
+        let line = CurveSegment::Line {
+            start: Point::new(190.0, 100.0),
+            end: Point::new(210.0, 100.0),
+        };
+        let bezier = CurveSegment::CubicBezier {
+            start: Point::new(210.0, 100.0),
+            base_point1: Point::new(260.0, 100.0),
+            base_point2: Point::new(280.0, 60.0),
+            end: Point::new(320.0, 60.0),
+        };
+
+        let glyph = layout.create_reaction_glyph("rg_r1", "r1");
+        glyph.set_curve(&[line]);
+        let reference =
+            glyph.create_species_reference_glyph("srg_B", "sg_B", SpeciesReferenceRole::Product);
+        reference.set_species_reference("sr_B");
+        reference.set_curve(&[line, bezier]);
+
+        assert_eq!(glyph.reaction(), "r1");
+        assert_eq!(glyph.curve(), vec![line]);
+        assert_eq!(reference.role(), SpeciesReferenceRole::Product);
+        assert_eq!(reference.curve(), vec![line, bezier]);
+
+        // Replacing a curve drops the previous segments
+        reference.set_curve(&[bezier]);
+        assert_eq!(reference.curve(), vec![bezier]);
+    }
+
+    #[test]
+    fn test_layout_read_from_xml() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+        layout.set_name("Overview");
+        layout.create_species_glyph("sg_A", "A", &BoundingBox::new(20.0, 80.0, 60.0, 40.0));
+        let glyph = layout.create_reaction_glyph("rg_r1", "r1");
+        glyph.create_species_reference_glyph("srg_A", "sg_A", SpeciesReferenceRole::SideSubstrate);
+
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = read.model().expect("Model not found");
+        let layouts = model.layout_plugin().list_of_layouts();
+        assert_eq!(layouts.len(), 1);
+
+        let layout = &layouts[0];
+        assert_eq!(layout.id(), "diagram");
+        assert_eq!(layout.name(), "Overview");
+        assert_eq!(
+            layout.get_species_glyph("sg_A").unwrap().bounding_box(),
+            BoundingBox::new(20.0, 80.0, 60.0, 40.0)
+        );
+
+        let glyph = layout.get_reaction_glyph("rg_r1").unwrap();
+        let reference = glyph.get_species_reference_glyph("srg_A").unwrap();
+        assert_eq!(reference.species_glyph(), "sg_A");
+        assert_eq!(reference.role(), SpeciesReferenceRole::SideSubstrate);
+    }
+
+    #[test]
+    fn test_species_reference_role_parse() {
+        for role in [
+            SpeciesReferenceRole::Undefined,
+            SpeciesReferenceRole::Substrate,
+            SpeciesReferenceRole::Product,
+            SpeciesReferenceRole::SideSubstrate,
+            SpeciesReferenceRole::SideProduct,
+            SpeciesReferenceRole::Modifier,
+            SpeciesReferenceRole::Activator,
+            SpeciesReferenceRole::Inhibitor,
+        ] {
+            assert_eq!(role.to_string().parse::<SpeciesReferenceRole>(), Ok(role));
+        }
+        assert!("catalyst".parse::<SpeciesReferenceRole>().is_err());
+    }
+}
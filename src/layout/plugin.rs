@@ -0,0 +1,108 @@
+//! This module provides a safe Rust interface to the libSBML LayoutModelPlugin class.
+//!
+//! The LayoutModelPlugin class holds the content the layout package adds to a model:
+//! the list of layouts, each of which is a diagram of (parts of) the model.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML LayoutModelPlugin class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use crate::{
+    inner,
+    layout::diagram::Layout,
+    pin_ptr,
+    sbmlcxx::{self},
+    traits::fromptr::FromPtr,
+};
+
+/// A safe wrapper around the libSBML LayoutModelPlugin class.
+///
+/// This struct maintains a reference to the underlying C++ LayoutModelPlugin object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the layouts of the model.
+pub struct LayoutModelPlugin<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::LayoutModelPlugin>>,
+    layouts: RefCell<Vec<Rc<Layout<'a>>>>,
+}
+
+// Set the inner trait for the LayoutModelPlugin struct
+inner!(sbmlcxx::LayoutModelPlugin, LayoutModelPlugin<'a>);
+
+impl<'a> LayoutModelPlugin<'a> {
+    /// Returns a reference to the inner RefCell containing the LayoutModelPlugin pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::LayoutModelPlugin>> {
+        &self.inner
+    }
+
+    /// Creates a new layout.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new layout
+    /// * `width` - The width of the diagram
+    /// * `height` - The height of the diagram
+    ///
+    /// # Returns
+    /// The newly created Layout wrapped in an Rc
+    pub fn create_layout(&self, id: &str, width: f64, height: f64) -> Rc<Layout<'a>> {
+        let layout = Rc::new(Layout::new(self, id, width, height));
+        self.layouts.borrow_mut().push(Rc::clone(&layout));
+        layout
+    }
+
+    /// Returns a vector of all layouts of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Layouts of the model
+    pub fn list_of_layouts(&self) -> Vec<Rc<Layout<'a>>> {
+        self.layouts.borrow().to_vec()
+    }
+
+    /// Retrieves a layout by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the layout
+    ///
+    /// # Returns
+    /// Some(Rc<Layout>) if found, None if not found
+    pub fn get_layout(&self, id: &str) -> Option<Rc<Layout<'a>>> {
+        self.layouts
+            .borrow()
+            .iter()
+            .find(|layout| (*layout).id() == id)
+            .map(Rc::clone)
+    }
+}
+
+impl FromPtr<sbmlcxx::LayoutModelPlugin> for LayoutModelPlugin<'_> {
+    /// Creates a new LayoutModelPlugin instance from a unique pointer to a libSBML
+    /// LayoutModelPlugin.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// LayoutModelPlugin instances from libSBML LayoutModelPlugin pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML LayoutModelPlugin
+    ///
+    /// # Returns
+    /// A new LayoutModelPlugin instance
+    fn from_ptr(ptr: *mut sbmlcxx::LayoutModelPlugin) -> Self {
+        let plugin = RefCell::new(pin_ptr!(ptr, sbmlcxx::LayoutModelPlugin));
+
+        // Fetch all layouts
+        let n_layouts = plugin.borrow().getNumLayouts().0;
+        let layouts: Vec<_> = (0..n_layouts)
+            .map(|i| {
+                let layout = plugin.borrow_mut().as_mut().getLayout1(i.into());
+                Rc::new(Layout::from_ptr(layout))
+            })
+            .collect();
+
+        Self {
+            inner: plugin,
+            layouts: RefCell::new(layouts),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! This module provides a safe Rust interface to the libSBML ReactionGlyph class.
+//!
+//! The ReactionGlyph class of the layout package draws a reaction of the model. The
+//! reaction itself is usually a short curve or a small box, connected to the glyphs of
+//! its participants by species reference glyphs.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML ReactionGlyph class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    layout::{
+        diagram::Layout,
+        geometry::{
+            read_bounding_box, read_curve, write_bounding_box, write_curve, BoundingBox,
+            CurveSegment,
+        },
+        speciesrefglyph::{SpeciesReferenceGlyph, SpeciesReferenceRole},
+    },
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML ReactionGlyph class.
+///
+/// This struct maintains a reference to the underlying C++ ReactionGlyph object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the species reference glyphs of the reaction.
+pub struct ReactionGlyph<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::ReactionGlyph>>,
+    species_reference_glyphs: RefCell<Vec<Rc<SpeciesReferenceGlyph<'a>>>>,
+}
+
+// Set the inner trait for the ReactionGlyph struct
+inner!(sbmlcxx::ReactionGlyph, ReactionGlyph<'a>);
+
+// Set the annotation trait for the ReactionGlyph struct
+upcast_annotation!(ReactionGlyph<'a>, sbmlcxx::ReactionGlyph, sbmlcxx::SBase);
+
+// Set the SBase trait for the ReactionGlyph struct
+sbase!(ReactionGlyph<'a>, sbmlcxx::ReactionGlyph);
+
+impl<'a> ReactionGlyph<'a> {
+    /// Creates a new ReactionGlyph instance within the given Layout.
+    ///
+    /// # Arguments
+    /// * `layout` - The parent Layout that will contain this glyph
+    /// * `id` - The identifier for this glyph
+    /// * `reaction` - The identifier of the reaction drawn by the glyph
+    ///
+    /// # Returns
+    /// A new ReactionGlyph instance
+    pub fn new(layout: &Layout<'a>, id: &str, reaction: &str) -> Self {
+        let glyph_ptr = layout.inner().borrow_mut().as_mut().createReactionGlyph();
+        let glyph = pin_ptr!(glyph_ptr, sbmlcxx::ReactionGlyph);
+
+        let glyph = Self {
+            inner: RefCell::new(glyph),
+            species_reference_glyphs: RefCell::new(Vec::new()),
+        };
+        glyph.set_id(id);
+        glyph.set_reaction(reaction);
+        glyph
+    }
+
+    /// Returns a reference to the inner RefCell containing the ReactionGlyph pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::ReactionGlyph>> {
+        &self.inner
+    }
+
+    /// Returns the id of the glyph.
+    ///
+    /// # Returns
+    /// The id of the glyph as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::ReactionGlyph, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::ReactionGlyph, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the identifier of the reaction drawn by the glyph.
+    ///
+    /// # Returns
+    /// The reaction identifier, empty if not set
+    pub fn reaction(&self) -> String {
+        self.inner
+            .borrow()
+            .getReactionId()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the reaction drawn by the glyph.
+    ///
+    /// # Arguments
+    /// * `reaction` - The reaction identifier
+    pub fn set_reaction(&self, reaction: &str) {
+        let_cxx_string!(reaction = reaction);
+        self.inner.borrow_mut().as_mut().setReactionId(&reaction);
+    }
+
+    /// Returns the position and size of the glyph.
+    ///
+    /// Reactions drawn as a curve usually leave the bounding box empty.
+    ///
+    /// # Returns
+    /// The bounding box of the glyph
+    pub fn bounding_box(&self) -> BoundingBox {
+        let object = upcast!(self, sbmlcxx::ReactionGlyph, sbmlcxx::GraphicalObject);
+        read_bounding_box(object)
+    }
+
+    /// Sets the position and size of the glyph.
+    ///
+    /// # Arguments
+    /// * `bounding_box` - The bounding box to set
+    pub fn set_bounding_box(&self, bounding_box: &BoundingBox) {
+        let object = upcast!(self, sbmlcxx::ReactionGlyph, sbmlcxx::GraphicalObject);
+        write_bounding_box(object, bounding_box);
+    }
+
+    /// Returns the curve of the reaction.
+    ///
+    /// # Returns
+    /// The segments of the curve, empty if no curve is set
+    pub fn curve(&self) -> Vec<CurveSegment> {
+        let curve = self.inner.borrow_mut().as_mut().getCurve1();
+        read_curve(curve)
+    }
+
+    /// Replaces the curve of the reaction.
+    ///
+    /// # Arguments
+    /// * `segments` - The segments of the new curve
+    pub fn set_curve(&self, segments: &[CurveSegment]) {
+        let curve_ptr = self.inner.borrow_mut().as_mut().getCurve1();
+        let curve = pin_ptr!(curve_ptr, sbmlcxx::Curve);
+        write_curve(curve, segments);
+    }
+
+    /// Creates a new species reference glyph connecting the reaction to a species glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new glyph
+    /// * `species_glyph` - The identifier of the species glyph
+    /// * `role` - The role of the species in the reaction
+    ///
+    /// # Returns
+    /// The newly created SpeciesReferenceGlyph wrapped in an Rc
+    pub fn create_species_reference_glyph(
+        &self,
+        id: &str,
+        species_glyph: &str,
+        role: SpeciesReferenceRole,
+    ) -> Rc<SpeciesReferenceGlyph<'a>> {
+        let glyph = Rc::new(SpeciesReferenceGlyph::new(self, id, species_glyph, role));
+        self.species_reference_glyphs
+            .borrow_mut()
+            .push(Rc::clone(&glyph));
+        glyph
+    }
+
+    /// Returns a vector of all species reference glyphs of the reaction.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all SpeciesReferenceGlyphs
+    pub fn list_of_species_reference_glyphs(&self) -> Vec<Rc<SpeciesReferenceGlyph<'a>>> {
+        self.species_reference_glyphs.borrow().to_vec()
+    }
+
+    /// Retrieves a species reference glyph by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    ///
+    /// # Returns
+    /// Some(Rc<SpeciesReferenceGlyph>) if found, None if not found
+    pub fn get_species_reference_glyph(&self, id: &str) -> Option<Rc<SpeciesReferenceGlyph<'a>>> {
+        self.species_reference_glyphs
+            .borrow()
+            .iter()
+            .find(|glyph| (*glyph).id() == id)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::ReactionGlyph, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::ReactionGlyph> for ReactionGlyph<'_> {
+    /// Creates a new ReactionGlyph instance from a unique pointer to a libSBML ReactionGlyph.
+    ///
+    /// This method is primarily used internally by the Layout class to create
+    /// ReactionGlyph instances from libSBML ReactionGlyph pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML ReactionGlyph
+    ///
+    /// # Returns
+    /// A new ReactionGlyph instance
+    fn from_ptr(ptr: *mut sbmlcxx::ReactionGlyph) -> Self {
+        let glyph = RefCell::new(pin_ptr!(ptr, sbmlcxx::ReactionGlyph));
+
+        // Fetch all species reference glyphs
+        let n_glyphs = glyph.borrow().getNumSpeciesReferenceGlyphs().0;
+        let species_reference_glyphs: Vec<_> = (0..n_glyphs)
+            .map(|i| {
+                let reference = glyph
+                    .borrow_mut()
+                    .as_mut()
+                    .getSpeciesReferenceGlyph1(i.into());
+                Rc::new(SpeciesReferenceGlyph::from_ptr(reference))
+            })
+            .collect();
+
+        Self {
+            inner: glyph,
+            species_reference_glyphs: RefCell::new(species_reference_glyphs),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! This module provides a safe Rust interface to the libSBML SpeciesGlyph class.
+//!
+//! The SpeciesGlyph class of the layout package places a species of the model in a
+//! diagram. A species can be drawn by several glyphs, e.g. to show a currency
+//! metabolite next to each reaction it takes part in.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML SpeciesGlyph class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    layout::{
+        diagram::Layout,
+        geometry::{read_bounding_box, write_bounding_box, BoundingBox},
+    },
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML SpeciesGlyph class.
+///
+/// This struct maintains a reference to the underlying C++ SpeciesGlyph object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct SpeciesGlyph<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::SpeciesGlyph>>,
+}
+
+// Set the inner trait for the SpeciesGlyph struct
+inner!(sbmlcxx::SpeciesGlyph, SpeciesGlyph<'a>);
+
+// Set the annotation trait for the SpeciesGlyph struct
+upcast_annotation!(SpeciesGlyph<'a>, sbmlcxx::SpeciesGlyph, sbmlcxx::SBase);
+
+// Set the SBase trait for the SpeciesGlyph struct
+sbase!(SpeciesGlyph<'a>, sbmlcxx::SpeciesGlyph);
+
+impl<'a> SpeciesGlyph<'a> {
+    /// Creates a new SpeciesGlyph instance within the given Layout.
+    ///
+    /// # Arguments
+    /// * `layout` - The parent Layout that will contain this glyph
+    /// * `id` - The identifier for this glyph
+    /// * `species` - The identifier of the species drawn by the glyph
+    /// * `bounding_box` - The position and size of the glyph
+    ///
+    /// # Returns
+    /// A new SpeciesGlyph instance
+    pub fn new(layout: &Layout<'a>, id: &str, species: &str, bounding_box: &BoundingBox) -> Self {
+        let glyph_ptr = layout.inner().borrow_mut().as_mut().createSpeciesGlyph();
+        let glyph = pin_ptr!(glyph_ptr, sbmlcxx::SpeciesGlyph);
+
+        let glyph = Self {
+            inner: RefCell::new(glyph),
+        };
+        glyph.set_id(id);
+        glyph.set_species(species);
+        glyph.set_bounding_box(bounding_box);
+        glyph
+    }
+
+    /// Returns the id of the glyph.
+    ///
+    /// # Returns
+    /// The id of the glyph as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::SpeciesGlyph, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::SpeciesGlyph, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the identifier of the species drawn by the glyph.
+    ///
+    /// # Returns
+    /// The species identifier, empty if not set
+    pub fn species(&self) -> String {
+        self.inner
+            .borrow()
+            .getSpeciesId()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the species drawn by the glyph.
+    ///
+    /// # Arguments
+    /// * `species` - The species identifier
+    pub fn set_species(&self, species: &str) {
+        let_cxx_string!(species = species);
+        self.inner.borrow_mut().as_mut().setSpeciesId(&species);
+    }
+
+    /// Returns the position and size of the glyph.
+    ///
+    /// # Returns
+    /// The bounding box of the glyph
+    pub fn bounding_box(&self) -> BoundingBox {
+        let object = upcast!(self, sbmlcxx::SpeciesGlyph, sbmlcxx::GraphicalObject);
+        read_bounding_box(object)
+    }
+
+    /// Sets the position and size of the glyph.
+    ///
+    /// # Arguments
+    /// * `bounding_box` - The bounding box to set
+    pub fn set_bounding_box(&self, bounding_box: &BoundingBox) {
+        let object = upcast!(self, sbmlcxx::SpeciesGlyph, sbmlcxx::GraphicalObject);
+        write_bounding_box(object, bounding_box);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::SpeciesGlyph, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::SpeciesGlyph> for SpeciesGlyph<'_> {
+    /// Creates a new SpeciesGlyph instance from a unique pointer to a libSBML SpeciesGlyph.
+    ///
+    /// This method is primarily used internally by the Layout class to create
+    /// SpeciesGlyph instances from libSBML SpeciesGlyph pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML SpeciesGlyph
+    ///
+    /// # Returns
+    /// A new SpeciesGlyph instance
+    fn from_ptr(ptr: *mut sbmlcxx::SpeciesGlyph) -> Self {
+        let glyph = pin_ptr!(ptr, sbmlcxx::SpeciesGlyph);
+        Self {
+            inner: RefCell::new(glyph),
+        }
+    }
+}
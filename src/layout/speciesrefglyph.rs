@@ -0,0 +1,277 @@
+//! This module provides a safe Rust interface to the libSBML SpeciesReferenceGlyph class.
+//!
+//! The SpeciesReferenceGlyph class of the layout package draws the participation of a
+//! species in a reaction as a curve between the glyph of the reaction and a species
+//! glyph. The role tells renderers how to decorate the curve, e.g. with an arrow head
+//! for products.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML SpeciesReferenceGlyph
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, fmt, pin::Pin, str::FromStr};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    layout::{
+        geometry::{read_curve, write_curve, CurveSegment},
+        reactionglyph::ReactionGlyph,
+    },
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// The role of a species in a reaction, as drawn by a species reference glyph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpeciesReferenceRole {
+    /// The role is not specified
+    #[default]
+    Undefined,
+    /// A main reactant of the reaction
+    Substrate,
+    /// A main product of the reaction
+    Product,
+    /// A reactant of secondary interest, e.g. a cofactor
+    SideSubstrate,
+    /// A product of secondary interest, e.g. a cofactor
+    SideProduct,
+    /// A modifier without further specification
+    Modifier,
+    /// A modifier that increases the rate of the reaction
+    Activator,
+    /// A modifier that decreases the rate of the reaction
+    Inhibitor,
+}
+
+impl SpeciesReferenceRole {
+    /// Returns the value of the role as written to SBML.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpeciesReferenceRole::Undefined => "undefined",
+            SpeciesReferenceRole::Substrate => "substrate",
+            SpeciesReferenceRole::Product => "product",
+            SpeciesReferenceRole::SideSubstrate => "sidesubstrate",
+            SpeciesReferenceRole::SideProduct => "sideproduct",
+            SpeciesReferenceRole::Modifier => "modifier",
+            SpeciesReferenceRole::Activator => "activator",
+            SpeciesReferenceRole::Inhibitor => "inhibitor",
+        }
+    }
+}
+
+impl fmt::Display for SpeciesReferenceRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for SpeciesReferenceRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "undefined" | "" => Ok(SpeciesReferenceRole::Undefined),
+            "substrate" => Ok(SpeciesReferenceRole::Substrate),
+            "product" => Ok(SpeciesReferenceRole::Product),
+            "sidesubstrate" => Ok(SpeciesReferenceRole::SideSubstrate),
+            "sideproduct" => Ok(SpeciesReferenceRole::SideProduct),
+            "modifier" => Ok(SpeciesReferenceRole::Modifier),
+            "activator" => Ok(SpeciesReferenceRole::Activator),
+            "inhibitor" => Ok(SpeciesReferenceRole::Inhibitor),
+            _ => Err(format!("Invalid species reference role: {}", s)),
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML SpeciesReferenceGlyph class.
+///
+/// This struct maintains a reference to the underlying C++ SpeciesReferenceGlyph object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct SpeciesReferenceGlyph<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::SpeciesReferenceGlyph>>,
+}
+
+// Set the inner trait for the SpeciesReferenceGlyph struct
+inner!(sbmlcxx::SpeciesReferenceGlyph, SpeciesReferenceGlyph<'a>);
+
+// Set the annotation trait for the SpeciesReferenceGlyph struct
+upcast_annotation!(
+    SpeciesReferenceGlyph<'a>,
+    sbmlcxx::SpeciesReferenceGlyph,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the SpeciesReferenceGlyph struct
+sbase!(SpeciesReferenceGlyph<'a>, sbmlcxx::SpeciesReferenceGlyph);
+
+impl<'a> SpeciesReferenceGlyph<'a> {
+    /// Creates a new SpeciesReferenceGlyph instance within the given ReactionGlyph.
+    ///
+    /// # Arguments
+    /// * `reaction_glyph` - The parent ReactionGlyph that will contain this glyph
+    /// * `id` - The identifier for this glyph
+    /// * `species_glyph` - The identifier of the species glyph the curve connects to
+    /// * `role` - The role of the species in the reaction
+    ///
+    /// # Returns
+    /// A new SpeciesReferenceGlyph instance
+    pub fn new(
+        reaction_glyph: &ReactionGlyph<'a>,
+        id: &str,
+        species_glyph: &str,
+        role: SpeciesReferenceRole,
+    ) -> Self {
+        let glyph_ptr = reaction_glyph
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createSpeciesReferenceGlyph();
+        let glyph = pin_ptr!(glyph_ptr, sbmlcxx::SpeciesReferenceGlyph);
+
+        let glyph = Self {
+            inner: RefCell::new(glyph),
+        };
+        glyph.set_id(id);
+        glyph.set_species_glyph(species_glyph);
+        glyph.set_role(role);
+        glyph
+    }
+
+    /// Returns the id of the glyph.
+    ///
+    /// # Returns
+    /// The id of the glyph as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::SpeciesReferenceGlyph, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::SpeciesReferenceGlyph, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the identifier of the species glyph the curve connects to.
+    ///
+    /// # Returns
+    /// The species glyph identifier, empty if not set
+    pub fn species_glyph(&self) -> String {
+        self.inner
+            .borrow()
+            .getSpeciesGlyphId()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the species glyph the curve connects to.
+    ///
+    /// # Arguments
+    /// * `species_glyph` - The species glyph identifier
+    pub fn set_species_glyph(&self, species_glyph: &str) {
+        let_cxx_string!(species_glyph = species_glyph);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setSpeciesGlyphId(&species_glyph);
+    }
+
+    /// Returns the identifier of the species reference drawn by the glyph.
+    ///
+    /// # Returns
+    /// The species reference identifier, empty if not set
+    pub fn species_reference(&self) -> String {
+        self.inner
+            .borrow()
+            .getSpeciesReferenceId()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the identifier of the species reference drawn by the glyph.
+    ///
+    /// # Arguments
+    /// * `species_reference` - The species reference identifier
+    pub fn set_species_reference(&self, species_reference: &str) {
+        let_cxx_string!(species_reference = species_reference);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setSpeciesReferenceId(&species_reference);
+    }
+
+    /// Returns the role of the species in the reaction.
+    ///
+    /// # Returns
+    /// The role, Undefined if not set or not recognized
+    pub fn role(&self) -> SpeciesReferenceRole {
+        self.inner
+            .borrow()
+            .getRoleString()
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap_or_default()
+    }
+
+    /// Sets the role of the species in the reaction.
+    ///
+    /// # Arguments
+    /// * `role` - The role to set
+    pub fn set_role(&self, role: SpeciesReferenceRole) {
+        let_cxx_string!(role = role.as_str());
+        self.inner.borrow_mut().as_mut().setRole(&role);
+    }
+
+    /// Returns the curve between the reaction and the species glyph.
+    ///
+    /// # Returns
+    /// The segments of the curve, empty if no curve is set
+    pub fn curve(&self) -> Vec<CurveSegment> {
+        let curve = self.inner.borrow_mut().as_mut().getCurve1();
+        read_curve(curve)
+    }
+
+    /// Replaces the curve between the reaction and the species glyph.
+    ///
+    /// # Arguments
+    /// * `segments` - The segments of the new curve
+    pub fn set_curve(&self, segments: &[CurveSegment]) {
+        let curve_ptr = self.inner.borrow_mut().as_mut().getCurve1();
+        let curve = pin_ptr!(curve_ptr, sbmlcxx::Curve);
+        write_curve(curve, segments);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::SpeciesReferenceGlyph, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::SpeciesReferenceGlyph> for SpeciesReferenceGlyph<'_> {
+    /// Creates a new SpeciesReferenceGlyph instance from a unique pointer to a libSBML
+    /// SpeciesReferenceGlyph.
+    ///
+    /// This method is primarily used internally by the ReactionGlyph class to create
+    /// SpeciesReferenceGlyph instances from libSBML SpeciesReferenceGlyph pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML SpeciesReferenceGlyph
+    ///
+    /// # Returns
+    /// A new SpeciesReferenceGlyph instance
+    fn from_ptr(ptr: *mut sbmlcxx::SpeciesReferenceGlyph) -> Self {
+        let glyph = pin_ptr!(ptr, sbmlcxx::SpeciesReferenceGlyph);
+        Self {
+            inner: RefCell::new(glyph),
+        }
+    }
+}
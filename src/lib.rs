@@ -15,38 +15,159 @@
 //! - Parameter: Represents numerical parameters used in the model
 //! - Unit/UnitDefinition: Represents units of measurement
 //! - SpeciesReference: Represents species participating in reactions
+//! - KineticLaw/LocalParameter: Represents the rate laws of reactions
+//! - InitialAssignment/Event: Represents initial values and discontinuous changes
+//! - FbcModelPlugin/Objective/GeneProduct: Represents flux balance constraints (fbc package)
+//! - Layout/SpeciesGlyph/ReactionGlyph: Represents diagrams of models (layout package)
+//! - RenderInformation/ColorDefinition/Style: Represents the styling of diagrams (render package)
+//! - Submodel/Port/ReplacedElement: Represents hierarchical model composition (comp package)
+//! - Group/Member: Represents groupings of model elements (groups package)
+//! - QualitativeSpecies/Transition: Represents logical models (qual package)
+//! - Uncertainty/UncertParameter: Represents uncertain values (distrib package)
+//!
+//! Models can be simulated using the [`simulate`] module, compared using the [`diff`]
+//! module, analyzed structurally using the [`stoichiometry`] module and optimized with
+//! flux balance analysis using the [`fba`] module. Logical models are simulated and
+//! searched for attractors using the [`logical`] module. Parameter sets are drawn from
+//! the uncertainties of a model using the [`sampling`] module.
+//!
+//! # Features
+//!
+//! SBML Level 3 packages are selected through Cargo features, so that only the packages
+//! in use are built into libSBML:
+//!
+//! - `fbc`, `layout`, `render`, `comp`, `groups`, `qual` and `distrib` (default) enable
+//!   the package and its wrappers. `render` requires `layout`.
+//! - `multi` and `spatial` build the package into libSBML, so that documents using it
+//!   are read and validated. There are no wrappers for these packages yet.
+//!
+//! The analysis modules require their package: [`fba`] requires `fbc`, [`logical`]
+//! requires `qual` and [`sampling`] requires `distrib`.
+//!
+//! By default, libSBML is built from source. The `system-libsbml` feature links against
+//! an installed libSBML found via pkg-config instead, which can also be located
+//! explicitly with the `SBML_LIB_DIR` and `SBML_INCLUDE_DIR` environment variables.
+//!
+//! When building from source, the XML parser of libSBML is selected through one of the
+//! mutually exclusive features `xml-expat` (default), `xml-libxml2` and `xml-xerces`.
+//! Expat and libxml2 are vendored, while Xerces-C++ has to be installed on the system.
+//! To select another parser than Expat, disable the default features.
+
+#[cfg(any(
+    all(feature = "xml-expat", feature = "xml-libxml2"),
+    all(feature = "xml-expat", feature = "xml-xerces"),
+    all(feature = "xml-libxml2", feature = "xml-xerces"),
+))]
+compile_error!(
+    "The features xml-expat, xml-libxml2 and xml-xerces are mutually exclusive, enable only one XML parser"
+);
 
 /// Module providing traits for the SBML library
 pub mod traits {
     pub mod annotation;
     pub mod fromptr;
     pub mod inner;
+    pub mod sbase;
 }
 
+/// Internal module providing conversions between formulas and ASTNodes
+pub(crate) mod astnode;
 /// Module providing upcast functionality
 pub mod cast;
+/// Module providing comp package functionality
+#[cfg(feature = "comp")]
+pub mod comp;
 /// Module providing compartment functionality
 pub mod compartment;
+/// Module providing event delay functionality
+pub mod delay;
+/// Module providing unit algebra and conversion
+pub mod derivedunit;
+/// Module providing semantic differences between models
+pub mod diff;
+/// Module providing distrib package functionality
+#[cfg(feature = "distrib")]
+pub mod distrib;
+/// Module providing the error log of SBML documents
+pub mod errors;
+/// Module providing event functionality
+pub mod event;
+/// Module providing event assignment functionality
+pub mod eventassign;
+/// Module providing flux balance analysis functionality
+#[cfg(feature = "fbc")]
+pub mod fba;
+/// Module providing fbc package functionality
+#[cfg(feature = "fbc")]
+pub mod fbc;
+/// Module providing formula parsing and evaluation
+pub mod formula;
+/// Module providing groups package functionality
+#[cfg(feature = "groups")]
+pub mod groups;
+/// Module providing initial assignment functionality
+pub mod initassign;
+/// Module providing kinetic law functionality
+pub mod kineticlaw;
+/// Module providing layout package functionality
+#[cfg(feature = "layout")]
+pub mod layout;
+/// Internal module providing dense linear algebra routines
+pub(crate) mod linalg;
+/// Module providing local parameter functionality
+pub mod localparam;
+/// Module providing simulation and attractor analysis of logical models
+#[cfg(feature = "qual")]
+pub mod logical;
+/// Module providing the merging of models
+pub mod merge;
 /// Module providing model functionality
 pub mod model;
 /// Module providing modifier species reference functionality
 pub mod modref;
+/// Module providing SBML Level 3 package functionality
+pub mod packages;
 /// Module providing parameter functionality
 pub mod parameter;
+/// Module providing qual package functionality
+#[cfg(feature = "qual")]
+pub mod qual;
 /// Module providing reaction functionality
 pub mod reaction;
+/// Module providing the renaming of identifiers across a model
+pub mod rename;
+/// Module providing render package functionality
+#[cfg(feature = "render")]
+pub mod render;
 /// Module providing rate rule functionality
 pub mod rule;
+/// Module providing sampling of parameter sets from uncertainties
+#[cfg(feature = "distrib")]
+pub mod sampling;
 /// Module providing core SBML document functionality
 pub mod sbmldoc;
+/// Module providing simulation functionality
+pub mod simulate;
 /// Module providing species functionality
 pub mod species;
 /// Module providing species reference functionality
 pub mod speciesref;
+/// Module providing stoichiometry matrix and structural analysis functionality
+pub mod stoichiometry;
+/// Module providing event trigger functionality
+pub mod trigger;
 /// Module providing unit functionality
 pub mod unit;
+/// Module providing the consistency checks of units
+pub mod unitcheck;
 /// Module providing unit definition functionality
 pub mod unitdef;
+/// Module providing the parsing and formatting of unit strings
+pub mod unitstr;
+/// Module providing the references between the elements of a model
+pub mod usage;
+/// Module providing the traversal of all elements of a document
+pub mod visitor;
 
 /// Module containing helper macros
 pub mod macros;
@@ -66,19 +187,66 @@ pub use traits::annotation::Annotation;
 
 /// Prelude module providing convenient imports of commonly used types
 pub mod prelude {
+    #[cfg(feature = "comp")]
+    pub use crate::comp::{
+        CompDocumentPlugin, CompModelPlugin, Deletion, ExternalModelDefinition, FlattenError,
+        Flattener, Port, ReferenceTarget, ReplacedBy, ReplacedElement, Submodel,
+    };
     pub use crate::compartment::Compartment;
+    pub use crate::delay::*;
+    pub use crate::derivedunit::DerivedUnit;
+    pub use crate::diff::{diff, Change, ElementDiff, ElementKind, ModelDiff, SpeciesRole};
+    #[cfg(feature = "distrib")]
+    pub use crate::distrib::{UncertParameter, UncertType, Uncertainty};
+    pub use crate::errors::{ConversionError, SBMLError, Severity};
+    pub use crate::event::*;
+    pub use crate::eventassign::*;
+    #[cfg(feature = "fbc")]
+    pub use crate::fbc::{
+        FbcModelPlugin, FluxBound, FluxBoundOperation, FluxObjective, GeneProduct,
+        GeneProductAssociation, GeneProductBuilder, GeneRule, Objective, ObjectiveBuilder,
+        ObjectiveSense,
+    };
+    #[cfg(feature = "groups")]
+    pub use crate::groups::{Group, GroupBuilder, GroupKind, GroupsModelPlugin, Member, MemberRef};
+    pub use crate::initassign::*;
+    pub use crate::kineticlaw::*;
+    #[cfg(feature = "layout")]
+    pub use crate::layout::{
+        BoundingBox, CompartmentGlyph, CurveSegment, Layout, LayoutModelPlugin, Point,
+        ReactionGlyph, SpeciesGlyph, SpeciesReferenceGlyph, SpeciesReferenceRole,
+    };
+    pub use crate::localparam::*;
+    pub use crate::merge::{IdConflict, MergeError, MergeOptions, MergeReport};
     pub use crate::model::*;
     pub use crate::modref::*;
+    pub use crate::packages::Package;
     pub use crate::parameter::*;
+    #[cfg(feature = "qual")]
+    pub use crate::qual::{
+        DefaultTerm, FunctionTerm, Input, InputEffect, InputSign, Output, OutputEffect,
+        QualModelPlugin, QualitativeSpecies, QualitativeSpeciesBuilder, Transition,
+        TransitionBuilder,
+    };
     pub use crate::reaction::*;
     pub use crate::reader::*;
+    pub use crate::rename::RenameError;
+    #[cfg(feature = "render")]
+    pub use crate::render::{ColorDefinition, RenderInformation, Style, StyleBuilder};
     pub use crate::rule::*;
     pub use crate::sbmldoc::*;
     pub use crate::species::*;
     pub use crate::speciesref::*;
+    pub use crate::stoichiometry::*;
     pub use crate::traits::annotation::*;
+    pub use crate::traits::sbase::SBase;
+    pub use crate::trigger::*;
     pub use crate::unit::*;
+    pub use crate::unitcheck::UnitMismatch;
     pub use crate::unitdef::*;
+    pub use crate::unitstr::UnitParseError;
+    pub use crate::usage::{ElementRef, UnusedElements};
+    pub use crate::visitor::Visitor;
 }
 
 /// Internal module containing the raw FFI bindings to libSBML.
@@ -89,46 +257,9 @@ pub mod prelude {
 pub(crate) mod sbmlcxx {
     use autocxx::prelude::*;
 
-    include_cpp! {
-        // Includes //
-        #include "sbml/SBMLTypes.h"
-        safety!(unsafe_ffi)
-
-        // Base types
-        generate!("SBase")
-
-        // Root types
-        generate!("SBMLDocument")
-        generate!("Model")
-
-        // Leaf types
-        generate!("Species")
-        generate!("Parameter")
-        generate!("Compartment")
-        generate!("UnitDefinition")
-        generate!("Unit")
-        generate!("UnitKind_t")
-        generate!("Reaction")
-        generate!("SpeciesReference")
-        generate!("SimpleSpeciesReference")
-        generate!("ModifierSpeciesReference")
-        generate!("InitialAssignment")
-        generate!("RateRule")
-        generate!("AssignmentRule")
-        generate!("Rule")
-
-        // IO types
-        generate!("SBMLWriter")
-        generate!("SBMLReader")
-
-        // Container types
-        generate!("ListOfParameters")
-        generate!("ListOfUnitDefinitions")
-        generate!("ListOfCompartments")
-        generate!("ListOfSpecies")
-        generate!("ListOfReactions")
-        generate!("ListOfUnitDefinitions")
-    }
+    // The `include_cpp!` block is generated by the build script, because the
+    // bindings depend on the packages enabled through Cargo features.
+    include!(concat!(env!("OUT_DIR"), "/sbmlcxx.rs"));
 
     pub use ffi::*;
 }
@@ -0,0 +1,187 @@
+//! Small dense linear algebra helpers.
+//!
+//! The numerical routines of this crate (simulation, structural analysis) only deal
+//! with matrices of the size of a model's species and reactions. This module provides
+//! the few dense operations they need without pulling in a full linear algebra crate.
+
+/// An LU factorization with partial pivoting of a square, row-major matrix.
+pub(crate) struct Lu {
+    /// Size of the factorized matrix
+    n: usize,
+    /// Combined L (unit lower triangle) and U factors in row-major order
+    lu: Vec<f64>,
+    /// Row permutation applied during pivoting
+    pivots: Vec<usize>,
+}
+
+impl Lu {
+    /// Factorizes a square matrix given in row-major order.
+    ///
+    /// # Arguments
+    /// * `matrix` - The matrix entries, `n * n` values in row-major order
+    /// * `n` - The number of rows and columns
+    ///
+    /// # Returns
+    /// The factorization or None if the matrix is (numerically) singular
+    pub(crate) fn factorize(matrix: &[f64], n: usize) -> Option<Self> {
+        let mut lu = matrix.to_vec();
+        let mut pivots: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            // Find the pivot row with the largest absolute value in column k
+            let (pivot, max) = (k..n)
+                .map(|i| (i, lu[i * n + k].abs()))
+                .fold((k, 0.0), |acc, (i, v)| if v > acc.1 { (i, v) } else { acc });
+
+            if max == 0.0 || !max.is_finite() {
+                return None;
+            }
+
+            if pivot != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, pivot * n + j);
+                }
+                pivots.swap(k, pivot);
+            }
+
+            let diagonal = lu[k * n + k];
+            for i in (k + 1)..n {
+                let factor = lu[i * n + k] / diagonal;
+                lu[i * n + k] = factor;
+                if factor != 0.0 {
+                    for j in (k + 1)..n {
+                        lu[i * n + j] -= factor * lu[k * n + j];
+                    }
+                }
+            }
+        }
+
+        Some(Self { n, lu, pivots })
+    }
+
+    /// Solves the system `A x = b` using the factorization.
+    ///
+    /// # Arguments
+    /// * `b` - The right-hand side of the system
+    ///
+    /// # Returns
+    /// The solution vector `x`
+    pub(crate) fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.n;
+        let mut x: Vec<f64> = self.pivots.iter().map(|&p| b[p]).collect();
+
+        // Forward substitution with the unit lower triangle
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|j| self.lu[i * n + j] * x[j]).sum();
+            x[i] -= sum;
+        }
+
+        // Backward substitution with the upper triangle
+        for i in (0..n).rev() {
+            let sum: f64 = ((i + 1)..n).map(|j| self.lu[i * n + j] * x[j]).sum();
+            x[i] = (x[i] - sum) / self.lu[i * n + i];
+        }
+
+        x
+    }
+}
+
+/// Brings a row-major matrix into reduced row echelon form in place.
+///
+/// Columns are processed from left to right, such that the pivot columns are the
+/// first linearly independent columns of the matrix. Entries with an absolute
+/// value below `tolerance` are treated as zero.
+///
+/// # Arguments
+/// * `matrix` - The matrix entries, `rows * cols` values in row-major order
+/// * `rows` - The number of rows
+/// * `cols` - The number of columns
+/// * `tolerance` - The threshold below which entries are considered zero
+///
+/// # Returns
+/// The pivot column of each non-zero row, in row order
+pub(crate) fn rref(matrix: &mut [f64], rows: usize, cols: usize, tolerance: f64) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    let mut row = 0;
+
+    for col in 0..cols {
+        if row == rows {
+            break;
+        }
+
+        // Find the pivot row with the largest absolute value in this column
+        let (pivot, max) = (row..rows).map(|i| (i, matrix[i * cols + col].abs())).fold(
+            (row, 0.0),
+            |acc, (i, v)| if v > acc.1 { (i, v) } else { acc },
+        );
+
+        if max <= tolerance {
+            for i in row..rows {
+                matrix[i * cols + col] = 0.0;
+            }
+            continue;
+        }
+
+        if pivot != row {
+            for j in 0..cols {
+                matrix.swap(row * cols + j, pivot * cols + j);
+            }
+        }
+
+        let diagonal = matrix[row * cols + col];
+        for j in 0..cols {
+            matrix[row * cols + j] /= diagonal;
+        }
+
+        for i in 0..rows {
+            let factor = matrix[i * cols + col];
+            if i == row || factor == 0.0 {
+                continue;
+            }
+            for j in 0..cols {
+                matrix[i * cols + j] -= factor * matrix[row * cols + j];
+            }
+            matrix[i * cols + col] = 0.0;
+        }
+
+        pivots.push(col);
+        row += 1;
+    }
+
+    pivots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lu_solve() {
+        // 2x + y = 3, x + 3y = 5 => x = 0.8, y = 1.4
+        let lu = Lu::factorize(&[2.0, 1.0, 1.0, 3.0], 2).expect("Matrix is singular");
+        let x = lu.solve(&[3.0, 5.0]);
+        assert!((x[0] - 0.8).abs() < 1e-12);
+        assert!((x[1] - 1.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lu_requires_pivoting() {
+        let lu = Lu::factorize(&[0.0, 1.0, 1.0, 0.0], 2).expect("Matrix is singular");
+        assert_eq!(lu.solve(&[2.0, 3.0]), vec![3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        assert!(Lu::factorize(&[1.0, 2.0, 2.0, 4.0], 2).is_none());
+    }
+
+    #[test]
+    fn test_rref() {
+        // The second column is twice the first one
+        let mut matrix = vec![1.0, 2.0, 1.0, 2.0, 4.0, 0.0];
+        let pivots = rref(&mut matrix, 2, 3, 1e-12);
+
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(matrix, vec![1.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
+    }
+}
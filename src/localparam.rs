@@ -0,0 +1,278 @@
+//! This module provides a safe Rust interface to the libSBML LocalParameter class.
+//!
+//! The LocalParameter class represents a parameter that is only visible within the
+//! kinetic law that defines it. Local parameters shadow model-wide identifiers of the
+//! same name within the kinetic law's formula.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML LocalParameter class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, kineticlaw::KineticLaw, pin_ptr, sbase, sbmlcxx, sbo_term, traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML LocalParameter class.
+///
+/// This struct maintains a reference to the underlying C++ LocalParameter object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct LocalParameter<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::LocalParameter>>,
+}
+
+// Set the inner trait for the LocalParameter struct
+inner!(sbmlcxx::LocalParameter, LocalParameter<'a>);
+
+// Set the annotation trait for the LocalParameter struct
+upcast_annotation!(LocalParameter<'a>, sbmlcxx::LocalParameter, sbmlcxx::SBase);
+
+// Set the SBase trait for the LocalParameter struct
+sbase!(LocalParameter<'a>, sbmlcxx::LocalParameter);
+
+impl<'a> LocalParameter<'a> {
+    /// Creates a new LocalParameter instance within the given KineticLaw.
+    ///
+    /// # Arguments
+    /// * `kinetic_law` - The parent KineticLaw that will contain this parameter
+    /// * `id` - The identifier for this parameter
+    ///
+    /// # Returns
+    /// A new LocalParameter instance
+    pub fn new(kinetic_law: &KineticLaw<'a>, id: &str) -> Self {
+        let parameter_ptr = kinetic_law
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createLocalParameter();
+        let parameter = pin_ptr!(parameter_ptr, sbmlcxx::LocalParameter);
+
+        let local_parameter = Self {
+            inner: RefCell::new(parameter),
+        };
+        local_parameter.set_id(id);
+        local_parameter
+    }
+
+    /// Gets the parameter's identifier.
+    ///
+    /// # Returns
+    /// The parameter's ID as a String
+    pub fn id(&self) -> String {
+        let parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        parameter.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the parameter's identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The new identifier to set
+    pub fn set_id(&self, id: &str) {
+        let mut parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        let_cxx_string!(id = id);
+        parameter.as_mut().setId(&id);
+    }
+
+    /// Gets the parameter's name.
+    ///
+    /// # Returns
+    /// The parameter's name as a String
+    pub fn name(&self) -> String {
+        let parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        parameter.getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the parameter's name.
+    ///
+    /// # Arguments
+    /// * `name` - The new name to set
+    pub fn set_name(&self, name: &str) {
+        let mut parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        let_cxx_string!(name = name);
+        parameter.as_mut().setName(&name);
+    }
+
+    /// Gets the parameter's value.
+    ///
+    /// # Returns
+    /// Some(value) if the parameter has a value set, None otherwise
+    pub fn value(&self) -> Option<f64> {
+        let parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        if parameter.isSetValue() {
+            Some(parameter.getValue())
+        } else {
+            None
+        }
+    }
+
+    /// Sets the parameter's value.
+    ///
+    /// # Arguments
+    /// * `value` - The new value to set
+    pub fn set_value(&self, value: f64) {
+        let mut parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        parameter.as_mut().setValue(value);
+    }
+
+    /// Gets the parameter's units.
+    ///
+    /// # Returns
+    /// The parameter's units as a String
+    pub fn units(&self) -> String {
+        let parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        parameter.getUnits().to_str().unwrap().to_string()
+    }
+
+    /// Sets the parameter's units.
+    ///
+    /// # Arguments
+    /// * `units` - The new units to set
+    pub fn set_units(&self, units: &str) {
+        let mut parameter = upcast!(self, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        let_cxx_string!(units = units);
+        parameter.as_mut().setUnits(&units);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::LocalParameter, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::LocalParameter> for LocalParameter<'_> {
+    /// Creates a new LocalParameter instance from a unique pointer to a libSBML LocalParameter.
+    ///
+    /// This method is primarily used internally by the KineticLaw class to create
+    /// LocalParameter instances from libSBML LocalParameter pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML LocalParameter
+    ///
+    /// # Returns
+    /// A new LocalParameter instance
+    fn from_ptr(ptr: *mut sbmlcxx::LocalParameter) -> Self {
+        let parameter = pin_ptr!(ptr, sbmlcxx::LocalParameter);
+        Self {
+            inner: RefCell::new(parameter),
+        }
+    }
+}
+
+/// A builder for constructing LocalParameter instances with a fluent API.
+///
+/// This struct provides a builder pattern interface for creating and configuring
+/// LocalParameter objects. It allows chaining method calls to set various properties
+/// before finally constructing the LocalParameter.
+pub struct LocalParameterBuilder<'a> {
+    parameter: Rc<LocalParameter<'a>>,
+}
+
+impl<'a> LocalParameterBuilder<'a> {
+    /// Creates a new LocalParameterBuilder.
+    ///
+    /// # Arguments
+    /// * `kinetic_law` - The kinetic law that will contain the parameter
+    /// * `id` - The identifier for the new parameter
+    pub fn new(kinetic_law: &KineticLaw<'a>, id: &str) -> Self {
+        let parameter = kinetic_law.create_local_parameter(id);
+        Self { parameter }
+    }
+
+    /// Sets the name of the parameter.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn name(self, name: &str) -> Self {
+        self.parameter.set_name(name);
+        self
+    }
+
+    /// Sets the value of the parameter.
+    ///
+    /// # Arguments
+    /// * `value` - The value to set
+    pub fn value(self, value: f64) -> Self {
+        self.parameter.set_value(value);
+        self
+    }
+
+    /// Sets the units of the parameter.
+    ///
+    /// # Arguments
+    /// * `units` - The units to set
+    pub fn units(self, units: &str) -> Self {
+        self.parameter.set_units(units);
+        self
+    }
+
+    /// Sets an annotation on the parameter.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.parameter
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.parameter.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured LocalParameter.
+    ///
+    /// # Returns
+    /// The constructed LocalParameter instance wrapped in an Rc
+    pub fn build(self) -> Rc<LocalParameter<'a>> {
+        self.parameter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_local_parameter_builder() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = model.build_reaction("r").kinetic_law("k * A").build();
+        let kinetic_law = reaction.kinetic_law().expect("Kinetic law not found");
+
+        let parameter = kinetic_law
+            .build_local_parameter("k")
+            .name("rate constant")
+            .value(2.0)
+            .units("per_second")
+            .build();
+
+        assert_eq!(parameter.id(), "k");
+        assert_eq!(parameter.name(), "rate constant");
+        assert_eq!(parameter.value(), Some(2.0));
+        assert_eq!(parameter.units(), "per_second");
+    }
+
+    #[test]
+    fn test_local_parameter_without_value() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = model.build_reaction("r").kinetic_law("k * A").build();
+        let kinetic_law = reaction.kinetic_law().expect("Kinetic law not found");
+
+        let parameter = kinetic_law.create_local_parameter("k");
+        assert_eq!(parameter.value(), None);
+    }
+}
@@ -0,0 +1,146 @@
+//! Attractors of state transition graphs.
+//!
+//! The attractors of a logical network are the terminal strongly connected components
+//! of its state transition graph, i.e. the sets of mutually reachable states that
+//! cannot be left. They are found with an iterative version of Tarjan's algorithm,
+//! which avoids deep recursion on large state spaces.
+
+/// A set of states a logical network cannot leave once reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attractor {
+    /// The states of the attractor in ascending order
+    states: Vec<Vec<i32>>,
+}
+
+impl Attractor {
+    /// Creates an attractor from its states.
+    pub(crate) fn new(states: Vec<Vec<i32>>) -> Self {
+        Self { states }
+    }
+
+    /// Returns the states of the attractor in ascending order.
+    pub fn states(&self) -> &[Vec<i32>] {
+        &self.states
+    }
+
+    /// Returns the number of states of the attractor.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns whether the attractor has no states, which never holds for attractors
+    /// found in a network.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Returns whether the attractor is a steady state.
+    pub fn is_steady_state(&self) -> bool {
+        self.states.len() == 1
+    }
+}
+
+/// Marker for nodes that have not been visited yet.
+const UNVISITED: usize = usize::MAX;
+
+/// Finds the terminal strongly connected components of a directed graph.
+///
+/// # Arguments
+/// * `graph` - The successors of each node
+///
+/// # Returns
+/// The nodes of each component without outgoing edges
+pub(crate) fn terminal_components(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    let mut index = vec![UNVISITED; n];
+    let mut lowlink = vec![0; n];
+    let mut component = vec![UNVISITED; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    let mut counter = 0;
+
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+
+        // Each frame holds a node and the position of the next successor to visit
+        let mut frames = vec![(root, 0)];
+        index[root] = counter;
+        lowlink[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((node, next)) = frames.last_mut() {
+            let node = *node;
+            if let Some(&successor) = graph[node].get(*next) {
+                *next += 1;
+                if index[successor] == UNVISITED {
+                    index[successor] = counter;
+                    lowlink[successor] = counter;
+                    counter += 1;
+                    stack.push(successor);
+                    on_stack[successor] = true;
+                    frames.push((successor, 0));
+                } else if on_stack[successor] {
+                    lowlink[node] = lowlink[node].min(index[successor]);
+                }
+                continue;
+            }
+
+            frames.pop();
+            if let Some((parent, _)) = frames.last() {
+                lowlink[*parent] = lowlink[*parent].min(lowlink[node]);
+            }
+
+            if lowlink[node] == index[node] {
+                let id = components.len();
+                let mut members = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component[member] = id;
+                    members.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(members);
+            }
+        }
+    }
+
+    components
+        .into_iter()
+        .enumerate()
+        .filter(|(id, members)| {
+            members.iter().all(|&node| {
+                graph[node]
+                    .iter()
+                    .all(|&successor| component[successor] == *id)
+            })
+        })
+        .map(|(_, members)| members)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_components() {
+        // 0 -> 1 <-> 2, 0 -> 3 (sink), 4 -> 4
+        let graph = vec![vec![1, 3], vec![2], vec![1], vec![], vec![4]];
+        let mut components: Vec<Vec<usize>> = terminal_components(&graph)
+            .into_iter()
+            .map(|mut members| {
+                members.sort();
+                members
+            })
+            .collect();
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2], vec![3], vec![4]]);
+    }
+}
@@ -0,0 +1,776 @@
+//! Simulation and attractor analysis of logical models.
+//!
+//! Logical models describe each entity of a network by a discrete level, 0 or 1 for
+//! Boolean networks. For every entity, a rule maps the current levels of all entities
+//! to a target level. In SBML, such models are written with the qual package:
+//! [`LogicalNetwork::from_model`] reads the qualitative species and transitions of a
+//! model, while [`LogicalNetwork::new`] starts an empty network for rules given as
+//! formulas.
+//!
+//! Levels move towards their targets by one step at a time, following either
+//!
+//! - the synchronous scheme, where all entities are updated at once and each state has
+//!   a single successor, or
+//! - the asynchronous scheme, where a single entity is updated and each entity that
+//!   can change yields a successor.
+//!
+//! Besides single trajectories, the attractors of a network, i.e. its steady states and
+//! cyclic behaviours, are found by exhaustive enumeration of the state space. This is
+//! limited to networks with at most [`MAX_STATES`] states.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//! use sbml::logical::{LogicalNetwork, UpdateScheme};
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("toggle");
+//! model.build_compartment("cell").build();
+//!
+//! let qual = model.qual_plugin();
+//! qual.build_qualitative_species("A", "cell").max_level(1).initial_level(1).build();
+//! qual.build_qualitative_species("B", "cell").max_level(1).build();
+//! qual.build_transition("tA")
+//!     .input("B", InputSign::Negative)
+//!     .output("A")
+//!     .function_term(1, "B == 0")
+//!     .default_level(0)
+//!     .build();
+//! qual.build_transition("tB")
+//!     .input("A", InputSign::Negative)
+//!     .output("B")
+//!     .function_term(1, "A == 0")
+//!     .default_level(0)
+//!     .build();
+//!
+//! let network = LogicalNetwork::from_model(&model).unwrap();
+//! for attractor in network.attractors(UpdateScheme::Asynchronous).unwrap() {
+//!     println!("{:?}", attractor.states());
+//! }
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    formula::{Expr, FormulaError},
+    model::Model,
+    qual::OutputEffect,
+};
+
+mod attractors;
+
+pub use attractors::Attractor;
+
+/// The largest number of states enumerated by [`LogicalNetwork::attractors`].
+pub const MAX_STATES: usize = 1 << 20;
+
+/// The order in which the entities of a network are updated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UpdateScheme {
+    /// All entities are updated at once
+    #[default]
+    Synchronous,
+    /// A single entity is updated per step
+    Asynchronous,
+}
+
+/// The rule setting the target level of an entity.
+#[derive(Debug, Clone)]
+struct Rule {
+    /// Levels and conditions, checked in order
+    terms: Vec<(i32, Expr)>,
+    /// The level if no condition holds
+    default_level: i32,
+    /// Threshold levels of the inputs, referenced by the input ids
+    thresholds: HashMap<String, i32>,
+}
+
+/// A logical model with discrete levels and update rules.
+#[derive(Debug, Clone, Default)]
+pub struct LogicalNetwork {
+    /// Entity identifiers in state order
+    species: Vec<String>,
+    /// Highest level of each entity
+    max_levels: Vec<i32>,
+    /// Level of each entity at the start
+    initial_levels: Vec<i32>,
+    /// Update rule of each entity, None for entities that keep their level
+    rules: Vec<Option<Rule>>,
+}
+
+impl LogicalNetwork {
+    /// Creates an empty network.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a network from the qual package content of a model.
+    ///
+    /// Qualitative species without a maximum level are treated as Boolean unless a
+    /// higher level is used by their transitions, and start at level 0 if no initial
+    /// level is set. Constant species keep their level. The function terms may refer to
+    /// the levels of qualitative species and to the threshold levels of inputs.
+    ///
+    /// # Arguments
+    /// * `model` - The model holding the qualitative species and transitions
+    ///
+    /// # Returns
+    /// The network or an error if the transitions cannot be interpreted
+    pub fn from_model(model: &Model) -> Result<Self, LogicalError> {
+        let qual = model.qual_plugin();
+        let mut network = Self::new();
+
+        for species in qual.list_of_qualitative_species() {
+            let id = species.id();
+            let initial = species.initial_level().unwrap_or(0);
+            network.add_species(&id, species.max_level().unwrap_or(1).max(initial), initial)?;
+        }
+
+        for transition in qual.list_of_transitions() {
+            let default_level = transition
+                .default_term()
+                .ok_or_else(|| {
+                    LogicalError::InvalidModel(format!(
+                        "Transition '{}' has no default term",
+                        transition.id()
+                    ))
+                })?
+                .result_level();
+
+            let terms = transition
+                .list_of_function_terms()
+                .iter()
+                .map(|term| Ok((term.result_level(), Expr::parse(&term.formula())?)))
+                .collect::<Result<Vec<_>, FormulaError>>()?;
+
+            let thresholds = transition
+                .list_of_inputs()
+                .iter()
+                .filter(|input| !input.id().is_empty())
+                .filter_map(|input| Some((input.id(), input.threshold_level()?)))
+                .collect::<HashMap<_, _>>();
+
+            for output in transition.list_of_outputs() {
+                if output.effect() == Some(OutputEffect::Production) {
+                    return Err(LogicalError::InvalidModel(format!(
+                        "Transition '{}' uses the unsupported production effect",
+                        transition.id()
+                    )));
+                }
+
+                let target = output.qualitative_species();
+                let index = network.index(&target)?;
+                if qual
+                    .get_qualitative_species(&target)
+                    .is_some_and(|species| species.constant())
+                {
+                    return Err(LogicalError::InvalidModel(format!(
+                        "Constant species '{}' is the output of transition '{}'",
+                        target,
+                        transition.id()
+                    )));
+                }
+
+                // Levels reached by the transition extend an unset maximum level
+                let highest = terms
+                    .iter()
+                    .map(|(level, _)| *level)
+                    .fold(default_level, i32::max);
+                let species = qual.get_qualitative_species(&target);
+                if species.is_some_and(|species| species.max_level().is_none()) {
+                    network.max_levels[index] = network.max_levels[index].max(highest);
+                }
+
+                network.set_rule(
+                    index,
+                    Rule {
+                        terms: terms.clone(),
+                        default_level,
+                        thresholds: thresholds.clone(),
+                    },
+                )?;
+            }
+        }
+
+        Ok(network)
+    }
+
+    /// Adds an entity to the network.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the entity
+    /// * `max_level` - The highest level of the entity, 1 for Boolean entities
+    /// * `initial_level` - The level of the entity at the start
+    ///
+    /// # Returns
+    /// An error if the identifier is taken or the levels are out of range
+    pub fn add_species(
+        &mut self,
+        id: &str,
+        max_level: i32,
+        initial_level: i32,
+    ) -> Result<(), LogicalError> {
+        if self.species.iter().any(|species| species == id) {
+            return Err(LogicalError::InvalidModel(format!(
+                "Species '{}' is defined twice",
+                id
+            )));
+        }
+        if max_level < 1 || !(0..=max_level).contains(&initial_level) {
+            return Err(LogicalError::InvalidModel(format!(
+                "Invalid levels of species '{}': initial {}, maximum {}",
+                id, initial_level, max_level
+            )));
+        }
+
+        self.species.push(id.to_string());
+        self.max_levels.push(max_level);
+        self.initial_levels.push(initial_level);
+        self.rules.push(None);
+        Ok(())
+    }
+
+    /// Sets the update rule of an entity.
+    ///
+    /// The conditions are formulas over the entity identifiers and are checked in
+    /// order; the level of the first condition that holds becomes the target level.
+    ///
+    /// # Arguments
+    /// * `target` - The identifier of the updated entity
+    /// * `terms` - Target levels and their conditions
+    /// * `default_level` - The target level if no condition holds
+    ///
+    /// # Returns
+    /// An error if an entity is unknown, a formula is invalid or a level is out of range
+    pub fn add_rule(
+        &mut self,
+        target: &str,
+        terms: &[(i32, &str)],
+        default_level: i32,
+    ) -> Result<(), LogicalError> {
+        let index = self.index(target)?;
+        let terms = terms
+            .iter()
+            .map(|(level, formula)| Ok((*level, Expr::parse(formula)?)))
+            .collect::<Result<Vec<_>, FormulaError>>()?;
+
+        self.set_rule(
+            index,
+            Rule {
+                terms,
+                default_level,
+                thresholds: HashMap::new(),
+            },
+        )
+    }
+
+    /// Validates and stores the rule of an entity.
+    fn set_rule(&mut self, index: usize, rule: Rule) -> Result<(), LogicalError> {
+        let id = &self.species[index];
+        if self.rules[index].is_some() {
+            return Err(LogicalError::InvalidModel(format!(
+                "Species '{}' is updated by several rules",
+                id
+            )));
+        }
+
+        let levels = 0..=self.max_levels[index];
+        let invalid_level = std::iter::once(rule.default_level)
+            .chain(rule.terms.iter().map(|(level, _)| *level))
+            .find(|level| !levels.contains(level));
+        if let Some(level) = invalid_level {
+            return Err(LogicalError::InvalidModel(format!(
+                "Level {} exceeds the range of species '{}'",
+                level, id
+            )));
+        }
+
+        for (_, expr) in &rule.terms {
+            if let Some(symbol) = expr.symbols().into_iter().find(|symbol| {
+                !self.species.contains(symbol) && !rule.thresholds.contains_key(symbol)
+            }) {
+                return Err(LogicalError::UnknownSpecies(symbol));
+            }
+        }
+
+        self.rules[index] = Some(rule);
+        Ok(())
+    }
+
+    /// Returns the entity identifiers in the order of the state vectors.
+    pub fn species(&self) -> &[String] {
+        &self.species
+    }
+
+    /// Returns the highest level of each entity.
+    pub fn max_levels(&self) -> &[i32] {
+        &self.max_levels
+    }
+
+    /// Returns the levels of all entities at the start.
+    pub fn initial_state(&self) -> Vec<i32> {
+        self.initial_levels.clone()
+    }
+
+    /// Returns the position of an entity in the state vectors.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the entity
+    ///
+    /// # Returns
+    /// The index or an error if the entity is unknown
+    pub fn index(&self, id: &str) -> Result<usize, LogicalError> {
+        self.species
+            .iter()
+            .position(|species| species == id)
+            .ok_or_else(|| LogicalError::UnknownSpecies(id.to_string()))
+    }
+
+    /// Returns the number of states of the network.
+    ///
+    /// # Returns
+    /// The number of states, None if it overflows
+    pub fn state_count(&self) -> Option<usize> {
+        self.max_levels
+            .iter()
+            .try_fold(1usize, |count, max| count.checked_mul(*max as usize + 1))
+    }
+
+    /// Computes the target level of every entity in a state.
+    ///
+    /// Entities without a rule keep their level.
+    ///
+    /// # Arguments
+    /// * `state` - The levels of all entities
+    ///
+    /// # Returns
+    /// The target levels or an error if a condition cannot be evaluated
+    pub fn targets(&self, state: &[i32]) -> Result<Vec<i32>, LogicalError> {
+        self.check_state(state)?;
+
+        self.rules
+            .iter()
+            .zip(state)
+            .map(|(rule, level)| match rule {
+                Some(rule) => self.evaluate(rule, state),
+                None => Ok(*level),
+            })
+            .collect()
+    }
+
+    /// Evaluates the rule of an entity in a state.
+    fn evaluate(&self, rule: &Rule, state: &[i32]) -> Result<i32, LogicalError> {
+        let lookup = |name: &str| -> Option<f64> {
+            match self.species.iter().position(|species| species == name) {
+                Some(index) => Some(state[index] as f64),
+                None => rule.thresholds.get(name).map(|level| *level as f64),
+            }
+        };
+
+        for (level, condition) in &rule.terms {
+            if condition.eval(&lookup)? != 0.0 {
+                return Ok(*level);
+            }
+        }
+        Ok(rule.default_level)
+    }
+
+    /// Returns the states reachable in a single update.
+    ///
+    /// Levels change by at most one per update. A state without successors is a
+    /// steady state.
+    ///
+    /// # Arguments
+    /// * `state` - The levels of all entities
+    /// * `scheme` - The update scheme
+    ///
+    /// # Returns
+    /// The successor states or an error if a condition cannot be evaluated
+    pub fn successors(
+        &self,
+        state: &[i32],
+        scheme: UpdateScheme,
+    ) -> Result<Vec<Vec<i32>>, LogicalError> {
+        let targets = self.targets(state)?;
+        let changing: Vec<usize> = (0..state.len())
+            .filter(|&i| targets[i] != state[i])
+            .collect();
+
+        if changing.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let step = |successor: &mut Vec<i32>, i: usize| {
+            successor[i] += (targets[i] - state[i]).signum();
+        };
+
+        Ok(match scheme {
+            UpdateScheme::Synchronous => {
+                let mut successor = state.to_vec();
+                changing.iter().for_each(|&i| step(&mut successor, i));
+                vec![successor]
+            }
+            UpdateScheme::Asynchronous => changing
+                .iter()
+                .map(|&i| {
+                    let mut successor = state.to_vec();
+                    step(&mut successor, i);
+                    successor
+                })
+                .collect(),
+        })
+    }
+
+    /// Simulates a trajectory of the network.
+    ///
+    /// With the asynchronous scheme, the updated entity is drawn uniformly among those
+    /// that can change. The simulation ends early in a steady state.
+    ///
+    /// # Arguments
+    /// * `initial` - The levels of all entities at the start
+    /// * `scheme` - The update scheme
+    /// * `steps` - The maximum number of updates
+    /// * `seed` - Seed of the random number generator, drawn from the system if None
+    ///
+    /// # Returns
+    /// The visited states including the initial one, or an error if a condition
+    /// cannot be evaluated
+    pub fn simulate(
+        &self,
+        initial: &[i32],
+        scheme: UpdateScheme,
+        steps: usize,
+        seed: Option<u64>,
+    ) -> Result<Vec<Vec<i32>>, LogicalError> {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut trajectory = vec![initial.to_vec()];
+        for _ in 0..steps {
+            let mut successors = self.successors(trajectory.last().unwrap(), scheme)?;
+            if successors.is_empty() {
+                break;
+            }
+            let next = rng.gen_range(0..successors.len());
+            trajectory.push(successors.swap_remove(next));
+        }
+
+        Ok(trajectory)
+    }
+
+    /// Finds all attractors of the network.
+    ///
+    /// Attractors are the terminal strongly connected components of the state
+    /// transition graph: steady states and the sets of states the network keeps
+    /// cycling through. They are sorted by their smallest state.
+    ///
+    /// # Arguments
+    /// * `scheme` - The update scheme
+    ///
+    /// # Returns
+    /// The attractors or an error if the state space exceeds [`MAX_STATES`] or a
+    /// condition cannot be evaluated
+    pub fn attractors(&self, scheme: UpdateScheme) -> Result<Vec<Attractor>, LogicalError> {
+        let n_states = self
+            .state_count()
+            .filter(|&count| count <= MAX_STATES)
+            .ok_or_else(|| LogicalError::StateSpaceTooLarge(self.state_count()))?;
+
+        let mut graph = Vec::with_capacity(n_states);
+        for index in 0..n_states {
+            let state = self.decode(index);
+            let successors = self.successors(&state, scheme)?;
+            graph.push(
+                successors
+                    .iter()
+                    .map(|successor| self.encode(successor))
+                    .collect(),
+            );
+        }
+
+        let mut attractors: Vec<Attractor> = attractors::terminal_components(&graph)
+            .into_iter()
+            .map(|component| {
+                let mut states: Vec<_> = component
+                    .into_iter()
+                    .map(|index| self.decode(index))
+                    .collect();
+                states.sort();
+                Attractor::new(states)
+            })
+            .collect();
+        attractors.sort_by(|a, b| a.states().cmp(b.states()));
+
+        Ok(attractors)
+    }
+
+    /// Returns the steady states of the network.
+    ///
+    /// Steady states do not depend on the update scheme.
+    ///
+    /// # Returns
+    /// The steady states or an error as for [`LogicalNetwork::attractors`]
+    pub fn steady_states(&self) -> Result<Vec<Vec<i32>>, LogicalError> {
+        Ok(self
+            .attractors(UpdateScheme::Asynchronous)?
+            .into_iter()
+            .filter(Attractor::is_steady_state)
+            .map(|attractor| attractor.states()[0].clone())
+            .collect())
+    }
+
+    /// Checks that a state matches the entities of the network.
+    fn check_state(&self, state: &[i32]) -> Result<(), LogicalError> {
+        if state.len() != self.species.len() {
+            return Err(LogicalError::InvalidState(format!(
+                "Expected {} levels, got {}",
+                self.species.len(),
+                state.len()
+            )));
+        }
+        if let Some(i) = (0..state.len()).find(|&i| !(0..=self.max_levels[i]).contains(&state[i])) {
+            return Err(LogicalError::InvalidState(format!(
+                "Level {} of species '{}' is out of range",
+                state[i], self.species[i]
+            )));
+        }
+        Ok(())
+    }
+
+    /// Maps a state to its position in the enumeration of all states.
+    fn encode(&self, state: &[i32]) -> usize {
+        state
+            .iter()
+            .zip(&self.max_levels)
+            .rev()
+            .fold(0, |index, (level, max)| {
+                index * (*max as usize + 1) + *level as usize
+            })
+    }
+
+    /// Maps a position in the enumeration of all states to the state.
+    fn decode(&self, mut index: usize) -> Vec<i32> {
+        self.max_levels
+            .iter()
+            .map(|max| {
+                let radix = *max as usize + 1;
+                let level = index % radix;
+                index /= radix;
+                level as i32
+            })
+            .collect()
+    }
+}
+
+/// Errors that can occur while building or analyzing a logical network.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogicalError {
+    /// The qualitative species or transitions are inconsistent
+    InvalidModel(String),
+    /// A rule or the caller references an entity that does not exist
+    UnknownSpecies(String),
+    /// A state does not match the entities of the network
+    InvalidState(String),
+    /// A condition could not be parsed or evaluated
+    Formula(FormulaError),
+    /// The state space is too large to enumerate, None if its size overflows
+    StateSpaceTooLarge(Option<usize>),
+}
+
+impl From<FormulaError> for LogicalError {
+    fn from(error: FormulaError) -> Self {
+        LogicalError::Formula(error)
+    }
+}
+
+impl fmt::Display for LogicalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogicalError::InvalidModel(message) => write!(f, "Invalid model: {}", message),
+            LogicalError::UnknownSpecies(id) => write!(f, "Unknown species '{}'", id),
+            LogicalError::InvalidState(message) => write!(f, "Invalid state: {}", message),
+            LogicalError::Formula(error) => write!(f, "Invalid condition: {}", error),
+            LogicalError::StateSpaceTooLarge(Some(count)) => write!(
+                f,
+                "The network has {} states, at most {} can be enumerated",
+                count, MAX_STATES
+            ),
+            LogicalError::StateSpaceTooLarge(None) => {
+                write!(f, "The number of states of the network overflows")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LogicalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two mutually inhibiting genes.
+    fn toggle_switch() -> LogicalNetwork {
+        let mut network = LogicalNetwork::new();
+        network.add_species("A", 1, 1).unwrap();
+        network.add_species("B", 1, 0).unwrap();
+        network.add_rule("A", &[(1, "B == 0")], 0).unwrap();
+        network.add_rule("B", &[(1, "A == 0")], 0).unwrap();
+        network
+    }
+
+    /// A negative feedback loop of three genes.
+    fn repressilator() -> LogicalNetwork {
+        let mut network = LogicalNetwork::new();
+        for id in ["A", "B", "C"] {
+            network.add_species(id, 1, 0).unwrap();
+        }
+        network.add_rule("A", &[(1, "C == 0")], 0).unwrap();
+        network.add_rule("B", &[(1, "A == 1")], 0).unwrap();
+        network.add_rule("C", &[(1, "B == 1")], 0).unwrap();
+        network
+    }
+
+    #[test]
+    fn test_successors() {
+        let network = toggle_switch();
+        assert_eq!(network.targets(&[0, 0]).unwrap(), vec![1, 1]);
+        assert_eq!(
+            network
+                .successors(&[0, 0], UpdateScheme::Synchronous)
+                .unwrap(),
+            vec![vec![1, 1]]
+        );
+        assert_eq!(
+            network
+                .successors(&[0, 0], UpdateScheme::Asynchronous)
+                .unwrap(),
+            vec![vec![1, 0], vec![0, 1]]
+        );
+        assert!(network
+            .successors(&[1, 0], UpdateScheme::Synchronous)
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            network.targets(&[2, 0]),
+            Err(LogicalError::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn test_attractors() {
+        let network = toggle_switch();
+
+        // Asynchronous updates settle in one of the two steady states
+        let attractors = network.attractors(UpdateScheme::Asynchronous).unwrap();
+        assert_eq!(attractors.len(), 2);
+        assert!(attractors.iter().all(Attractor::is_steady_state));
+        assert_eq!(
+            network.steady_states().unwrap(),
+            vec![vec![0, 1], vec![1, 0]]
+        );
+
+        // Synchronous updates additionally oscillate between both genes on and off
+        let attractors = network.attractors(UpdateScheme::Synchronous).unwrap();
+        assert_eq!(attractors.len(), 3);
+        assert_eq!(attractors[0].states(), &[vec![0, 0], vec![1, 1]]);
+        assert!(!attractors[0].is_steady_state());
+
+        // The repressilator has no steady state but cycles through six states, and
+        // synchronously also alternates between two further states
+        let network = repressilator();
+        let attractors = network.attractors(UpdateScheme::Asynchronous).unwrap();
+        assert_eq!(attractors.len(), 1);
+        assert_eq!(attractors[0].len(), 6);
+
+        let attractors = network.attractors(UpdateScheme::Synchronous).unwrap();
+        let sizes: Vec<_> = attractors.iter().map(Attractor::len).collect();
+        assert_eq!(sizes, vec![6, 2]);
+        assert_eq!(attractors[1].states(), &[vec![0, 1, 0], vec![1, 0, 1]]);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let network = repressilator();
+        let trajectory = network
+            .simulate(&[0, 0, 0], UpdateScheme::Synchronous, 6, None)
+            .unwrap();
+        assert_eq!(trajectory.len(), 7);
+        assert_eq!(trajectory[1], vec![1, 0, 0]);
+        assert_eq!(trajectory[6], vec![0, 0, 0]);
+
+        // Seeded asynchronous runs are reproducible and take single steps
+        let run = |seed| {
+            network
+                .simulate(&[0, 0, 0], UpdateScheme::Asynchronous, 20, Some(seed))
+                .unwrap()
+        };
+        assert_eq!(run(7), run(7));
+        for pair in run(3).windows(2) {
+            let changes = pair[0].iter().zip(&pair[1]).filter(|(a, b)| a != b).count();
+            assert_eq!(changes, 1);
+        }
+
+        // Simulations stop in steady states
+        let trajectory = toggle_switch()
+            .simulate(&[1, 0], UpdateScheme::Asynchronous, 10, Some(0))
+            .unwrap();
+        assert_eq!(trajectory, vec![vec![1, 0]]);
+    }
+
+    #[test]
+    fn test_multivalued_levels() {
+        let mut network = LogicalNetwork::new();
+        network.add_species("signal", 1, 1).unwrap();
+        network.add_species("X", 2, 0).unwrap();
+        network
+            .add_rule("X", &[(2, "signal == 1"), (1, "X >= 1")], 0)
+            .unwrap();
+
+        // Levels change by one step per update
+        let trajectory = network
+            .simulate(&[1, 0], UpdateScheme::Synchronous, 10, None)
+            .unwrap();
+        assert_eq!(trajectory, vec![vec![1, 0], vec![1, 1], vec![1, 2]]);
+        assert_eq!(network.state_count(), Some(6));
+        assert_eq!(
+            network.steady_states().unwrap(),
+            vec![vec![0, 0], vec![0, 1], vec![1, 2]]
+        );
+    }
+
+    #[test]
+    fn test_invalid_networks() {
+        let mut network = LogicalNetwork::new();
+        network.add_species("A", 1, 0).unwrap();
+        assert!(matches!(
+            network.add_species("A", 1, 0),
+            Err(LogicalError::InvalidModel(_))
+        ));
+        assert!(network.add_species("B", 1, 2).is_err());
+        assert_eq!(
+            network.add_rule("A", &[(1, "Z == 1")], 0),
+            Err(LogicalError::UnknownSpecies("Z".to_string()))
+        );
+        assert!(matches!(
+            network.add_rule("A", &[(3, "A == 0")], 0),
+            Err(LogicalError::InvalidModel(_))
+        ));
+        assert!(matches!(
+            network.add_rule("A", &[(1, "A ==")], 0),
+            Err(LogicalError::Formula(_))
+        ));
+
+        let mut large = LogicalNetwork::new();
+        for i in 0..21 {
+            large.add_species(&format!("S{}", i), 1, 0).unwrap();
+        }
+        assert_eq!(
+            large.attractors(UpdateScheme::Synchronous).err(),
+            Some(LogicalError::StateSpaceTooLarge(Some(1 << 21)))
+        );
+    }
+}
@@ -200,6 +200,90 @@ macro_rules! upcast_annotation {
     };
 }
 
+/// Implements the SBase trait for a wrapper type.
+///
+/// This macro generates an implementation of the SBase trait for a wrapper type that contains
+/// a pinned reference to a C++ object derived from SBase. Each method upcasts the object to
+/// SBase and delegates to the corresponding function of the `traits::sbase` module.
+///
+/// # Arguments
+/// * `$type` - The Rust wrapper type (e.g. Species<'a>)
+/// * `$cxx_type` - The C++ type that is being wrapped (e.g. sbmlcxx::Species)
+#[macro_export]
+macro_rules! sbase {
+    ($type:ty, $cxx_type:ty) => {
+        impl<'a> crate::traits::sbase::SBase for $type {
+            fn id(&self) -> String {
+                crate::traits::sbase::id(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+
+            fn name(&self) -> String {
+                crate::traits::sbase::name(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+
+            fn meta_id(&self) -> String {
+                crate::traits::sbase::meta_id(crate::upcast!(
+                    self,
+                    $cxx_type,
+                    crate::sbmlcxx::SBase
+                ))
+            }
+
+            fn sbo_term_id(&self) -> String {
+                crate::traits::sbase::sbo_term_id(crate::upcast!(
+                    self,
+                    $cxx_type,
+                    crate::sbmlcxx::SBase
+                ))
+            }
+
+            fn notes(&self) -> String {
+                crate::traits::sbase::notes(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+
+            fn annotation(&self) -> String {
+                crate::traits::sbase::annotation(crate::upcast!(
+                    self,
+                    $cxx_type,
+                    crate::sbmlcxx::SBase
+                ))
+            }
+
+            fn level(&self) -> u32 {
+                crate::traits::sbase::level(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+
+            fn version(&self) -> u32 {
+                crate::traits::sbase::version(crate::upcast!(
+                    self,
+                    $cxx_type,
+                    crate::sbmlcxx::SBase
+                ))
+            }
+
+            fn line(&self) -> u32 {
+                crate::traits::sbase::line(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+
+            fn column(&self) -> u32 {
+                crate::traits::sbase::column(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+
+            fn element_name(&self) -> String {
+                crate::traits::sbase::element_name(crate::upcast!(
+                    self,
+                    $cxx_type,
+                    crate::sbmlcxx::SBase
+                ))
+            }
+
+            fn parent(&self) -> Option<crate::traits::sbase::Element<'_>> {
+                crate::traits::sbase::parent(crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase))
+            }
+        }
+    };
+}
+
 /// A macro for generating SBO (Systems Biology Ontology) term related methods.
 ///
 /// This macro generates three methods for handling SBO terms:
@@ -253,3 +337,207 @@ macro_rules! sbo_term {
         }
     };
 }
+
+/// Generates methods for the replacements of the comp package.
+///
+/// Any element of a model can replace elements of submodels, or be replaced by an
+/// element of a submodel. libSBML stores this information in the comp plugin of the
+/// element, which this macro accesses through an upcast to SBase.
+///
+/// # Arguments
+/// * `$cxx_type` - The C++ type of the element (e.g. sbmlcxx::Species)
+///
+/// # Generated Methods
+/// The macro generates the following methods:
+///
+/// - `create_replaced_element(&self, submodel, target) -> ReplacedElement` - Replaces an element of a submodel
+/// - `list_of_replaced_elements(&self) -> Vec<ReplacedElement>` - Gets the replaced elements
+/// - `replaced_by(&self) -> Option<ReplacedBy>` - Gets the element replacing this one
+/// - `set_replaced_by(&self, submodel, target) -> ReplacedBy` - Sets the element replacing this one
+///
+/// The methods are only generated if the `comp` feature is enabled.
+#[macro_export]
+macro_rules! comp_replacements {
+    ($cxx_type:ty) => {
+        /// Returns the comp plugin of the element.
+        ///
+        /// # Arguments
+        /// * `enable` - Whether to enable the comp package on the document if needed
+        #[cfg(feature = "comp")]
+        fn comp_plugin(
+            &self,
+            enable: bool,
+        ) -> Option<std::pin::Pin<&'a mut crate::sbmlcxx::CompSBasePlugin>> {
+            let base = crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase);
+            crate::packages::get_plugin(base, crate::packages::Package::Comp, enable)
+        }
+
+        /// Declares that this element replaces an element of a submodel (comp package).
+        ///
+        /// # Arguments
+        /// * `submodel` - The identifier of the submodel containing the replaced element
+        /// * `target` - The replaced element within the submodel
+        ///
+        /// # Returns
+        /// The newly created ReplacedElement
+        ///
+        /// # Panics
+        /// If the element is not part of a document
+        #[cfg(feature = "comp")]
+        pub fn create_replaced_element(
+            &self,
+            submodel: &str,
+            target: &crate::comp::ReferenceTarget,
+        ) -> crate::comp::ReplacedElement<'a> {
+            let plugin = self
+                .comp_plugin(true)
+                .expect("Element must be part of a document to use the comp package");
+            crate::comp::ReplacedElement::new(plugin, submodel, target)
+        }
+
+        /// Returns the elements of submodels replaced by this element (comp package).
+        ///
+        /// # Returns
+        /// A vector of ReplacedElements, empty if the comp package is not enabled
+        #[cfg(feature = "comp")]
+        pub fn list_of_replaced_elements(&self) -> Vec<crate::comp::ReplacedElement<'a>> {
+            let Some(mut plugin) = self.comp_plugin(false) else {
+                return Vec::new();
+            };
+
+            let n_replaced = plugin.getNumReplacedElements().0;
+            (0..n_replaced)
+                .map(|i| {
+                    let replaced = plugin.as_mut().getReplacedElement1(i.into());
+                    <crate::comp::ReplacedElement<'a> as crate::traits::fromptr::FromPtr<
+                        crate::sbmlcxx::ReplacedElement,
+                    >>::from_ptr(replaced)
+                })
+                .collect()
+        }
+
+        /// Returns the element of a submodel replacing this element (comp package).
+        ///
+        /// # Returns
+        /// The ReplacedBy, None if the element is not replaced
+        #[cfg(feature = "comp")]
+        pub fn replaced_by(&self) -> Option<crate::comp::ReplacedBy<'a>> {
+            let mut plugin = self.comp_plugin(false)?;
+            if !plugin.isSetReplacedBy() {
+                return None;
+            }
+
+            let replaced_by = plugin.as_mut().getReplacedBy1();
+            Some(
+                <crate::comp::ReplacedBy<'a> as crate::traits::fromptr::FromPtr<
+                    crate::sbmlcxx::ReplacedBy,
+                >>::from_ptr(replaced_by),
+            )
+        }
+
+        /// Declares that this element is replaced by an element of a submodel (comp package).
+        ///
+        /// An existing ReplacedBy of the element is overwritten.
+        ///
+        /// # Arguments
+        /// * `submodel` - The identifier of the submodel containing the replacement
+        /// * `target` - The replacement within the submodel
+        ///
+        /// # Returns
+        /// The newly created ReplacedBy
+        ///
+        /// # Panics
+        /// If the element is not part of a document
+        #[cfg(feature = "comp")]
+        pub fn set_replaced_by(
+            &self,
+            submodel: &str,
+            target: &crate::comp::ReferenceTarget,
+        ) -> crate::comp::ReplacedBy<'a> {
+            let plugin = self
+                .comp_plugin(true)
+                .expect("Element must be part of a document to use the comp package");
+            crate::comp::ReplacedBy::new(plugin, submodel, target)
+        }
+    };
+}
+
+/// Generates methods for the uncertainties of the distrib package.
+///
+/// Any element of a model can carry uncertainties describing the statistics or the
+/// distribution of its value. libSBML stores them in the distrib plugin of the
+/// element, which this macro accesses through an upcast to SBase.
+///
+/// # Arguments
+/// * `$cxx_type` - The C++ type of the element (e.g. sbmlcxx::Parameter)
+///
+/// # Generated Methods
+/// The macro generates the following methods:
+///
+/// - `create_uncertainty(&self) -> Uncertainty` - Adds an uncertainty to the element
+/// - `list_of_uncertainties(&self) -> Vec<Uncertainty>` - Gets the uncertainties
+/// - `uncertainty(&self) -> Option<Uncertainty>` - Gets the first uncertainty
+///
+/// The methods are only generated if the `distrib` feature is enabled.
+#[macro_export]
+macro_rules! distrib_uncertainties {
+    ($cxx_type:ty) => {
+        /// Returns the distrib plugin of the element.
+        ///
+        /// # Arguments
+        /// * `enable` - Whether to enable the distrib package on the document if needed
+        #[cfg(feature = "distrib")]
+        fn distrib_plugin(
+            &self,
+            enable: bool,
+        ) -> Option<std::pin::Pin<&'a mut crate::sbmlcxx::DistribSBasePlugin>> {
+            let base = crate::upcast!(self, $cxx_type, crate::sbmlcxx::SBase);
+            crate::packages::get_plugin(base, crate::packages::Package::Distrib, enable)
+        }
+
+        /// Adds an uncertainty to the element (distrib package).
+        ///
+        /// # Returns
+        /// The newly created Uncertainty
+        ///
+        /// # Panics
+        /// If the element is not part of a document
+        #[cfg(feature = "distrib")]
+        pub fn create_uncertainty(&self) -> crate::distrib::Uncertainty<'a> {
+            let plugin = self
+                .distrib_plugin(true)
+                .expect("Element must be part of a document to use the distrib package");
+            crate::distrib::Uncertainty::new(plugin)
+        }
+
+        /// Returns the uncertainties of the element (distrib package).
+        ///
+        /// # Returns
+        /// A vector of Uncertainties, empty if the distrib package is not enabled
+        #[cfg(feature = "distrib")]
+        pub fn list_of_uncertainties(&self) -> Vec<crate::distrib::Uncertainty<'a>> {
+            let Some(mut plugin) = self.distrib_plugin(false) else {
+                return Vec::new();
+            };
+
+            let n_uncertainties = plugin.getNumUncertainties().0;
+            (0..n_uncertainties)
+                .map(|i| {
+                    let uncertainty = plugin.as_mut().getUncertainty1(i.into());
+                    <crate::distrib::Uncertainty<'a> as crate::traits::fromptr::FromPtr<
+                        crate::sbmlcxx::Uncertainty,
+                    >>::from_ptr(uncertainty)
+                })
+                .collect()
+        }
+
+        /// Returns the first uncertainty of the element (distrib package).
+        ///
+        /// # Returns
+        /// The Uncertainty, None if the element has none
+        #[cfg(feature = "distrib")]
+        pub fn uncertainty(&self) -> Option<crate::distrib::Uncertainty<'a>> {
+            self.list_of_uncertainties().into_iter().next()
+        }
+    };
+}
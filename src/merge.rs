@@ -0,0 +1,934 @@
+//! Merging of models assembled from separate fragments.
+//!
+//! [`Model::merge_from`] copies the unit definitions, compartments, species,
+//! parameters, rules and reactions of another model into a model. Identifiers used
+//! by both models are resolved according to [`MergeOptions`]: incoming elements are
+//! renamed, dropped in favor of the existing ones, or the merge fails. References
+//! within the copied elements, including the `ci` symbols of their math, are
+//! rewritten to the renamed identifiers.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let pathway = SBMLReader::from_file("glycolysis.xml").unwrap();
+//! let fragment = SBMLReader::from_file("pentose_phosphate.xml").unwrap();
+//!
+//! let options = MergeOptions {
+//!     on_conflict: IdConflict::Rename("ppp_".to_string()),
+//!     ..Default::default()
+//! };
+//! let model = pathway.model().unwrap();
+//! let report = model.merge_from(&fragment.model().unwrap(), options).unwrap();
+//! for (old, new) in &report.renamed {
+//!     println!("Renamed {} to {}", old, new);
+//! }
+//! ```
+
+use std::{collections::HashSet, error::Error, fmt, pin::Pin};
+
+use cxx::{let_cxx_string, UniquePtr};
+
+use crate::{
+    cast,
+    model::Model,
+    packages::{self, Package},
+    pin_ptr,
+    reaction::Reaction,
+    sbmlcxx,
+    traits::{fromptr::FromPtr, inner::Inner},
+    unitdef::UnitDefinition,
+    upcast,
+};
+
+/// libSBML return code of a successful operation
+const LIBSBML_OPERATION_SUCCESS: i32 = 0;
+/// libSBML return code of an element that lacks required attributes or children
+const LIBSBML_INVALID_OBJECT: i32 = -5;
+
+/// Strategy for incoming elements whose identifier is already used in the model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdConflict {
+    /// Renames the incoming element by prepending the given prefix to its identifier
+    Rename(String),
+    /// Drops the incoming element, so that references to it point to the existing one
+    KeepExisting,
+    /// Fails the merge before the model is changed
+    Error,
+}
+
+/// Settings of [`Model::merge_from`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeOptions {
+    /// How identifiers used by both models are resolved
+    pub on_conflict: IdConflict,
+    /// Whether incoming unit definitions identical to an existing one are replaced by it
+    pub unify_unit_definitions: bool,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            on_conflict: IdConflict::Error,
+            unify_unit_definitions: true,
+        }
+    }
+}
+
+/// Summary of a merge.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MergeReport {
+    /// Identifiers of the copied elements in the merged model (rules by their variable)
+    pub added: Vec<String>,
+    /// Incoming identifiers renamed due to conflicts, as (incoming, merged) pairs
+    pub renamed: Vec<(String, String)>,
+    /// Incoming identifiers dropped in favor of an existing element
+    pub kept: Vec<String>,
+    /// Incoming unit definitions replaced by identical existing ones, as (incoming,
+    /// existing) pairs
+    pub unified_units: Vec<(String, String)>,
+}
+
+/// Errors that can occur while merging models.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeError {
+    /// The models have different SBML levels or versions
+    IncompatibleLevel {
+        model: (u32, u32),
+        other: (u32, u32),
+    },
+    /// An identifier of the incoming model is already used in the model
+    Conflict(String),
+    /// Both models define a rule for the same variable
+    RuleConflict(String),
+    /// libSBML rejected the copy of an element, e.g. due to an invalid identifier, with
+    /// its return code
+    Rejected { id: String, code: i32 },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeError::IncompatibleLevel { model, other } => write!(
+                f,
+                "Cannot merge a Level {} Version {} model into a Level {} Version {} model",
+                other.0, other.1, model.0, model.1
+            ),
+            MergeError::Conflict(id) => write!(f, "Identifier '{}' is used by both models", id),
+            MergeError::RuleConflict(variable) => {
+                write!(f, "Both models define a rule for '{}'", variable)
+            }
+            MergeError::Rejected { id, code } => write!(
+                f,
+                "libSBML rejected the copy of '{}' with return code {}",
+                id, code
+            ),
+        }
+    }
+}
+
+impl Error for MergeError {}
+
+/// Identifier replacements applied to the copied elements.
+#[derive(Default)]
+struct Renames {
+    /// Replacements of SIds, i.e. of species, compartments, parameters and reactions
+    sids: Vec<(String, String)>,
+    /// Replacements of UnitSIds
+    units: Vec<(String, String)>,
+}
+
+impl Renames {
+    /// Returns the merged SId of an incoming SId.
+    fn sid<'b>(&'b self, id: &'b str) -> &'b str {
+        self.sids
+            .iter()
+            .find(|(old, _)| old == id)
+            .map(|(_, new)| new.as_str())
+            .unwrap_or(id)
+    }
+
+    /// Returns the merged UnitSId of an incoming UnitSId.
+    fn unit<'b>(&'b self, id: &'b str) -> &'b str {
+        self.units
+            .iter()
+            .find(|(old, _)| old == id)
+            .map(|(_, new)| new.as_str())
+            .unwrap_or(id)
+    }
+
+    /// Prepares the clone of an incoming element for addition to the model.
+    ///
+    /// Rewrites the references of the clone, sets its identifier if given and checks
+    /// that libSBML will accept it. Renames due to conflicts come first, as their new
+    /// identifiers are unique, whereas unified unit definitions point to existing
+    /// identifiers.
+    ///
+    /// # Arguments
+    /// * `base` - The clone of the incoming element
+    /// * `name` - The incoming identifier of the element, used in errors
+    /// * `id` - The identifier of the element in the merged model, if it has one
+    fn apply(
+        &self,
+        mut base: Pin<&mut sbmlcxx::SBase>,
+        name: &str,
+        id: Option<&str>,
+    ) -> Result<(), MergeError> {
+        for (old, new) in &self.sids {
+            let_cxx_string!(old = old.as_str());
+            let_cxx_string!(new = new.as_str());
+            base.as_mut().renameSIdRefs(&old, &new);
+        }
+        for (old, new) in &self.units {
+            let_cxx_string!(old = old.as_str());
+            let_cxx_string!(new = new.as_str());
+            base.as_mut().renameUnitSIdRefs(&old, &new);
+        }
+
+        if let Some(id) = id {
+            if base.getId().to_str().unwrap() != id {
+                let_cxx_string!(id = id);
+                let code = base.as_mut().setId(&id).0;
+                if code != LIBSBML_OPERATION_SUCCESS {
+                    return Err(MergeError::Rejected {
+                        id: name.to_string(),
+                        code,
+                    });
+                }
+            }
+        }
+
+        if !base.hasRequiredAttributes() || !base.hasRequiredElements() {
+            return Err(MergeError::Rejected {
+                id: name.to_string(),
+                code: LIBSBML_INVALID_OBJECT,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Clones an element of the incoming model, so that the clone can be renamed and
+/// validated before anything is added to the model.
+macro_rules! clone_element {
+    ($element:expr, $ty:ty) => {{
+        let inner = $element.inner().borrow();
+        unsafe { UniquePtr::<$ty>::from_raw(<$ty>::clone(&inner)) }
+    }};
+}
+
+/// Adds the prepared clones of elements to the libSBML model, which copies them.
+macro_rules! add_elements {
+    ($target:expr, $clones:expr, $add:ident) => {{
+        let mut model = $target.inner().borrow_mut();
+        for (id, clone) in &$clones {
+            let code = unsafe { model.as_mut().$add(clone.as_mut_ptr()) }.0;
+            if code != LIBSBML_OPERATION_SUCCESS {
+                return Err(MergeError::Rejected {
+                    id: id.to_string(),
+                    code,
+                });
+            }
+        }
+    }};
+}
+
+/// Merges the elements of `other` into `model`, see [`Model::merge_from`].
+///
+/// The wrappers of the copied elements are not created here, but by the caller.
+pub(crate) fn merge(
+    model: &Model,
+    other: &Model,
+    options: &MergeOptions,
+) -> Result<MergeReport, MergeError> {
+    let levels = (level_version(model), level_version(other));
+    if levels.0 != levels.1 {
+        return Err(MergeError::IncompatibleLevel {
+            model: levels.0,
+            other: levels.1,
+        });
+    }
+
+    let mut report = MergeReport::default();
+    let mut renames = Renames::default();
+
+    // Plan the resolution of all conflicts before changing the model
+    let existing_sids = sids(model);
+    let incoming_sids = sids(other);
+    let mut taken: HashSet<String> = existing_sids.union(&incoming_sids).cloned().collect();
+    let mut skipped: HashSet<String> = HashSet::new();
+
+    let incoming: Vec<String> = other
+        .list_of_compartments()
+        .iter()
+        .map(|compartment| compartment.id())
+        .chain(other.list_of_species().iter().map(|species| species.id()))
+        .chain(
+            other
+                .list_of_parameters()
+                .iter()
+                .map(|parameter| parameter.id()),
+        )
+        .chain(
+            other
+                .list_of_reactions()
+                .iter()
+                .map(|reaction| reaction.id()),
+        )
+        .collect();
+    for id in incoming {
+        if existing_sids.contains(&id) {
+            match resolve(&id, &options.on_conflict, &mut taken)? {
+                Some(new_id) => renames.sids.push((id, new_id)),
+                None => {
+                    skipped.insert(id.clone());
+                    report.kept.push(id);
+                }
+            }
+        }
+    }
+
+    let existing_units = model.list_of_unit_definitions();
+    let mut taken_units: HashSet<String> = existing_units
+        .iter()
+        .map(|unit_definition| unit_definition.id())
+        .chain(
+            other
+                .list_of_unit_definitions()
+                .iter()
+                .map(|unit_definition| unit_definition.id()),
+        )
+        .collect();
+    let mut skipped_units: HashSet<String> = HashSet::new();
+    let mut unified: Vec<(String, String)> = Vec::new();
+
+    for unit_definition in other.list_of_unit_definitions() {
+        let id = unit_definition.id();
+        let equivalent = options
+            .unify_unit_definitions
+            .then(|| {
+                existing_units
+                    .iter()
+                    .find(|existing| same_units(existing, &unit_definition))
+            })
+            .flatten();
+
+        if let Some(existing) = equivalent {
+            if existing.id() != id {
+                unified.push((id.clone(), existing.id()));
+            }
+            report.unified_units.push((id.clone(), existing.id()));
+            skipped_units.insert(id);
+        } else if existing_units.iter().any(|existing| existing.id() == id) {
+            match resolve(&id, &options.on_conflict, &mut taken_units)? {
+                Some(new_id) => renames.units.push((id, new_id)),
+                None => {
+                    skipped_units.insert(id.clone());
+                    report.kept.push(id);
+                }
+            }
+        }
+    }
+    renames.units.extend(unified);
+
+    let existing_rules: HashSet<String> = model
+        .list_of_rate_rules()
+        .iter()
+        .chain(model.list_of_assignment_rules().iter())
+        .map(|rule| rule.variable())
+        .collect();
+    let mut skipped_rules: HashSet<String> = HashSet::new();
+    for rule in other
+        .list_of_rate_rules()
+        .iter()
+        .chain(other.list_of_assignment_rules().iter())
+    {
+        let variable = renames.sid(&rule.variable()).to_string();
+        if existing_rules.contains(&variable) {
+            match options.on_conflict {
+                IdConflict::KeepExisting => {
+                    skipped_rules.insert(rule.variable());
+                }
+                _ => return Err(MergeError::RuleConflict(variable)),
+            }
+        }
+    }
+
+    // Clone, rename and validate all elements before the first one is added, so that
+    // most errors are found before the model is changed
+    let mut unit_definitions = Vec::new();
+    for unit_definition in other.list_of_unit_definitions() {
+        let id = unit_definition.id();
+        if skipped_units.contains(&id) {
+            continue;
+        }
+        let clone = clone_element!(unit_definition, sbmlcxx::UnitDefinition);
+        let new_id = renames.unit(&id);
+        renames.apply(
+            unsafe { cast::upcast(clone.as_mut_ptr()) },
+            &id,
+            Some(new_id),
+        )?;
+        report.added.push(new_id.to_string());
+        unit_definitions.push((id, clone));
+    }
+
+    let mut compartments = Vec::new();
+    for compartment in other.list_of_compartments() {
+        let id = compartment.id();
+        if skipped.contains(&id) {
+            continue;
+        }
+        let clone = clone_element!(compartment, sbmlcxx::Compartment);
+        let new_id = renames.sid(&id);
+        renames.apply(
+            unsafe { cast::upcast(clone.as_mut_ptr()) },
+            &id,
+            Some(new_id),
+        )?;
+        report.added.push(new_id.to_string());
+        compartments.push((id, clone));
+    }
+
+    let mut species_list = Vec::new();
+    for species in other.list_of_species() {
+        let id = species.id();
+        if skipped.contains(&id) {
+            continue;
+        }
+        let clone = clone_element!(species, sbmlcxx::Species);
+        let new_id = renames.sid(&id);
+        renames.apply(
+            unsafe { cast::upcast(clone.as_mut_ptr()) },
+            &id,
+            Some(new_id),
+        )?;
+        report.added.push(new_id.to_string());
+        species_list.push((id, clone));
+    }
+
+    let mut parameters = Vec::new();
+    for parameter in other.list_of_parameters() {
+        let id = parameter.id();
+        if skipped.contains(&id) {
+            continue;
+        }
+        let clone = clone_element!(parameter, sbmlcxx::Parameter);
+        let new_id = renames.sid(&id);
+        renames.apply(
+            unsafe { cast::upcast(clone.as_mut_ptr()) },
+            &id,
+            Some(new_id),
+        )?;
+        report.added.push(new_id.to_string());
+        parameters.push((id, clone));
+    }
+
+    let mut rules = Vec::new();
+    for rule in other
+        .list_of_rate_rules()
+        .iter()
+        .chain(other.list_of_assignment_rules().iter())
+    {
+        let variable = rule.variable();
+        if skipped_rules.contains(&variable) {
+            continue;
+        }
+        let clone = clone_element!(rule, sbmlcxx::Rule);
+        renames.apply(unsafe { cast::upcast(clone.as_mut_ptr()) }, &variable, None)?;
+        report.added.push(renames.sid(&variable).to_string());
+        rules.push((variable, clone));
+    }
+
+    let mut reactions = Vec::new();
+    for reaction in other.list_of_reactions() {
+        let id = reaction.id();
+        if skipped.contains(&id) {
+            continue;
+        }
+        let clone = clone_element!(reaction, sbmlcxx::Reaction);
+        let new_id = renames.sid(&id);
+        rename_reaction(
+            &Reaction::from_ptr(clone.as_mut_ptr()),
+            &id,
+            new_id,
+            &renames,
+        )?;
+        report.added.push(new_id.to_string());
+        reactions.push((id, clone));
+    }
+
+    // The incoming elements may carry package content, which libSBML only accepts
+    // into a document with the same packages enabled
+    let counts = element_counts(model);
+    let enabled = enable_packages(model, other);
+
+    // Add the elements in the order of their dependencies. libSBML may still reject an
+    // element, in which case everything added so far is removed again.
+    let added = (|| {
+        add_elements!(model, unit_definitions, addUnitDefinition);
+        add_elements!(model, compartments, addCompartment);
+        add_elements!(model, species_list, addSpecies);
+        add_elements!(model, parameters, addParameter);
+        add_elements!(model, rules, addRule);
+        add_elements!(model, reactions, addReaction);
+        Ok(())
+    })();
+    if let Err(error) = added {
+        remove_added(model, counts);
+        disable_packages(model, &enabled);
+        return Err(error);
+    }
+
+    report.renamed = renames
+        .sids
+        .iter()
+        .chain(renames.units.iter())
+        .filter(|(old, _)| !report.unified_units.iter().any(|(id, _)| id == old))
+        .cloned()
+        .collect();
+
+    Ok(report)
+}
+
+/// Sets the identifier of the clone of a reaction and rewrites the references of the
+/// reaction, its species references and its kinetic law.
+fn rename_reaction(
+    reaction: &Reaction,
+    name: &str,
+    id: &str,
+    renames: &Renames,
+) -> Result<(), MergeError> {
+    renames.apply(
+        upcast!(reaction, sbmlcxx::Reaction, sbmlcxx::SBase),
+        name,
+        Some(id),
+    )?;
+
+    for reference in reaction
+        .reactants()
+        .borrow()
+        .iter()
+        .chain(reaction.products().borrow().iter())
+    {
+        renames.apply(
+            upcast!(reference, sbmlcxx::SpeciesReference, sbmlcxx::SBase),
+            name,
+            None,
+        )?;
+    }
+    for modifier in reaction.modifiers().borrow().iter() {
+        renames.apply(
+            upcast!(modifier, sbmlcxx::ModifierSpeciesReference, sbmlcxx::SBase),
+            name,
+            None,
+        )?;
+    }
+
+    if let Some(kinetic_law) = reaction.kinetic_law() {
+        renames.apply(
+            upcast!(kinetic_law, sbmlcxx::KineticLaw, sbmlcxx::SBase),
+            name,
+            None,
+        )?;
+        for parameter in kinetic_law.list_of_local_parameters() {
+            renames.apply(
+                upcast!(parameter, sbmlcxx::LocalParameter, sbmlcxx::SBase),
+                name,
+                None,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Enables the packages of the incoming model on the document of the model.
+///
+/// # Returns
+/// The packages that were enabled
+fn enable_packages(model: &Model, other: &Model) -> Vec<Package> {
+    let mut base = upcast!(model, sbmlcxx::Model, sbmlcxx::SBase);
+    let other_base = upcast!(other, sbmlcxx::Model, sbmlcxx::SBase);
+    let mut enabled = Vec::new();
+    for package in Package::ALL {
+        let_cxx_string!(name = package.name());
+        if other_base.isPackageEnabled(&name) && !base.isPackageEnabled(&name) {
+            let document_ptr = base.as_mut().getSBMLDocument1();
+            if !document_ptr.is_null() {
+                packages::enable_package(pin_ptr!(document_ptr, sbmlcxx::SBMLDocument), package);
+                enabled.push(package);
+            }
+        }
+    }
+    enabled
+}
+
+/// Disables packages enabled by [`enable_packages`] again.
+fn disable_packages(model: &Model, packages: &[Package]) {
+    let mut base = upcast!(model, sbmlcxx::Model, sbmlcxx::SBase);
+    let document_ptr = base.as_mut().getSBMLDocument1();
+    if document_ptr.is_null() {
+        return;
+    }
+    for package in packages {
+        packages::disable_package(pin_ptr!(document_ptr, sbmlcxx::SBMLDocument), *package);
+    }
+}
+
+/// Returns the numbers of unit definitions, compartments, species, parameters, rules
+/// and reactions of the model.
+fn element_counts(model: &Model) -> [u32; 6] {
+    let model = model.inner().borrow();
+    [
+        model.getNumUnitDefinitions().0,
+        model.getNumCompartments().0,
+        model.getNumSpecies().0,
+        model.getNumParameters().0,
+        model.getNumRules().0,
+        model.getNumReactions().0,
+    ]
+}
+
+/// Removes the elements appended to the model since [`element_counts`] was taken.
+fn remove_added(model: &Model, counts: [u32; 6]) {
+    macro_rules! remove_appended {
+        ($count:expr, $num:ident, $remove:ident) => {{
+            let mut model = model.inner().borrow_mut();
+            while model.$num().0 > $count {
+                let last = model.$num().0 - 1;
+                let removed = model.as_mut().$remove(last.into());
+                // The caller owns the removed element
+                drop(unsafe { UniquePtr::from_raw(removed) });
+            }
+        }};
+    }
+
+    remove_appended!(counts[0], getNumUnitDefinitions, removeUnitDefinition);
+    remove_appended!(counts[1], getNumCompartments, removeCompartment);
+    remove_appended!(counts[2], getNumSpecies, removeSpecies);
+    remove_appended!(counts[3], getNumParameters, removeParameter);
+    remove_appended!(counts[4], getNumRules, removeRule);
+    remove_appended!(counts[5], getNumReactions, removeReaction);
+}
+
+/// Resolves a conflicting identifier.
+///
+/// # Returns
+/// The new identifier if the element is renamed, None if it is dropped
+fn resolve(
+    id: &str,
+    strategy: &IdConflict,
+    taken: &mut HashSet<String>,
+) -> Result<Option<String>, MergeError> {
+    match strategy {
+        IdConflict::Rename(prefix) => {
+            let mut new_id = format!("{}{}", prefix, id);
+            let mut n = 2;
+            while taken.contains(&new_id) {
+                new_id = format!("{}{}_{}", prefix, id, n);
+                n += 1;
+            }
+            taken.insert(new_id.clone());
+            Ok(Some(new_id))
+        }
+        IdConflict::KeepExisting => Ok(None),
+        IdConflict::Error => Err(MergeError::Conflict(id.to_string())),
+    }
+}
+
+/// Collects the SIds of the elements of a model that can collide with incoming ones.
+fn sids(model: &Model) -> HashSet<String> {
+    model
+        .list_of_compartments()
+        .iter()
+        .map(|compartment| compartment.id())
+        .chain(model.list_of_species().iter().map(|species| species.id()))
+        .chain(
+            model
+                .list_of_parameters()
+                .iter()
+                .map(|parameter| parameter.id()),
+        )
+        .chain(
+            model
+                .list_of_reactions()
+                .iter()
+                .map(|reaction| reaction.id()),
+        )
+        .chain(model.list_of_events().iter().map(|event| event.id()))
+        .filter(|id| !id.is_empty())
+        .collect()
+}
+
+/// Returns whether two unit definitions consist of the same units, in any order.
+fn same_units(a: &UnitDefinition, b: &UnitDefinition) -> bool {
+    let units = |unit_definition: &UnitDefinition| {
+        let mut units: Vec<String> = unit_definition
+            .units()
+            .iter()
+            .map(|unit| {
+                format!(
+                    "{:?} {} {} {}",
+                    unit.kind(),
+                    unit.exponent(),
+                    unit.scale(),
+                    unit.multiplier()
+                )
+            })
+            .collect();
+        units.sort();
+        units
+    };
+    units(a) == units(b)
+}
+
+/// Returns the SBML level and version of the document of a model.
+fn level_version(model: &Model) -> (u32, u32) {
+    let base = upcast!(model, sbmlcxx::Model, sbmlcxx::SBase);
+    (base.getLevel().0, base.getVersion().0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{formula::Expr, prelude::*};
+
+    /// Builds a fragment with a compartment, two species and a reaction
+    fn build_fragment<'a>(doc: &'a SBMLDocument<'a>, id: &str) {
+        let model = doc.create_model(id);
+        model.build_compartment("cytosol").size(1.0).build();
+        model
+            .build_species("glc")
+            .compartment("cytosol")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_species("g6p")
+            .compartment("cytosol")
+            .initial_concentration(0.0)
+            .build();
+        model.build_parameter("k1").value(0.1).build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .kinetic_law("k1 * glc")
+            .build();
+    }
+
+    #[test]
+    fn test_merge_disjoint_models() {
+        let doc = SBMLDocument::default();
+        build_fragment(&doc, "glycolysis");
+        let other = SBMLDocument::default();
+        let fragment = other.create_model("ppp");
+        fragment.build_parameter("k2").value(1.0).build();
+        fragment
+            .build_reaction("g6pdh")
+            .reactant("g6p", 1.0)
+            .kinetic_law("k2 * g6p")
+            .build();
+
+        let model = doc.model().unwrap();
+        let report = model
+            .merge_from(&fragment, MergeOptions::default())
+            .unwrap();
+
+        assert_eq!(report.added, vec!["k2", "g6pdh"]);
+        assert!(report.renamed.is_empty());
+        assert!(model.get_parameter("k2").is_some());
+        let reaction = model.get_reaction("g6pdh").unwrap();
+        assert_eq!(reaction.reactants().borrow()[0].species(), "g6p");
+    }
+
+    #[test]
+    fn test_merge_conflict_error() {
+        let doc = SBMLDocument::default();
+        build_fragment(&doc, "a");
+        let other = SBMLDocument::default();
+        build_fragment(&other, "b");
+
+        let model = doc.model().unwrap();
+        let result = model.merge_from(&other.model().unwrap(), MergeOptions::default());
+        assert_eq!(result, Err(MergeError::Conflict("cytosol".to_string())));
+        assert_eq!(model.list_of_species().len(), 2);
+    }
+
+    #[test]
+    fn test_merge_rename_rewrites_references() {
+        let doc = SBMLDocument::default();
+        build_fragment(&doc, "a");
+        let other = SBMLDocument::default();
+        build_fragment(&other, "b");
+
+        let model = doc.model().unwrap();
+        let options = MergeOptions {
+            on_conflict: IdConflict::Rename("b_".to_string()),
+            ..Default::default()
+        };
+        let report = model.merge_from(&other.model().unwrap(), options).unwrap();
+
+        assert!(report
+            .renamed
+            .contains(&("glc".to_string(), "b_glc".to_string())));
+        assert_eq!(model.list_of_species().len(), 4);
+
+        let species = model.get_species("b_glc").unwrap();
+        assert_eq!(species.compartment(), "b_cytosol");
+
+        let reaction = model.get_reaction("b_hk").unwrap();
+        assert_eq!(reaction.reactants().borrow()[0].species(), "b_glc");
+        assert_eq!(reaction.products().borrow()[0].species(), "b_g6p");
+        let formula = reaction.kinetic_law().unwrap().formula();
+        assert_eq!(
+            Expr::parse(&formula).unwrap(),
+            Expr::parse("b_k1 * b_glc").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_merge_rejected_leaves_model_unchanged() {
+        let doc = SBMLDocument::default();
+        build_fragment(&doc, "a");
+        let other = SBMLDocument::default();
+        build_fragment(&other, "b");
+        other
+            .model()
+            .unwrap()
+            .build_parameter("k2")
+            .value(1.0)
+            .build();
+
+        let model = doc.model().unwrap();
+        let options = MergeOptions {
+            on_conflict: IdConflict::Rename("1_".to_string()),
+            ..Default::default()
+        };
+        let result = model.merge_from(&other.model().unwrap(), options);
+
+        assert!(matches!(result, Err(MergeError::Rejected { id, .. }) if id == "cytosol"));
+        assert_eq!(model.list_of_compartments().len(), 1);
+        assert_eq!(model.list_of_species().len(), 2);
+        assert_eq!(model.list_of_parameters().len(), 1);
+
+        // libSBML rejects the second rule for the same variable only when it is added,
+        // after the compartment and the parameter
+        let other = SBMLDocument::default();
+        let fragment = other.create_model("ppp");
+        fragment.build_compartment("nucleus").size(1.0).build();
+        fragment.build_parameter("k2").value(1.0).build();
+        fragment.build_assignment_rule("k2", "2 * k1").build();
+        fragment.build_rate_rule("k2", "k1").build();
+        #[cfg(feature = "fbc")]
+        fragment.fbc_plugin();
+
+        let result = model.merge_from(&fragment, MergeOptions::default());
+
+        assert!(matches!(result, Err(MergeError::Rejected { id, .. }) if id == "k2"));
+        assert_eq!(model.list_of_compartments().len(), 1);
+        assert_eq!(model.list_of_parameters().len(), 1);
+        assert!(model.get_parameter("k2").is_none());
+        assert!(model.list_of_assignment_rules().is_empty());
+        #[cfg(feature = "fbc")]
+        assert!(!doc.is_package_enabled(Package::Fbc));
+    }
+
+    #[test]
+    fn test_merge_into_model_with_algebraic_rule() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level3/version2/core" level="3" version="2">
+  <model id="m">
+    <listOfParameters>
+      <parameter id="x" constant="false"/>
+      <parameter id="y" constant="false"/>
+    </listOfParameters>
+    <listOfRules>
+      <algebraicRule>
+        <math xmlns="http://www.w3.org/1998/Math/MathML">
+          <apply><minus/><ci> x </ci><cn> 1 </cn></apply>
+        </math>
+      </algebraicRule>
+      <assignmentRule variable="y">
+        <math xmlns="http://www.w3.org/1998/Math/MathML">
+          <ci> x </ci>
+        </math>
+      </assignmentRule>
+    </listOfRules>
+  </model>
+</sbml>"#;
+        let doc = SBMLReader::from_xml_string(xml);
+        let model = doc.model().unwrap();
+        let other = SBMLDocument::default();
+        let fragment = other.create_model("fragment");
+        fragment.build_parameter("z").value(0.0).build();
+        fragment.build_rate_rule("z", "1").build();
+
+        model
+            .merge_from(&fragment, MergeOptions::default())
+            .unwrap();
+
+        assert_eq!(model.list_of_assignment_rules().len(), 1);
+        assert_eq!(model.list_of_rate_rules().len(), 1);
+        assert_eq!(model.list_of_rate_rules()[0].variable(), "z");
+    }
+
+    #[test]
+    fn test_merge_keep_existing() {
+        let doc = SBMLDocument::default();
+        build_fragment(&doc, "a");
+        let other = SBMLDocument::default();
+        build_fragment(&other, "b");
+        other
+            .model()
+            .unwrap()
+            .build_species("atp")
+            .compartment("cytosol")
+            .build();
+
+        let model = doc.model().unwrap();
+        let options = MergeOptions {
+            on_conflict: IdConflict::KeepExisting,
+            ..Default::default()
+        };
+        let report = model.merge_from(&other.model().unwrap(), options).unwrap();
+
+        assert_eq!(report.added, vec!["atp"]);
+        assert_eq!(report.kept.len(), 5);
+        assert_eq!(model.get_species("atp").unwrap().compartment(), "cytosol");
+    }
+
+    #[test]
+    fn test_merge_unifies_unit_definitions() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("a");
+        model
+            .build_unit_definition("mM", "millimolar")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Litre, Some(-1), Some(0), None, None)
+            .build();
+
+        let other = SBMLDocument::default();
+        let fragment = other.create_model("b");
+        fragment
+            .build_unit_definition("millimolar", "millimolar")
+            .unit(UnitKind::Litre, Some(-1), Some(0), None, None)
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .build();
+        fragment
+            .build_parameter("km")
+            .value(0.5)
+            .units("millimolar")
+            .build();
+
+        let report = model
+            .merge_from(&fragment, MergeOptions::default())
+            .unwrap();
+
+        assert_eq!(
+            report.unified_units,
+            vec![("millimolar".to_string(), "mM".to_string())]
+        );
+        assert_eq!(model.list_of_unit_definitions().len(), 1);
+        assert_eq!(model.get_parameter("km").unwrap().units(), "mM");
+    }
+}
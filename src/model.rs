@@ -7,24 +7,56 @@
 //! This wrapper provides safe access to the underlying C++ libSBML Model class while
 //! maintaining Rust's safety guarantees through the use of RefCell and Pin.
 
-use std::{cell::RefCell, pin::Pin, rc::Rc};
+use std::{cell::RefCell, pin::Pin, rc::Rc, str::FromStr};
 
 use cxx::let_cxx_string;
 
 use crate::{
     compartment::{Compartment, CompartmentBuilder},
+    derivedunit::DerivedUnit,
+    event::{Event, EventBuilder},
+    initassign::{InitialAssignment, InitialAssignmentBuilder},
     inner,
+    merge::{self, MergeError, MergeOptions, MergeReport},
     parameter::{Parameter, ParameterBuilder},
     pin_ptr,
     reaction::{Reaction, ReactionBuilder},
+    rename::{self, RenameError},
     rule::{AssignmentRuleBuilder, RateRuleBuilder, Rule, RuleType},
+    sbase,
     sbmlcxx::{self},
     sbmldoc::SBMLDocument,
     sbo_term,
     species::{Species, SpeciesBuilder},
-    traits::fromptr::FromPtr,
+    stoichiometry::StoichiometryMatrix,
+    traits::{fromptr::FromPtr, sbase::SBase},
+    unit::UnitKind,
+    unitcheck::{self, UnitMismatch},
     unitdef::{UnitDefinition, UnitDefinitionBuilder},
-    upcast_annotation,
+    unitstr, upcast_annotation,
+    usage::{self, ElementRef, UnusedElements},
+};
+
+#[cfg(feature = "comp")]
+use crate::comp::modelplugin::CompModelPlugin;
+#[cfg(feature = "fbc")]
+use crate::fbc::plugin::FbcModelPlugin;
+#[cfg(feature = "groups")]
+use crate::groups::plugin::GroupsModelPlugin;
+#[cfg(feature = "layout")]
+use crate::layout::plugin::LayoutModelPlugin;
+#[cfg(feature = "qual")]
+use crate::qual::plugin::QualModelPlugin;
+#[cfg(any(
+    feature = "fbc",
+    feature = "layout",
+    feature = "comp",
+    feature = "groups",
+    feature = "qual"
+))]
+use crate::{
+    packages::{get_plugin, Package},
+    upcast,
 };
 
 /// A safe wrapper around the libSBML Model class.
@@ -55,6 +87,25 @@ pub struct Model<'a> {
     list_of_rate_rules: RefCell<Vec<Rc<Rule<'a>>>>,
     /// List of all AssignmentRules in the model
     list_of_assignment_rules: RefCell<Vec<Rc<Rule<'a>>>>,
+    /// List of all InitialAssignments in the model
+    list_of_initial_assignments: RefCell<Vec<Rc<InitialAssignment<'a>>>>,
+    /// List of all Events in the model
+    list_of_events: RefCell<Vec<Rc<Event<'a>>>>,
+    /// The fbc package content of the model, loaded on first access
+    #[cfg(feature = "fbc")]
+    fbc_plugin: RefCell<Option<Rc<FbcModelPlugin<'a>>>>,
+    /// The layout package content of the model, loaded on first access
+    #[cfg(feature = "layout")]
+    layout_plugin: RefCell<Option<Rc<LayoutModelPlugin<'a>>>>,
+    /// The comp package content of the model, loaded on first access
+    #[cfg(feature = "comp")]
+    comp_plugin: RefCell<Option<Rc<CompModelPlugin<'a>>>>,
+    /// The groups package content of the model, loaded on first access
+    #[cfg(feature = "groups")]
+    groups_plugin: RefCell<Option<Rc<GroupsModelPlugin<'a>>>>,
+    /// The qual package content of the model, loaded on first access
+    #[cfg(feature = "qual")]
+    qual_plugin: RefCell<Option<Rc<QualModelPlugin<'a>>>>,
 }
 
 // Set the inner trait for the Model struct
@@ -63,6 +114,9 @@ inner!(sbmlcxx::Model, Model<'a>);
 // Set the annotation trait for the Model struct
 upcast_annotation!(Model<'a>, sbmlcxx::Model, sbmlcxx::SBase);
 
+// Set the SBase trait for the Model struct
+sbase!(Model<'a>, sbmlcxx::Model);
+
 impl<'a> Model<'a> {
     /// Creates a new Model instance within the given SBMLDocument.
     ///
@@ -85,6 +139,18 @@ impl<'a> Model<'a> {
             list_of_parameters: RefCell::new(Vec::new()),
             list_of_rate_rules: RefCell::new(Vec::new()),
             list_of_assignment_rules: RefCell::new(Vec::new()),
+            list_of_initial_assignments: RefCell::new(Vec::new()),
+            list_of_events: RefCell::new(Vec::new()),
+            #[cfg(feature = "fbc")]
+            fbc_plugin: RefCell::new(None),
+            #[cfg(feature = "layout")]
+            layout_plugin: RefCell::new(None),
+            #[cfg(feature = "comp")]
+            comp_plugin: RefCell::new(None),
+            #[cfg(feature = "groups")]
+            groups_plugin: RefCell::new(None),
+            #[cfg(feature = "qual")]
+            qual_plugin: RefCell::new(None),
         }
     }
 
@@ -130,6 +196,166 @@ impl<'a> Model<'a> {
         self.inner.borrow_mut().as_mut().setName(&name);
     }
 
+    /// Gets the default units of the model for the amounts of species.
+    ///
+    /// # Returns
+    /// The identifier of the substance units, empty if not set
+    pub fn substance_units(&self) -> String {
+        self.inner
+            .borrow()
+            .getSubstanceUnits()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the default units of the model for the amounts of species.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn set_substance_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setSubstanceUnits(&units);
+    }
+
+    /// Gets the default units of the model for time.
+    ///
+    /// # Returns
+    /// The identifier of the time units, empty if not set
+    pub fn time_units(&self) -> String {
+        self.inner
+            .borrow()
+            .getTimeUnits()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the default units of the model for time.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn set_time_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setTimeUnits(&units);
+    }
+
+    /// Gets the default units of the model for the sizes of three-dimensional compartments.
+    ///
+    /// # Returns
+    /// The identifier of the volume units, empty if not set
+    pub fn volume_units(&self) -> String {
+        self.inner
+            .borrow()
+            .getVolumeUnits()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the default units of the model for the sizes of three-dimensional compartments.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn set_volume_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setVolumeUnits(&units);
+    }
+
+    /// Gets the default units of the model for the sizes of two-dimensional compartments.
+    ///
+    /// # Returns
+    /// The identifier of the area units, empty if not set
+    pub fn area_units(&self) -> String {
+        self.inner
+            .borrow()
+            .getAreaUnits()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the default units of the model for the sizes of two-dimensional compartments.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn set_area_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setAreaUnits(&units);
+    }
+
+    /// Gets the default units of the model for the sizes of one-dimensional compartments.
+    ///
+    /// # Returns
+    /// The identifier of the length units, empty if not set
+    pub fn length_units(&self) -> String {
+        self.inner
+            .borrow()
+            .getLengthUnits()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the default units of the model for the sizes of one-dimensional compartments.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn set_length_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setLengthUnits(&units);
+    }
+
+    /// Gets the default units of the model for the extent of reactions.
+    ///
+    /// # Returns
+    /// The identifier of the extent units, empty if not set
+    pub fn extent_units(&self) -> String {
+        self.inner
+            .borrow()
+            .getExtentUnits()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the default units of the model for the extent of reactions.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn set_extent_units(&self, units: &str) {
+        let_cxx_string!(units = units);
+        self.inner.borrow_mut().as_mut().setExtentUnits(&units);
+    }
+
+    /// Gets the conversion factor of the model.
+    ///
+    /// The conversion factor is a parameter converting the extent of reactions into
+    /// the substance units of species that do not define their own conversion factor.
+    ///
+    /// # Returns
+    /// The identifier of the conversion factor parameter, empty if not set
+    pub fn conversion_factor(&self) -> String {
+        self.inner
+            .borrow()
+            .getConversionFactor()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the conversion factor of the model.
+    ///
+    /// # Arguments
+    /// * `conversion_factor` - The identifier of the conversion factor parameter
+    pub fn set_conversion_factor(&self, conversion_factor: &str) {
+        let_cxx_string!(conversion_factor = conversion_factor);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setConversionFactor(&conversion_factor);
+    }
+
     /// Creates a new Species within this model.
     ///
     /// # Arguments
@@ -289,6 +515,49 @@ impl<'a> Model<'a> {
             .map(|unit_definition| Rc::clone(unit_definition))
     }
 
+    /// Returns a unit definition with the given units, creating it if necessary.
+    ///
+    /// An existing unit definition is reused if its units are equivalent to the given
+    /// units with a conversion factor of 1, e.g. "mol / m^3" for millimolar (see
+    /// [`DerivedUnit::is_identical`]). Equivalent units that differ by a factor, such
+    /// as "mol / l" for millimolar, are not reused, as values in them would have to be
+    /// rescaled. Otherwise a new unit definition is created with an identifier derived
+    /// from the units, e.g. "mmol_per_l" or "per_s", and the units formatted as a unit
+    /// string as its name.
+    ///
+    /// # Arguments
+    /// * `unit` - The units of the unit definition
+    ///
+    /// # Example
+    /// ```
+    /// use sbml::prelude::*;
+    ///
+    /// let doc = SBMLDocument::default();
+    /// let model = doc.create_model("test");
+    /// let unit_definition = model.ensure_unit(&DerivedUnit::millimolar());
+    /// assert_eq!(unit_definition.id(), "mmol_per_l");
+    /// assert_eq!(model.ensure_unit(&"mM".parse().unwrap()).id(), "mmol_per_l");
+    /// ```
+    pub fn ensure_unit(&self, unit: &DerivedUnit) -> Rc<UnitDefinition<'a>> {
+        let existing = self
+            .list_of_unit_definitions()
+            .into_iter()
+            .find(|unit_definition| DerivedUnit::from(unit_definition.as_ref()).is_identical(unit));
+        if let Some(unit_definition) = existing {
+            return unit_definition;
+        }
+
+        let terms = unitstr::terms(unit);
+        let base = unitstr::unit_id(&terms);
+        let is_taken =
+            |id: &str| self.get_unit_definition(id).is_some() || UnitKind::from_str(id).is_ok();
+        let id = std::iter::once(base.clone())
+            .chain((2..).map(|i| format!("{}_{}", base, i)))
+            .find(|id| !is_taken(id))
+            .unwrap();
+        UnitDefinition::from_terms(self, &id, &unitstr::format(&terms), &terms)
+    }
+
     /// Creates a new Reaction within this model.
     ///
     /// # Arguments
@@ -509,6 +778,688 @@ impl<'a> Model<'a> {
             .map(|rule| Rc::clone(rule))
     }
 
+    /// Creates a new InitialAssignment within this model.
+    ///
+    /// # Arguments
+    /// * `symbol` - The identifier of the quantity to assign
+    /// * `formula` - The formula defining the initial value
+    ///
+    /// # Returns
+    /// A new InitialAssignment instance wrapped in an Rc
+    pub fn create_initial_assignment(
+        &self,
+        symbol: &str,
+        formula: &str,
+    ) -> Rc<InitialAssignment<'a>> {
+        let initial_assignment = Rc::new(InitialAssignment::new(self, symbol, formula));
+        self.list_of_initial_assignments
+            .borrow_mut()
+            .push(Rc::clone(&initial_assignment));
+        initial_assignment
+    }
+
+    /// Creates a new InitialAssignmentBuilder for constructing an InitialAssignment with a fluent API.
+    ///
+    /// # Arguments
+    /// * `symbol` - The identifier of the quantity to assign
+    /// * `formula` - The formula defining the initial value
+    ///
+    /// # Returns
+    /// An InitialAssignmentBuilder instance that can be used to configure and create the InitialAssignment
+    pub fn build_initial_assignment(
+        &self,
+        symbol: &str,
+        formula: &str,
+    ) -> InitialAssignmentBuilder<'a> {
+        InitialAssignmentBuilder::new(self, symbol, formula)
+    }
+
+    /// Returns a vector of all initial assignments in the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all InitialAssignments in the model
+    pub fn list_of_initial_assignments(&self) -> Vec<Rc<InitialAssignment<'a>>> {
+        self.list_of_initial_assignments.borrow().to_vec()
+    }
+
+    /// Retrieves an initial assignment from the model by the symbol it assigns.
+    ///
+    /// # Arguments
+    /// * `symbol` - The identifier of the assigned quantity
+    ///
+    /// # Returns
+    /// Some(Rc<InitialAssignment>) if found, None if not found
+    pub fn get_initial_assignment(&self, symbol: &str) -> Option<Rc<InitialAssignment<'a>>> {
+        self.list_of_initial_assignments
+            .borrow()
+            .iter()
+            .find(|assignment| (*assignment).symbol() == symbol)
+            .map(Rc::clone)
+    }
+
+    /// Creates a new Event within this model.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new event
+    ///
+    /// # Returns
+    /// A new Event instance wrapped in an Rc
+    pub fn create_event(&self, id: &str) -> Rc<Event<'a>> {
+        let event = Rc::new(Event::new(self, id));
+        self.list_of_events.borrow_mut().push(Rc::clone(&event));
+        event
+    }
+
+    /// Creates a new EventBuilder for constructing an Event with a fluent API.
+    ///
+    /// The builder allows setting the trigger, delay and event assignments
+    /// of the event before building it.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new event
+    ///
+    /// # Returns
+    /// An EventBuilder instance that can be used to configure and create the Event
+    pub fn build_event(&self, id: &str) -> EventBuilder<'a> {
+        EventBuilder::new(self, id)
+    }
+
+    /// Returns a vector of all events in the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Events in the model
+    pub fn list_of_events(&self) -> Vec<Rc<Event<'a>>> {
+        self.list_of_events.borrow().to_vec()
+    }
+
+    /// Retrieves an event from the model by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the event to retrieve
+    ///
+    /// # Returns
+    /// Some(Rc<Event>) if found, None if not found
+    pub fn get_event(&self, id: &str) -> Option<Rc<Event<'a>>> {
+        self.list_of_events
+            .borrow()
+            .iter()
+            .find(|event| (*event).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Builds the stoichiometry matrix of the model.
+    ///
+    /// Rows follow the order of the species and columns the order of the reactions.
+    /// The matrix is the starting point for the structural analysis of the model,
+    /// such as its conservation laws.
+    ///
+    /// # Arguments
+    /// * `exclude_boundary` - Whether to leave out species with a boundary condition
+    ///
+    /// # Returns
+    /// The labelled stoichiometry matrix
+    pub fn stoichiometry_matrix(&self, exclude_boundary: bool) -> StoichiometryMatrix {
+        StoichiometryMatrix::from_model(self, exclude_boundary)
+    }
+
+    /// Merges the elements of another model into this model.
+    ///
+    /// Unit definitions, compartments, species, parameters, rules and reactions of
+    /// `other` are copied into this model. Identifiers used by both models are
+    /// resolved according to `options`, and references within the copied elements
+    /// are rewritten to the renamed identifiers. All elements are renamed and validated
+    /// before the first one is added. If libSBML still rejects an element, the elements
+    /// added so far are removed and the packages enabled for the merge are disabled
+    /// again, so that an error leaves the model unchanged.
+    ///
+    /// # Arguments
+    /// * `other` - The model to copy the elements from
+    /// * `options` - How to resolve conflicting identifiers and unit definitions
+    ///
+    /// # Returns
+    /// A report of the copied, renamed and dropped elements, or a MergeError
+    pub fn merge_from(
+        &self,
+        other: &Model,
+        options: MergeOptions,
+    ) -> Result<MergeReport, MergeError> {
+        let n_rules = self.inner.borrow().getNumRules().0;
+        let result = merge::merge(self, other, &options);
+        self.load_appended_elements(n_rules);
+        result
+    }
+
+    /// Renames an element and rewrites every reference to it across the model.
+    ///
+    /// Unlike setting the identifier of the element, this also updates species
+    /// references, rule and assignment variables, compartments of species, flux bounds,
+    /// gene product references, references of other packages such as layout glyphs and
+    /// group members, and all `ci` symbols in math. Kinetic laws with a local parameter
+    /// of the same identifier are left unchanged, as the local parameter takes
+    /// precedence.
+    ///
+    /// # Arguments
+    /// * `old` - The current identifier of the element
+    /// * `new` - The new identifier of the element
+    ///
+    /// # Returns
+    /// Ok if the element was renamed, or a RenameError
+    pub fn rename_sid(&self, old: &str, new: &str) -> Result<(), RenameError> {
+        rename::rename_sid(self, old, new)
+    }
+
+    /// Renames a unit definition and rewrites every reference to it across the model.
+    ///
+    /// This updates the units of the model, compartments, species, parameters and
+    /// local parameters as well as the units of numbers in math.
+    ///
+    /// # Arguments
+    /// * `old` - The current identifier of the unit definition
+    /// * `new` - The new identifier of the unit definition
+    ///
+    /// # Returns
+    /// Ok if the unit definition was renamed, or a RenameError
+    pub fn rename_unit_sid(&self, old: &str, new: &str) -> Result<(), RenameError> {
+        rename::rename_unit_sid(self, old, new)
+    }
+
+    /// Returns all elements contained in the model.
+    ///
+    /// Elements are returned in document order, with nested elements such as units,
+    /// species references, kinetic laws, local parameters, triggers, delays and event
+    /// assignments following their parent. Replacements (comp) and uncertainties
+    /// (distrib) follow the element they belong to. The content of the fbc, layout,
+    /// render, comp, groups and qual packages follows the core elements if the package
+    /// is enabled. The model itself is not included.
+    ///
+    /// Elements are returned as shared trait objects rather than references, as the
+    /// wrappers of nested and package elements are created on demand and are not owned
+    /// by the model.
+    ///
+    /// # Returns
+    /// An iterator over the elements as SBase trait objects
+    pub fn all_elements(&self) -> impl Iterator<Item = Rc<dyn SBase + 'a>> {
+        let mut elements: Vec<Rc<dyn SBase + 'a>> = Vec::new();
+
+        for unit_definition in self.list_of_unit_definitions() {
+            let units = unit_definition.units();
+            elements.push(unit_definition);
+            elements.extend(units.into_iter().map(|unit| unit as Rc<dyn SBase + 'a>));
+        }
+
+        for compartment in self.list_of_compartments() {
+            elements.push(Rc::clone(&compartment) as Rc<dyn SBase + 'a>);
+            #[cfg(feature = "comp")]
+            push_replacements(
+                &mut elements,
+                compartment.list_of_replaced_elements(),
+                compartment.replaced_by(),
+            );
+            #[cfg(feature = "distrib")]
+            push_uncertainties(&mut elements, compartment.list_of_uncertainties());
+        }
+
+        for species in self.list_of_species() {
+            elements.push(Rc::clone(&species) as Rc<dyn SBase + 'a>);
+            #[cfg(feature = "comp")]
+            push_replacements(
+                &mut elements,
+                species.list_of_replaced_elements(),
+                species.replaced_by(),
+            );
+            #[cfg(feature = "distrib")]
+            push_uncertainties(&mut elements, species.list_of_uncertainties());
+        }
+
+        for parameter in self.list_of_parameters() {
+            elements.push(Rc::clone(&parameter) as Rc<dyn SBase + 'a>);
+            #[cfg(feature = "comp")]
+            push_replacements(
+                &mut elements,
+                parameter.list_of_replaced_elements(),
+                parameter.replaced_by(),
+            );
+            #[cfg(feature = "distrib")]
+            push_uncertainties(&mut elements, parameter.list_of_uncertainties());
+        }
+
+        elements.extend(
+            self.list_of_initial_assignments()
+                .into_iter()
+                .map(|assignment| assignment as Rc<dyn SBase + 'a>),
+        );
+
+        // Rate and assignment rules share a single list in the document
+        {
+            let mut model = self.inner.borrow_mut();
+            for i in 0..model.getNumRules().0 {
+                let rule = model.as_mut().getRule1(i.into());
+                elements.push(Rc::new(Rule::from_ptr(rule)));
+            }
+        }
+
+        for reaction in self.list_of_reactions() {
+            elements.push(Rc::clone(&reaction) as Rc<dyn SBase + 'a>);
+            for reference in reaction
+                .reactants()
+                .borrow()
+                .iter()
+                .chain(reaction.products().borrow().iter())
+            {
+                elements.push(Rc::clone(reference) as Rc<dyn SBase + 'a>);
+            }
+            for modifier in reaction.modifiers().borrow().iter() {
+                elements.push(Rc::clone(modifier) as Rc<dyn SBase + 'a>);
+            }
+            if let Some(kinetic_law) = reaction.kinetic_law() {
+                let local_parameters = kinetic_law.list_of_local_parameters();
+                elements.push(kinetic_law);
+                elements.extend(
+                    local_parameters
+                        .into_iter()
+                        .map(|parameter| parameter as Rc<dyn SBase + 'a>),
+                );
+            }
+            #[cfg(feature = "comp")]
+            push_replacements(
+                &mut elements,
+                reaction.list_of_replaced_elements(),
+                reaction.replaced_by(),
+            );
+        }
+
+        for event in self.list_of_events() {
+            elements.push(Rc::clone(&event) as Rc<dyn SBase + 'a>);
+            if let Some(trigger) = event.trigger() {
+                elements.push(trigger);
+            }
+            if let Some(delay) = event.delay() {
+                elements.push(delay);
+            }
+            elements.extend(
+                event
+                    .list_of_event_assignments()
+                    .into_iter()
+                    .map(|assignment| assignment as Rc<dyn SBase + 'a>),
+            );
+        }
+
+        #[cfg(feature = "fbc")]
+        if let Some(plugin) = self.fbc_plugin_if_enabled() {
+            for objective in plugin.list_of_objectives() {
+                let flux_objectives = objective.list_of_flux_objectives();
+                elements.push(objective);
+                elements.extend(
+                    flux_objectives
+                        .into_iter()
+                        .map(|flux_objective| flux_objective as Rc<dyn SBase + 'a>),
+                );
+            }
+            elements.extend(
+                plugin
+                    .list_of_gene_products()
+                    .into_iter()
+                    .map(|gene_product| gene_product as Rc<dyn SBase + 'a>),
+            );
+            elements.extend(
+                plugin
+                    .list_of_flux_bounds()
+                    .into_iter()
+                    .map(|flux_bound| flux_bound as Rc<dyn SBase + 'a>),
+            );
+        }
+
+        #[cfg(feature = "layout")]
+        if self.is_package_enabled(Package::Layout) {
+            for layout in self.layout_plugin().list_of_layouts() {
+                elements.push(Rc::clone(&layout) as Rc<dyn SBase + 'a>);
+                elements.extend(
+                    layout
+                        .list_of_compartment_glyphs()
+                        .into_iter()
+                        .map(|glyph| glyph as Rc<dyn SBase + 'a>),
+                );
+                elements.extend(
+                    layout
+                        .list_of_species_glyphs()
+                        .into_iter()
+                        .map(|glyph| glyph as Rc<dyn SBase + 'a>),
+                );
+                for glyph in layout.list_of_reaction_glyphs() {
+                    let reference_glyphs = glyph.list_of_species_reference_glyphs();
+                    elements.push(glyph);
+                    elements.extend(
+                        reference_glyphs
+                            .into_iter()
+                            .map(|glyph| glyph as Rc<dyn SBase + 'a>),
+                    );
+                }
+                #[cfg(feature = "render")]
+                for info in layout.list_of_render_information() {
+                    let colors = info.list_of_color_definitions();
+                    let styles = info.list_of_styles();
+                    elements.push(info);
+                    elements.extend(colors.into_iter().map(|color| color as Rc<dyn SBase + 'a>));
+                    elements.extend(styles.into_iter().map(|style| style as Rc<dyn SBase + 'a>));
+                }
+            }
+        }
+
+        #[cfg(feature = "comp")]
+        if self.is_package_enabled(Package::Comp) {
+            let plugin = self.comp_plugin();
+            for submodel in plugin.list_of_submodels() {
+                let deletions = submodel.list_of_deletions();
+                elements.push(submodel);
+                elements.extend(
+                    deletions
+                        .into_iter()
+                        .map(|deletion| deletion as Rc<dyn SBase + 'a>),
+                );
+            }
+            elements.extend(
+                plugin
+                    .list_of_ports()
+                    .into_iter()
+                    .map(|port| port as Rc<dyn SBase + 'a>),
+            );
+        }
+
+        #[cfg(feature = "groups")]
+        if self.is_package_enabled(Package::Groups) {
+            for group in self.groups_plugin().list_of_groups() {
+                let members = group.list_of_members();
+                elements.push(group);
+                elements.extend(
+                    members
+                        .into_iter()
+                        .map(|member| member as Rc<dyn SBase + 'a>),
+                );
+            }
+        }
+
+        #[cfg(feature = "qual")]
+        if self.is_package_enabled(Package::Qual) {
+            let plugin = self.qual_plugin();
+            elements.extend(
+                plugin
+                    .list_of_qualitative_species()
+                    .into_iter()
+                    .map(|species| species as Rc<dyn SBase + 'a>),
+            );
+            for transition in plugin.list_of_transitions() {
+                elements.push(Rc::clone(&transition) as Rc<dyn SBase + 'a>);
+                elements.extend(
+                    transition
+                        .list_of_inputs()
+                        .into_iter()
+                        .map(|input| input as Rc<dyn SBase + 'a>),
+                );
+                elements.extend(
+                    transition
+                        .list_of_outputs()
+                        .into_iter()
+                        .map(|output| output as Rc<dyn SBase + 'a>),
+                );
+                elements.extend(
+                    transition
+                        .list_of_function_terms()
+                        .into_iter()
+                        .map(|term| term as Rc<dyn SBase + 'a>),
+                );
+                if let Some(default_term) = transition.default_term() {
+                    elements.push(default_term);
+                }
+            }
+        }
+
+        elements.into_iter()
+    }
+
+    /// Lists every element that refers to an identifier.
+    ///
+    /// Attributes, such as the species of a species reference or the conversion factor
+    /// of a species, symbols in math and the units of numbers in math are considered.
+    /// Kinetic laws with a local parameter of the same identifier are not listed, as the
+    /// local parameter takes precedence.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier to look up
+    ///
+    /// # Returns
+    /// The referring elements in document order
+    pub fn usages(&self, id: &str) -> Vec<ElementRef> {
+        usage::usages(self, id)
+    }
+
+    /// Finds the parameters, unit definitions and species nothing refers to.
+    ///
+    /// # Returns
+    /// The identifiers of the unused elements
+    pub fn unused_elements(&self) -> UnusedElements {
+        usage::unused_elements(self)
+    }
+
+    /// Checks the units of all kinetic laws and rules of the model.
+    ///
+    /// The units of the math of each element are derived with libSBML and compared
+    /// to the units it must have: extent per time for kinetic laws, the units of the
+    /// variable per time for rate rules and the units of the variable for assignment
+    /// rules. Elements whose units cannot be fully determined are skipped.
+    ///
+    /// # Returns
+    /// The elements whose units do not match, empty if the units are consistent
+    pub fn check_units(&self) -> Vec<UnitMismatch> {
+        unitcheck::check_units(self)
+    }
+
+    /// Wraps the elements libSBML appended to the model after the wrapper was created.
+    ///
+    /// Elements added through the wrapper are cached as they are created, whereas
+    /// elements copied in by libSBML, e.g. when merging models, are picked up here.
+    ///
+    /// Rules are wrapped starting from `n_rules`, as algebraic rules are not cached and
+    /// the number of cached rules therefore does not tell which rules were appended.
+    /// Appended algebraic rules are skipped.
+    ///
+    /// # Arguments
+    /// * `n_rules` - The number of rules of the model before elements were appended
+    pub(crate) fn load_appended_elements(&self, n_rules: u32) {
+        let mut model = self.inner.borrow_mut();
+
+        let mut list_of_unit_definitions = self.list_of_unit_definitions.borrow_mut();
+        for i in list_of_unit_definitions.len() as u32..model.getNumUnitDefinitions().0 {
+            let unit_definition = model.as_mut().getUnitDefinition1(i.into());
+            list_of_unit_definitions.push(Rc::new(UnitDefinition::from_ptr(unit_definition)));
+        }
+
+        let mut list_of_compartments = self.list_of_compartments.borrow_mut();
+        for i in list_of_compartments.len() as u32..model.getNumCompartments().0 {
+            let compartment = model.as_mut().getCompartment1(i.into());
+            list_of_compartments.push(Rc::new(Compartment::from_ptr(compartment)));
+        }
+
+        let mut list_of_species = self.list_of_species.borrow_mut();
+        for i in list_of_species.len() as u32..model.getNumSpecies().0 {
+            let species = model.as_mut().getSpecies1(i.into());
+            list_of_species.push(Rc::new(Species::from_ptr(species)));
+        }
+
+        let mut list_of_parameters = self.list_of_parameters.borrow_mut();
+        for i in list_of_parameters.len() as u32..model.getNumParameters().0 {
+            let parameter = model.as_mut().getParameter1(i.into());
+            list_of_parameters.push(Rc::new(Parameter::from_ptr(parameter)));
+        }
+
+        let mut list_of_rate_rules = self.list_of_rate_rules.borrow_mut();
+        let mut list_of_assignment_rules = self.list_of_assignment_rules.borrow_mut();
+        for i in n_rules..model.getNumRules().0 {
+            let rule = model.as_mut().getRule1(i.into());
+            let rule = Rc::new(Rule::from_ptr(rule));
+            match rule.rule_type() {
+                Ok(RuleType::RateRule) => list_of_rate_rules.push(rule),
+                Ok(RuleType::AssignmentRule) => list_of_assignment_rules.push(rule),
+                Err(_) => {}
+            }
+        }
+
+        let mut list_of_reactions = self.list_of_reactions.borrow_mut();
+        for i in list_of_reactions.len() as u32..model.getNumReactions().0 {
+            let reaction = model.as_mut().getReaction1(i.into());
+            list_of_reactions.push(Rc::new(Reaction::from_ptr(reaction)));
+        }
+    }
+
+    /// Returns whether a package is enabled on the document of the model.
+    ///
+    /// Unlike the plugin accessors, this does not enable the package on the document.
+    #[cfg(any(
+        feature = "layout",
+        feature = "comp",
+        feature = "groups",
+        feature = "qual"
+    ))]
+    fn is_package_enabled(&self, package: Package) -> bool {
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        let_cxx_string!(name = package.name());
+        base.isPackageEnabled(&name)
+    }
+
+    /// Returns the fbc package content of the model.
+    ///
+    /// The fbc package is enabled on the document if needed.
+    ///
+    /// # Returns
+    /// The FbcModelPlugin holding the objectives and gene products of the model
+    ///
+    /// # Panics
+    /// If the model is not part of a document
+    #[cfg(feature = "fbc")]
+    pub fn fbc_plugin(&self) -> Rc<FbcModelPlugin<'a>> {
+        if let Some(plugin) = self.fbc_plugin.borrow().as_ref() {
+            return Rc::clone(plugin);
+        }
+
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        let mut plugin = get_plugin::<sbmlcxx::FbcModelPlugin>(base, Package::Fbc, true)
+            .expect("Model must be part of a document to use the fbc package");
+        let plugin_ptr = unsafe { plugin.as_mut().get_unchecked_mut() as *mut _ };
+
+        let plugin = Rc::new(FbcModelPlugin::from_ptr(plugin_ptr));
+        self.fbc_plugin.replace(Some(Rc::clone(&plugin)));
+        plugin
+    }
+
+    /// Returns the fbc package content of the model if the package is enabled.
+    ///
+    /// Unlike [`Model::fbc_plugin`], this does not enable the package on the document.
+    #[cfg(feature = "fbc")]
+    pub(crate) fn fbc_plugin_if_enabled(&self) -> Option<Rc<FbcModelPlugin<'a>>> {
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        get_plugin::<sbmlcxx::FbcModelPlugin>(base, Package::Fbc, false)?;
+        Some(self.fbc_plugin())
+    }
+
+    /// Returns the layout package content of the model.
+    ///
+    /// The layout package is enabled on the document if needed.
+    ///
+    /// # Returns
+    /// The LayoutModelPlugin holding the layouts of the model
+    ///
+    /// # Panics
+    /// If the model is not part of a document
+    #[cfg(feature = "layout")]
+    pub fn layout_plugin(&self) -> Rc<LayoutModelPlugin<'a>> {
+        if let Some(plugin) = self.layout_plugin.borrow().as_ref() {
+            return Rc::clone(plugin);
+        }
+
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        let mut plugin = get_plugin::<sbmlcxx::LayoutModelPlugin>(base, Package::Layout, true)
+            .expect("Model must be part of a document to use the layout package");
+        let plugin_ptr = unsafe { plugin.as_mut().get_unchecked_mut() as *mut _ };
+
+        let plugin = Rc::new(LayoutModelPlugin::from_ptr(plugin_ptr));
+        self.layout_plugin.replace(Some(Rc::clone(&plugin)));
+        plugin
+    }
+
+    /// Returns the comp package content of the model.
+    ///
+    /// The comp package is enabled on the document if needed.
+    ///
+    /// # Returns
+    /// The CompModelPlugin holding the submodels and ports of the model
+    ///
+    /// # Panics
+    /// If the model is not part of a document
+    #[cfg(feature = "comp")]
+    pub fn comp_plugin(&self) -> Rc<CompModelPlugin<'a>> {
+        if let Some(plugin) = self.comp_plugin.borrow().as_ref() {
+            return Rc::clone(plugin);
+        }
+
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        let mut plugin = get_plugin::<sbmlcxx::CompModelPlugin>(base, Package::Comp, true)
+            .expect("Model must be part of a document to use the comp package");
+        let plugin_ptr = unsafe { plugin.as_mut().get_unchecked_mut() as *mut _ };
+
+        let plugin = Rc::new(CompModelPlugin::from_ptr(plugin_ptr));
+        self.comp_plugin.replace(Some(Rc::clone(&plugin)));
+        plugin
+    }
+
+    /// Returns the groups package content of the model.
+    ///
+    /// The groups package is enabled on the document if needed.
+    ///
+    /// # Returns
+    /// The GroupsModelPlugin holding the groups of the model
+    ///
+    /// # Panics
+    /// If the model is not part of a document
+    #[cfg(feature = "groups")]
+    pub fn groups_plugin(&self) -> Rc<GroupsModelPlugin<'a>> {
+        if let Some(plugin) = self.groups_plugin.borrow().as_ref() {
+            return Rc::clone(plugin);
+        }
+
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        let mut plugin = get_plugin::<sbmlcxx::GroupsModelPlugin>(base, Package::Groups, true)
+            .expect("Model must be part of a document to use the groups package");
+        let plugin_ptr = unsafe { plugin.as_mut().get_unchecked_mut() as *mut _ };
+
+        let plugin = Rc::new(GroupsModelPlugin::from_ptr(plugin_ptr));
+        self.groups_plugin.replace(Some(Rc::clone(&plugin)));
+        plugin
+    }
+
+    /// Returns the qual package content of the model.
+    ///
+    /// The qual package is enabled on the document if needed.
+    ///
+    /// # Returns
+    /// The QualModelPlugin holding the qualitative species and transitions of the model
+    ///
+    /// # Panics
+    /// If the model is not part of a document
+    #[cfg(feature = "qual")]
+    pub fn qual_plugin(&self) -> Rc<QualModelPlugin<'a>> {
+        if let Some(plugin) = self.qual_plugin.borrow().as_ref() {
+            return Rc::clone(plugin);
+        }
+
+        let base = upcast!(self, sbmlcxx::Model, sbmlcxx::SBase);
+        let mut plugin = get_plugin::<sbmlcxx::QualModelPlugin>(base, Package::Qual, true)
+            .expect("Model must be part of a document to use the qual package");
+        let plugin_ptr = unsafe { plugin.as_mut().get_unchecked_mut() as *mut _ };
+
+        let plugin = Rc::new(QualModelPlugin::from_ptr(plugin_ptr));
+        self.qual_plugin.replace(Some(Rc::clone(&plugin)));
+        plugin
+    }
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Model, sbmlcxx::SBase);
 }
@@ -595,6 +1546,24 @@ impl<'a> FromPtr<sbmlcxx::Model> for Model<'a> {
             }
         }
 
+        // Fetch all initial assignments
+        let n_initial_assignments = model.borrow().getNumInitialAssignments().0;
+        let list_of_initial_assignments: Vec<_> = (0..n_initial_assignments)
+            .map(|i| {
+                let assignment = model.borrow_mut().as_mut().getInitialAssignment1(i.into());
+                Rc::new(InitialAssignment::from_ptr(assignment))
+            })
+            .collect();
+
+        // Fetch all events
+        let n_events = model.borrow().getNumEvents().0;
+        let list_of_events: Vec<_> = (0..n_events)
+            .map(|i| {
+                let event = model.borrow_mut().as_mut().getEvent1(i.into());
+                Rc::new(Event::from_ptr(event))
+            })
+            .collect();
+
         Self {
             inner: model,
             list_of_species: RefCell::new(list_of_species),
@@ -604,10 +1573,158 @@ impl<'a> FromPtr<sbmlcxx::Model> for Model<'a> {
             list_of_parameters: RefCell::new(list_of_parameters),
             list_of_rate_rules: RefCell::new(list_of_rate_rules),
             list_of_assignment_rules: RefCell::new(list_of_assignment_rules),
+            list_of_initial_assignments: RefCell::new(list_of_initial_assignments),
+            list_of_events: RefCell::new(list_of_events),
+            #[cfg(feature = "fbc")]
+            fbc_plugin: RefCell::new(None),
+            #[cfg(feature = "layout")]
+            layout_plugin: RefCell::new(None),
+            #[cfg(feature = "comp")]
+            comp_plugin: RefCell::new(None),
+            #[cfg(feature = "groups")]
+            groups_plugin: RefCell::new(None),
+            #[cfg(feature = "qual")]
+            qual_plugin: RefCell::new(None),
         }
     }
 }
 
+/// A builder for constructing a Model with a fluent interface.
+///
+/// This struct provides a builder pattern interface for creating and configuring
+/// the model of a document, in particular its default units. It allows chaining
+/// method calls to set various properties before finally returning the Model.
+pub struct ModelBuilder<'a> {
+    model: Rc<Model<'a>>,
+}
+
+impl<'a> ModelBuilder<'a> {
+    /// Creates a new ModelBuilder instance.
+    ///
+    /// # Arguments
+    /// * `document` - The document that will contain the model
+    /// * `id` - The identifier for the new model
+    ///
+    /// # Returns
+    /// A new ModelBuilder instance
+    pub fn new(document: &'a SBMLDocument<'a>, id: &str) -> Self {
+        let model = document.create_model(id);
+        Self { model }
+    }
+
+    /// Sets the name of the model.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn name(self, name: &str) -> Self {
+        self.model.set_name(name);
+        self
+    }
+
+    /// Sets the default substance units of the model.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn substance_units(self, units: &str) -> Self {
+        self.model.set_substance_units(units);
+        self
+    }
+
+    /// Sets the time units of the model.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn time_units(self, units: &str) -> Self {
+        self.model.set_time_units(units);
+        self
+    }
+
+    /// Sets the default volume units of the model.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn volume_units(self, units: &str) -> Self {
+        self.model.set_volume_units(units);
+        self
+    }
+
+    /// Sets the default area units of the model.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn area_units(self, units: &str) -> Self {
+        self.model.set_area_units(units);
+        self
+    }
+
+    /// Sets the default length units of the model.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn length_units(self, units: &str) -> Self {
+        self.model.set_length_units(units);
+        self
+    }
+
+    /// Sets the extent units of the model.
+    ///
+    /// # Arguments
+    /// * `units` - The identifier of a unit definition or base unit
+    pub fn extent_units(self, units: &str) -> Self {
+        self.model.set_extent_units(units);
+        self
+    }
+
+    /// Sets the conversion factor of the model.
+    ///
+    /// # Arguments
+    /// * `conversion_factor` - The identifier of the conversion factor parameter
+    pub fn conversion_factor(self, conversion_factor: &str) -> Self {
+        self.model.set_conversion_factor(conversion_factor);
+        self
+    }
+
+    /// Builds and returns the configured Model instance.
+    ///
+    /// # Returns
+    /// The newly created Model wrapped in an Rc
+    pub fn build(self) -> Rc<Model<'a>> {
+        self.model
+    }
+}
+
+/// Adds the replaced elements and the replacement of an element (comp package).
+#[cfg(feature = "comp")]
+fn push_replacements<'a>(
+    elements: &mut Vec<Rc<dyn SBase + 'a>>,
+    replaced_elements: Vec<crate::comp::ReplacedElement<'a>>,
+    replaced_by: Option<crate::comp::ReplacedBy<'a>>,
+) {
+    for replaced_element in replaced_elements {
+        elements.push(Rc::new(replaced_element));
+    }
+    if let Some(replaced_by) = replaced_by {
+        elements.push(Rc::new(replaced_by));
+    }
+}
+
+/// Adds the uncertainties of an element and their parameters (distrib package).
+#[cfg(feature = "distrib")]
+fn push_uncertainties<'a>(
+    elements: &mut Vec<Rc<dyn SBase + 'a>>,
+    uncertainties: Vec<crate::distrib::Uncertainty<'a>>,
+) {
+    for uncertainty in uncertainties {
+        let parameters = uncertainty.list_of_uncert_parameters();
+        elements.push(Rc::new(uncertainty));
+        elements.extend(
+            parameters
+                .into_iter()
+                .map(|parameter| parameter as Rc<dyn SBase + 'a>),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -624,6 +1741,51 @@ mod tests {
         assert_eq!(model.name(), "test2");
     }
 
+    #[test]
+    fn test_model_units() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = Model::new(&doc, "test");
+        assert_eq!(model.substance_units(), "");
+
+        model.set_substance_units("mole");
+        model.set_time_units("second");
+        model.set_volume_units("litre");
+        model.set_area_units("metre");
+        model.set_length_units("metre");
+        model.set_extent_units("mole");
+        model.set_conversion_factor("factor");
+
+        assert_eq!(model.substance_units(), "mole");
+        assert_eq!(model.time_units(), "second");
+        assert_eq!(model.volume_units(), "litre");
+        assert_eq!(model.area_units(), "metre");
+        assert_eq!(model.length_units(), "metre");
+        assert_eq!(model.extent_units(), "mole");
+        assert_eq!(model.conversion_factor(), "factor");
+    }
+
+    #[test]
+    fn test_model_builder() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = doc
+            .build_model("test")
+            .name("Test")
+            .substance_units("mole")
+            .time_units("second")
+            .volume_units("litre")
+            .extent_units("mole")
+            .conversion_factor("factor")
+            .build();
+
+        assert_eq!(model.name(), "Test");
+        assert_eq!(model.substance_units(), "mole");
+        assert_eq!(model.time_units(), "second");
+        assert_eq!(model.volume_units(), "litre");
+        assert_eq!(model.extent_units(), "mole");
+        assert_eq!(model.conversion_factor(), "factor");
+        assert!(doc.model().is_some());
+    }
+
     #[test]
     fn test_model_build_species() {
         let doc = SBMLDocument::default();
@@ -910,6 +2072,34 @@ mod tests {
         assert!(rule.is_none());
     }
 
+    #[test]
+    fn test_get_initial_assignment() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_initial_assignment("x", "k * 2").build();
+        model.create_initial_assignment("y", "3");
+        assert_eq!(model.list_of_initial_assignments().len(), 2);
+
+        let assignment = model
+            .get_initial_assignment("x")
+            .expect("InitialAssignment not found");
+        assert_eq!(assignment.formula(), "k * 2");
+        assert!(model.get_initial_assignment("z").is_none());
+    }
+
+    #[test]
+    fn test_get_event() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_event("e1").trigger("x > 1").build();
+        model.create_event("e2");
+        assert_eq!(model.list_of_events().len(), 2);
+
+        let event = model.get_event("e1").expect("Event not found");
+        assert_eq!(event.trigger().unwrap().formula(), "x > 1");
+        assert!(model.get_event("e3").is_none());
+    }
+
     #[test]
     fn test_set_annotation() {
         let doc = SBMLDocument::default();
@@ -947,4 +2137,59 @@ mod tests {
         let model = Model::new(&doc, "test");
         model.set_annotation_serde(&"invalid").unwrap();
     }
+
+    #[test]
+    fn test_ensure_unit() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+
+        let per_second = model.ensure_unit(&DerivedUnit::per_second());
+        assert_eq!(per_second.id(), "per_s");
+        assert_eq!(per_second.name(), "1 / s");
+        let per_minute = model.ensure_unit(&DerivedUnit::per_minute());
+        assert_eq!(per_minute.id(), "per_min");
+        assert_eq!(per_minute.to_unit_str(), "1 / min");
+
+        assert_eq!(model.ensure_unit(&DerivedUnit::per_second()).id(), "per_s");
+        assert_eq!(model.list_of_unit_definitions().len(), 2);
+    }
+
+    #[test]
+    fn test_ensure_unit_reuses_identical_units() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        UnitDefinition::from_unit_str(&model, "conc", "mol / m^3").unwrap();
+
+        assert_eq!(model.ensure_unit(&DerivedUnit::millimolar()).id(), "conc");
+        assert_eq!(model.ensure_unit(&DerivedUnit::molar()).id(), "mol_per_l");
+    }
+
+    #[test]
+    fn test_ensure_unit_ignores_scaled_units() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let conc = UnitDefinition::from_unit_str(&model, "conc", "mmol / ml").unwrap();
+
+        // Equivalent to millimolar, but a thousand times larger
+        let millimolar = DerivedUnit::millimolar();
+        let existing = DerivedUnit::from(conc.as_ref());
+        assert!(existing.is_equivalent(&millimolar));
+        let factor = existing.conversion_factor(&millimolar).unwrap();
+        assert!((factor - 1000.0).abs() < 1e-9);
+
+        assert_eq!(model.ensure_unit(&millimolar).id(), "mmol_per_l");
+        assert_eq!(model.list_of_unit_definitions().len(), 2);
+    }
+
+    #[test]
+    fn test_ensure_unit_avoids_taken_ids() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        UnitDefinition::from_unit_str(&model, "per_s", "1 / min").unwrap();
+
+        let per_second = model.ensure_unit(&DerivedUnit::per_second());
+        assert_eq!(per_second.id(), "per_s_2");
+        let dimensionless = model.ensure_unit(&DerivedUnit::dimensionless());
+        assert_eq!(dimensionless.id(), "dimensionless_2");
+    }
 }
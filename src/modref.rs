@@ -10,7 +10,8 @@
 use std::{cell::RefCell, pin::Pin};
 
 use crate::{
-    inner, pin_ptr, reaction::Reaction, sbmlcxx, sbo_term, upcast, upcast_annotation, upcast_pin,
+    inner, pin_ptr, reaction::Reaction, sbase, sbmlcxx, sbo_term, traits::fromptr::FromPtr, upcast,
+    upcast_annotation, upcast_pin,
 };
 use cxx::let_cxx_string;
 
@@ -35,6 +36,12 @@ upcast_annotation!(
     sbmlcxx::SBase
 );
 
+// Set the SBase trait for the ModifierSpeciesReference struct
+sbase!(
+    ModifierSpeciesReference<'a>,
+    sbmlcxx::ModifierSpeciesReference
+);
+
 impl<'a> ModifierSpeciesReference<'a> {
     /// Creates a new SimpleSpeciesReference instance within the given Reaction.
     ///
@@ -97,6 +104,25 @@ impl<'a> ModifierSpeciesReference<'a> {
     sbo_term!(sbmlcxx::ModifierSpeciesReference, sbmlcxx::SBase);
 }
 
+impl FromPtr<sbmlcxx::ModifierSpeciesReference> for ModifierSpeciesReference<'_> {
+    /// Creates a new ModifierSpeciesReference instance from a unique pointer to a libSBML ModifierSpeciesReference.
+    ///
+    /// This method is primarily used internally by the Reaction class to create
+    /// ModifierSpeciesReference instances from libSBML ModifierSpeciesReference pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML ModifierSpeciesReference
+    ///
+    /// # Returns
+    /// A new ModifierSpeciesReference instance
+    fn from_ptr(ptr: *mut sbmlcxx::ModifierSpeciesReference) -> Self {
+        let modifier_reference = pin_ptr!(ptr, sbmlcxx::ModifierSpeciesReference);
+        Self {
+            inner: RefCell::new(modifier_reference),
+        }
+    }
+}
+
 /// A builder for constructing ModifierSpeciesReference instances with a fluent API.
 ///
 /// This struct provides a builder pattern interface for creating and configuring
@@ -0,0 +1,195 @@
+//! SBML Level 3 packages.
+//!
+//! SBML Level 3 is modular: the core specification can be extended by packages that
+//! add new elements and attributes. libSBML represents the package content of an
+//! element as plugins attached to it, which only exist once the package is enabled
+//! on the document.
+//!
+//! This module lists the packages supported by this crate and provides the internal
+//! helpers to enable them and to access the plugins of an element. Packages are built
+//! into libSBML through Cargo features of the same name, see [`Package::is_available`].
+
+use std::pin::Pin;
+
+use cxx::let_cxx_string;
+
+use crate::{pin_ptr, sbmlcxx};
+
+/// An SBML Level 3 package supported by this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Package {
+    /// Flux balance constraints (flux bounds, objectives and gene products)
+    Fbc,
+    /// Diagrams of models (glyphs, bounding boxes and curves)
+    Layout,
+    /// Visual styles of layouts (colors and styles)
+    Render,
+    /// Hierarchical model composition (submodels, ports and replacements)
+    Comp,
+    /// Grouping of model elements (classifications, partonomies and collections)
+    Groups,
+    /// Qualitative models (qualitative species and logical transitions)
+    Qual,
+    /// Distributions and uncertainties of values
+    Distrib,
+    /// Multistate and multicomponent species
+    Multi,
+    /// Spatial geometries and processes
+    Spatial,
+}
+
+impl Package {
+    /// All packages supported by this crate.
+    pub const ALL: [Package; 9] = [
+        Package::Fbc,
+        Package::Layout,
+        Package::Render,
+        Package::Comp,
+        Package::Groups,
+        Package::Qual,
+        Package::Distrib,
+        Package::Multi,
+        Package::Spatial,
+    ];
+
+    /// Returns the short name of the package, which is also its default XML prefix.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Package::Fbc => "fbc",
+            Package::Layout => "layout",
+            Package::Render => "render",
+            Package::Comp => "comp",
+            Package::Groups => "groups",
+            Package::Qual => "qual",
+            Package::Distrib => "distrib",
+            Package::Multi => "multi",
+            Package::Spatial => "spatial",
+        }
+    }
+
+    /// Returns the XML namespace URI of the package version used by this crate.
+    pub fn uri(&self) -> &'static str {
+        match self {
+            Package::Fbc => "http://www.sbml.org/sbml/level3/version1/fbc/version2",
+            Package::Layout => "http://www.sbml.org/sbml/level3/version1/layout/version1",
+            Package::Render => "http://www.sbml.org/sbml/level3/version1/render/version1",
+            Package::Comp => "http://www.sbml.org/sbml/level3/version1/comp/version1",
+            Package::Groups => "http://www.sbml.org/sbml/level3/version1/groups/version1",
+            Package::Qual => "http://www.sbml.org/sbml/level3/version1/qual/version1",
+            Package::Distrib => "http://www.sbml.org/sbml/level3/version1/distrib/version1",
+            Package::Multi => "http://www.sbml.org/sbml/level3/version1/multi/version1",
+            Package::Spatial => "http://www.sbml.org/sbml/level3/version1/spatial/version1",
+        }
+    }
+
+    /// Returns whether the package can change the mathematical meaning of a model.
+    ///
+    /// This is written as the `required` attribute of the package on the document.
+    pub fn required(&self) -> bool {
+        match self {
+            Package::Fbc | Package::Layout | Package::Render | Package::Groups => false,
+            Package::Comp
+            | Package::Qual
+            | Package::Distrib
+            | Package::Multi
+            | Package::Spatial => true,
+        }
+    }
+
+    /// Returns whether the package is built into libSBML.
+    ///
+    /// Packages are selected through the Cargo feature of the same name. Enabling a
+    /// package that is not available on a document has no effect.
+    pub fn is_available(&self) -> bool {
+        match self {
+            Package::Fbc => cfg!(feature = "fbc"),
+            Package::Layout => cfg!(feature = "layout"),
+            Package::Render => cfg!(feature = "render"),
+            Package::Comp => cfg!(feature = "comp"),
+            Package::Groups => cfg!(feature = "groups"),
+            Package::Qual => cfg!(feature = "qual"),
+            Package::Distrib => cfg!(feature = "distrib"),
+            Package::Multi => cfg!(feature = "multi"),
+            Package::Spatial => cfg!(feature = "spatial"),
+        }
+    }
+}
+
+/// Enables a package on a document, including all elements it already contains.
+///
+/// # Arguments
+/// * `document` - The document to enable the package on
+/// * `package` - The package to enable
+pub(crate) fn enable_package(mut document: Pin<&mut sbmlcxx::SBMLDocument>, package: Package) {
+    let_cxx_string!(uri = package.uri());
+    let_cxx_string!(prefix = package.name());
+    let_cxx_string!(name = package.name());
+
+    document.as_mut().enablePackage(&uri, &prefix, true);
+    document
+        .as_mut()
+        .setPackageRequired(&name, package.required());
+}
+
+/// Disables a package on a document, removing its content from all elements.
+///
+/// # Arguments
+/// * `document` - The document to disable the package on
+/// * `package` - The package to disable
+pub(crate) fn disable_package(mut document: Pin<&mut sbmlcxx::SBMLDocument>, package: Package) {
+    let_cxx_string!(uri = package.uri());
+    let_cxx_string!(prefix = package.name());
+
+    document.as_mut().enablePackage(&uri, &prefix, false);
+}
+
+/// Returns the plugin of a package attached to an element.
+///
+/// The plugin is cast to the plugin class `T` of the package for the kind of element,
+/// e.g. `FbcModelPlugin` for models. The caller must make sure that `T` matches the
+/// element.
+///
+/// # Arguments
+/// * `base` - The element the plugin is attached to
+/// * `package` - The package of the plugin
+/// * `enable` - Whether to enable the package on the element's document if needed
+///
+/// # Returns
+/// The plugin, or None if the package is not enabled and could not be enabled
+#[cfg_attr(
+    not(any(
+        feature = "fbc",
+        feature = "layout",
+        feature = "comp",
+        feature = "groups",
+        feature = "qual",
+        feature = "distrib"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn get_plugin<'a, T>(
+    mut base: Pin<&mut sbmlcxx::SBase>,
+    package: Package,
+    enable: bool,
+) -> Option<Pin<&'a mut T>> {
+    let_cxx_string!(name = package.name());
+    let mut plugin_ptr = base.as_mut().getPlugin(&name);
+
+    if plugin_ptr.is_null() && enable {
+        let document_ptr = base.as_mut().getSBMLDocument1();
+        if document_ptr.is_null() {
+            return None;
+        }
+
+        let document = pin_ptr!(document_ptr, sbmlcxx::SBMLDocument);
+        enable_package(document, package);
+        plugin_ptr = base.as_mut().getPlugin(&name);
+    }
+
+    if plugin_ptr.is_null() {
+        return None;
+    }
+
+    let plugin_ptr = plugin_ptr.cast::<T>();
+    Some(pin_ptr!(plugin_ptr, T))
+}
@@ -13,9 +13,9 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use cxx::let_cxx_string;
 
 use crate::{
-    inner,
+    comp_replacements, distrib_uncertainties, inner,
     model::Model,
-    pin_ptr,
+    pin_ptr, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
@@ -36,6 +36,9 @@ inner!(sbmlcxx::Parameter, Parameter<'a>);
 // Set the annotation trait for the Parameter struct
 upcast_annotation!(Parameter<'a>, sbmlcxx::Parameter, sbmlcxx::SBase);
 
+// Set the SBase trait for the Parameter struct
+sbase!(Parameter<'a>, sbmlcxx::Parameter);
+
 impl<'a> Parameter<'a> {
     /// Creates a new Parameter instance within the given Model.
     ///
@@ -150,6 +153,12 @@ impl<'a> Parameter<'a> {
 
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Parameter, sbmlcxx::SBase);
+
+    // Replacement Methods generated by the `comp_replacements` macro
+    comp_replacements!(sbmlcxx::Parameter);
+
+    // Uncertainty Methods generated by the `distrib_uncertainties` macro
+    distrib_uncertainties!(sbmlcxx::Parameter);
 }
 
 impl FromPtr<sbmlcxx::Parameter> for Parameter<'_> {
@@ -0,0 +1,100 @@
+//! This module provides a safe Rust interface to the libSBML DefaultTerm class.
+//!
+//! The DefaultTerm class of the qual package gives the level the outputs of a
+//! transition take when none of its function terms is satisfied.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML DefaultTerm class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use autocxx::c_int;
+
+use crate::{
+    inner, pin_ptr,
+    qual::transition::Transition,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML DefaultTerm class.
+///
+/// This struct maintains a reference to the underlying C++ DefaultTerm object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct DefaultTerm<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::DefaultTerm>>,
+}
+
+// Set the inner trait for the DefaultTerm struct
+inner!(sbmlcxx::DefaultTerm, DefaultTerm<'a>);
+
+// Set the annotation trait for the DefaultTerm struct
+upcast_annotation!(DefaultTerm<'a>, sbmlcxx::DefaultTerm, sbmlcxx::SBase);
+
+// Set the SBase trait for the DefaultTerm struct
+sbase!(DefaultTerm<'a>, sbmlcxx::DefaultTerm);
+
+impl<'a> DefaultTerm<'a> {
+    /// Creates a new DefaultTerm instance within the given Transition.
+    ///
+    /// A transition has a single default term, which is replaced by this one.
+    ///
+    /// # Arguments
+    /// * `transition` - The parent Transition that will contain this term
+    /// * `result_level` - The level of the outputs if no function term is satisfied
+    ///
+    /// # Returns
+    /// A new DefaultTerm instance
+    pub fn new(transition: &Transition<'a>, result_level: i32) -> Self {
+        let term_ptr = transition.inner().borrow_mut().as_mut().createDefaultTerm();
+        let term = Self {
+            inner: RefCell::new(pin_ptr!(term_ptr, sbmlcxx::DefaultTerm)),
+        };
+        term.set_result_level(result_level);
+        term
+    }
+
+    /// Returns the level of the outputs if no function term is satisfied.
+    ///
+    /// # Returns
+    /// The result level as an i32
+    pub fn result_level(&self) -> i32 {
+        self.inner.borrow().getResultLevel().into()
+    }
+
+    /// Sets the level of the outputs if no function term is satisfied.
+    ///
+    /// # Arguments
+    /// * `level` - The result level
+    pub fn set_result_level(&self, level: i32) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setResultLevel(c_int::from(level));
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::DefaultTerm, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::DefaultTerm> for DefaultTerm<'_> {
+    /// Creates a new DefaultTerm instance from a unique pointer to a libSBML DefaultTerm.
+    ///
+    /// This method is primarily used internally by the Transition class to create
+    /// DefaultTerm instances from libSBML DefaultTerm pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML DefaultTerm
+    ///
+    /// # Returns
+    /// A new DefaultTerm instance
+    fn from_ptr(ptr: *mut sbmlcxx::DefaultTerm) -> Self {
+        let term = pin_ptr!(ptr, sbmlcxx::DefaultTerm);
+        Self {
+            inner: RefCell::new(term),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! This module provides a safe Rust interface to the libSBML FunctionTerm class.
+//!
+//! The FunctionTerm class of the qual package pairs a Boolean condition with the level
+//! the outputs of a transition take while the condition holds. The terms of a
+//! transition are checked in order and the first satisfied one determines the result.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML FunctionTerm class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use autocxx::c_int;
+
+use crate::{
+    astnode::{formula_to_string, parse_formula},
+    inner, pin_ptr,
+    qual::transition::Transition,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML FunctionTerm class.
+///
+/// This struct maintains a reference to the underlying C++ FunctionTerm object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct FunctionTerm<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::FunctionTerm>>,
+}
+
+// Set the inner trait for the FunctionTerm struct
+inner!(sbmlcxx::FunctionTerm, FunctionTerm<'a>);
+
+// Set the annotation trait for the FunctionTerm struct
+upcast_annotation!(FunctionTerm<'a>, sbmlcxx::FunctionTerm, sbmlcxx::SBase);
+
+// Set the SBase trait for the FunctionTerm struct
+sbase!(FunctionTerm<'a>, sbmlcxx::FunctionTerm);
+
+impl<'a> FunctionTerm<'a> {
+    /// Creates a new FunctionTerm instance within the given Transition.
+    ///
+    /// # Arguments
+    /// * `transition` - The parent Transition that will contain this term
+    /// * `result_level` - The level of the outputs while the condition holds
+    /// * `formula` - The condition, referring to inputs or qualitative species
+    ///
+    /// # Returns
+    /// A new FunctionTerm instance
+    pub fn new(transition: &Transition<'a>, result_level: i32, formula: &str) -> Self {
+        let term_ptr = transition
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createFunctionTerm();
+        let term = Self {
+            inner: RefCell::new(pin_ptr!(term_ptr, sbmlcxx::FunctionTerm)),
+        };
+        term.set_result_level(result_level);
+        term.set_formula(formula);
+        term
+    }
+
+    /// Returns the level of the outputs while the condition holds.
+    ///
+    /// # Returns
+    /// The result level as an i32
+    pub fn result_level(&self) -> i32 {
+        self.inner.borrow().getResultLevel().into()
+    }
+
+    /// Sets the level of the outputs while the condition holds.
+    ///
+    /// # Arguments
+    /// * `level` - The result level
+    pub fn set_result_level(&self, level: i32) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setResultLevel(c_int::from(level));
+    }
+
+    /// Returns the condition of the term.
+    ///
+    /// # Returns
+    /// The formula in the SBML Level 3 infix syntax, empty if no math is set
+    pub fn formula(&self) -> String {
+        formula_to_string(self.inner.borrow().getMath())
+    }
+
+    /// Sets the condition of the term.
+    ///
+    /// Formulas that cannot be parsed by libSBML leave the math unchanged.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula in the SBML Level 3 infix syntax
+    pub fn set_formula(&self, formula: &str) {
+        if let Some(math) = parse_formula(formula) {
+            unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        }
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::FunctionTerm, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::FunctionTerm> for FunctionTerm<'_> {
+    /// Creates a new FunctionTerm instance from a unique pointer to a libSBML FunctionTerm.
+    ///
+    /// This method is primarily used internally by the Transition class to create
+    /// FunctionTerm instances from libSBML FunctionTerm pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML FunctionTerm
+    ///
+    /// # Returns
+    /// A new FunctionTerm instance
+    fn from_ptr(ptr: *mut sbmlcxx::FunctionTerm) -> Self {
+        let term = pin_ptr!(ptr, sbmlcxx::FunctionTerm);
+        Self {
+            inner: RefCell::new(term),
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! This module provides a safe Rust interface to the libSBML Input class.
+//!
+//! The Input class of the qual package connects a qualitative species to a transition.
+//! The function terms of the transition compare the level of the species, referenced
+//! by its id, against constants or against the threshold level of the input,
+//! referenced by the id of the input. The sign of an input documents whether the
+//! species activates or inhibits the transition; it does not affect the function terms.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Input class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use autocxx::c_int;
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    qual::transition::Transition,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// The influence of an input on a transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InputSign {
+    /// The input activates the transition
+    Positive,
+    /// The input inhibits the transition
+    Negative,
+    /// The input activates or inhibits the transition depending on other inputs
+    Dual,
+    /// The influence is not known
+    #[default]
+    Unknown,
+}
+
+impl From<InputSign> for sbmlcxx::InputSign_t {
+    fn from(sign: InputSign) -> Self {
+        match sign {
+            InputSign::Positive => sbmlcxx::InputSign_t::INPUT_SIGN_POSITIVE,
+            InputSign::Negative => sbmlcxx::InputSign_t::INPUT_SIGN_NEGATIVE,
+            InputSign::Dual => sbmlcxx::InputSign_t::INPUT_SIGN_DUAL,
+            InputSign::Unknown => sbmlcxx::InputSign_t::INPUT_SIGN_UNKNOWN,
+        }
+    }
+}
+
+/// What happens to an input species when the transition fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InputEffect {
+    /// The level of the species is not changed
+    #[default]
+    None,
+    /// The level of the species is decreased by the threshold level
+    Consumption,
+}
+
+impl From<InputEffect> for sbmlcxx::InputTransitionEffect_t {
+    fn from(effect: InputEffect) -> Self {
+        match effect {
+            InputEffect::None => sbmlcxx::InputTransitionEffect_t::INPUT_TRANSITION_EFFECT_NONE,
+            InputEffect::Consumption => {
+                sbmlcxx::InputTransitionEffect_t::INPUT_TRANSITION_EFFECT_CONSUMPTION
+            }
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML Input class.
+///
+/// This struct maintains a reference to the underlying C++ Input object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Input<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Input>>,
+}
+
+// Set the inner trait for the Input struct
+inner!(sbmlcxx::Input, Input<'a>);
+
+// Set the annotation trait for the Input struct
+upcast_annotation!(Input<'a>, sbmlcxx::Input, sbmlcxx::SBase);
+
+// Set the SBase trait for the Input struct
+sbase!(Input<'a>, sbmlcxx::Input);
+
+impl<'a> Input<'a> {
+    /// Creates a new Input instance within the given Transition.
+    ///
+    /// The input does not change the level of the species.
+    ///
+    /// # Arguments
+    /// * `transition` - The parent Transition that will contain this input
+    /// * `species` - The identifier of the qualitative species
+    /// * `sign` - The influence of the species on the transition
+    ///
+    /// # Returns
+    /// A new Input instance
+    pub fn new(transition: &Transition<'a>, species: &str, sign: InputSign) -> Self {
+        let input_ptr = transition.inner().borrow_mut().as_mut().createInput();
+        let input = Self {
+            inner: RefCell::new(pin_ptr!(input_ptr, sbmlcxx::Input)),
+        };
+        input.set_qualitative_species(species);
+        input.set_sign(sign);
+        input.set_effect(InputEffect::None);
+        input
+    }
+
+    /// Returns the id of the input.
+    ///
+    /// # Returns
+    /// The id of the input as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the input.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the input.
+    ///
+    /// # Returns
+    /// The name of the input as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the input.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the identifier of the qualitative species.
+    ///
+    /// # Returns
+    /// The species identifier as a String
+    pub fn qualitative_species(&self) -> String {
+        self.inner
+            .borrow()
+            .getQualitativeSpecies()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the qualitative species of the input.
+    ///
+    /// # Arguments
+    /// * `species` - The species identifier
+    pub fn set_qualitative_species(&self, species: &str) {
+        let_cxx_string!(species = species);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setQualitativeSpecies(&species);
+    }
+
+    /// Returns the influence of the input on the transition.
+    ///
+    /// # Returns
+    /// The sign of the input, None if not set
+    pub fn sign(&self) -> Option<InputSign> {
+        match self.inner.borrow().getSign() {
+            sbmlcxx::InputSign_t::INPUT_SIGN_POSITIVE => Some(InputSign::Positive),
+            sbmlcxx::InputSign_t::INPUT_SIGN_NEGATIVE => Some(InputSign::Negative),
+            sbmlcxx::InputSign_t::INPUT_SIGN_DUAL => Some(InputSign::Dual),
+            sbmlcxx::InputSign_t::INPUT_SIGN_UNKNOWN => Some(InputSign::Unknown),
+            _ => None,
+        }
+    }
+
+    /// Sets the influence of the input on the transition.
+    ///
+    /// # Arguments
+    /// * `sign` - The sign of the input
+    pub fn set_sign(&self, sign: InputSign) {
+        self.inner.borrow_mut().as_mut().setSign(sign.into());
+    }
+
+    /// Returns what happens to the species when the transition fires.
+    ///
+    /// # Returns
+    /// The transition effect, None if not set
+    pub fn effect(&self) -> Option<InputEffect> {
+        match self.inner.borrow().getTransitionEffect() {
+            sbmlcxx::InputTransitionEffect_t::INPUT_TRANSITION_EFFECT_NONE => {
+                Some(InputEffect::None)
+            }
+            sbmlcxx::InputTransitionEffect_t::INPUT_TRANSITION_EFFECT_CONSUMPTION => {
+                Some(InputEffect::Consumption)
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets what happens to the species when the transition fires.
+    ///
+    /// # Arguments
+    /// * `effect` - The transition effect
+    pub fn set_effect(&self, effect: InputEffect) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setTransitionEffect(effect.into());
+    }
+
+    /// Returns the level the function terms compare the species against.
+    ///
+    /// # Returns
+    /// The threshold level, None if not set
+    pub fn threshold_level(&self) -> Option<i32> {
+        let input = self.inner.borrow();
+        input
+            .isSetThresholdLevel()
+            .then(|| input.getThresholdLevel().into())
+    }
+
+    /// Sets the level the function terms compare the species against.
+    ///
+    /// # Arguments
+    /// * `level` - The threshold level
+    pub fn set_threshold_level(&self, level: i32) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setThresholdLevel(c_int::from(level));
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Input, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Input> for Input<'_> {
+    /// Creates a new Input instance from a unique pointer to a libSBML Input.
+    ///
+    /// This method is primarily used internally by the Transition class to create
+    /// Input instances from libSBML Input pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Input
+    ///
+    /// # Returns
+    /// A new Input instance
+    fn from_ptr(ptr: *mut sbmlcxx::Input) -> Self {
+        let input = pin_ptr!(ptr, sbmlcxx::Input);
+        Self {
+            inner: RefCell::new(input),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Support for the SBML Level 3 qualitative models (qual) package.
+//!
+//! The qual package describes logical models, e.g. Boolean networks of gene regulation
+//! and signalling:
+//!
+//! - [`QualitativeSpecies`] carry a discrete level between zero and a maximum level
+//! - [`Transition`]s set the levels of their [`Output`]s depending on the levels of
+//!   their [`Input`]s
+//! - [`FunctionTerm`]s pair a condition with a result level and are checked in order,
+//!   with the [`DefaultTerm`] applying if no condition holds
+//!
+//! The content is accessed through [`Model::qual_plugin`]. For the simulation and
+//! attractor analysis of qual models, see the [`logical`](crate::logical) module.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("signalling");
+//! model.build_compartment("cell").build();
+//!
+//! let qual = model.qual_plugin();
+//! qual.build_qualitative_species("EGF", "cell").max_level(1).constant(true).build();
+//! qual.build_qualitative_species("ERK", "cell").max_level(1).initial_level(0).build();
+//! qual.build_transition("t_ERK")
+//!     .input("EGF", InputSign::Positive)
+//!     .output("ERK")
+//!     .function_term(1, "EGF == 1")
+//!     .default_level(0)
+//!     .build();
+//! ```
+//!
+//! [`Model::qual_plugin`]: crate::model::Model::qual_plugin
+
+/// Module providing default term functionality
+pub mod defaultterm;
+/// Module providing function term functionality
+pub mod functionterm;
+/// Module providing transition input functionality
+pub mod input;
+/// Module providing transition output functionality
+pub mod output;
+/// Module providing qual model plugin functionality
+pub mod plugin;
+/// Module providing qualitative species functionality
+pub mod qualspecies;
+/// Module providing transition functionality
+pub mod transition;
+
+pub use defaultterm::DefaultTerm;
+pub use functionterm::FunctionTerm;
+pub use input::{Input, InputEffect, InputSign};
+pub use output::{Output, OutputEffect};
+pub use plugin::QualModelPlugin;
+pub use qualspecies::{QualitativeSpecies, QualitativeSpeciesBuilder};
+pub use transition::{Transition, TransitionBuilder};
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logical::{LogicalError, LogicalNetwork, UpdateScheme},
+        prelude::*,
+    };
+
+    /// Adds a toggle switch of two mutually inhibiting genes to a model.
+    fn toggle_switch(model: &Model) {
+        model.build_compartment("cell").build();
+
+        let qual = model.qual_plugin();
+        qual.build_qualitative_species("A", "cell")
+            .max_level(1)
+            .initial_level(1)
+            .build();
+        qual.build_qualitative_species("B", "cell")
+            .max_level(1)
+            .initial_level(0)
+            .build();
+        qual.build_transition("tA")
+            .input("B", InputSign::Negative)
+            .output("A")
+            .function_term(1, "B == 0")
+            .default_level(0)
+            .build();
+        qual.build_transition("tB")
+            .input("A", InputSign::Negative)
+            .output("B")
+            .function_term(1, "A == 0")
+            .default_level(0)
+            .build();
+    }
+
+    #[test]
+    fn test_qual_model_plugin() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        assert!(!doc.is_package_enabled(Package::Qual));
+
+        toggle_switch(&model);
+        assert!(doc.is_package_enabled(Package::Qual));
+
+        let qual = model.qual_plugin();
+        let species = qual.get_qualitative_species("A").unwrap();
+        assert_eq!(species.compartment(), "cell");
+        assert_eq!(species.max_level(), Some(1));
+        assert_eq!(species.initial_level(), Some(1));
+        assert!(!species.constant());
+
+        let transition = qual.get_transition("tA").unwrap();
+        let input = transition.get_input("B").unwrap();
+        assert_eq!(input.sign(), Some(InputSign::Negative));
+        assert_eq!(input.effect(), Some(InputEffect::None));
+        assert_eq!(input.threshold_level(), None);
+
+        let output = transition.get_output("A").unwrap();
+        assert_eq!(output.effect(), Some(OutputEffect::AssignmentLevel));
+
+        let terms = transition.list_of_function_terms();
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].result_level(), 1);
+        assert_eq!(terms[0].formula(), "B == 0");
+        assert_eq!(transition.default_term().unwrap().result_level(), 0);
+
+        // Setting the default level again updates the existing term
+        transition.create_default_term(1);
+        assert_eq!(transition.default_term().unwrap().result_level(), 1);
+    }
+
+    #[test]
+    fn test_qual_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        toggle_switch(&model);
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains("qual:listOfTransitions"));
+
+        let read = SBMLReader::from_xml_string(&xml);
+        let model = read.model().expect("Model not found");
+        let qual = model.qual_plugin();
+        assert_eq!(qual.list_of_qualitative_species().len(), 2);
+
+        let transition = qual.get_transition("tB").unwrap();
+        assert_eq!(transition.list_of_inputs()[0].qualitative_species(), "A");
+        assert_eq!(transition.list_of_outputs()[0].qualitative_species(), "B");
+        assert_eq!(transition.list_of_function_terms()[0].formula(), "A == 0");
+        assert_eq!(transition.default_term().unwrap().result_level(), 0);
+    }
+
+    #[test]
+    fn test_logical_network_from_model() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        toggle_switch(&model);
+
+        let network = LogicalNetwork::from_model(&model).unwrap();
+        assert_eq!(network.species(), &["A".to_string(), "B".to_string()]);
+        assert_eq!(network.initial_state(), vec![1, 0]);
+        assert_eq!(
+            network.steady_states().unwrap(),
+            vec![vec![0, 1], vec![1, 0]]
+        );
+        assert_eq!(
+            network.attractors(UpdateScheme::Synchronous).unwrap().len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_logical_network_thresholds() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_compartment("cell").build();
+
+        // Species without a maximum level take the highest level of their transition
+        let qual = model.qual_plugin();
+        qual.build_qualitative_species("S", "cell")
+            .max_level(1)
+            .initial_level(1)
+            .constant(true)
+            .build();
+        qual.build_qualitative_species("X", "cell").build();
+        let transition = qual
+            .build_transition("tX")
+            .output("X")
+            .function_term(2, "S >= theta_S")
+            .default_level(0)
+            .build();
+        transition
+            .create_input("S", InputSign::Positive)
+            .set_id("theta_S");
+        transition.get_input("S").unwrap().set_threshold_level(1);
+
+        let network = LogicalNetwork::from_model(&model).unwrap();
+        assert_eq!(network.max_levels(), &[1, 2]);
+        assert_eq!(network.targets(&[1, 0]).unwrap(), vec![1, 2]);
+
+        // A missing default term is reported
+        qual.build_transition("tS")
+            .output("X")
+            .function_term(1, "S == 1")
+            .build();
+        assert!(matches!(
+            LogicalNetwork::from_model(&model),
+            Err(LogicalError::InvalidModel(_))
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+//! This module provides a safe Rust interface to the libSBML Output class.
+//!
+//! The Output class of the qual package names a qualitative species whose level is
+//! set by a transition. With the assignment effect, the species takes the result
+//! level of the transition's active term; with the production effect, the level is
+//! increased by the output level instead.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Output class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use autocxx::c_int;
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    qual::transition::Transition,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// How a transition changes the level of an output species.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutputEffect {
+    /// The species takes the result level of the transition
+    #[default]
+    AssignmentLevel,
+    /// The level of the species is increased by the output level
+    Production,
+}
+
+impl From<OutputEffect> for sbmlcxx::OutputTransitionEffect_t {
+    fn from(effect: OutputEffect) -> Self {
+        match effect {
+            OutputEffect::AssignmentLevel => {
+                sbmlcxx::OutputTransitionEffect_t::OUTPUT_TRANSITION_EFFECT_ASSIGNMENT_LEVEL
+            }
+            OutputEffect::Production => {
+                sbmlcxx::OutputTransitionEffect_t::OUTPUT_TRANSITION_EFFECT_PRODUCTION
+            }
+        }
+    }
+}
+
+/// A safe wrapper around the libSBML Output class.
+///
+/// This struct maintains a reference to the underlying C++ Output object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Output<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Output>>,
+}
+
+// Set the inner trait for the Output struct
+inner!(sbmlcxx::Output, Output<'a>);
+
+// Set the annotation trait for the Output struct
+upcast_annotation!(Output<'a>, sbmlcxx::Output, sbmlcxx::SBase);
+
+// Set the SBase trait for the Output struct
+sbase!(Output<'a>, sbmlcxx::Output);
+
+impl<'a> Output<'a> {
+    /// Creates a new Output instance within the given Transition.
+    ///
+    /// # Arguments
+    /// * `transition` - The parent Transition that will contain this output
+    /// * `species` - The identifier of the qualitative species
+    /// * `effect` - How the transition changes the level of the species
+    ///
+    /// # Returns
+    /// A new Output instance
+    pub fn new(transition: &Transition<'a>, species: &str, effect: OutputEffect) -> Self {
+        let output_ptr = transition.inner().borrow_mut().as_mut().createOutput();
+        let output = Self {
+            inner: RefCell::new(pin_ptr!(output_ptr, sbmlcxx::Output)),
+        };
+        output.set_qualitative_species(species);
+        output.set_effect(effect);
+        output
+    }
+
+    /// Returns the id of the output.
+    ///
+    /// # Returns
+    /// The id of the output as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the output.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the identifier of the qualitative species.
+    ///
+    /// # Returns
+    /// The species identifier as a String
+    pub fn qualitative_species(&self) -> String {
+        self.inner
+            .borrow()
+            .getQualitativeSpecies()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the qualitative species of the output.
+    ///
+    /// # Arguments
+    /// * `species` - The species identifier
+    pub fn set_qualitative_species(&self, species: &str) {
+        let_cxx_string!(species = species);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setQualitativeSpecies(&species);
+    }
+
+    /// Returns how the transition changes the level of the species.
+    ///
+    /// # Returns
+    /// The transition effect, None if not set
+    pub fn effect(&self) -> Option<OutputEffect> {
+        match self.inner.borrow().getTransitionEffect() {
+            sbmlcxx::OutputTransitionEffect_t::OUTPUT_TRANSITION_EFFECT_ASSIGNMENT_LEVEL => {
+                Some(OutputEffect::AssignmentLevel)
+            }
+            sbmlcxx::OutputTransitionEffect_t::OUTPUT_TRANSITION_EFFECT_PRODUCTION => {
+                Some(OutputEffect::Production)
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets how the transition changes the level of the species.
+    ///
+    /// # Arguments
+    /// * `effect` - The transition effect
+    pub fn set_effect(&self, effect: OutputEffect) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setTransitionEffect(effect.into());
+    }
+
+    /// Returns the level added to the species by the production effect.
+    ///
+    /// # Returns
+    /// The output level, None if not set
+    pub fn output_level(&self) -> Option<i32> {
+        let output = self.inner.borrow();
+        output
+            .isSetOutputLevel()
+            .then(|| output.getOutputLevel().into())
+    }
+
+    /// Sets the level added to the species by the production effect.
+    ///
+    /// # Arguments
+    /// * `level` - The output level
+    pub fn set_output_level(&self, level: i32) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setOutputLevel(c_int::from(level));
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Output, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Output> for Output<'_> {
+    /// Creates a new Output instance from a unique pointer to a libSBML Output.
+    ///
+    /// This method is primarily used internally by the Transition class to create
+    /// Output instances from libSBML Output pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Output
+    ///
+    /// # Returns
+    /// A new Output instance
+    fn from_ptr(ptr: *mut sbmlcxx::Output) -> Self {
+        let output = pin_ptr!(ptr, sbmlcxx::Output);
+        Self {
+            inner: RefCell::new(output),
+        }
+    }
+}
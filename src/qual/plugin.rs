@@ -0,0 +1,193 @@
+//! This module provides a safe Rust interface to the libSBML QualModelPlugin class.
+//!
+//! The QualModelPlugin class holds the content the qual package adds to a model: the
+//! qualitative species and the transitions between their levels. Together they define
+//! a logical model, which can be analyzed with the [`logical`](crate::logical) module.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML QualModelPlugin class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use crate::{
+    inner, pin_ptr,
+    qual::{
+        qualspecies::{QualitativeSpecies, QualitativeSpeciesBuilder},
+        transition::{Transition, TransitionBuilder},
+    },
+    sbmlcxx::{self},
+    traits::fromptr::FromPtr,
+};
+
+/// A safe wrapper around the libSBML QualModelPlugin class.
+///
+/// This struct maintains a reference to the underlying C++ QualModelPlugin object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the qualitative species and transitions of the model.
+pub struct QualModelPlugin<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::QualModelPlugin>>,
+    qualitative_species: RefCell<Vec<Rc<QualitativeSpecies<'a>>>>,
+    transitions: RefCell<Vec<Rc<Transition<'a>>>>,
+}
+
+// Set the inner trait for the QualModelPlugin struct
+inner!(sbmlcxx::QualModelPlugin, QualModelPlugin<'a>);
+
+impl<'a> QualModelPlugin<'a> {
+    /// Returns a reference to the inner RefCell containing the QualModelPlugin pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::QualModelPlugin>> {
+        &self.inner
+    }
+
+    /// Creates a new qualitative species.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new species
+    /// * `compartment` - The identifier of the compartment containing the species
+    ///
+    /// # Returns
+    /// The newly created QualitativeSpecies wrapped in an Rc
+    pub fn create_qualitative_species(
+        &self,
+        id: &str,
+        compartment: &str,
+    ) -> Rc<QualitativeSpecies<'a>> {
+        let species = Rc::new(QualitativeSpecies::new(self, id, compartment));
+        self.qualitative_species
+            .borrow_mut()
+            .push(Rc::clone(&species));
+        species
+    }
+
+    /// Creates a new QualitativeSpeciesBuilder for constructing a qualitative species.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new species
+    /// * `compartment` - The identifier of the compartment containing the species
+    ///
+    /// # Returns
+    /// A QualitativeSpeciesBuilder instance
+    pub fn build_qualitative_species(
+        &self,
+        id: &str,
+        compartment: &str,
+    ) -> QualitativeSpeciesBuilder<'a> {
+        QualitativeSpeciesBuilder::new(self, id, compartment)
+    }
+
+    /// Returns a vector of all qualitative species of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all QualitativeSpecies of the model
+    pub fn list_of_qualitative_species(&self) -> Vec<Rc<QualitativeSpecies<'a>>> {
+        self.qualitative_species.borrow().to_vec()
+    }
+
+    /// Retrieves a qualitative species by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the species
+    ///
+    /// # Returns
+    /// Some(Rc<QualitativeSpecies>) if found, None if not found
+    pub fn get_qualitative_species(&self, id: &str) -> Option<Rc<QualitativeSpecies<'a>>> {
+        self.qualitative_species
+            .borrow()
+            .iter()
+            .find(|species| (*species).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Creates a new transition.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new transition
+    ///
+    /// # Returns
+    /// The newly created Transition wrapped in an Rc
+    pub fn create_transition(&self, id: &str) -> Rc<Transition<'a>> {
+        let transition = Rc::new(Transition::new(self, id));
+        self.transitions.borrow_mut().push(Rc::clone(&transition));
+        transition
+    }
+
+    /// Creates a new TransitionBuilder for constructing a transition.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new transition
+    ///
+    /// # Returns
+    /// A TransitionBuilder instance
+    pub fn build_transition(&self, id: &str) -> TransitionBuilder<'a> {
+        TransitionBuilder::new(self, id)
+    }
+
+    /// Returns a vector of all transitions of the model.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Transitions of the model
+    pub fn list_of_transitions(&self) -> Vec<Rc<Transition<'a>>> {
+        self.transitions.borrow().to_vec()
+    }
+
+    /// Retrieves a transition by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the transition
+    ///
+    /// # Returns
+    /// Some(Rc<Transition>) if found, None if not found
+    pub fn get_transition(&self, id: &str) -> Option<Rc<Transition<'a>>> {
+        self.transitions
+            .borrow()
+            .iter()
+            .find(|transition| (*transition).id() == id)
+            .map(Rc::clone)
+    }
+}
+
+impl FromPtr<sbmlcxx::QualModelPlugin> for QualModelPlugin<'_> {
+    /// Creates a new QualModelPlugin instance from a unique pointer to a libSBML
+    /// QualModelPlugin.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// QualModelPlugin instances from libSBML QualModelPlugin pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML QualModelPlugin
+    ///
+    /// # Returns
+    /// A new QualModelPlugin instance
+    fn from_ptr(ptr: *mut sbmlcxx::QualModelPlugin) -> Self {
+        let plugin = RefCell::new(pin_ptr!(ptr, sbmlcxx::QualModelPlugin));
+
+        // Fetch all qualitative species
+        let n_species = plugin.borrow().getNumQualitativeSpecies().0;
+        let qualitative_species: Vec<_> = (0..n_species)
+            .map(|i| {
+                let species = plugin
+                    .borrow_mut()
+                    .as_mut()
+                    .getQualitativeSpecies1(i.into());
+                Rc::new(QualitativeSpecies::from_ptr(species))
+            })
+            .collect();
+
+        // Fetch all transitions
+        let n_transitions = plugin.borrow().getNumTransitions().0;
+        let transitions: Vec<_> = (0..n_transitions)
+            .map(|i| {
+                let transition = plugin.borrow_mut().as_mut().getTransition1(i.into());
+                Rc::new(Transition::from_ptr(transition))
+            })
+            .collect();
+
+        Self {
+            inner: plugin,
+            qualitative_species: RefCell::new(qualitative_species),
+            transitions: RefCell::new(transitions),
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! This module provides a safe Rust interface to the libSBML QualitativeSpecies class.
+//!
+//! The QualitativeSpecies class of the qual package represents an entity of a logical
+//! model, e.g. a gene or protein, whose activity is described by a discrete level
+//! between zero and its maximum level. Boolean models use the levels 0 and 1.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML QualitativeSpecies class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use autocxx::c_int;
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    qual::plugin::QualModelPlugin,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML QualitativeSpecies class.
+///
+/// This struct maintains a reference to the underlying C++ QualitativeSpecies object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct QualitativeSpecies<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::QualitativeSpecies>>,
+}
+
+// Set the inner trait for the QualitativeSpecies struct
+inner!(sbmlcxx::QualitativeSpecies, QualitativeSpecies<'a>);
+
+// Set the annotation trait for the QualitativeSpecies struct
+upcast_annotation!(
+    QualitativeSpecies<'a>,
+    sbmlcxx::QualitativeSpecies,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the QualitativeSpecies struct
+sbase!(QualitativeSpecies<'a>, sbmlcxx::QualitativeSpecies);
+
+impl<'a> QualitativeSpecies<'a> {
+    /// Creates a new QualitativeSpecies instance within the given qual model plugin.
+    ///
+    /// New qualitative species are not constant.
+    ///
+    /// # Arguments
+    /// * `plugin` - The qual plugin of the model that will contain this species
+    /// * `id` - The identifier for this species
+    /// * `compartment` - The identifier of the compartment containing the species
+    ///
+    /// # Returns
+    /// A new QualitativeSpecies instance
+    pub fn new(plugin: &QualModelPlugin<'a>, id: &str, compartment: &str) -> Self {
+        let species_ptr = plugin
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createQualitativeSpecies();
+        let mut species = pin_ptr!(species_ptr, sbmlcxx::QualitativeSpecies);
+
+        let_cxx_string!(id = id);
+        let_cxx_string!(compartment = compartment);
+        species.as_mut().setId(&id);
+        species.as_mut().setCompartment(&compartment);
+        species.as_mut().setConstant(false);
+
+        Self {
+            inner: RefCell::new(species),
+        }
+    }
+
+    /// Returns the id of the species.
+    ///
+    /// # Returns
+    /// The id of the species as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the species.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the species.
+    ///
+    /// # Returns
+    /// The name of the species as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the species.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Returns the identifier of the compartment containing the species.
+    ///
+    /// # Returns
+    /// The compartment identifier as a String
+    pub fn compartment(&self) -> String {
+        self.inner
+            .borrow()
+            .getCompartment()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the compartment containing the species.
+    ///
+    /// # Arguments
+    /// * `compartment` - The compartment identifier
+    pub fn set_compartment(&self, compartment: &str) {
+        let_cxx_string!(compartment = compartment);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setCompartment(&compartment);
+    }
+
+    /// Returns whether the level of the species is fixed.
+    ///
+    /// # Returns
+    /// true if no transition may change the level, false otherwise
+    pub fn constant(&self) -> bool {
+        self.inner.borrow().getConstant()
+    }
+
+    /// Sets whether the level of the species is fixed.
+    ///
+    /// # Arguments
+    /// * `constant` - Whether the species is constant
+    pub fn set_constant(&self, constant: bool) {
+        self.inner.borrow_mut().as_mut().setConstant(constant);
+    }
+
+    /// Returns the level of the species at the start of a simulation.
+    ///
+    /// # Returns
+    /// The initial level, None if not set
+    pub fn initial_level(&self) -> Option<i32> {
+        let species = self.inner.borrow();
+        species
+            .isSetInitialLevel()
+            .then(|| species.getInitialLevel().into())
+    }
+
+    /// Sets the level of the species at the start of a simulation.
+    ///
+    /// # Arguments
+    /// * `level` - The initial level
+    pub fn set_initial_level(&self, level: i32) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setInitialLevel(c_int::from(level));
+    }
+
+    /// Returns the highest level the species can reach.
+    ///
+    /// # Returns
+    /// The maximum level, None if not set
+    pub fn max_level(&self) -> Option<i32> {
+        let species = self.inner.borrow();
+        species
+            .isSetMaxLevel()
+            .then(|| species.getMaxLevel().into())
+    }
+
+    /// Sets the highest level the species can reach.
+    ///
+    /// # Arguments
+    /// * `level` - The maximum level, 1 for Boolean species
+    pub fn set_max_level(&self, level: i32) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setMaxLevel(c_int::from(level));
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::QualitativeSpecies, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::QualitativeSpecies> for QualitativeSpecies<'_> {
+    /// Creates a new QualitativeSpecies instance from a unique pointer to a libSBML
+    /// QualitativeSpecies.
+    ///
+    /// This method is primarily used internally by the QualModelPlugin class to create
+    /// QualitativeSpecies instances from libSBML QualitativeSpecies pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML QualitativeSpecies
+    ///
+    /// # Returns
+    /// A new QualitativeSpecies instance
+    fn from_ptr(ptr: *mut sbmlcxx::QualitativeSpecies) -> Self {
+        let species = pin_ptr!(ptr, sbmlcxx::QualitativeSpecies);
+        Self {
+            inner: RefCell::new(species),
+        }
+    }
+}
+
+/// A builder for creating QualitativeSpecies instances with a fluent interface.
+pub struct QualitativeSpeciesBuilder<'a> {
+    species: Rc<QualitativeSpecies<'a>>,
+}
+
+impl<'a> QualitativeSpeciesBuilder<'a> {
+    /// Creates a new QualitativeSpeciesBuilder instance.
+    ///
+    /// # Arguments
+    /// * `plugin` - The qual plugin of the model that will contain the species
+    /// * `id` - The identifier for the species
+    /// * `compartment` - The identifier of the compartment containing the species
+    ///
+    /// # Returns
+    /// A new QualitativeSpeciesBuilder instance
+    pub fn new(plugin: &QualModelPlugin<'a>, id: &str, compartment: &str) -> Self {
+        let species = plugin.create_qualitative_species(id, compartment);
+        Self { species }
+    }
+
+    /// Sets the name of the species.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.species.set_name(name);
+        self
+    }
+
+    /// Sets whether the level of the species is fixed.
+    ///
+    /// # Arguments
+    /// * `constant` - Whether the species is constant
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn constant(self, constant: bool) -> Self {
+        self.species.set_constant(constant);
+        self
+    }
+
+    /// Sets the level of the species at the start of a simulation.
+    ///
+    /// # Arguments
+    /// * `level` - The initial level
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn initial_level(self, level: i32) -> Self {
+        self.species.set_initial_level(level);
+        self
+    }
+
+    /// Sets the highest level the species can reach.
+    ///
+    /// # Arguments
+    /// * `level` - The maximum level, 1 for Boolean species
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn max_level(self, level: i32) -> Self {
+        self.species.set_max_level(level);
+        self
+    }
+
+    /// Sets the annotation string for this species.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.species
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.species.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured QualitativeSpecies.
+    ///
+    /// # Returns
+    /// The constructed QualitativeSpecies instance wrapped in an Rc
+    pub fn build(self) -> Rc<QualitativeSpecies<'a>> {
+        self.species
+    }
+}
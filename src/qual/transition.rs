@@ -0,0 +1,419 @@
+//! This module provides a safe Rust interface to the libSBML Transition class.
+//!
+//! The Transition class of the qual package describes how the levels of its output
+//! species change depending on the levels of its input species. The function terms
+//! are checked in order and the first one whose condition holds gives the result
+//! level; if none holds, the default term applies.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Transition class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    qual::{
+        defaultterm::DefaultTerm,
+        functionterm::FunctionTerm,
+        input::{Input, InputSign},
+        output::{Output, OutputEffect},
+        plugin::QualModelPlugin,
+    },
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Transition class.
+///
+/// This struct maintains a reference to the underlying C++ Transition object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the inputs, outputs and terms of the transition.
+pub struct Transition<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Transition>>,
+    inputs: RefCell<Vec<Rc<Input<'a>>>>,
+    outputs: RefCell<Vec<Rc<Output<'a>>>>,
+    function_terms: RefCell<Vec<Rc<FunctionTerm<'a>>>>,
+    default_term: RefCell<Option<Rc<DefaultTerm<'a>>>>,
+}
+
+// Set the inner trait for the Transition struct
+inner!(sbmlcxx::Transition, Transition<'a>);
+
+// Set the annotation trait for the Transition struct
+upcast_annotation!(Transition<'a>, sbmlcxx::Transition, sbmlcxx::SBase);
+
+// Set the SBase trait for the Transition struct
+sbase!(Transition<'a>, sbmlcxx::Transition);
+
+impl<'a> Transition<'a> {
+    /// Creates a new Transition instance within the given qual model plugin.
+    ///
+    /// # Arguments
+    /// * `plugin` - The qual plugin of the model that will contain this transition
+    /// * `id` - The identifier for this transition
+    ///
+    /// # Returns
+    /// A new Transition instance
+    pub fn new(plugin: &QualModelPlugin<'a>, id: &str) -> Self {
+        let transition_ptr = plugin.inner().borrow_mut().as_mut().createTransition();
+        let mut transition = pin_ptr!(transition_ptr, sbmlcxx::Transition);
+
+        let_cxx_string!(id = id);
+        transition.as_mut().setId(&id);
+
+        Self {
+            inner: RefCell::new(transition),
+            inputs: RefCell::new(Vec::new()),
+            outputs: RefCell::new(Vec::new()),
+            function_terms: RefCell::new(Vec::new()),
+            default_term: RefCell::new(None),
+        }
+    }
+
+    /// Returns a reference to the inner RefCell containing the Transition pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::Transition>> {
+        &self.inner
+    }
+
+    /// Returns the id of the transition.
+    ///
+    /// # Returns
+    /// The id of the transition as a String
+    pub fn id(&self) -> String {
+        self.inner.borrow().getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the transition.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().setId(&id);
+    }
+
+    /// Returns the name of the transition.
+    ///
+    /// # Returns
+    /// The name of the transition as a String
+    pub fn name(&self) -> String {
+        self.inner.borrow().getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the transition.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        self.inner.borrow_mut().as_mut().setName(&name);
+    }
+
+    /// Adds an input species to the transition.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the qualitative species
+    /// * `sign` - The influence of the species on the transition
+    ///
+    /// # Returns
+    /// The newly created Input wrapped in an Rc
+    pub fn create_input(&self, species: &str, sign: InputSign) -> Rc<Input<'a>> {
+        let input = Rc::new(Input::new(self, species, sign));
+        self.inputs.borrow_mut().push(Rc::clone(&input));
+        input
+    }
+
+    /// Returns a vector of all inputs of the transition.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Inputs of the transition
+    pub fn list_of_inputs(&self) -> Vec<Rc<Input<'a>>> {
+        self.inputs.borrow().to_vec()
+    }
+
+    /// Retrieves an input by the species it refers to.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the qualitative species
+    ///
+    /// # Returns
+    /// Some(Rc<Input>) if found, None if not found
+    pub fn get_input(&self, species: &str) -> Option<Rc<Input<'a>>> {
+        self.inputs
+            .borrow()
+            .iter()
+            .find(|input| (*input).qualitative_species() == species)
+            .map(Rc::clone)
+    }
+
+    /// Adds an output species to the transition.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the qualitative species
+    /// * `effect` - How the transition changes the level of the species
+    ///
+    /// # Returns
+    /// The newly created Output wrapped in an Rc
+    pub fn create_output(&self, species: &str, effect: OutputEffect) -> Rc<Output<'a>> {
+        let output = Rc::new(Output::new(self, species, effect));
+        self.outputs.borrow_mut().push(Rc::clone(&output));
+        output
+    }
+
+    /// Returns a vector of all outputs of the transition.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Outputs of the transition
+    pub fn list_of_outputs(&self) -> Vec<Rc<Output<'a>>> {
+        self.outputs.borrow().to_vec()
+    }
+
+    /// Retrieves an output by the species it refers to.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the qualitative species
+    ///
+    /// # Returns
+    /// Some(Rc<Output>) if found, None if not found
+    pub fn get_output(&self, species: &str) -> Option<Rc<Output<'a>>> {
+        self.outputs
+            .borrow()
+            .iter()
+            .find(|output| (*output).qualitative_species() == species)
+            .map(Rc::clone)
+    }
+
+    /// Appends a function term to the transition.
+    ///
+    /// # Arguments
+    /// * `result_level` - The level of the outputs while the condition holds
+    /// * `formula` - The condition, referring to inputs or qualitative species
+    ///
+    /// # Returns
+    /// The newly created FunctionTerm wrapped in an Rc
+    pub fn create_function_term(&self, result_level: i32, formula: &str) -> Rc<FunctionTerm<'a>> {
+        let term = Rc::new(FunctionTerm::new(self, result_level, formula));
+        self.function_terms.borrow_mut().push(Rc::clone(&term));
+        term
+    }
+
+    /// Returns a vector of all function terms of the transition, in evaluation order.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all FunctionTerms of the transition
+    pub fn list_of_function_terms(&self) -> Vec<Rc<FunctionTerm<'a>>> {
+        self.function_terms.borrow().to_vec()
+    }
+
+    /// Returns the default term of the transition.
+    ///
+    /// # Returns
+    /// Some(Rc<DefaultTerm>) if set, None otherwise
+    pub fn default_term(&self) -> Option<Rc<DefaultTerm<'a>>> {
+        self.default_term.borrow().as_ref().map(Rc::clone)
+    }
+
+    /// Sets the level of the outputs if no function term is satisfied.
+    ///
+    /// The default term is created if needed, otherwise its result level is updated.
+    ///
+    /// # Arguments
+    /// * `result_level` - The result level of the default term
+    ///
+    /// # Returns
+    /// The DefaultTerm of the transition wrapped in an Rc
+    pub fn create_default_term(&self, result_level: i32) -> Rc<DefaultTerm<'a>> {
+        if let Some(term) = self.default_term() {
+            term.set_result_level(result_level);
+            return term;
+        }
+
+        let term = Rc::new(DefaultTerm::new(self, result_level));
+        self.default_term.replace(Some(Rc::clone(&term)));
+        term
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Transition, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Transition> for Transition<'_> {
+    /// Creates a new Transition instance from a unique pointer to a libSBML Transition.
+    ///
+    /// This method is primarily used internally by the QualModelPlugin class to create
+    /// Transition instances from libSBML Transition pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Transition
+    ///
+    /// # Returns
+    /// A new Transition instance
+    fn from_ptr(ptr: *mut sbmlcxx::Transition) -> Self {
+        let transition = RefCell::new(pin_ptr!(ptr, sbmlcxx::Transition));
+
+        // Fetch all inputs
+        let n_inputs = transition.borrow().getNumInputs().0;
+        let inputs: Vec<_> = (0..n_inputs)
+            .map(|i| {
+                let input = transition.borrow_mut().as_mut().getInput1(i.into());
+                Rc::new(Input::from_ptr(input))
+            })
+            .collect();
+
+        // Fetch all outputs
+        let n_outputs = transition.borrow().getNumOutputs().0;
+        let outputs: Vec<_> = (0..n_outputs)
+            .map(|i| {
+                let output = transition.borrow_mut().as_mut().getOutput1(i.into());
+                Rc::new(Output::from_ptr(output))
+            })
+            .collect();
+
+        // Fetch all function terms
+        let n_terms = transition.borrow().getNumFunctionTerms().0;
+        let function_terms: Vec<_> = (0..n_terms)
+            .map(|i| {
+                let term = transition.borrow_mut().as_mut().getFunctionTerm1(i.into());
+                Rc::new(FunctionTerm::from_ptr(term))
+            })
+            .collect();
+
+        // Fetch the default term
+        let default_term = transition.borrow().isSetDefaultTerm().then(|| {
+            let term = transition.borrow_mut().as_mut().getDefaultTerm1();
+            Rc::new(DefaultTerm::from_ptr(term))
+        });
+
+        Self {
+            inner: transition,
+            inputs: RefCell::new(inputs),
+            outputs: RefCell::new(outputs),
+            function_terms: RefCell::new(function_terms),
+            default_term: RefCell::new(default_term),
+        }
+    }
+}
+
+/// A builder for creating Transition instances with a fluent interface.
+pub struct TransitionBuilder<'a> {
+    transition: Rc<Transition<'a>>,
+}
+
+impl<'a> TransitionBuilder<'a> {
+    /// Creates a new TransitionBuilder instance.
+    ///
+    /// # Arguments
+    /// * `plugin` - The qual plugin of the model that will contain the transition
+    /// * `id` - The identifier for the transition
+    ///
+    /// # Returns
+    /// A new TransitionBuilder instance
+    pub fn new(plugin: &QualModelPlugin<'a>, id: &str) -> Self {
+        let transition = plugin.create_transition(id);
+        Self { transition }
+    }
+
+    /// Sets the name of the transition.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.transition.set_name(name);
+        self
+    }
+
+    /// Adds an input species to the transition.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the qualitative species
+    /// * `sign` - The influence of the species on the transition
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn input(self, species: &str, sign: InputSign) -> Self {
+        self.transition.create_input(species, sign);
+        self
+    }
+
+    /// Adds an output species whose level is assigned by the transition.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the qualitative species
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn output(self, species: &str) -> Self {
+        self.transition
+            .create_output(species, OutputEffect::AssignmentLevel);
+        self
+    }
+
+    /// Appends a function term to the transition.
+    ///
+    /// # Arguments
+    /// * `result_level` - The level of the outputs while the condition holds
+    /// * `formula` - The condition, referring to inputs or qualitative species
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn function_term(self, result_level: i32, formula: &str) -> Self {
+        self.transition.create_function_term(result_level, formula);
+        self
+    }
+
+    /// Sets the level of the outputs if no function term is satisfied.
+    ///
+    /// # Arguments
+    /// * `result_level` - The result level of the default term
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn default_level(self, result_level: i32) -> Self {
+        self.transition.create_default_term(result_level);
+        self
+    }
+
+    /// Sets the annotation string for this transition.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.transition
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.transition.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Transition.
+    ///
+    /// # Returns
+    /// The constructed Transition instance wrapped in an Rc
+    pub fn build(self) -> Rc<Transition<'a>> {
+        self.transition
+    }
+}
@@ -13,10 +13,11 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use cxx::let_cxx_string;
 
 use crate::{
-    inner,
+    comp_replacements, inner,
+    kineticlaw::KineticLaw,
     model::Model,
     modref::{ModifierSpeciesReference, ModifierSpeciesReferenceBuilder},
-    pin_ptr,
+    pin_ptr, sbase,
     sbmlcxx::{self},
     sbo_term,
     speciesref::{SpeciesReference, SpeciesReferenceBuilder, SpeciesReferenceType},
@@ -24,16 +25,25 @@ use crate::{
     upcast_annotation,
 };
 
+#[cfg(feature = "fbc")]
+use crate::{
+    fbc::association::{GeneProductAssociation, GeneRule},
+    packages::{get_plugin, Package},
+    upcast,
+};
+
 /// A safe wrapper around the libSBML Reaction class.
 ///
 /// This struct maintains a reference to the underlying C++ Reaction object
 /// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
-/// It also maintains vectors of reactants and products associated with the reaction.
+/// It also maintains vectors of reactants and products associated with the reaction,
+/// as well as its kinetic law.
 pub struct Reaction<'a> {
     inner: RefCell<Pin<&'a mut sbmlcxx::Reaction>>,
     reactants: RefCell<Vec<Rc<SpeciesReference<'a>>>>,
     products: RefCell<Vec<Rc<SpeciesReference<'a>>>>,
     modifiers: RefCell<Vec<Rc<ModifierSpeciesReference<'a>>>>,
+    kinetic_law: RefCell<Option<Rc<KineticLaw<'a>>>>,
 }
 
 // Set the inner trait for the Reaction struct
@@ -42,6 +52,9 @@ inner!(sbmlcxx::Reaction, Reaction<'a>);
 // Set the annotation trait for the Reaction struct
 upcast_annotation!(Reaction<'a>, sbmlcxx::Reaction, sbmlcxx::SBase);
 
+// Set the SBase trait for the Reaction struct
+sbase!(Reaction<'a>, sbmlcxx::Reaction);
+
 impl<'a> Reaction<'a> {
     /// Creates a new Reaction instance within the given Model.
     ///
@@ -64,6 +77,7 @@ impl<'a> Reaction<'a> {
             reactants: RefCell::new(Vec::new()),
             products: RefCell::new(Vec::new()),
             modifiers: RefCell::new(Vec::new()),
+            kinetic_law: RefCell::new(None),
         }
     }
 
@@ -108,6 +122,65 @@ impl<'a> Reaction<'a> {
         self.inner.borrow_mut().as_mut().setName(&name);
     }
 
+    /// Returns whether the reaction can proceed in both directions.
+    ///
+    /// # Returns
+    /// true if the reaction is reversible, false otherwise
+    pub fn reversible(&self) -> bool {
+        self.inner.borrow().getReversible()
+    }
+
+    /// Sets whether the reaction can proceed in both directions.
+    ///
+    /// # Arguments
+    /// * `reversible` - Whether the reaction is reversible
+    pub fn set_reversible(&self, reversible: bool) {
+        self.inner.borrow_mut().as_mut().setReversible(reversible);
+    }
+
+    /// Returns whether the reaction is fast compared to the other reactions.
+    ///
+    /// The attribute is only part of SBML Level 3 Version 1 and earlier.
+    ///
+    /// # Returns
+    /// true if the reaction is fast, false otherwise
+    pub fn fast(&self) -> bool {
+        self.inner.borrow().getFast()
+    }
+
+    /// Sets whether the reaction is fast compared to the other reactions.
+    ///
+    /// # Arguments
+    /// * `fast` - Whether the reaction is fast
+    pub fn set_fast(&self, fast: bool) {
+        self.inner.borrow_mut().as_mut().setFast(fast);
+    }
+
+    /// Returns the compartment in which the reaction takes place.
+    ///
+    /// # Returns
+    /// The identifier of the compartment, empty if not set
+    pub fn compartment(&self) -> String {
+        self.inner
+            .borrow()
+            .getCompartment()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the compartment in which the reaction takes place.
+    ///
+    /// # Arguments
+    /// * `compartment` - The identifier of the compartment
+    pub fn set_compartment(&self, compartment: &str) {
+        let_cxx_string!(compartment = compartment);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setCompartment(&compartment);
+    }
+
     /// Creates a new product species reference for this reaction.
     ///
     /// # Arguments
@@ -260,8 +333,134 @@ impl<'a> Reaction<'a> {
             .map(|modifier| Rc::clone(modifier))
     }
 
+    /// Returns the kinetic law of this reaction.
+    ///
+    /// # Returns
+    /// Some(Rc<KineticLaw>) if the reaction has a kinetic law, None otherwise
+    pub fn kinetic_law(&self) -> Option<Rc<KineticLaw<'a>>> {
+        self.kinetic_law.borrow().as_ref().map(Rc::clone)
+    }
+
+    /// Creates the kinetic law of this reaction, replacing an existing one.
+    ///
+    /// # Arguments
+    /// * `formula` - The rate expression of the reaction
+    ///
+    /// # Returns
+    /// A reference-counted pointer to the new KineticLaw
+    pub fn create_kinetic_law(&self, formula: &str) -> Rc<KineticLaw<'a>> {
+        let kinetic_law = Rc::new(KineticLaw::new(self, formula));
+        self.kinetic_law
+            .borrow_mut()
+            .replace(Rc::clone(&kinetic_law));
+        kinetic_law
+    }
+
+    /// Returns the fbc plugin of the reaction.
+    ///
+    /// # Arguments
+    /// * `enable` - Whether to enable the fbc package on the document if needed
+    #[cfg(feature = "fbc")]
+    pub(crate) fn fbc_plugin(
+        &self,
+        enable: bool,
+    ) -> Option<Pin<&'a mut sbmlcxx::FbcReactionPlugin>> {
+        let base = upcast!(self, sbmlcxx::Reaction, sbmlcxx::SBase);
+        get_plugin(base, Package::Fbc, enable)
+    }
+
+    /// Returns the parameter holding the lower flux bound (fbc package).
+    ///
+    /// # Returns
+    /// The identifier of the parameter, None if not set
+    #[cfg(feature = "fbc")]
+    pub fn lower_flux_bound(&self) -> Option<String> {
+        let plugin = self.fbc_plugin(false)?;
+        if !plugin.isSetLowerFluxBound() {
+            return None;
+        }
+        Some(plugin.getLowerFluxBound().to_str().unwrap().to_string())
+    }
+
+    /// Sets the parameter holding the lower flux bound (fbc package).
+    ///
+    /// The fbc package is enabled on the document if needed.
+    ///
+    /// # Arguments
+    /// * `parameter` - The identifier of a constant parameter
+    #[cfg(feature = "fbc")]
+    pub fn set_lower_flux_bound(&self, parameter: &str) {
+        if let Some(mut plugin) = self.fbc_plugin(true) {
+            let_cxx_string!(parameter = parameter);
+            plugin.as_mut().setLowerFluxBound(&parameter);
+        }
+    }
+
+    /// Returns the parameter holding the upper flux bound (fbc package).
+    ///
+    /// # Returns
+    /// The identifier of the parameter, None if not set
+    #[cfg(feature = "fbc")]
+    pub fn upper_flux_bound(&self) -> Option<String> {
+        let plugin = self.fbc_plugin(false)?;
+        if !plugin.isSetUpperFluxBound() {
+            return None;
+        }
+        Some(plugin.getUpperFluxBound().to_str().unwrap().to_string())
+    }
+
+    /// Sets the parameter holding the upper flux bound (fbc package).
+    ///
+    /// The fbc package is enabled on the document if needed.
+    ///
+    /// # Arguments
+    /// * `parameter` - The identifier of a constant parameter
+    #[cfg(feature = "fbc")]
+    pub fn set_upper_flux_bound(&self, parameter: &str) {
+        if let Some(mut plugin) = self.fbc_plugin(true) {
+            let_cxx_string!(parameter = parameter);
+            plugin.as_mut().setUpperFluxBound(&parameter);
+        }
+    }
+
+    /// Returns the gene product association of the reaction (fbc package).
+    ///
+    /// # Returns
+    /// Some(Rc<GeneProductAssociation>) if the reaction has one, None otherwise
+    #[cfg(feature = "fbc")]
+    pub fn gene_product_association(&self) -> Option<Rc<GeneProductAssociation<'a>>> {
+        let mut plugin = self.fbc_plugin(false)?;
+        if !plugin.isSetGeneProductAssociation() {
+            return None;
+        }
+
+        let association = plugin.as_mut().getGeneProductAssociation1();
+        Some(Rc::new(GeneProductAssociation::from_ptr(association)))
+    }
+
+    /// Creates the gene product association of the reaction (fbc package).
+    ///
+    /// An existing association is replaced. The fbc package is enabled on the
+    /// document if needed.
+    ///
+    /// # Arguments
+    /// * `rule` - The gene products required by the reaction
+    ///
+    /// # Returns
+    /// The newly created GeneProductAssociation wrapped in an Rc
+    #[cfg(feature = "fbc")]
+    pub fn create_gene_product_association(
+        &self,
+        rule: &GeneRule,
+    ) -> Rc<GeneProductAssociation<'a>> {
+        Rc::new(GeneProductAssociation::new(self, rule))
+    }
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Reaction, sbmlcxx::SBase);
+
+    // Replacement Methods generated by the `comp_replacements` macro
+    comp_replacements!(sbmlcxx::Reaction);
 }
 
 impl FromPtr<sbmlcxx::Reaction> for Reaction<'_> {
@@ -276,12 +475,48 @@ impl FromPtr<sbmlcxx::Reaction> for Reaction<'_> {
     /// # Returns
     /// A new Reaction instance
     fn from_ptr(ptr: *mut sbmlcxx::Reaction) -> Self {
-        let reaction = pin_ptr!(ptr, sbmlcxx::Reaction);
+        let reaction = RefCell::new(pin_ptr!(ptr, sbmlcxx::Reaction));
+
+        // Fetch all reactants
+        let n_reactants = reaction.borrow().getNumReactants().0;
+        let reactants: Vec<_> = (0..n_reactants)
+            .map(|i| {
+                let reactant = reaction.borrow_mut().as_mut().getReactant1(i.into());
+                Rc::new(SpeciesReference::from_ptr(reactant))
+            })
+            .collect();
+
+        // Fetch all products
+        let n_products = reaction.borrow().getNumProducts().0;
+        let products: Vec<_> = (0..n_products)
+            .map(|i| {
+                let product = reaction.borrow_mut().as_mut().getProduct1(i.into());
+                Rc::new(SpeciesReference::from_ptr(product))
+            })
+            .collect();
+
+        // Fetch all modifiers
+        let n_modifiers = reaction.borrow().getNumModifiers().0;
+        let modifiers: Vec<_> = (0..n_modifiers)
+            .map(|i| {
+                let modifier = reaction.borrow_mut().as_mut().getModifier1(i.into());
+                Rc::new(ModifierSpeciesReference::from_ptr(modifier))
+            })
+            .collect();
+
+        let kinetic_law = if reaction.borrow().isSetKineticLaw() {
+            let kinetic_law = reaction.borrow_mut().as_mut().getKineticLaw1();
+            Some(Rc::new(KineticLaw::from_ptr(kinetic_law)))
+        } else {
+            None
+        };
+
         Self {
-            inner: RefCell::new(reaction),
-            reactants: RefCell::new(Vec::new()),
-            products: RefCell::new(Vec::new()),
-            modifiers: RefCell::new(Vec::new()),
+            inner: reaction,
+            reactants: RefCell::new(reactants),
+            products: RefCell::new(products),
+            modifiers: RefCell::new(modifiers),
+            kinetic_law: RefCell::new(kinetic_law),
         }
     }
 }
@@ -316,6 +551,18 @@ impl<'a> ReactionBuilder<'a> {
         self
     }
 
+    /// Sets whether the reaction being built is reversible.
+    ///
+    /// # Arguments
+    /// * `reversible` - Whether the reaction is reversible
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn reversible(self, reversible: bool) -> Self {
+        self.reaction.set_reversible(reversible);
+        self
+    }
+
     /// Adds a product to the reaction being built.
     ///
     /// # Arguments
@@ -353,6 +600,62 @@ impl<'a> ReactionBuilder<'a> {
         self.reaction.create_modifier(sid);
         self
     }
+
+    /// Sets the kinetic law of the reaction being built.
+    ///
+    /// # Arguments
+    /// * `formula` - The rate expression of the reaction
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn kinetic_law(self, formula: &str) -> Self {
+        self.reaction.create_kinetic_law(formula);
+        self
+    }
+
+    /// Sets the parameter holding the lower flux bound (fbc package).
+    ///
+    /// # Arguments
+    /// * `parameter` - The identifier of a constant parameter
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    #[cfg(feature = "fbc")]
+    pub fn lower_flux_bound(self, parameter: &str) -> Self {
+        self.reaction.set_lower_flux_bound(parameter);
+        self
+    }
+
+    /// Sets the parameter holding the upper flux bound (fbc package).
+    ///
+    /// # Arguments
+    /// * `parameter` - The identifier of a constant parameter
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    #[cfg(feature = "fbc")]
+    pub fn upper_flux_bound(self, parameter: &str) -> Self {
+        self.reaction.set_upper_flux_bound(parameter);
+        self
+    }
+
+    /// Sets the gene product association of the reaction (fbc package).
+    ///
+    /// # Arguments
+    /// * `rule` - The gene products required by the reaction
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    #[cfg(feature = "fbc")]
+    pub fn gene_product_association(self, rule: &GeneRule) -> Self {
+        self.reaction.create_gene_product_association(rule);
+        self
+    }
+
+    /// Builds and returns the configured Reaction.
+    ///
+    /// # Returns
+    /// The constructed Reaction instance wrapped in an Rc
     pub fn build(self) -> Rc<Reaction<'a>> {
         self.reaction
     }
@@ -374,6 +677,9 @@ mod tests {
 
         assert_eq!(reaction.id(), "test2");
         assert_eq!(reaction.name(), "test2");
+
+        reaction.set_reversible(false);
+        assert!(!reaction.reversible());
     }
 
     #[test]
@@ -541,6 +847,27 @@ mod tests {
         assert_eq!(modifiers.borrow().len(), 1);
     }
 
+    #[test]
+    fn test_reaction_read_from_xml() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = Model::new(&doc, "test");
+        ReactionBuilder::new(&model, "r1")
+            .reactant("A", 2.0)
+            .product("B", 1.0)
+            .modifier("E")
+            .kinetic_law("k * A")
+            .build();
+
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = read.model().expect("Model not found");
+        let reaction = model.get_reaction("r1").expect("Reaction not found");
+
+        assert_eq!(reaction.get_reactant("A").unwrap().stoichiometry(), 2.0);
+        assert_eq!(reaction.get_product("B").unwrap().stoichiometry(), 1.0);
+        assert!(reaction.get_modifier("E").is_some());
+        assert_eq!(reaction.kinetic_law().unwrap().formula(), "k * A");
+    }
+
     #[test]
     fn test_annotation() {
         let doc = SBMLDocument::new(3, 2);
@@ -7,7 +7,7 @@
 //! This wrapper provides safe access to the underlying C++ libSBML SBMLReader class while
 //! maintaining Rust's safety guarantees through the use of RefCell and Pin.
 
-use std::{cell::RefCell, pin::Pin};
+use std::{cell::RefCell, io, path::Path, pin::Pin};
 
 use autocxx::WithinBox;
 use cxx::{let_cxx_string, UniquePtr};
@@ -45,6 +45,35 @@ impl SBMLReader {
             unsafe { UniquePtr::from_raw(reader.0.borrow_mut().as_mut().readSBMLFromString(&xml)) };
         SBMLDocument::from_unique_ptr(ptr)
     }
+
+    /// Reads an SBML document from a file.
+    ///
+    /// Files compressed with gzip are decompressed if libSBML was built with zlib.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the SBML file
+    ///
+    /// # Returns
+    /// An SBMLDocument instance containing the parsed model, or an error if the file
+    /// cannot be opened
+    pub fn from_file<'a>(path: impl AsRef<Path>) -> io::Result<SBMLDocument<'a>> {
+        let path = path.as_ref();
+
+        // libSBML only reports unreadable files as errors of the returned document
+        std::fs::File::open(path)?;
+        let path = path.to_str().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Path is not valid UTF-8: {}", path.display()),
+            )
+        })?;
+
+        let reader = Self::new();
+        let_cxx_string!(path = path);
+        let ptr =
+            unsafe { UniquePtr::from_raw(reader.0.borrow_mut().as_mut().readSBMLFromFile(&path)) };
+        Ok(SBMLDocument::from_unique_ptr(ptr))
+    }
 }
 
 impl Default for SBMLReader {
@@ -120,5 +149,28 @@ mod tests {
         // There are 0 assignment rules
         let list_of_assignment_rules = model.list_of_assignment_rules();
         assert_eq!(list_of_assignment_rules.len(), 0);
+
+        // The model sets its volume units only
+        assert_eq!(model.volume_units(), "u5");
+        assert_eq!(model.time_units(), "");
+
+        // The units of the unit definitions are read as well
+        let units = model.get_unit_definition("u6").unwrap().units();
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].scale(), -3);
+    }
+
+    #[test]
+    fn test_read_sbml_from_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/example.xml");
+        let doc = SBMLReader::from_file(path).expect("Failed to read file");
+        let model = doc.model().expect("Model not found");
+        assert_eq!(model.id(), "example");
+        assert_eq!(model.list_of_species().len(), 2);
+
+        let error = SBMLReader::from_file("tests/data/missing.xml")
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
     }
 }
@@ -0,0 +1,428 @@
+//! Renaming of identifiers across a model.
+//!
+//! Setting the identifier of an element, e.g. with [`Species::set_id`], leaves all
+//! references to the old identifier in place. [`Model::rename_sid`] and
+//! [`Model::rename_unit_sid`] rename an element and rewrite every reference to it,
+//! including the `ci` symbols of all math, by applying libSBML's `renameSIdRefs` and
+//! `renameUnitSIdRefs` to every element of the model.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLReader::from_file("model.xml").unwrap();
+//! let model = doc.model().unwrap();
+//! model.rename_sid("s1", "glucose").unwrap();
+//! model.rename_unit_sid("u5", "litre_per_second").unwrap();
+//! ```
+//!
+//! [`Species::set_id`]: crate::species::Species::set_id
+
+use std::{collections::HashSet, error::Error, fmt, pin::Pin, str::FromStr};
+
+use cxx::{let_cxx_string, UniquePtr};
+
+use crate::{
+    model::Model,
+    pin_ptr, sbmlcxx,
+    traits::{
+        inner::Inner,
+        sbase::{self, SBase},
+    },
+    unit::UnitKind,
+    upcast,
+};
+
+/// Errors that can occur while renaming an identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// The new identifier is not a valid SId or UnitSId
+    InvalidId(String),
+    /// No element of the model has the old identifier
+    NotFound(String),
+    /// The new identifier is already used by another element
+    AlreadyUsed(String),
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenameError::InvalidId(id) => write!(f, "'{}' is not a valid identifier", id),
+            RenameError::NotFound(id) => write!(f, "No element has the identifier '{}'", id),
+            RenameError::AlreadyUsed(id) => {
+                write!(f, "Identifier '{}' is already used by another element", id)
+            }
+        }
+    }
+}
+
+impl Error for RenameError {}
+
+/// Renames the element with the SId `old` and rewrites all references to it, see
+/// [`Model::rename_sid`].
+pub(crate) fn rename_sid(model: &Model, old: &str, new: &str) -> Result<(), RenameError> {
+    if !is_valid_sid(new) {
+        return Err(RenameError::InvalidId(new.to_string()));
+    }
+    if old == new {
+        return Ok(());
+    }
+    if set_id(model, new, None) {
+        return Err(RenameError::AlreadyUsed(new.to_string()));
+    }
+    if !set_id(model, old, Some(new)) {
+        return Err(RenameError::NotFound(old.to_string()));
+    }
+
+    let_cxx_string!(old_id = old);
+    let_cxx_string!(new_id = new);
+    rename_references(model, Some(old), |mut base| {
+        base.as_mut().renameSIdRefs(&old_id, &new_id);
+    });
+
+    Ok(())
+}
+
+/// Renames the unit definition with the UnitSId `old` and rewrites all references to
+/// it, see [`Model::rename_unit_sid`].
+pub(crate) fn rename_unit_sid(model: &Model, old: &str, new: &str) -> Result<(), RenameError> {
+    // Base units are predefined and cannot be redefined
+    if !is_valid_sid(new) || UnitKind::from_str(new).is_ok() {
+        return Err(RenameError::InvalidId(new.to_string()));
+    }
+    if old == new {
+        return Ok(());
+    }
+    if model.get_unit_definition(new).is_some() {
+        return Err(RenameError::AlreadyUsed(new.to_string()));
+    }
+    let Some(unit_definition) = model.get_unit_definition(old) else {
+        return Err(RenameError::NotFound(old.to_string()));
+    };
+    unit_definition.set_id(new);
+
+    let_cxx_string!(old_id = old);
+    let_cxx_string!(new_id = new);
+    rename_references(model, None, |mut base| {
+        base.as_mut().renameUnitSIdRefs(&old_id, &new_id);
+    });
+
+    Ok(())
+}
+
+/// Returns whether an identifier follows the SId syntax of the SBML specification.
+fn is_valid_sid(id: &str) -> bool {
+    let mut chars = id.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Finds the element with an SId in the model and optionally sets its identifier.
+///
+/// Local parameters are not considered, as their identifiers are scoped to their
+/// kinetic law.
+///
+/// # Returns
+/// Whether an element with the identifier exists
+fn set_id(model: &Model, id: &str, new: Option<&str>) -> bool {
+    macro_rules! find_and_set {
+        ($element:expr) => {
+            if let Some(element) = $element {
+                if let Some(new) = new {
+                    element.set_id(new);
+                }
+                return true;
+            }
+        };
+    }
+
+    if model.id() == id {
+        if let Some(new) = new {
+            model.set_id(new);
+        }
+        return true;
+    }
+    find_and_set!(model.get_compartment(id));
+    find_and_set!(model.get_species(id));
+    find_and_set!(model.get_parameter(id));
+    find_and_set!(model.get_reaction(id));
+    find_and_set!(model.get_event(id));
+
+    #[cfg(feature = "fbc")]
+    if let Some(plugin) = model.fbc_plugin_if_enabled() {
+        if let Some(objective) = plugin.get_objective(id) {
+            if let Some(new) = new {
+                objective.set_id(new);
+                if plugin.active_objective() == id {
+                    plugin.set_active_objective(new);
+                }
+            }
+            return true;
+        }
+        find_and_set!(plugin.get_gene_product(id));
+    }
+
+    false
+}
+
+/// Applies a renaming function to the model and every element it contains.
+///
+/// The elements are listed by libSBML's `getAllElements`, which includes the content
+/// of all packages, such as gene product references and layout glyphs.
+///
+/// # Arguments
+/// * `model` - The model to rename references in
+/// * `shadowed` - An SId that refers to a local parameter within kinetic laws
+///   defining a local parameter with this identifier, which are then skipped
+/// * `rename` - The function renaming the references of an element
+fn rename_references<F>(model: &Model, shadowed: Option<&str>, mut rename: F)
+where
+    F: FnMut(Pin<&mut sbmlcxx::SBase>),
+{
+    // Reactions whose kinetic law defines a local parameter with the identifier
+    let shadowing: HashSet<String> = shadowed
+        .map(|id| {
+            model
+                .list_of_reactions()
+                .into_iter()
+                .filter(|reaction| {
+                    reaction.kinetic_law().is_some_and(|kinetic_law| {
+                        kinetic_law
+                            .list_of_local_parameters()
+                            .iter()
+                            .any(|parameter| parameter.id() == id)
+                    })
+                })
+                .map(|reaction| reaction.id())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut base = upcast!(model, sbmlcxx::Model, sbmlcxx::SBase);
+    let elements_ptr = base.as_mut().getAllElements(std::ptr::null_mut());
+    rename(base);
+    if elements_ptr.is_null() {
+        return;
+    }
+
+    // The caller owns the returned list, but not the elements in it
+    let elements = unsafe { UniquePtr::from_raw(elements_ptr) };
+    for i in 0..elements.getSize().0 {
+        let element_ptr = elements.get(i.into()).cast::<sbmlcxx::SBase>();
+        let mut element = pin_ptr!(element_ptr, sbmlcxx::SBase);
+        if sbase::element_name(element.as_mut()) == "kineticLaw" {
+            let reaction = sbase::parent(element.as_mut()).map(|parent| parent.id());
+            if reaction.is_some_and(|id| shadowing.contains(&id)) {
+                continue;
+            }
+        }
+        rename(element);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{formula::Expr, prelude::*};
+
+    /// Builds a model referencing the species `glc` in many places
+    fn build_model<'a>(doc: &'a SBMLDocument<'a>) -> Rc<Model<'a>> {
+        let model = doc.create_model("glycolysis");
+        model.build_compartment("cytosol").size(1.0).build();
+        model
+            .build_species("glc")
+            .compartment("cytosol")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_species("g6p")
+            .compartment("cytosol")
+            .initial_concentration(0.0)
+            .build();
+        model.build_parameter("k1").value(0.1).build();
+        model.build_parameter("total").value(0.0).build();
+        model.build_assignment_rule("total", "glc + g6p").build();
+        model.build_initial_assignment("g6p", "glc / 10").build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .kinetic_law("k1 * glc")
+            .build();
+        model
+    }
+
+    /// Asserts that a formula is structurally equal to the expected one
+    fn assert_formula(formula: &str, expected: &str) {
+        assert_eq!(
+            Expr::parse(formula).unwrap(),
+            Expr::parse(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rename_species() {
+        let doc = SBMLDocument::default();
+        let model = build_model(&doc);
+
+        model.rename_sid("glc", "glucose").unwrap();
+
+        assert!(model.get_species("glc").is_none());
+        assert!(model.get_species("glucose").is_some());
+
+        let reaction = model.get_reaction("hk").unwrap();
+        assert_eq!(reaction.reactants().borrow()[0].species(), "glucose");
+        assert_formula(&reaction.kinetic_law().unwrap().formula(), "k1 * glucose");
+        assert_formula(
+            &model.get_assignment_rule("total").unwrap().formula(),
+            "glucose + g6p",
+        );
+        assert_formula(
+            &model.get_initial_assignment("g6p").unwrap().formula(),
+            "glucose / 10",
+        );
+    }
+
+    #[test]
+    fn test_rename_compartment_and_rule_variable() {
+        let doc = SBMLDocument::default();
+        let model = build_model(&doc);
+
+        model.rename_sid("cytosol", "cyt").unwrap();
+        model.rename_sid("total", "glc_total").unwrap();
+
+        for species in model.list_of_species() {
+            assert_eq!(species.compartment(), "cyt");
+        }
+        assert!(model.get_assignment_rule("glc_total").is_some());
+    }
+
+    #[test]
+    fn test_rename_errors() {
+        let doc = SBMLDocument::default();
+        let model = build_model(&doc);
+
+        assert_eq!(
+            model.rename_sid("glc", "g6p"),
+            Err(RenameError::AlreadyUsed("g6p".to_string()))
+        );
+        assert_eq!(
+            model.rename_sid("missing", "other"),
+            Err(RenameError::NotFound("missing".to_string()))
+        );
+        assert_eq!(
+            model.rename_sid("glc", "2glc"),
+            Err(RenameError::InvalidId("2glc".to_string()))
+        );
+        assert!(model.get_species("glc").is_some());
+    }
+
+    #[test]
+    fn test_rename_skips_shadowing_local_parameters() {
+        let doc = SBMLDocument::default();
+        let model = build_model(&doc);
+        let kinetic_law = model.get_reaction("hk").unwrap().kinetic_law().unwrap();
+        kinetic_law.build_local_parameter("k1").value(1.0).build();
+
+        model.rename_sid("k1", "k_hk").unwrap();
+
+        assert!(model.get_parameter("k_hk").is_some());
+        assert_eq!(kinetic_law.list_of_local_parameters()[0].id(), "k1");
+        assert_formula(&kinetic_law.formula(), "k1 * glc");
+    }
+
+    #[test]
+    fn test_rename_unit_sid() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("units");
+        model
+            .build_unit_definition("u1", "per second")
+            .unit(UnitKind::Second, Some(-1), Some(0), None, None)
+            .build();
+        model.build_parameter("k1").value(0.1).units("u1").build();
+
+        model.rename_unit_sid("u1", "per_second").unwrap();
+
+        assert!(model.get_unit_definition("per_second").is_some());
+        assert_eq!(model.get_parameter("k1").unwrap().units(), "per_second");
+        assert_eq!(
+            model.rename_unit_sid("per_second", "second"),
+            Err(RenameError::InvalidId("second".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fbc")]
+    fn test_rename_gene_product() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("genes");
+        let rule = GeneRule::And(vec![
+            GeneRule::Gene("g1".to_string()),
+            GeneRule::Gene("g2".to_string()),
+        ]);
+        model
+            .build_reaction("pfk")
+            .gene_product_association(&rule)
+            .build();
+        let fbc = model.fbc_plugin();
+        fbc.create_gene_product("g1", "b3916");
+        fbc.create_gene_product("g2", "b1723");
+
+        model.rename_sid("g1", "pfkA").unwrap();
+
+        assert!(fbc.get_gene_product("pfkA").is_some());
+        let association = model
+            .get_reaction("pfk")
+            .unwrap()
+            .gene_product_association()
+            .unwrap();
+        assert_eq!(
+            association.association(),
+            Some(GeneRule::And(vec![
+                GeneRule::Gene("pfkA".to_string()),
+                GeneRule::Gene("g2".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fbc")]
+    fn test_rename_reaction_with_flux_bound() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level3/version1/core" xmlns:fbc="http://www.sbml.org/sbml/level3/version1/fbc/version1" level="3" version="1" fbc:required="false">
+  <model id="m">
+    <listOfReactions>
+      <reaction id="uptake" reversible="true" fast="false"/>
+    </listOfReactions>
+    <fbc:listOfFluxBounds>
+      <fbc:fluxBound fbc:id="b1" fbc:reaction="uptake" fbc:operation="lessEqual" fbc:value="8"/>
+    </fbc:listOfFluxBounds>
+    <fbc:listOfObjectives fbc:activeObjective="obj">
+      <fbc:objective fbc:id="obj" fbc:type="maximize">
+        <fbc:listOfFluxObjectives>
+          <fbc:fluxObjective fbc:reaction="uptake" fbc:coefficient="1"/>
+        </fbc:listOfFluxObjectives>
+      </fbc:objective>
+    </fbc:listOfObjectives>
+  </model>
+</sbml>"#;
+        let doc = SBMLReader::from_xml_string(xml);
+        let model = doc.model().unwrap();
+
+        model.rename_sid("uptake", "glc_uptake").unwrap();
+
+        let fbc = model.fbc_plugin();
+        assert_eq!(fbc.list_of_flux_bounds()[0].reaction(), "glc_uptake");
+        let objective = fbc.get_objective("obj").unwrap();
+        assert_eq!(
+            objective.list_of_flux_objectives()[0].reaction(),
+            "glc_uptake"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! This module provides a safe Rust interface to the libSBML ColorDefinition class.
+//!
+//! The ColorDefinition class of the render package gives a name to a color, so that
+//! styles can refer to it by identifier instead of repeating its value. Values are
+//! written in hexadecimal notation, either as `#rrggbb` or with an alpha channel as
+//! `#rrggbbaa`.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML ColorDefinition class
+//! while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    render::info::RenderInformation,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML ColorDefinition class.
+///
+/// This struct maintains a reference to the underlying C++ ColorDefinition object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ColorDefinition<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::ColorDefinition>>,
+}
+
+// Set the inner trait for the ColorDefinition struct
+inner!(sbmlcxx::ColorDefinition, ColorDefinition<'a>);
+
+// Set the annotation trait for the ColorDefinition struct
+upcast_annotation!(
+    ColorDefinition<'a>,
+    sbmlcxx::ColorDefinition,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the ColorDefinition struct
+sbase!(ColorDefinition<'a>, sbmlcxx::ColorDefinition);
+
+impl<'a> ColorDefinition<'a> {
+    /// Creates a new ColorDefinition instance within the given RenderInformation.
+    ///
+    /// # Arguments
+    /// * `information` - The parent RenderInformation that will contain this color
+    /// * `id` - The identifier for this color
+    /// * `value` - The color in hexadecimal notation, e.g. `#ff0000`
+    ///
+    /// # Returns
+    /// A new ColorDefinition instance
+    pub fn new(information: &RenderInformation<'a>, id: &str, value: &str) -> Self {
+        let color_ptr = information.base().as_mut().createColorDefinition();
+        let color = pin_ptr!(color_ptr, sbmlcxx::ColorDefinition);
+
+        let color = Self {
+            inner: RefCell::new(color),
+        };
+        color.set_id(id);
+        color.set_value(value);
+        color
+    }
+
+    /// Returns the id of the color.
+    ///
+    /// # Returns
+    /// The id of the color as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::ColorDefinition, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the color.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::ColorDefinition, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the value of the color.
+    ///
+    /// # Returns
+    /// The color in hexadecimal notation, e.g. `#ff0000ff`
+    pub fn value(&self) -> String {
+        self.inner
+            .borrow()
+            .createValueString()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the value of the color.
+    ///
+    /// Values that are not in hexadecimal notation are ignored by libSBML.
+    ///
+    /// # Arguments
+    /// * `value` - The color in hexadecimal notation, e.g. `#ff0000`
+    pub fn set_value(&self, value: &str) {
+        let_cxx_string!(value = value);
+        self.inner.borrow_mut().as_mut().setColorValue(&value);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::ColorDefinition, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::ColorDefinition> for ColorDefinition<'_> {
+    /// Creates a new ColorDefinition instance from a unique pointer to a libSBML
+    /// ColorDefinition.
+    ///
+    /// This method is primarily used internally by the RenderInformation class to create
+    /// ColorDefinition instances from libSBML ColorDefinition pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML ColorDefinition
+    ///
+    /// # Returns
+    /// A new ColorDefinition instance
+    fn from_ptr(ptr: *mut sbmlcxx::ColorDefinition) -> Self {
+        let color = pin_ptr!(ptr, sbmlcxx::ColorDefinition);
+        Self {
+            inner: RefCell::new(color),
+        }
+    }
+}
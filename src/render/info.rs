@@ -0,0 +1,292 @@
+//! This module provides a safe Rust interface to the libSBML LocalRenderInformation class.
+//!
+//! The LocalRenderInformation class of the render package belongs to a single layout
+//! and holds the color definitions and styles used to draw its glyphs. A layout can
+//! carry several render information objects, e.g. a colored and a grayscale variant.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML LocalRenderInformation
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner,
+    layout::Layout,
+    pin_ptr,
+    render::{
+        color::ColorDefinition,
+        style::{Style, StyleBuilder},
+    },
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML LocalRenderInformation class.
+///
+/// This struct maintains a reference to the underlying C++ LocalRenderInformation object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains the color definitions and styles of the render information.
+pub struct RenderInformation<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::LocalRenderInformation>>,
+    color_definitions: RefCell<Vec<Rc<ColorDefinition<'a>>>>,
+    styles: RefCell<Vec<Rc<Style<'a>>>>,
+}
+
+// Set the inner trait for the RenderInformation struct
+inner!(sbmlcxx::LocalRenderInformation, RenderInformation<'a>);
+
+// Set the annotation trait for the RenderInformation struct
+upcast_annotation!(
+    RenderInformation<'a>,
+    sbmlcxx::LocalRenderInformation,
+    sbmlcxx::SBase
+);
+
+// Set the SBase trait for the RenderInformation struct
+sbase!(RenderInformation<'a>, sbmlcxx::LocalRenderInformation);
+
+impl<'a> RenderInformation<'a> {
+    /// Creates a new RenderInformation instance within the given Layout.
+    ///
+    /// The render package is enabled on the document if needed.
+    ///
+    /// # Arguments
+    /// * `layout` - The parent Layout that will contain this render information
+    /// * `id` - The identifier for this render information
+    ///
+    /// # Returns
+    /// A new RenderInformation instance
+    ///
+    /// # Panics
+    /// If the layout is not part of a document
+    pub fn new(layout: &Layout<'a>, id: &str) -> Self {
+        let mut plugin = layout
+            .render_plugin(true)
+            .expect("Layout must be part of a document to use the render package");
+        let information_ptr = plugin.as_mut().createLocalRenderInformation();
+        let information = pin_ptr!(information_ptr, sbmlcxx::LocalRenderInformation);
+
+        let information = Self {
+            inner: RefCell::new(information),
+            color_definitions: RefCell::new(Vec::new()),
+            styles: RefCell::new(Vec::new()),
+        };
+        information.set_id(id);
+        information
+    }
+
+    /// Returns a reference to the inner RefCell containing the LocalRenderInformation
+    /// pointer.
+    ///
+    /// This is primarily used internally by other parts of the library.
+    pub(crate) fn inner(&self) -> &RefCell<Pin<&'a mut sbmlcxx::LocalRenderInformation>> {
+        &self.inner
+    }
+
+    /// Returns the base class, which holds the color definitions.
+    pub(crate) fn base(&self) -> Pin<&'a mut sbmlcxx::RenderInformationBase> {
+        upcast!(
+            self,
+            sbmlcxx::LocalRenderInformation,
+            sbmlcxx::RenderInformationBase
+        )
+    }
+
+    /// Returns the id of the render information.
+    ///
+    /// # Returns
+    /// The id of the render information as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::LocalRenderInformation, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the render information.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::LocalRenderInformation, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the name of the render information.
+    ///
+    /// # Returns
+    /// The name of the render information as a String
+    pub fn name(&self) -> String {
+        let base = upcast!(self, sbmlcxx::LocalRenderInformation, sbmlcxx::SBase);
+        base.getName().to_str().unwrap().to_string()
+    }
+
+    /// Sets the name of the render information.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    pub fn set_name(&self, name: &str) {
+        let_cxx_string!(name = name);
+        let mut base = upcast!(self, sbmlcxx::LocalRenderInformation, sbmlcxx::SBase);
+        base.as_mut().setName(&name);
+    }
+
+    /// Creates a new color definition.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new color
+    /// * `value` - The color in hexadecimal notation, e.g. `#ff0000`
+    ///
+    /// # Returns
+    /// The newly created ColorDefinition wrapped in an Rc
+    pub fn create_color_definition(&self, id: &str, value: &str) -> Rc<ColorDefinition<'a>> {
+        let color = Rc::new(ColorDefinition::new(self, id, value));
+        self.color_definitions.borrow_mut().push(Rc::clone(&color));
+        color
+    }
+
+    /// Returns a vector of all color definitions.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all ColorDefinitions
+    pub fn list_of_color_definitions(&self) -> Vec<Rc<ColorDefinition<'a>>> {
+        self.color_definitions.borrow().to_vec()
+    }
+
+    /// Retrieves a color definition by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the color
+    ///
+    /// # Returns
+    /// Some(Rc<ColorDefinition>) if found, None if not found
+    pub fn get_color_definition(&self, id: &str) -> Option<Rc<ColorDefinition<'a>>> {
+        self.color_definitions
+            .borrow()
+            .iter()
+            .find(|color| (*color).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Creates a new style.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new style
+    ///
+    /// # Returns
+    /// The newly created Style wrapped in an Rc
+    pub fn create_style(&self, id: &str) -> Rc<Style<'a>> {
+        let style = Rc::new(Style::new(self, id));
+        self.styles.borrow_mut().push(Rc::clone(&style));
+        style
+    }
+
+    /// Creates a new StyleBuilder for constructing a style.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new style
+    ///
+    /// # Returns
+    /// A StyleBuilder instance
+    pub fn build_style(&self, id: &str) -> StyleBuilder<'a> {
+        StyleBuilder::new(self, id)
+    }
+
+    /// Returns a vector of all styles.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Styles
+    pub fn list_of_styles(&self) -> Vec<Rc<Style<'a>>> {
+        self.styles.borrow().to_vec()
+    }
+
+    /// Retrieves a style by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the style
+    ///
+    /// # Returns
+    /// Some(Rc<Style>) if found, None if not found
+    pub fn get_style(&self, id: &str) -> Option<Rc<Style<'a>>> {
+        self.styles
+            .borrow()
+            .iter()
+            .find(|style| (*style).id() == id)
+            .map(Rc::clone)
+    }
+
+    /// Returns the styles that apply to a glyph.
+    ///
+    /// A style applies if it lists the glyph's identifier, its role or its type.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    /// * `role` - The role of the glyph, if any
+    /// * `glyph_type` - The type of the glyph, e.g. `SPECIESGLYPH`
+    ///
+    /// # Returns
+    /// A vector containing Rc references to the matching Styles
+    pub fn styles_for(&self, id: &str, role: Option<&str>, glyph_type: &str) -> Vec<Rc<Style<'a>>> {
+        self.styles
+            .borrow()
+            .iter()
+            .filter(|style| {
+                style.ids().iter().any(|target| target == id)
+                    || role.is_some_and(|role| style.roles().iter().any(|r| r == role))
+                    || style.types().iter().any(|t| t == glyph_type || t == "ANY")
+            })
+            .map(Rc::clone)
+            .collect()
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::LocalRenderInformation, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::LocalRenderInformation> for RenderInformation<'_> {
+    /// Creates a new RenderInformation instance from a unique pointer to a libSBML
+    /// LocalRenderInformation.
+    ///
+    /// This method is primarily used internally by the Layout class to create
+    /// RenderInformation instances from libSBML LocalRenderInformation pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML LocalRenderInformation
+    ///
+    /// # Returns
+    /// A new RenderInformation instance
+    fn from_ptr(ptr: *mut sbmlcxx::LocalRenderInformation) -> Self {
+        let information = Self {
+            inner: RefCell::new(pin_ptr!(ptr, sbmlcxx::LocalRenderInformation)),
+            color_definitions: RefCell::new(Vec::new()),
+            styles: RefCell::new(Vec::new()),
+        };
+
+        // Fetch all color definitions
+        let mut base = information.base();
+        let n_colors = base.getNumColorDefinitions().0;
+        let color_definitions: Vec<_> = (0..n_colors)
+            .map(|i| {
+                let color = base.as_mut().getColorDefinition1(i.into());
+                Rc::new(ColorDefinition::from_ptr(color))
+            })
+            .collect();
+        information.color_definitions.replace(color_definitions);
+
+        // Fetch all styles
+        let n_styles = information.inner.borrow().getNumStyles().0;
+        let styles: Vec<_> = (0..n_styles)
+            .map(|i| {
+                let style = information.inner.borrow_mut().as_mut().getStyle1(i.into());
+                Rc::new(Style::from_ptr(style))
+            })
+            .collect();
+        information.styles.replace(styles);
+
+        information
+    }
+}
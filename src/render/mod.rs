@@ -0,0 +1,111 @@
+//! Support for the SBML Level 3 render package.
+//!
+//! The render package adds visual styling to the diagrams of the
+//! [`layout`](crate::layout) package. Each [`RenderInformation`] belongs to a layout
+//! and holds:
+//!
+//! - [`ColorDefinition`]s, named colors in hexadecimal notation
+//! - [`Style`]s, which set stroke and fill colors and the stroke width of the glyphs
+//!   they apply to, selected by glyph identifier, role or glyph type
+//!
+//! Render information is created with [`Layout::create_render_information`], which
+//! enables the package on the document.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("example");
+//! let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+//!
+//! let render = layout.create_render_information("colors");
+//! render.create_color_definition("metabolite", "#a0e0a0");
+//! render
+//!     .build_style("species_style")
+//!     .target_type("SPECIESGLYPH")
+//!     .fill("metabolite")
+//!     .stroke("#000000")
+//!     .stroke_width(2.0)
+//!     .build();
+//! ```
+//!
+//! [`Layout::create_render_information`]: crate::layout::Layout::create_render_information
+
+/// Module providing color definition functionality
+pub mod color;
+/// Module providing render information functionality
+pub mod info;
+/// Module providing style functionality
+pub mod style;
+
+pub use color::ColorDefinition;
+pub use info::RenderInformation;
+pub use style::{Style, StyleBuilder};
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_information() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+        assert!(!doc.is_package_enabled(Package::Render));
+
+        let render = layout.create_render_information("colors");
+        assert!(doc.is_package_enabled(Package::Render));
+
+        render.create_color_definition("red", "#ff0000");
+        let style = render
+            .build_style("products")
+            .role("product")
+            .target_id("srg_B")
+            .stroke("red")
+            .stroke_width(2.0)
+            .build();
+        render
+            .build_style("species")
+            .target_type("SPECIESGLYPH")
+            .fill("#a0e0a0")
+            .build();
+
+        assert_eq!(
+            render.get_color_definition("red").unwrap().value(),
+            "#ff0000ff"
+        );
+        assert_eq!(style.roles(), vec!["product"]);
+        assert_eq!(style.ids(), vec!["srg_B"]);
+        assert_eq!(style.stroke(), "red");
+        assert_eq!(style.stroke_width(), 2.0);
+
+        let styles = render.styles_for("sg_A", None, "SPECIESGLYPH");
+        assert_eq!(styles.len(), 1);
+        assert_eq!(styles[0].fill(), "#a0e0a0");
+    }
+
+    #[test]
+    fn test_render_read_from_xml() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let layout = model.layout_plugin().create_layout("diagram", 400.0, 200.0);
+        let render = layout.create_render_information("colors");
+        render.create_color_definition("blue", "#0000ff");
+        render
+            .build_style("all")
+            .target_type("ANY")
+            .stroke("blue")
+            .build();
+
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = read.model().expect("Model not found");
+        let layout = model.layout_plugin().get_layout("diagram").unwrap();
+        let render = layout.get_render_information("colors").unwrap();
+
+        assert_eq!(render.list_of_color_definitions().len(), 1);
+        let style = render.get_style("all").unwrap();
+        assert_eq!(style.types(), vec!["ANY"]);
+        assert_eq!(style.stroke(), "blue");
+    }
+}
@@ -0,0 +1,357 @@
+//! This module provides a safe Rust interface to the libSBML LocalStyle class.
+//!
+//! The LocalStyle class of the render package describes how glyphs of a layout are
+//! drawn. A style applies to the glyphs listed by identifier, to glyphs with one of
+//! the listed roles (e.g. `product`) and to glyphs of one of the listed types (e.g.
+//! `SPECIESGLYPH`). The graphical properties are stored in the group of the style;
+//! this wrapper exposes the stroke and fill colors and the stroke width.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML LocalStyle class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    inner, pin_ptr,
+    render::info::RenderInformation,
+    sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast, upcast_annotation, upcast_pin,
+};
+
+/// A safe wrapper around the libSBML LocalStyle class.
+///
+/// This struct maintains a reference to the underlying C++ LocalStyle object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Style<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::LocalStyle>>,
+}
+
+// Set the inner trait for the Style struct
+inner!(sbmlcxx::LocalStyle, Style<'a>);
+
+// Set the annotation trait for the Style struct
+upcast_annotation!(Style<'a>, sbmlcxx::LocalStyle, sbmlcxx::SBase);
+
+// Set the SBase trait for the Style struct
+sbase!(Style<'a>, sbmlcxx::LocalStyle);
+
+impl<'a> Style<'a> {
+    /// Creates a new Style instance within the given RenderInformation.
+    ///
+    /// # Arguments
+    /// * `information` - The parent RenderInformation that will contain this style
+    /// * `id` - The identifier for this style
+    ///
+    /// # Returns
+    /// A new Style instance
+    pub fn new(information: &RenderInformation<'a>, id: &str) -> Self {
+        let_cxx_string!(id = id);
+        let style_ptr = information.inner().borrow_mut().as_mut().createStyle(&id);
+        let style = pin_ptr!(style_ptr, sbmlcxx::LocalStyle);
+
+        Self {
+            inner: RefCell::new(style),
+        }
+    }
+
+    /// Returns the id of the style.
+    ///
+    /// # Returns
+    /// The id of the style as a String
+    pub fn id(&self) -> String {
+        let base = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::SBase);
+        base.getId().to_str().unwrap().to_string()
+    }
+
+    /// Sets the id of the style.
+    ///
+    /// # Arguments
+    /// * `id` - The id to set
+    pub fn set_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        let mut base = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::SBase);
+        base.as_mut().setId(&id);
+    }
+
+    /// Returns the identifiers of the glyphs the style applies to.
+    ///
+    /// # Returns
+    /// A vector of glyph identifiers
+    pub fn ids(&self) -> Vec<String> {
+        let ids = self.inner.borrow().createIdString();
+        split_list(ids.to_str().unwrap())
+    }
+
+    /// Adds a glyph the style applies to.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    pub fn add_id(&self, id: &str) {
+        let_cxx_string!(id = id);
+        self.inner.borrow_mut().as_mut().addId(&id);
+    }
+
+    /// Returns the roles of the glyphs the style applies to.
+    ///
+    /// # Returns
+    /// A vector of roles, e.g. `substrate` or `product`
+    pub fn roles(&self) -> Vec<String> {
+        let style = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::Style);
+        let roles = style.createRoleString();
+        split_list(roles.to_str().unwrap())
+    }
+
+    /// Adds a role of the glyphs the style applies to.
+    ///
+    /// # Arguments
+    /// * `role` - The role, e.g. `product`
+    pub fn add_role(&self, role: &str) {
+        let_cxx_string!(role = role);
+        let mut style = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::Style);
+        style.as_mut().addRole(&role);
+    }
+
+    /// Returns the types of the glyphs the style applies to.
+    ///
+    /// # Returns
+    /// A vector of glyph types, e.g. `SPECIESGLYPH`
+    pub fn types(&self) -> Vec<String> {
+        let style = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::Style);
+        let types = style.createTypeString();
+        split_list(types.to_str().unwrap())
+    }
+
+    /// Adds a type of the glyphs the style applies to.
+    ///
+    /// # Arguments
+    /// * `glyph_type` - The glyph type, e.g. `SPECIESGLYPH` or `ANY`
+    pub fn add_type(&self, glyph_type: &str) {
+        let_cxx_string!(glyph_type = glyph_type);
+        let mut style = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::Style);
+        style.as_mut().addType(&glyph_type);
+    }
+
+    /// Returns the stroke color of the style.
+    ///
+    /// # Returns
+    /// The color value or the identifier of a color definition, empty if not set
+    pub fn stroke(&self) -> String {
+        let mut group = self.group();
+        let primitive = upcast_pin!(group, sbmlcxx::RenderGroup, sbmlcxx::GraphicalPrimitive1D);
+        primitive.getStroke().to_str().unwrap().to_string()
+    }
+
+    /// Sets the stroke color of the style.
+    ///
+    /// # Arguments
+    /// * `stroke` - A color value or the identifier of a color definition
+    pub fn set_stroke(&self, stroke: &str) {
+        let_cxx_string!(stroke = stroke);
+        let mut group = self.group();
+        let mut primitive = upcast_pin!(group, sbmlcxx::RenderGroup, sbmlcxx::GraphicalPrimitive1D);
+        primitive.as_mut().setStroke(&stroke);
+    }
+
+    /// Returns the stroke width of the style.
+    pub fn stroke_width(&self) -> f64 {
+        let mut group = self.group();
+        let primitive = upcast_pin!(group, sbmlcxx::RenderGroup, sbmlcxx::GraphicalPrimitive1D);
+        primitive.getStrokeWidth()
+    }
+
+    /// Sets the stroke width of the style.
+    ///
+    /// # Arguments
+    /// * `width` - The stroke width
+    pub fn set_stroke_width(&self, width: f64) {
+        let mut group = self.group();
+        let mut primitive = upcast_pin!(group, sbmlcxx::RenderGroup, sbmlcxx::GraphicalPrimitive1D);
+        primitive.as_mut().setStrokeWidth(width);
+    }
+
+    /// Returns the fill color of the style.
+    ///
+    /// # Returns
+    /// The color value or the identifier of a color definition, empty if not set
+    pub fn fill(&self) -> String {
+        let mut group = self.group();
+        let primitive = upcast_pin!(group, sbmlcxx::RenderGroup, sbmlcxx::GraphicalPrimitive2D);
+        primitive.getFillColor().to_str().unwrap().to_string()
+    }
+
+    /// Sets the fill color of the style.
+    ///
+    /// # Arguments
+    /// * `fill` - A color value or the identifier of a color definition
+    pub fn set_fill(&self, fill: &str) {
+        let_cxx_string!(fill = fill);
+        let mut group = self.group();
+        let mut primitive = upcast_pin!(group, sbmlcxx::RenderGroup, sbmlcxx::GraphicalPrimitive2D);
+        primitive.as_mut().setFillColor(&fill);
+    }
+
+    /// Returns the group holding the graphical properties of the style.
+    fn group(&self) -> Pin<&'a mut sbmlcxx::RenderGroup> {
+        let mut style = upcast!(self, sbmlcxx::LocalStyle, sbmlcxx::Style);
+        let group_ptr = style.as_mut().getGroup1();
+        pin_ptr!(group_ptr, sbmlcxx::RenderGroup)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::LocalStyle, sbmlcxx::SBase);
+}
+
+/// Splits a whitespace-separated list attribute of a style.
+fn split_list(list: &str) -> Vec<String> {
+    list.split_whitespace().map(str::to_string).collect()
+}
+
+impl FromPtr<sbmlcxx::LocalStyle> for Style<'_> {
+    /// Creates a new Style instance from a unique pointer to a libSBML LocalStyle.
+    ///
+    /// This method is primarily used internally by the RenderInformation class to create
+    /// Style instances from libSBML LocalStyle pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML LocalStyle
+    ///
+    /// # Returns
+    /// A new Style instance
+    fn from_ptr(ptr: *mut sbmlcxx::LocalStyle) -> Self {
+        let style = pin_ptr!(ptr, sbmlcxx::LocalStyle);
+        Self {
+            inner: RefCell::new(style),
+        }
+    }
+}
+
+/// A builder for creating Style instances with a fluent interface.
+pub struct StyleBuilder<'a> {
+    style: Rc<Style<'a>>,
+}
+
+impl<'a> StyleBuilder<'a> {
+    /// Creates a new StyleBuilder instance.
+    ///
+    /// # Arguments
+    /// * `information` - The RenderInformation that will contain the style
+    /// * `id` - The identifier for the style
+    ///
+    /// # Returns
+    /// A new StyleBuilder instance
+    pub fn new(information: &RenderInformation<'a>, id: &str) -> Self {
+        let style = information.create_style(id);
+        Self { style }
+    }
+
+    /// Applies the style to a glyph.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the glyph
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn target_id(self, id: &str) -> Self {
+        self.style.add_id(id);
+        self
+    }
+
+    /// Applies the style to glyphs with a role.
+    ///
+    /// # Arguments
+    /// * `role` - The role, e.g. `product`
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn role(self, role: &str) -> Self {
+        self.style.add_role(role);
+        self
+    }
+
+    /// Applies the style to glyphs of a type.
+    ///
+    /// # Arguments
+    /// * `glyph_type` - The glyph type, e.g. `SPECIESGLYPH`
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn target_type(self, glyph_type: &str) -> Self {
+        self.style.add_type(glyph_type);
+        self
+    }
+
+    /// Sets the stroke color of the style.
+    ///
+    /// # Arguments
+    /// * `stroke` - A color value or the identifier of a color definition
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn stroke(self, stroke: &str) -> Self {
+        self.style.set_stroke(stroke);
+        self
+    }
+
+    /// Sets the stroke width of the style.
+    ///
+    /// # Arguments
+    /// * `width` - The stroke width
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn stroke_width(self, width: f64) -> Self {
+        self.style.set_stroke_width(width);
+        self
+    }
+
+    /// Sets the fill color of the style.
+    ///
+    /// # Arguments
+    /// * `fill` - A color value or the identifier of a color definition
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn fill(self, fill: &str) -> Self {
+        self.style.set_fill(fill);
+        self
+    }
+
+    /// Sets the annotation string for this style.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or an error
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.style
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder for chaining or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.style.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Style.
+    ///
+    /// # Returns
+    /// The constructed Style instance wrapped in an Rc
+    pub fn build(self) -> Rc<Style<'a>> {
+        self.style
+    }
+}
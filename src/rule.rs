@@ -13,7 +13,7 @@ use cxx::let_cxx_string;
 use crate::{
     inner,
     model::Model,
-    pin_ptr,
+    pin_ptr, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
@@ -42,6 +42,9 @@ inner!(sbmlcxx::Rule, Rule<'a>);
 // Set the annotation trait for the Rule struct
 upcast_annotation!(Rule<'a>, sbmlcxx::Rule, sbmlcxx::SBase);
 
+// Set the SBase trait for the Rule struct
+sbase!(Rule<'a>, sbmlcxx::Rule);
+
 impl<'a> Rule<'a> {
     /// Creates a new RateRule instance within the given Model.
     ///
@@ -137,6 +140,11 @@ impl<'a> Rule<'a> {
             .to_string()
     }
 
+    /// Returns the math of the rule, null if no math is set.
+    pub(crate) fn math(&self) -> *const sbmlcxx::ASTNode {
+        self.inner.borrow().getMath()
+    }
+
     /// Sets the formula of the rate rule.
     ///
     /// # Arguments
@@ -0,0 +1,798 @@
+//! Sampling of parameter sets from the uncertainties of a model.
+//!
+//! Parameters annotated with the distributions (distrib) package carry uncertainties,
+//! which either give a full distribution like `normal(1, 0.1)` or statistics like the
+//! mean and standard deviation. [`ParameterSampler::from_model`] turns these into
+//! [`Distribution`]s, from which parameter sets are drawn for Monte Carlo studies:
+//!
+//! - A distribution formula is used as given. Its arguments may refer to the values of
+//!   other parameters.
+//! - Otherwise, a normal distribution is formed from the standard deviation (or the
+//!   variance) and the mean, which defaults to the value of the parameter.
+//!
+//! The distribution functions of the distrib package are supported: `normal`,
+//! `lognormal`, `uniform`, `exponential`, `gamma`, `poisson`, `bernoulli`, `binomial`,
+//! `cauchy`, `chisquare`, `laplace` and `rayleigh`, including the optional truncation
+//! bounds. Samples of truncated distributions are drawn by rejection.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//! use sbml::sampling::ParameterSampler;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("uncertain");
+//! let k = model.build_parameter("k").value(1.0).build();
+//! k.create_uncertainty().set_distribution("lognormal(0, 0.2)");
+//!
+//! let sampler = ParameterSampler::from_model(&model).unwrap();
+//! for set in sampler.sample_n(1000, Some(42)) {
+//!     sampler.apply(&model, &set).unwrap();
+//!     // simulate the model ...
+//! }
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::{
+    Bernoulli, Binomial, Cauchy, ChiSquared, Distribution as _, Exp, Gamma, LogNormal, Normal,
+    Poisson,
+};
+
+use crate::{
+    distrib::UncertType,
+    formula::{Expr, FormulaError},
+    model::Model,
+};
+
+/// The largest number of draws for a single sample of a truncated distribution.
+const MAX_REJECTIONS: usize = 10_000;
+
+/// Values of sampled parameters by their identifiers.
+pub type ParameterSet = BTreeMap<String, f64>;
+
+/// A probability distribution of a parameter value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Distribution {
+    /// A fixed value
+    Constant(f64),
+    /// A normal distribution
+    Normal { mean: f64, std_dev: f64 },
+    /// A distribution whose logarithm is normal with the given parameters
+    LogNormal { mu: f64, sigma: f64 },
+    /// A continuous uniform distribution on `[min, max)`
+    Uniform { min: f64, max: f64 },
+    /// An exponential distribution
+    Exponential { rate: f64 },
+    /// A gamma distribution
+    Gamma { shape: f64, scale: f64 },
+    /// A Poisson distribution
+    Poisson { rate: f64 },
+    /// A Bernoulli distribution with values 0 and 1
+    Bernoulli { p: f64 },
+    /// A binomial distribution
+    Binomial { trials: u64, p: f64 },
+    /// A Cauchy distribution
+    Cauchy { location: f64, scale: f64 },
+    /// A chi-squared distribution
+    ChiSquare { degrees_of_freedom: f64 },
+    /// A Laplace distribution
+    Laplace { location: f64, scale: f64 },
+    /// A Rayleigh distribution
+    Rayleigh { scale: f64 },
+    /// A distribution restricted to `[min, max]`
+    Truncated {
+        distribution: Box<Distribution>,
+        min: f64,
+        max: f64,
+    },
+}
+
+impl Distribution {
+    /// Creates a distribution from a formula of the distrib package.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula, e.g. `normal(1, 0.1)` or `uniform(0, k_max)`
+    /// * `lookup` - Resolves identifiers used in the arguments to their values
+    ///
+    /// # Returns
+    /// The distribution or an error if the formula does not describe a valid one
+    pub fn from_formula(
+        formula: &str,
+        lookup: &dyn Fn(&str) -> Option<f64>,
+    ) -> Result<Self, SamplingError> {
+        let (name, args) = match Expr::parse(formula)? {
+            Expr::Call(name, args) => (name, args),
+            // A plain expression is a fixed value
+            expr => return Ok(Distribution::Constant(expr.eval(lookup)?)),
+        };
+        let args = args
+            .iter()
+            .map(|arg| arg.eval(lookup))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let arity = |counts: &[usize]| -> Result<(), SamplingError> {
+            if counts.contains(&args.len()) {
+                Ok(())
+            } else {
+                Err(FormulaError::InvalidArity {
+                    function: name.clone(),
+                    arguments: args.len(),
+                }
+                .into())
+            }
+        };
+
+        let distribution = match name.as_str() {
+            "normal" => {
+                arity(&[2, 4])?;
+                Distribution::Normal {
+                    mean: args[0],
+                    std_dev: args[1],
+                }
+            }
+            "lognormal" => {
+                arity(&[2, 4])?;
+                Distribution::LogNormal {
+                    mu: args[0],
+                    sigma: args[1],
+                }
+            }
+            "uniform" => {
+                arity(&[2])?;
+                Distribution::Uniform {
+                    min: args[0],
+                    max: args[1],
+                }
+            }
+            "exponential" => {
+                arity(&[1, 3])?;
+                Distribution::Exponential { rate: args[0] }
+            }
+            "gamma" => {
+                arity(&[2, 4])?;
+                Distribution::Gamma {
+                    shape: args[0],
+                    scale: args[1],
+                }
+            }
+            "poisson" => {
+                arity(&[1, 3])?;
+                Distribution::Poisson { rate: args[0] }
+            }
+            "bernoulli" => {
+                arity(&[1])?;
+                Distribution::Bernoulli { p: args[0] }
+            }
+            "binomial" => {
+                arity(&[2, 4])?;
+                if args[0] < 0.0 || args[0].fract() != 0.0 {
+                    return Err(SamplingError::InvalidDistribution(format!(
+                        "Number of trials must be a non-negative integer, got {}",
+                        args[0]
+                    )));
+                }
+                Distribution::Binomial {
+                    trials: args[0] as u64,
+                    p: args[1],
+                }
+            }
+            "cauchy" => {
+                arity(&[2, 4])?;
+                Distribution::Cauchy {
+                    location: args[0],
+                    scale: args[1],
+                }
+            }
+            "chisquare" => {
+                arity(&[1, 3])?;
+                Distribution::ChiSquare {
+                    degrees_of_freedom: args[0],
+                }
+            }
+            "laplace" => {
+                arity(&[2, 4])?;
+                Distribution::Laplace {
+                    location: args[0],
+                    scale: args[1],
+                }
+            }
+            "rayleigh" => {
+                arity(&[1, 3])?;
+                Distribution::Rayleigh { scale: args[0] }
+            }
+            _ => return Err(FormulaError::UnknownFunction(name).into()),
+        };
+
+        // Functions with optional arguments take the truncation bounds last
+        let distribution = match (name.as_str(), args.len()) {
+            ("uniform" | "bernoulli", _) => distribution,
+            (_, 3 | 4) => Distribution::Truncated {
+                distribution: Box::new(distribution),
+                min: args[args.len() - 2],
+                max: args[args.len() - 1],
+            },
+            _ => distribution,
+        };
+
+        distribution.validate()?;
+        Ok(distribution)
+    }
+
+    /// Checks that the parameters of the distribution are valid.
+    ///
+    /// # Returns
+    /// An error describing the first invalid parameter
+    pub fn validate(&self) -> Result<(), SamplingError> {
+        let check = |valid: bool, message: &str| {
+            if valid {
+                Ok(())
+            } else {
+                Err(SamplingError::InvalidDistribution(format!(
+                    "{}: {}",
+                    message, self
+                )))
+            }
+        };
+
+        match self {
+            Distribution::Constant(value) => check(value.is_finite(), "Value must be finite"),
+            Distribution::Normal { std_dev, .. } => {
+                check(*std_dev >= 0.0, "Standard deviation must not be negative")
+            }
+            Distribution::LogNormal { sigma, .. } => {
+                check(*sigma >= 0.0, "Standard deviation must not be negative")
+            }
+            Distribution::Uniform { min, max } => check(min < max, "Empty range"),
+            Distribution::Exponential { rate } | Distribution::Poisson { rate } => {
+                check(*rate > 0.0, "Rate must be positive")
+            }
+            Distribution::Gamma { shape, scale } => check(
+                *shape > 0.0 && *scale > 0.0,
+                "Shape and scale must be positive",
+            ),
+            Distribution::Bernoulli { p } | Distribution::Binomial { p, .. } => {
+                check((0.0..=1.0).contains(p), "Probability must be within [0, 1]")
+            }
+            Distribution::Cauchy { scale, .. }
+            | Distribution::Laplace { scale, .. }
+            | Distribution::Rayleigh { scale } => check(*scale > 0.0, "Scale must be positive"),
+            Distribution::ChiSquare { degrees_of_freedom } => check(
+                *degrees_of_freedom > 0.0,
+                "Degrees of freedom must be positive",
+            ),
+            Distribution::Truncated {
+                distribution,
+                min,
+                max,
+            } => {
+                check(min <= max, "Empty range")?;
+                distribution.validate()
+            }
+        }
+    }
+
+    /// Draws a single value from the distribution.
+    ///
+    /// The distribution must be valid, see [`Distribution::validate`].
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    ///
+    /// # Returns
+    /// The sampled value or an error if a truncated distribution rarely hits its range
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<f64, SamplingError> {
+        let invalid =
+            |error: &dyn fmt::Display| SamplingError::InvalidDistribution(error.to_string());
+
+        Ok(match self {
+            Distribution::Constant(value) => *value,
+            Distribution::Normal { mean, std_dev } => Normal::new(*mean, *std_dev)
+                .map_err(|e| invalid(&e))?
+                .sample(rng),
+            Distribution::LogNormal { mu, sigma } => LogNormal::new(*mu, *sigma)
+                .map_err(|e| invalid(&e))?
+                .sample(rng),
+            Distribution::Uniform { min, max } => rng.gen_range(*min..*max),
+            Distribution::Exponential { rate } => {
+                Exp::new(*rate).map_err(|e| invalid(&e))?.sample(rng)
+            }
+            Distribution::Gamma { shape, scale } => Gamma::new(*shape, *scale)
+                .map_err(|e| invalid(&e))?
+                .sample(rng),
+            Distribution::Poisson { rate } => {
+                Poisson::new(*rate).map_err(|e| invalid(&e))?.sample(rng)
+            }
+            Distribution::Bernoulli { p } => {
+                let success = Bernoulli::new(*p).map_err(|e| invalid(&e))?.sample(rng);
+                if success {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Distribution::Binomial { trials, p } => Binomial::new(*trials, *p)
+                .map_err(|e| invalid(&e))?
+                .sample(rng) as f64,
+            Distribution::Cauchy { location, scale } => Cauchy::new(*location, *scale)
+                .map_err(|e| invalid(&e))?
+                .sample(rng),
+            Distribution::ChiSquare { degrees_of_freedom } => ChiSquared::new(*degrees_of_freedom)
+                .map_err(|e| invalid(&e))?
+                .sample(rng),
+            Distribution::Laplace { location, scale } => {
+                // Inverse of the cumulative distribution function
+                let u: f64 = rng.gen_range(-0.5..0.5);
+                location - scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+            }
+            Distribution::Rayleigh { scale } => {
+                let u: f64 = rng.gen();
+                scale * (-2.0 * (1.0 - u).ln()).sqrt()
+            }
+            Distribution::Truncated {
+                distribution,
+                min,
+                max,
+            } => {
+                for _ in 0..MAX_REJECTIONS {
+                    let value = distribution.sample(rng)?;
+                    if (*min..=*max).contains(&value) {
+                        return Ok(value);
+                    }
+                }
+                return Err(SamplingError::InvalidDistribution(format!(
+                    "No sample within [{}, {}] after {} draws: {}",
+                    min, max, MAX_REJECTIONS, distribution
+                )));
+            }
+        })
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distribution::Constant(value) => write!(f, "{}", value),
+            Distribution::Normal { mean, std_dev } => write!(f, "normal({}, {})", mean, std_dev),
+            Distribution::LogNormal { mu, sigma } => write!(f, "lognormal({}, {})", mu, sigma),
+            Distribution::Uniform { min, max } => write!(f, "uniform({}, {})", min, max),
+            Distribution::Exponential { rate } => write!(f, "exponential({})", rate),
+            Distribution::Gamma { shape, scale } => write!(f, "gamma({}, {})", shape, scale),
+            Distribution::Poisson { rate } => write!(f, "poisson({})", rate),
+            Distribution::Bernoulli { p } => write!(f, "bernoulli({})", p),
+            Distribution::Binomial { trials, p } => write!(f, "binomial({}, {})", trials, p),
+            Distribution::Cauchy { location, scale } => {
+                write!(f, "cauchy({}, {})", location, scale)
+            }
+            Distribution::ChiSquare { degrees_of_freedom } => {
+                write!(f, "chisquare({})", degrees_of_freedom)
+            }
+            Distribution::Laplace { location, scale } => {
+                write!(f, "laplace({}, {})", location, scale)
+            }
+            Distribution::Rayleigh { scale } => write!(f, "rayleigh({})", scale),
+            Distribution::Truncated {
+                distribution,
+                min,
+                max,
+            } => {
+                // Append the bounds to the arguments of the function
+                let inner = distribution.to_string();
+                write!(f, "{}, {}, {})", inner.trim_end_matches(')'), min, max)
+            }
+        }
+    }
+}
+
+/// Draws parameter sets from the distributions of parameters.
+#[derive(Debug, Clone, Default)]
+pub struct ParameterSampler {
+    /// Parameter identifiers and their distributions, in insertion order
+    distributions: Vec<(String, Distribution)>,
+}
+
+impl ParameterSampler {
+    /// Creates a sampler without parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a sampler for the uncertain parameters of a model.
+    ///
+    /// Only the first uncertainty of each parameter is used. Parameters without
+    /// uncertainties are not sampled.
+    ///
+    /// # Arguments
+    /// * `model` - The model whose parameters carry distrib uncertainties
+    ///
+    /// # Returns
+    /// The sampler or an error if an uncertainty does not define a distribution
+    pub fn from_model(model: &Model) -> Result<Self, SamplingError> {
+        let lookup = |id: &str| model.get_parameter(id).and_then(|p| p.value());
+        let mut sampler = Self::new();
+
+        for parameter in model.list_of_parameters() {
+            let Some(uncertainty) = parameter.uncertainty() else {
+                continue;
+            };
+            let id = parameter.id();
+
+            if let Some(formula) = uncertainty.distribution() {
+                sampler.add(&id, Distribution::from_formula(&formula, &lookup)?)?;
+                continue;
+            }
+
+            // Statistics are given as values or as references to other parameters
+            let statistic = |uncert_type: UncertType| {
+                let statistic = uncertainty.get_uncert_parameter(uncert_type)?;
+                statistic
+                    .value()
+                    .or_else(|| statistic.var().and_then(|var| lookup(&var)))
+            };
+
+            let std_dev = statistic(UncertType::StandardDeviation)
+                .or_else(|| statistic(UncertType::Variance).map(f64::sqrt));
+            let mean = statistic(UncertType::Mean).or_else(|| parameter.value());
+
+            match (mean, std_dev) {
+                (Some(mean), Some(std_dev)) => {
+                    sampler.add(&id, Distribution::Normal { mean, std_dev })?
+                }
+                _ => {
+                    return Err(SamplingError::InvalidModel(format!(
+                        "Uncertainty of parameter '{}' defines neither a distribution nor a mean and standard deviation",
+                        id
+                    )))
+                }
+            }
+        }
+
+        Ok(sampler)
+    }
+
+    /// Adds a parameter to the sampler.
+    ///
+    /// A previous distribution of the parameter is replaced.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the parameter
+    /// * `distribution` - The distribution of its value
+    ///
+    /// # Returns
+    /// An error if the distribution is invalid
+    pub fn add(&mut self, id: &str, distribution: Distribution) -> Result<(), SamplingError> {
+        distribution.validate()?;
+
+        match self.distributions.iter_mut().find(|(other, _)| other == id) {
+            Some(entry) => entry.1 = distribution,
+            None => self.distributions.push((id.to_string(), distribution)),
+        }
+        Ok(())
+    }
+
+    /// Returns the identifiers of the sampled parameters.
+    pub fn parameters(&self) -> Vec<&str> {
+        self.distributions
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect()
+    }
+
+    /// Returns the distribution of a parameter.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the parameter
+    ///
+    /// # Returns
+    /// The distribution, None if the parameter is not sampled
+    pub fn distribution(&self, id: &str) -> Option<&Distribution> {
+        self.distributions
+            .iter()
+            .find(|(other, _)| other == id)
+            .map(|(_, distribution)| distribution)
+    }
+
+    /// Draws a single parameter set.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    ///
+    /// # Returns
+    /// The sampled values or an error if a truncated distribution rarely hits its range
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<ParameterSet, SamplingError> {
+        self.distributions
+            .iter()
+            .map(|(id, distribution)| Ok((id.clone(), distribution.sample(rng)?)))
+            .collect()
+    }
+
+    /// Draws independent parameter sets.
+    ///
+    /// # Arguments
+    /// * `n` - The number of parameter sets
+    /// * `seed` - Seed of the random number generator, drawn from the system if None
+    ///
+    /// # Returns
+    /// The sampled values or an error if a truncated distribution rarely hits its range
+    pub fn sample_n(
+        &self,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Result<Vec<ParameterSet>, SamplingError> {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        (0..n).map(|_| self.sample(&mut rng)).collect()
+    }
+
+    /// Sets the values of a parameter set on a model.
+    ///
+    /// # Arguments
+    /// * `model` - The model containing the parameters
+    /// * `set` - The values to set
+    ///
+    /// # Returns
+    /// An error if a parameter does not exist in the model
+    pub fn apply(&self, model: &Model, set: &ParameterSet) -> Result<(), SamplingError> {
+        for (id, value) in set {
+            model
+                .get_parameter(id)
+                .ok_or_else(|| SamplingError::UnknownParameter(id.clone()))?
+                .set_value(*value);
+        }
+        Ok(())
+    }
+}
+
+/// Errors that can occur while building a sampler or drawing samples.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SamplingError {
+    /// The uncertainties of the model cannot be interpreted
+    InvalidModel(String),
+    /// The parameters of a distribution are invalid
+    InvalidDistribution(String),
+    /// A parameter does not exist in the model
+    UnknownParameter(String),
+    /// A distribution formula could not be parsed or evaluated
+    Formula(FormulaError),
+}
+
+impl From<FormulaError> for SamplingError {
+    fn from(error: FormulaError) -> Self {
+        SamplingError::Formula(error)
+    }
+}
+
+impl fmt::Display for SamplingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SamplingError::InvalidModel(message) => write!(f, "Invalid model: {}", message),
+            SamplingError::InvalidDistribution(message) => {
+                write!(f, "Invalid distribution: {}", message)
+            }
+            SamplingError::UnknownParameter(id) => write!(f, "Unknown parameter '{}'", id),
+            SamplingError::Formula(error) => write!(f, "Invalid distribution formula: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SamplingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_symbols(_: &str) -> Option<f64> {
+        None
+    }
+
+    /// Returns the mean and standard deviation of samples.
+    fn moments(samples: &[f64]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, variance.sqrt())
+    }
+
+    #[test]
+    fn test_from_formula() {
+        assert_eq!(
+            Distribution::from_formula("normal(1, 0.1)", &no_symbols),
+            Ok(Distribution::Normal {
+                mean: 1.0,
+                std_dev: 0.1
+            })
+        );
+        assert_eq!(
+            Distribution::from_formula("uniform(0, 2 * k)", &|id| (id == "k").then_some(3.0)),
+            Ok(Distribution::Uniform { min: 0.0, max: 6.0 })
+        );
+
+        let truncated = Distribution::from_formula("normal(0, 1, -1, 1)", &no_symbols).unwrap();
+        assert_eq!(truncated.to_string(), "normal(0, 1, -1, 1)");
+        assert_eq!(
+            Distribution::from_formula("2.5", &no_symbols),
+            Ok(Distribution::Constant(2.5))
+        );
+
+        assert!(matches!(
+            Distribution::from_formula("normal(1)", &no_symbols),
+            Err(SamplingError::Formula(FormulaError::InvalidArity { .. }))
+        ));
+        assert!(matches!(
+            Distribution::from_formula("weibull(1, 2)", &no_symbols),
+            Err(SamplingError::Formula(FormulaError::UnknownFunction(_)))
+        ));
+        assert!(matches!(
+            Distribution::from_formula("normal(0, -1)", &no_symbols),
+            Err(SamplingError::InvalidDistribution(_))
+        ));
+        assert!(matches!(
+            Distribution::from_formula("binomial(2.5, 0.5)", &no_symbols),
+            Err(SamplingError::InvalidDistribution(_))
+        ));
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let cases = [
+            (
+                Distribution::Normal {
+                    mean: 2.0,
+                    std_dev: 0.5,
+                },
+                2.0,
+                0.5,
+            ),
+            (
+                Distribution::Uniform { min: 0.0, max: 1.0 },
+                0.5,
+                (1.0f64 / 12.0).sqrt(),
+            ),
+            (Distribution::Exponential { rate: 2.0 }, 0.5, 0.5),
+            (
+                Distribution::Gamma {
+                    shape: 4.0,
+                    scale: 0.5,
+                },
+                2.0,
+                1.0,
+            ),
+            (Distribution::Poisson { rate: 4.0 }, 4.0, 2.0),
+            (
+                Distribution::Binomial { trials: 10, p: 0.5 },
+                5.0,
+                2.5f64.sqrt(),
+            ),
+            (
+                Distribution::Laplace {
+                    location: 1.0,
+                    scale: 1.0,
+                },
+                1.0,
+                2.0f64.sqrt(),
+            ),
+            (
+                Distribution::Rayleigh { scale: 1.0 },
+                (std::f64::consts::PI / 2.0).sqrt(),
+                ((4.0 - std::f64::consts::PI) / 2.0).sqrt(),
+            ),
+        ];
+
+        for (distribution, mean, std_dev) in cases {
+            let samples: Vec<f64> = (0..20_000)
+                .map(|_| distribution.sample(&mut rng).unwrap())
+                .collect();
+            let (sample_mean, sample_std_dev) = moments(&samples);
+            assert!(
+                (sample_mean - mean).abs() < 0.05 * mean.abs().max(1.0),
+                "{}: mean {} != {}",
+                distribution,
+                sample_mean,
+                mean
+            );
+            assert!(
+                (sample_std_dev - std_dev).abs() < 0.05 * std_dev,
+                "{}: std dev {} != {}",
+                distribution,
+                sample_std_dev,
+                std_dev
+            );
+        }
+
+        let truncated = Distribution::from_formula("normal(0, 1, 0, 0.5)", &no_symbols).unwrap();
+        for _ in 0..1000 {
+            let value = truncated.sample(&mut rng).unwrap();
+            assert!((0.0..=0.5).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_parameter_sampler() {
+        let mut sampler = ParameterSampler::new();
+        sampler
+            .add(
+                "k1",
+                Distribution::Normal {
+                    mean: 1.0,
+                    std_dev: 0.1,
+                },
+            )
+            .unwrap();
+        sampler
+            .add("k2", Distribution::Uniform { min: 0.0, max: 1.0 })
+            .unwrap();
+        sampler.add("k1", Distribution::Constant(2.0)).unwrap();
+        assert!(sampler
+            .add("k3", Distribution::Exponential { rate: 0.0 })
+            .is_err());
+
+        assert_eq!(sampler.parameters(), vec!["k1", "k2"]);
+        assert_eq!(
+            sampler.distribution("k1"),
+            Some(&Distribution::Constant(2.0))
+        );
+
+        // Seeded sampling is reproducible
+        let sets = sampler.sample_n(100, Some(7)).unwrap();
+        assert_eq!(sets, sampler.sample_n(100, Some(7)).unwrap());
+        assert_ne!(sets, sampler.sample_n(100, Some(8)).unwrap());
+        assert!(sets.iter().all(|set| set["k1"] == 2.0));
+        assert!(sets.iter().all(|set| (0.0..1.0).contains(&set["k2"])));
+    }
+
+    #[test]
+    fn test_sampler_from_model() {
+        use crate::prelude::*;
+
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let k1 = model.build_parameter("k1").value(1.0).build();
+        let k2 = model.build_parameter("k2").value(5.0).build();
+        model.build_parameter("k_max").value(4.0).build();
+        model.build_parameter("fixed").value(3.0).build();
+
+        // A distribution referring to another parameter
+        k1.create_uncertainty()
+            .set_distribution("uniform(0, k_max)");
+
+        // Statistics with the parameter value as mean
+        let uncertainty = k2.create_uncertainty();
+        uncertainty.set_statistic(UncertType::Variance, 0.25);
+        assert!(doc.is_package_enabled(Package::Distrib));
+
+        // Read back from XML
+        let read = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = read.model().expect("Model not found");
+        let uncertainty = model.get_parameter("k2").unwrap().uncertainty().unwrap();
+        assert_eq!(uncertainty.statistic(UncertType::Variance), Some(0.25));
+
+        let sampler = ParameterSampler::from_model(&model).unwrap();
+        assert_eq!(sampler.parameters(), vec!["k1", "k2"]);
+        assert_eq!(
+            sampler.distribution("k1"),
+            Some(&Distribution::Uniform { min: 0.0, max: 4.0 })
+        );
+        assert_eq!(
+            sampler.distribution("k2"),
+            Some(&Distribution::Normal {
+                mean: 5.0,
+                std_dev: 0.5
+            })
+        );
+
+        let set = &sampler.sample_n(1, Some(0)).unwrap()[0];
+        sampler.apply(&model, set).unwrap();
+        assert_eq!(model.get_parameter("k1").unwrap().value(), Some(set["k1"]));
+
+        let mut unknown = ParameterSet::new();
+        unknown.insert("missing".to_string(), 1.0);
+        assert_eq!(
+            sampler.apply(&model, &unknown),
+            Err(SamplingError::UnknownParameter("missing".to_string()))
+        );
+    }
+}
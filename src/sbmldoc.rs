@@ -10,7 +10,24 @@ use std::{cell::RefCell, rc::Rc};
 use autocxx::{c_uint, WithinUniquePtr};
 use cxx::UniquePtr;
 
-use crate::{cast::upcast, model::Model, sbmlcxx, traits::fromptr::FromPtr};
+use crate::{
+    cast::upcast,
+    errors::{self, ConversionError, SBMLError},
+    model::{Model, ModelBuilder},
+    packages::{self, Package},
+    sbmlcxx,
+    traits::fromptr::FromPtr,
+    visitor::{self, Visitor},
+};
+
+#[cfg(feature = "comp")]
+use crate::{
+    comp::{
+        docplugin::CompDocumentPlugin,
+        flatten::{FlattenError, Flattener},
+    },
+    packages::get_plugin,
+};
 
 /// A wrapper around libSBML's SBMLDocument class that provides a safe Rust interface.
 ///
@@ -21,6 +38,9 @@ pub struct SBMLDocument<'a> {
     document: RefCell<UniquePtr<sbmlcxx::SBMLDocument>>,
     /// The optional Model contained in this document
     model: RefCell<Option<Rc<Model<'a>>>>,
+    /// The comp package content of the document, loaded on first access
+    #[cfg(feature = "comp")]
+    comp_plugin: RefCell<Option<Rc<CompDocumentPlugin<'a>>>>,
 }
 
 impl<'a> SBMLDocument<'a> {
@@ -38,6 +58,8 @@ impl<'a> SBMLDocument<'a> {
         Self {
             document: RefCell::new(document),
             model: RefCell::new(None),
+            #[cfg(feature = "comp")]
+            comp_plugin: RefCell::new(None),
         }
     }
 
@@ -66,6 +88,8 @@ impl<'a> SBMLDocument<'a> {
         Self {
             document,
             model: RefCell::new(model),
+            #[cfg(feature = "comp")]
+            comp_plugin: RefCell::new(None),
         }
     }
 
@@ -76,6 +100,15 @@ impl<'a> SBMLDocument<'a> {
         &self.document
     }
 
+    /// Consumes the wrapper and returns the underlying libSBML document.
+    ///
+    /// This is used internally to rebuild the wrappers after libSBML replaced parts of
+    /// the document, e.g. during flattening.
+    #[cfg(feature = "comp")]
+    pub(crate) fn into_inner(self) -> UniquePtr<sbmlcxx::SBMLDocument> {
+        self.document.into_inner()
+    }
+
     /// Returns the SBML level of the document.
     pub fn level(&self) -> u32 {
         let base = unsafe {
@@ -94,6 +127,107 @@ impl<'a> SBMLDocument<'a> {
         base.getVersion().0
     }
 
+    /// Enables an SBML Level 3 package on the document.
+    ///
+    /// Packages are also enabled automatically when content of the package is created
+    /// through the wrappers of this crate.
+    ///
+    /// # Arguments
+    /// * `package` - The package to enable
+    pub fn enable_package(&self, package: Package) {
+        if let Some(document) = self.document.borrow_mut().as_mut() {
+            packages::enable_package(document, package);
+        }
+    }
+
+    /// Returns whether an SBML Level 3 package is enabled on the document.
+    ///
+    /// # Arguments
+    /// * `package` - The package to check
+    pub fn is_package_enabled(&self, package: Package) -> bool {
+        let base = unsafe {
+            upcast::<sbmlcxx::SBMLDocument, sbmlcxx::SBase>(self.document.borrow_mut().as_mut_ptr())
+        };
+
+        cxx::let_cxx_string!(name = package.name());
+        base.isPackageEnabled(&name)
+    }
+
+    /// Returns the SBML Level 3 packages enabled on the document.
+    ///
+    /// Only packages built into libSBML are recognized, see [`Package::is_available`].
+    pub fn packages(&self) -> Vec<Package> {
+        Package::ALL
+            .into_iter()
+            .filter(|package| self.is_package_enabled(*package))
+            .collect()
+    }
+
+    /// Returns the entries of the error log of the document.
+    ///
+    /// The log contains the problems found while reading the document, as well as
+    /// those of previous consistency checks and conversions.
+    pub fn errors(&self) -> Vec<SBMLError> {
+        errors::error_log(self)
+    }
+
+    /// Validates the document against the rules of the SBML specification.
+    ///
+    /// This runs the consistency checks of libSBML, including identifier, MathML,
+    /// unit and modeling practice checks. The results are added to the error log.
+    ///
+    /// # Returns
+    /// All entries of the error log after the checks, see [`SBMLDocument::errors`]
+    pub fn check_consistency(&self) -> Vec<SBMLError> {
+        if let Some(document) = self.document.borrow_mut().as_mut() {
+            document.checkConsistency();
+        }
+        self.errors()
+    }
+
+    /// Converts the document to another SBML level and version in place.
+    ///
+    /// The conversion is strict, i.e. it fails instead of losing information or
+    /// producing an invalid document. Wrappers of the model obtained before the
+    /// conversion must not be used afterwards, as libSBML may replace the model.
+    ///
+    /// # Arguments
+    /// * `level` - The target SBML Level (e.g. 3)
+    /// * `version` - The target Version within the SBML Level (e.g. 2)
+    ///
+    /// # Returns
+    /// Ok if the document was converted, or an error with the messages of libSBML
+    pub fn set_level_and_version(&self, level: u32, version: u32) -> Result<(), ConversionError> {
+        let converted = match self.document.borrow_mut().as_mut() {
+            Some(mut document) => {
+                let converted = document.as_mut().setLevelAndVersion(
+                    c_uint::from(level),
+                    c_uint::from(version),
+                    true,
+                    false,
+                );
+
+                if converted {
+                    let model_ptr = document.getModel1();
+                    let model = (!model_ptr.is_null()).then(|| Rc::new(Model::from_ptr(model_ptr)));
+                    self.model.replace(model);
+                }
+                converted
+            }
+            None => false,
+        };
+
+        if converted {
+            Ok(())
+        } else {
+            Err(ConversionError {
+                level,
+                version,
+                errors: self.errors(),
+            })
+        }
+    }
+
     /// Creates a new Model within this document with the given ID.
     ///
     /// # Arguments
@@ -107,11 +241,77 @@ impl<'a> SBMLDocument<'a> {
         model
     }
 
+    /// Creates a new ModelBuilder for constructing the Model of this document.
+    ///
+    /// The builder allows setting the default units and conversion factor of the
+    /// model before building it.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the new Model
+    ///
+    /// # Returns
+    /// A ModelBuilder instance that can be used to configure and create the Model
+    pub fn build_model(&'a self, id: &str) -> ModelBuilder<'a> {
+        ModelBuilder::new(self, id)
+    }
+
     /// Returns a reference to the Model if one exists.
     pub fn model(&self) -> Option<Rc<Model<'a>>> {
         self.model.borrow().as_ref().map(|model| Rc::clone(model))
     }
 
+    /// Traverses all elements of the document in document order.
+    ///
+    /// The callbacks of the visitor are called for the model and each of its elements,
+    /// including nested elements such as the units of a unit definition or the
+    /// participants of a reaction. Nothing is visited if the document has no model.
+    ///
+    /// # Arguments
+    /// * `visitor` - The visitor whose callbacks are called for each element
+    pub fn walk(&self, visitor: &mut impl Visitor) {
+        if let Some(model) = self.model() {
+            visitor::walk_model(&model, visitor);
+        }
+    }
+
+    /// Returns the comp package content of the document.
+    ///
+    /// The comp package is enabled on the document if needed.
+    ///
+    /// # Returns
+    /// The CompDocumentPlugin holding the model definitions of the document
+    #[cfg(feature = "comp")]
+    pub fn comp_plugin(&self) -> Rc<CompDocumentPlugin<'a>> {
+        if let Some(plugin) = self.comp_plugin.borrow().as_ref() {
+            return Rc::clone(plugin);
+        }
+
+        let base = unsafe {
+            upcast::<sbmlcxx::SBMLDocument, sbmlcxx::SBase>(self.document.borrow_mut().as_mut_ptr())
+        };
+        let mut plugin = get_plugin::<sbmlcxx::CompSBMLDocumentPlugin>(base, Package::Comp, true)
+            .expect("Document must be valid to use the comp package");
+        let plugin_ptr = unsafe { plugin.as_mut().get_unchecked_mut() as *mut _ };
+
+        let plugin = Rc::new(CompDocumentPlugin::from_ptr(plugin_ptr));
+        self.comp_plugin.replace(Some(Rc::clone(&plugin)));
+        plugin
+    }
+
+    /// Flattens a composed model into a new document with a single model.
+    ///
+    /// Submodels are instantiated, deletions and replacements are resolved and all
+    /// comp constructs are removed. External model definitions are resolved relative to
+    /// the current directory and never fetched from the network; use
+    /// [`Flattener`] to configure the base path or to map sources to local files.
+    ///
+    /// # Returns
+    /// The flattened document, or an error if flattening failed
+    #[cfg(feature = "comp")]
+    pub fn flatten<'b>(&self) -> Result<SBMLDocument<'b>, FlattenError> {
+        Flattener::new().flatten(self)
+    }
+
     /// Converts the SBML document to an XML string representation.
     ///
     /// This function uses the SBMLWriter to serialize the current state of the
@@ -168,6 +368,30 @@ mod tests {
         assert_eq!(model.id(), "test");
     }
 
+    #[test]
+    fn test_sbmldoc_set_level_and_version() {
+        let doc = SBMLDocument::new(3, 1);
+        let model = doc.create_model("test");
+        model.build_compartment("cytosol").constant(true).build();
+
+        doc.set_level_and_version(3, 2).expect("Conversion failed");
+        assert_eq!(doc.level(), 3);
+        assert_eq!(doc.version(), 2);
+
+        let model = doc.model().expect("Model not found");
+        assert_eq!(model.list_of_compartments().len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "fbc")]
+    fn test_sbmldoc_packages() {
+        let doc = SBMLDocument::new(3, 2);
+        assert!(doc.packages().is_empty());
+
+        doc.enable_package(Package::Fbc);
+        assert_eq!(doc.packages(), vec![Package::Fbc]);
+    }
+
     #[test]
     fn test_sbmldoc_to_xml_string() {
         let doc = SBMLDocument::new(3, 2);
@@ -0,0 +1,251 @@
+//! Time course simulation of an [`OdeSystem`] with events.
+//!
+//! The simulation loop advances the integrator one accepted step at a time. Steps are
+//! capped at the next output time and the next scheduled event, so that outputs are
+//! reported exactly at the requested times. After every step the event triggers are
+//! checked; if a trigger switched from false to true, the time of the switch is
+//! located by bisection and the event is executed there.
+
+use super::{
+    events::{execute_pending, fire_events, next_pending, rising, Pending, MAX_BISECTIONS},
+    solver::Integrator,
+    system::OdeSystem,
+    SimulationError, SimulationOptions, TimeCourse,
+};
+
+/// Simulates a system from time zero to `t_end`.
+///
+/// # Arguments
+/// * `system` - The system to simulate
+/// * `t_end` - The end time of the simulation
+/// * `options` - Solver settings and output resolution
+///
+/// # Returns
+/// The values of all reported symbols at the output times
+pub(crate) fn run(
+    system: &OdeSystem,
+    t_end: f64,
+    options: &SimulationOptions,
+) -> Result<TimeCourse, SimulationError> {
+    options.validate(t_end)?;
+
+    let n_points = options.n_points;
+    let times: Vec<f64> = (0..n_points)
+        .map(|i| t_end * i as f64 / (n_points - 1) as f64)
+        .collect();
+
+    let mut values = system.initial_values()?;
+    system.update(0.0, &mut values)?;
+
+    // Triggers that are true at the start only fire if their initial value is false
+    let mut triggers: Vec<bool> = system.events().iter().map(|e| e.initial_value).collect();
+    let mut pending: Vec<Pending> = Vec::new();
+    fire_events(system, 0.0, &mut values, &mut triggers, &mut pending)?;
+
+    let mut course = TimeCourse::new(system.output_names());
+    course.push(0.0, system.outputs(&values));
+
+    let mut integrator = Integrator::new(
+        options.solver,
+        options.rtol,
+        options.atol,
+        f64::INFINITY,
+        options.initial_step,
+    );
+
+    let mut t = 0.0;
+    let mut y = system.states(&values);
+    let mut next_output = 1;
+    let mut steps = 0;
+
+    while next_output < times.len() {
+        steps += 1;
+        if steps > options.max_steps {
+            return Err(SimulationError::MaxStepsExceeded { time: t });
+        }
+
+        let t_max = next_pending(&pending, times[next_output]);
+
+        if t_max > t {
+            let base = values.clone();
+            let mut rhs = |t: f64, y: &[f64]| system.derivatives(t, y, &base);
+            let (mut t_new, mut y_new) = integrator.step(&mut rhs, t, &y, t_max)?;
+
+            let mut values_new = base.clone();
+            system.load(t_new, &y_new, &mut values_new)?;
+
+            // Locate the first trigger that switched from false to true within the step
+            let now = system.triggers(t_new, &values_new)?;
+            let rising = rising(&now, &triggers);
+
+            if !rising.is_empty() {
+                let (mut lo, mut hi) = (t, t_new);
+                for _ in 0..MAX_BISECTIONS {
+                    if hi - lo <= 4.0 * f64::EPSILON * hi.abs().max(1.0) {
+                        break;
+                    }
+
+                    let mid = 0.5 * (lo + hi);
+                    let y_mid = integrator.fixed_step(&mut rhs, t, &y, mid - t)?;
+                    let mut values_mid = base.clone();
+                    system.load(mid, &y_mid, &mut values_mid)?;
+
+                    let fired = system.triggers(mid, &values_mid)?;
+                    if rising.iter().any(|&i| fired[i]) {
+                        hi = mid;
+                        y_new = y_mid;
+                        values_new = values_mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+                t_new = hi;
+            }
+
+            t = t_new;
+            y = y_new;
+            values = values_new;
+        }
+
+        // Execute delayed events that are due
+        let mut fired = execute_pending(system, t, &mut values, &mut pending)?;
+        fired |= fire_events(system, t, &mut values, &mut triggers, &mut pending)?;
+        if fired {
+            integrator.reset();
+            y = system.states(&values);
+        }
+
+        if t >= times[next_output] {
+            course.push(times[next_output], system.outputs(&values));
+            next_output += 1;
+        }
+    }
+
+    Ok(course)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::Expr;
+    use crate::simulate::{
+        system::{EventDefinition, InitialValue, OdeSystemBuilder, SpeciesDefinition, TIME_SYMBOL},
+        Solver,
+    };
+
+    /// Builds the system A -> B with mass action rate k * A in a unit compartment.
+    fn decay() -> OdeSystemBuilder {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("c", 1.0);
+        builder.add_parameter("k", 1.0);
+        for (id, amount) in [("A", 10.0), ("B", 0.0)] {
+            builder.add_species(SpeciesDefinition {
+                id: id.to_string(),
+                compartment: "c".to_string(),
+                initial: InitialValue::Amount(amount),
+                has_only_substance_units: false,
+                boundary_condition: false,
+                constant: false,
+            });
+        }
+        builder.add_reaction(
+            "r",
+            Expr::parse("k * A").unwrap(),
+            vec![("A".to_string(), -1.0), ("B".to_string(), 1.0)],
+        );
+        builder
+    }
+
+    #[test]
+    fn test_decay_solvers() {
+        for solver in [Solver::Rosenbrock, Solver::Rk45] {
+            let system = decay().finish().unwrap();
+            let options = SimulationOptions {
+                solver,
+                n_points: 11,
+                ..Default::default()
+            };
+            let course = run(&system, 2.0, &options).unwrap();
+
+            assert_eq!(course.time.len(), 11);
+            assert!((course.time[10] - 2.0).abs() < 1e-12);
+
+            let a = course.get("A").unwrap();
+            let b = course.get("B").unwrap();
+            for (i, &t) in course.time.iter().enumerate() {
+                assert!((a[i] - 10.0 * (-t).exp()).abs() < 1e-4);
+                assert!((a[i] + b[i] - 10.0).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_event_resets_species() {
+        let mut builder = decay();
+        builder.add_event(EventDefinition {
+            id: "refill".to_string(),
+            trigger: Expr::parse("A < 5").unwrap(),
+            delay: None,
+            assignments: vec![("A".to_string(), Expr::parse("10").unwrap())],
+            initial_value: true,
+            use_values_from_trigger_time: true,
+        });
+        let system = builder.finish().unwrap();
+
+        let options = SimulationOptions {
+            n_points: 2,
+            ..Default::default()
+        };
+        let course = run(&system, 1.0, &options).unwrap();
+
+        // A falls below 5 at t = ln 2 and is reset to 10, decaying for the remaining time
+        let expected = 10.0 * (-(1.0 - std::f64::consts::LN_2)).exp();
+        let a = course.get("A").unwrap();
+        assert!((a[1] - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_delayed_event_and_time() {
+        let mut builder = decay();
+        builder.add_parameter("k2", 0.0);
+        builder.add_event(EventDefinition {
+            id: "switch".to_string(),
+            trigger: Expr::parse("time >= 0.5")
+                .unwrap()
+                .substitute("time", &Expr::Symbol(TIME_SYMBOL.to_string())),
+            delay: Some(Expr::parse("0.25").unwrap()),
+            assignments: vec![("k".to_string(), Expr::parse("0").unwrap())],
+            initial_value: true,
+            use_values_from_trigger_time: true,
+        });
+        let system = builder.finish().unwrap();
+
+        let options = SimulationOptions {
+            n_points: 5,
+            ..Default::default()
+        };
+        let course = run(&system, 2.0, &options).unwrap();
+
+        // The reaction stops at t = 0.75
+        let a = course.get("A").unwrap();
+        let stopped = 10.0 * (-0.75_f64).exp();
+        assert!((a[4] - stopped).abs() < 1e-4);
+        assert_eq!(course.get("k").unwrap(), vec![1.0, 1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_rate_and_assignment_rules() {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("x", 1.0);
+        builder.add_parameter("y", 0.0);
+        builder.add_rate_rule("x", Expr::parse("-2 * x").unwrap());
+        builder.add_assignment_rule("y", Expr::parse("3 * x").unwrap());
+        let system = builder.finish().unwrap();
+
+        let course = run(&system, 1.0, &SimulationOptions::default()).unwrap();
+        let x = course.get("x").unwrap();
+        let y = course.get("y").unwrap();
+        assert!((x[100] - (-2.0_f64).exp()).abs() < 1e-5);
+        assert!((y[100] - 3.0 * x[100]).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,123 @@
+//! Event handling shared by the deterministic and stochastic simulators.
+//!
+//! Events fire when their trigger switches from false to true. Events without delay
+//! are executed right away, which may trigger further events at the same point in
+//! time. Delayed events are kept as [`Pending`] until the simulation time reaches
+//! their execution time.
+
+use super::{system::OdeSystem, SimulationError};
+
+/// Upper bound for events that trigger each other at the same point in time.
+const MAX_CASCADE: usize = 1000;
+
+/// Maximum number of bisection steps used to locate an event.
+pub(crate) const MAX_BISECTIONS: usize = 64;
+
+/// An event that has been triggered and waits for its delay to pass.
+pub(crate) struct Pending {
+    /// Time at which the event executes
+    pub(crate) time: f64,
+    /// Index of the event
+    event: usize,
+    /// Assignment values computed at trigger time, if requested by the event
+    values: Option<Vec<f64>>,
+}
+
+/// Returns the earliest execution time of the pending events, capped at `limit`.
+pub(crate) fn next_pending(pending: &[Pending], limit: f64) -> f64 {
+    pending.iter().map(|p| p.time).fold(limit, f64::min)
+}
+
+/// Returns the events whose trigger is true now but was false before.
+pub(crate) fn rising(now: &[bool], before: &[bool]) -> Vec<usize> {
+    (0..now.len()).filter(|&i| now[i] && !before[i]).collect()
+}
+
+/// Executes all delayed events that are due at time `t`.
+///
+/// # Returns
+/// Whether any event assignments were executed
+pub(crate) fn execute_pending(
+    system: &OdeSystem,
+    t: f64,
+    values: &mut [f64],
+    pending: &mut Vec<Pending>,
+) -> Result<bool, SimulationError> {
+    let mut fired = false;
+    while let Some(position) = pending.iter().position(|p| p.time <= t) {
+        let Pending {
+            event,
+            values: assigned,
+            ..
+        } = pending.remove(position);
+        let assigned = match assigned {
+            Some(assigned) => assigned,
+            None => system.event_values(event, t, values)?,
+        };
+        system.apply_event(event, &assigned, t, values)?;
+        system.update(t, values)?;
+        fired = true;
+    }
+    Ok(fired)
+}
+
+/// Executes all events whose trigger switched from false to true at time `t`.
+///
+/// Events without delay are executed immediately, such that events triggered by
+/// their assignments are executed in turn. Delayed events are scheduled.
+///
+/// # Returns
+/// Whether any event assignments were executed
+pub(crate) fn fire_events(
+    system: &OdeSystem,
+    t: f64,
+    values: &mut [f64],
+    triggers: &mut [bool],
+    pending: &mut Vec<Pending>,
+) -> Result<bool, SimulationError> {
+    let mut fired = false;
+
+    for _ in 0..MAX_CASCADE {
+        let now = system.triggers(t, values)?;
+        let rising = rising(&now, triggers);
+        triggers.copy_from_slice(&now);
+
+        if rising.is_empty() {
+            return Ok(fired);
+        }
+
+        // Simultaneous events all see the values from before any of them executed
+        let mut executions = Vec::new();
+        for i in rising {
+            let event = &system.events()[i];
+            match &event.delay {
+                Some(delay) => {
+                    let delay = system.eval(delay, &event.id, t, values)?;
+                    let assigned = if event.use_values_from_trigger_time {
+                        Some(system.event_values(i, t, values)?)
+                    } else {
+                        None
+                    };
+                    pending.push(Pending {
+                        time: t + delay.max(0.0),
+                        event: i,
+                        values: assigned,
+                    });
+                }
+                None => executions.push((i, system.event_values(i, t, values)?)),
+            }
+        }
+
+        if executions.is_empty() {
+            return Ok(fired);
+        }
+
+        for (i, assigned) in executions {
+            system.apply_event(i, &assigned, t, values)?;
+        }
+        system.update(t, values)?;
+        fired = true;
+    }
+
+    Err(SimulationError::MaxStepsExceeded { time: t })
+}
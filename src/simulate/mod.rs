@@ -0,0 +1,572 @@
+//! Deterministic and stochastic time course simulation of SBML models.
+//!
+//! This module integrates the ODE system defined by a [`Model`]'s reactions, rules,
+//! initial assignments and events, without leaving Rust. For models with low copy
+//! numbers, [`simulate_stochastic`] samples single realizations with Gillespie's
+//! direct method or with tau-leaping. It is meant for quick sanity
+//! checks of models, e.g. those assembled with the builders in unit tests, rather than
+//! as a replacement for a dedicated simulation tool.
+//!
+//! Supported are:
+//!
+//! - Reactions with kinetic laws, including local parameters
+//! - Assignment rules, rate rules and initial assignments
+//! - Events with delays, `initialValue` and `useValuesFromTriggerTime`
+//! - Species in amount or concentration units, as given by `hasOnlySubstanceUnits`
+//! - The time csymbol, under whichever name the document gives it
+//!
+//! Function definitions, algebraic rules and variable stoichiometries are not supported.
+//! Stochastic simulations additionally do not support rate rules.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//! use sbml::simulate::{simulate, SimulationOptions};
+//!
+//! let doc = SBMLDocument::default();
+//! let model = Model::new(&doc, "decay");
+//! model.build_compartment("c").size(1.0).build();
+//! model.build_species("A").compartment("c").initial_amount(10.0).build();
+//! model.build_parameter("k").value(0.5).build();
+//! model
+//!     .build_reaction("r")
+//!     .reactant("A", 1.0)
+//!     .kinetic_law("k * A")
+//!     .build();
+//!
+//! let course = simulate(&model, 10.0, SimulationOptions::default()).unwrap();
+//! println!("{:?}", course.get("A"));
+//! ```
+
+use std::fmt;
+
+use crate::{
+    astnode::formula_with_time,
+    formula::{Expr, FormulaError},
+    model::Model,
+    sbmlcxx,
+};
+
+mod deterministic;
+mod events;
+mod solver;
+mod stochastic;
+mod system;
+
+pub use solver::Solver;
+pub use stochastic::StochasticMethod;
+
+use system::{
+    EventDefinition, InitialValue, OdeSystem, OdeSystemBuilder, SpeciesDefinition, TIME_SYMBOL,
+};
+
+/// Settings of a deterministic simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationOptions {
+    /// The integration method
+    pub solver: Solver,
+    /// Relative tolerance of the local error
+    pub rtol: f64,
+    /// Absolute tolerance of the local error
+    pub atol: f64,
+    /// Maximum number of integration steps
+    pub max_steps: usize,
+    /// Number of equally spaced output points, including start and end time
+    pub n_points: usize,
+    /// Size of the first step, estimated automatically if None
+    pub initial_step: Option<f64>,
+}
+
+impl Default for SimulationOptions {
+    fn default() -> Self {
+        Self {
+            solver: Solver::default(),
+            rtol: 1e-6,
+            atol: 1e-9,
+            max_steps: 100_000,
+            n_points: 101,
+            initial_step: None,
+        }
+    }
+}
+
+impl SimulationOptions {
+    /// Checks that the options and the end time are usable.
+    fn validate(&self, t_end: f64) -> Result<(), SimulationError> {
+        validate_output(t_end, self.n_points)?;
+        if !(self.rtol > 0.0 && self.atol > 0.0) {
+            return Err(SimulationError::InvalidOptions(
+                "Tolerances must be positive".to_string(),
+            ));
+        }
+        if let Some(step) = self.initial_step {
+            if !(step.is_finite() && step > 0.0) {
+                return Err(SimulationError::InvalidOptions(format!(
+                    "Initial step must be positive and finite, got {}",
+                    step
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Settings of a stochastic simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticOptions {
+    /// The method used to sample reaction events
+    pub method: StochasticMethod,
+    /// Seed of the random number generator, drawn from the system if None
+    pub seed: Option<u64>,
+    /// Bound of the relative change of propensities within a leap, only used by tau-leaping
+    pub epsilon: f64,
+    /// Maximum number of steps, each firing one reaction or one leap
+    pub max_steps: usize,
+    /// Number of equally spaced output points, including start and end time
+    pub n_points: usize,
+}
+
+impl Default for StochasticOptions {
+    fn default() -> Self {
+        Self {
+            method: StochasticMethod::default(),
+            seed: None,
+            epsilon: 0.03,
+            max_steps: 10_000_000,
+            n_points: 101,
+        }
+    }
+}
+
+impl StochasticOptions {
+    /// Checks that the options and the end time are usable.
+    fn validate(&self, t_end: f64) -> Result<(), SimulationError> {
+        validate_output(t_end, self.n_points)?;
+        if !(self.epsilon > 0.0 && self.epsilon < 1.0) {
+            return Err(SimulationError::InvalidOptions(format!(
+                "Epsilon must be between zero and one, got {}",
+                self.epsilon
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the end time and the number of output points are usable.
+fn validate_output(t_end: f64, n_points: usize) -> Result<(), SimulationError> {
+    if !(t_end.is_finite() && t_end > 0.0) {
+        return Err(SimulationError::InvalidOptions(format!(
+            "End time must be positive and finite, got {}",
+            t_end
+        )));
+    }
+    if n_points < 2 {
+        return Err(SimulationError::InvalidOptions(
+            "At least two output points are required".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// The result of a simulation as a table of values over time.
+///
+/// Species are reported as concentrations, unless they have only substance units.
+/// Besides species, all quantities changed by rules or events are reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeCourse {
+    /// The output times
+    pub time: Vec<f64>,
+    /// The identifiers of the reported quantities, one per column
+    pub names: Vec<String>,
+    /// The reported values, one row per output time
+    pub rows: Vec<Vec<f64>>,
+}
+
+impl TimeCourse {
+    /// Creates an empty time course with the given columns.
+    fn new(names: Vec<String>) -> Self {
+        Self {
+            time: Vec::new(),
+            names,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row to the time course.
+    fn push(&mut self, time: f64, row: Vec<f64>) {
+        self.time.push(time);
+        self.rows.push(row);
+    }
+
+    /// Returns the values of a quantity over time.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the quantity
+    ///
+    /// # Returns
+    /// Some(values) with one value per output time, None if the quantity is not reported
+    pub fn get(&self, id: &str) -> Option<Vec<f64>> {
+        let column = self.names.iter().position(|name| name == id)?;
+        Some(self.rows.iter().map(|row| row[column]).collect())
+    }
+}
+
+/// Errors that can occur while setting up or running a simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimulationError {
+    /// A formula of a model element could not be parsed or evaluated
+    Formula {
+        element: String,
+        source: FormulaError,
+    },
+    /// The model cannot be simulated as it is
+    InvalidModel(String),
+    /// The given simulation options are invalid
+    InvalidOptions(String),
+    /// Assignment rules or initial assignments depend on each other in a cycle
+    AlgebraicLoop(Vec<String>),
+    /// The step size became too small to satisfy the tolerances
+    StepSizeTooSmall { time: f64 },
+    /// The iteration matrix of the implicit solver is singular
+    SingularJacobian { time: f64 },
+    /// The maximum number of steps was exceeded
+    MaxStepsExceeded { time: f64 },
+    /// A quantity became infinite or NaN
+    NonFinite { symbol: String, time: f64 },
+    /// The rate of a reaction became negative during a stochastic simulation
+    NegativePropensity { reaction: String, time: f64 },
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::Formula { element, source } => {
+                write!(f, "Invalid formula of '{}': {}", element, source)
+            }
+            SimulationError::InvalidModel(message) => write!(f, "Invalid model: {}", message),
+            SimulationError::InvalidOptions(message) => {
+                write!(f, "Invalid simulation options: {}", message)
+            }
+            SimulationError::AlgebraicLoop(symbols) => {
+                write!(f, "Algebraic loop between {}", symbols.join(", "))
+            }
+            SimulationError::StepSizeTooSmall { time } => {
+                write!(f, "Step size became too small at t = {}", time)
+            }
+            SimulationError::SingularJacobian { time } => {
+                write!(f, "Singular iteration matrix at t = {}", time)
+            }
+            SimulationError::MaxStepsExceeded { time } => {
+                write!(f, "Maximum number of steps exceeded at t = {}", time)
+            }
+            SimulationError::NonFinite { symbol, time } => {
+                write!(f, "Value of '{}' is not finite at t = {}", symbol, time)
+            }
+            SimulationError::NegativePropensity { reaction, time } => {
+                write!(
+                    f,
+                    "Rate of reaction '{}' is negative at t = {}",
+                    reaction, time
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+/// Simulates a model from time zero to `t_end`.
+///
+/// # Arguments
+/// * `model` - The model to simulate
+/// * `t_end` - The end time of the simulation
+/// * `options` - Solver settings and output resolution
+///
+/// # Returns
+/// The time course of the species and all quantities changed by rules or events
+pub fn simulate(
+    model: &Model,
+    t_end: f64,
+    options: SimulationOptions,
+) -> Result<TimeCourse, SimulationError> {
+    let system = ode_system(model)?;
+    deterministic::run(&system, t_end, &options)
+}
+
+/// Simulates a model stochastically from time zero to `t_end`.
+///
+/// Species amounts are interpreted as molecule counts and the kinetic laws as
+/// reaction propensities, which must not become negative. Species that do not have
+/// only substance units are still reported as concentrations. Rate rules are not
+/// supported.
+///
+/// # Arguments
+/// * `model` - The model to simulate
+/// * `t_end` - The end time of the simulation
+/// * `options` - Sampling method, seed and output resolution
+///
+/// # Returns
+/// A single realization of the time course of the species and all quantities
+/// changed by rules or events
+pub fn simulate_stochastic(
+    model: &Model,
+    t_end: f64,
+    options: StochasticOptions,
+) -> Result<TimeCourse, SimulationError> {
+    let system = ode_system(model)?;
+    stochastic::run(&system, t_end, &options)
+}
+
+/// Parses the math of a model element, with time csymbols referring to the simulation time.
+fn parse(math: *const sbmlcxx::ASTNode, element: &str) -> Result<Expr, SimulationError> {
+    let (formula, time) = formula_with_time(math);
+    let expr = Expr::parse(&formula).map_err(|source| SimulationError::Formula {
+        element: element.to_string(),
+        source,
+    })?;
+    Ok(expr.substitute(&time, &Expr::Symbol(TIME_SYMBOL.to_string())))
+}
+
+/// Translates a model into an ODE system.
+fn ode_system(model: &Model) -> Result<OdeSystem, SimulationError> {
+    let mut builder = OdeSystemBuilder::default();
+
+    for compartment in model.list_of_compartments() {
+        builder.add_parameter(&compartment.id(), compartment.size());
+    }
+
+    for parameter in model.list_of_parameters() {
+        builder.add_parameter(&parameter.id(), parameter.value().unwrap_or(f64::NAN));
+    }
+
+    for species in model.list_of_species() {
+        let initial = if species.inner().borrow().isSetInitialConcentration() {
+            InitialValue::Concentration(species.initial_concentration())
+        } else {
+            InitialValue::Amount(species.initial_amount())
+        };
+
+        builder.add_species(SpeciesDefinition {
+            id: species.id(),
+            compartment: species.compartment(),
+            initial,
+            has_only_substance_units: species.has_only_substance_units(),
+            boundary_condition: species.boundary_condition(),
+            constant: species.constant(),
+        });
+    }
+
+    for reaction in model.list_of_reactions() {
+        let id = reaction.id();
+        let kinetic_law = reaction.kinetic_law().ok_or_else(|| {
+            SimulationError::InvalidModel(format!("Reaction '{}' has no kinetic law", id))
+        })?;
+
+        // Local parameters shadow global quantities, so they are substituted directly
+        let mut rate = parse(kinetic_law.math(), &id)?;
+        for parameter in kinetic_law.list_of_local_parameters() {
+            let value = parameter.value().unwrap_or(f64::NAN);
+            rate = rate.substitute(&parameter.id(), &Expr::Number(value));
+        }
+
+        let mut stoichiometry = Vec::new();
+        for reactant in reaction.reactants().borrow().iter() {
+            stoichiometry.push((reactant.species(), -reactant.stoichiometry()));
+        }
+        for product in reaction.products().borrow().iter() {
+            stoichiometry.push((product.species(), product.stoichiometry()));
+        }
+
+        builder.add_reaction(&id, rate, stoichiometry);
+    }
+
+    for rule in model.list_of_assignment_rules() {
+        let variable = rule.variable();
+        builder.add_assignment_rule(&variable, parse(rule.math(), &variable)?);
+    }
+
+    for rule in model.list_of_rate_rules() {
+        let variable = rule.variable();
+        builder.add_rate_rule(&variable, parse(rule.math(), &variable)?);
+    }
+
+    for assignment in model.list_of_initial_assignments() {
+        let symbol = assignment.symbol();
+        builder.add_initial_assignment(&symbol, parse(assignment.math(), &symbol)?);
+    }
+
+    for event in model.list_of_events() {
+        let id = event.id();
+        let trigger = event.trigger().ok_or_else(|| {
+            SimulationError::InvalidModel(format!("Event '{}' has no trigger", id))
+        })?;
+        let delay = match event.delay() {
+            Some(delay) => Some(parse(delay.math(), &id)?),
+            None => None,
+        };
+
+        let mut assignments = Vec::new();
+        for assignment in event.list_of_event_assignments() {
+            assignments.push((assignment.variable(), parse(assignment.math(), &id)?));
+        }
+
+        builder.add_event(EventDefinition {
+            trigger: parse(trigger.math(), &id)?,
+            delay,
+            assignments,
+            initial_value: trigger.initial_value(),
+            use_values_from_trigger_time: event.use_values_from_trigger_time(),
+            id,
+        });
+    }
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_simulate_model() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_compartment("c").size(2.0).build();
+        model
+            .build_species("A")
+            .compartment("c")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_species("B")
+            .compartment("c")
+            .initial_amount(0.0)
+            .build();
+        model.build_parameter("k").value(1.0).build();
+        model
+            .build_reaction("r")
+            .reactant("A", 1.0)
+            .product("B", 1.0)
+            .kinetic_law("k * A * c")
+            .build();
+
+        let course = simulate(&model, 1.0, SimulationOptions::default()).unwrap();
+        let a = course.get("A").unwrap();
+        let b = course.get("B").unwrap();
+
+        assert_eq!(course.names, vec!["A", "B"]);
+        assert!((a[100] - 5.0 * (-1.0_f64).exp()).abs() < 1e-5);
+        assert!((a[100] + b[100] - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_simulate_rules_and_events() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_parameter("x").value(0.0).build();
+        model.build_parameter("y").value(0.0).build();
+        model.build_parameter("k").value(1.0).build();
+        model.create_rate_rule("x", "k");
+        model.create_assignment_rule("y", "2 * x");
+        model.create_initial_assignment("k", "0.5 * 4");
+        model
+            .build_event("stop")
+            .trigger("x >= 1")
+            .assignment("k", "0")
+            .build();
+
+        let course = simulate(&model, 2.0, SimulationOptions::default()).unwrap();
+        let x = course.get("x").unwrap();
+        let y = course.get("y").unwrap();
+
+        // x grows with rate 2 until it reaches 1 at t = 0.5
+        assert!((x[100] - 1.0).abs() < 1e-6);
+        assert!((y[100] - 2.0).abs() < 1e-6);
+        assert_eq!(course.get("k").unwrap()[100], 0.0);
+    }
+
+    #[test]
+    fn test_simulate_time_csymbol() {
+        // The time csymbol is named t, like a parameter of the model
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level3/version2/core" level="3" version="2">
+  <model id="test">
+    <listOfParameters>
+      <parameter id="t" value="100" constant="true"/>
+      <parameter id="x" value="0" constant="false"/>
+      <parameter id="y" value="0" constant="false"/>
+    </listOfParameters>
+    <listOfRules>
+      <rateRule variable="x">
+        <math xmlns="http://www.w3.org/1998/Math/MathML">
+          <csymbol encoding="text" definitionURL="http://www.sbml.org/sbml/symbols/time"> t </csymbol>
+        </math>
+      </rateRule>
+      <assignmentRule variable="y">
+        <math xmlns="http://www.w3.org/1998/Math/MathML">
+          <ci> t </ci>
+        </math>
+      </assignmentRule>
+    </listOfRules>
+  </model>
+</sbml>"#;
+        let doc = SBMLReader::from_xml_string(xml);
+        let model = doc.model().unwrap();
+
+        let course = simulate(&model, 1.0, SimulationOptions::default()).unwrap();
+        let x = course.get("x").unwrap();
+        let y = course.get("y").unwrap();
+
+        // dx/dt = t integrates to t^2 / 2, while y follows the parameter
+        assert!((x[100] - 0.5).abs() < 1e-6);
+        assert_eq!(y[100], 100.0);
+    }
+
+    #[test]
+    fn test_simulate_stochastic_model() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_compartment("c").size(1.0).build();
+        model
+            .build_species("mRNA")
+            .compartment("c")
+            .initial_amount(0.0)
+            .has_only_substance_units(true)
+            .build();
+        model.build_parameter("k_tx").value(5.0).build();
+        model.build_parameter("k_deg").value(0.5).build();
+        model
+            .build_reaction("transcription")
+            .product("mRNA", 1.0)
+            .kinetic_law("k_tx")
+            .build();
+        model
+            .build_reaction("degradation")
+            .reactant("mRNA", 1.0)
+            .kinetic_law("k_deg * mRNA")
+            .build();
+
+        let options = StochasticOptions {
+            seed: Some(3),
+            ..Default::default()
+        };
+        let course = simulate_stochastic(&model, 20.0, options.clone()).unwrap();
+        let again = simulate_stochastic(&model, 20.0, options).unwrap();
+        let mrna = course.get("mRNA").unwrap();
+
+        assert_eq!(course, again);
+        assert!(mrna.iter().all(|x| x.fract() == 0.0 && *x >= 0.0));
+        assert!(mrna[100] > 0.0);
+    }
+
+    #[test]
+    fn test_simulate_missing_kinetic_law() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_reaction("r").build();
+
+        let result = simulate(&model, 1.0, SimulationOptions::default());
+        assert!(matches!(result, Err(SimulationError::InvalidModel(_))));
+    }
+}
@@ -0,0 +1,441 @@
+//! Adaptive ODE integrators used by the deterministic simulator.
+//!
+//! Two methods are provided:
+//!
+//! - An explicit Dormand-Prince 5(4) Runge-Kutta method for non-stiff systems
+//! - A linearly implicit Rosenbrock 2(3) method (as in MATLAB's `ode23s`) for stiff
+//!   systems, using a finite-difference Jacobian
+//!
+//! Both share the same step size control. The integrators only ever take a single
+//! accepted step at a time, so that the caller can interleave output sampling and
+//! event detection between steps.
+
+use crate::linalg::Lu;
+
+use super::SimulationError;
+
+/// The right-hand side `dy/dt = f(t, y)` of an ODE system.
+pub(crate) type Rhs<'r> = dyn FnMut(f64, &[f64]) -> Result<Vec<f64>, SimulationError> + 'r;
+
+/// The integration method used by the deterministic simulator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Solver {
+    /// Linearly implicit Rosenbrock 2(3) method, suitable for stiff systems
+    #[default]
+    Rosenbrock,
+    /// Explicit Dormand-Prince 5(4) Runge-Kutta method for non-stiff systems
+    Rk45,
+}
+
+/// Dormand-Prince nodes
+const DP_C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// Dormand-Prince Runge-Kutta matrix (lower triangle)
+const DP_A: [[f64; 6]; 7] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+        0.0,
+        0.0,
+    ],
+    [
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+        0.0,
+    ],
+    [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+
+/// Fifth order weights (identical to the last row of `DP_A`)
+const DP_B: [f64; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+
+/// Difference between the fifth and the embedded fourth order weights
+const DP_E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// An adaptive integrator that advances an ODE system one accepted step at a time.
+pub(crate) struct Integrator {
+    /// The integration method
+    solver: Solver,
+    /// Relative tolerance of the local error estimate
+    rtol: f64,
+    /// Absolute tolerance of the local error estimate
+    atol: f64,
+    /// Upper bound for the step size
+    max_step: f64,
+    /// Step size used after a reset, None if it has to be estimated
+    first_step: Option<f64>,
+    /// Step size proposed for the next step, None if it has to be estimated
+    h: Option<f64>,
+}
+
+impl Integrator {
+    /// Creates a new integrator.
+    ///
+    /// # Arguments
+    /// * `solver` - The integration method
+    /// * `rtol` - Relative tolerance
+    /// * `atol` - Absolute tolerance
+    /// * `max_step` - Largest step the integrator may take
+    /// * `first_step` - Size of the first step, estimated from the system if None
+    pub(crate) fn new(
+        solver: Solver,
+        rtol: f64,
+        atol: f64,
+        max_step: f64,
+        first_step: Option<f64>,
+    ) -> Self {
+        Self {
+            solver,
+            rtol,
+            atol,
+            max_step,
+            first_step,
+            h: first_step,
+        }
+    }
+
+    /// Forgets the current step size, e.g. after a discontinuity caused by an event.
+    pub(crate) fn reset(&mut self) {
+        self.h = self.first_step;
+    }
+
+    /// Takes a single accepted step from `t` that does not go beyond `t_max`.
+    ///
+    /// # Arguments
+    /// * `f` - The right-hand side of the system
+    /// * `t` - The current time
+    /// * `y` - The current state
+    /// * `t_max` - The time the step must not exceed
+    ///
+    /// # Returns
+    /// The time and state reached by the accepted step
+    pub(crate) fn step(
+        &mut self,
+        f: &mut Rhs,
+        t: f64,
+        y: &[f64],
+        t_max: f64,
+    ) -> Result<(f64, Vec<f64>), SimulationError> {
+        let span = t_max - t;
+        let min_step = 16.0 * f64::EPSILON * t.abs().max(span.abs()).max(1e-300);
+        let mut h = match self.h {
+            Some(h) => h,
+            None => self.initial_step(f, t, y, span)?,
+        }
+        .min(self.max_step)
+        .min(span);
+
+        loop {
+            let (y_new, error) = self.attempt(f, t, y, h)?;
+
+            if error <= 1.0 {
+                // Propose the next step from the error estimate, limiting the growth
+                let factor = if error == 0.0 {
+                    5.0
+                } else {
+                    (0.9 * error.powf(-1.0 / self.order())).clamp(0.2, 5.0)
+                };
+
+                // Do not let a step that was shortened to hit t_max shrink the proposal
+                let proposal = (h * factor).max(self.h.unwrap_or(0.0).min(h * 5.0));
+                self.h = Some(proposal.min(self.max_step));
+                return Ok((if h == span { t_max } else { t + h }, y_new));
+            }
+
+            // Reject the step and retry with a smaller one
+            let factor = if error.is_finite() {
+                (0.9 * error.powf(-1.0 / self.order())).clamp(0.1, 0.5)
+            } else {
+                0.1
+            };
+            h *= factor;
+            self.h = Some(h);
+
+            if h < min_step {
+                return Err(SimulationError::StepSizeTooSmall { time: t });
+            }
+        }
+    }
+
+    /// Takes a single step of the given size without error control.
+    ///
+    /// This is used to locate events within an already accepted step.
+    pub(crate) fn fixed_step(
+        &self,
+        f: &mut Rhs,
+        t: f64,
+        y: &[f64],
+        h: f64,
+    ) -> Result<Vec<f64>, SimulationError> {
+        if h == 0.0 {
+            return Ok(y.to_vec());
+        }
+        Ok(self.attempt(f, t, y, h)?.0)
+    }
+
+    /// Returns the order used for the step size control.
+    fn order(&self) -> f64 {
+        match self.solver {
+            Solver::Rk45 => 5.0,
+            Solver::Rosenbrock => 3.0,
+        }
+    }
+
+    /// Computes a step with the configured method and its scaled error norm.
+    fn attempt(
+        &self,
+        f: &mut Rhs,
+        t: f64,
+        y: &[f64],
+        h: f64,
+    ) -> Result<(Vec<f64>, f64), SimulationError> {
+        let (y_new, error) = match self.solver {
+            Solver::Rk45 => dormand_prince(f, t, y, h)?,
+            Solver::Rosenbrock => rosenbrock(f, t, y, h)?,
+        };
+
+        if y_new.iter().any(|v| !v.is_finite()) {
+            return Ok((y_new, f64::INFINITY));
+        }
+
+        let norm = self.error_norm(y, &y_new, &error);
+        Ok((y_new, norm))
+    }
+
+    /// Computes the RMS norm of the local error scaled by the tolerances.
+    fn error_norm(&self, y: &[f64], y_new: &[f64], error: &[f64]) -> f64 {
+        if error.is_empty() {
+            return 0.0;
+        }
+
+        let sum: f64 = error
+            .iter()
+            .zip(y.iter().zip(y_new))
+            .map(|(e, (a, b))| {
+                let scale = self.atol + self.rtol * a.abs().max(b.abs());
+                (e / scale).powi(2)
+            })
+            .sum();
+
+        (sum / error.len() as f64).sqrt()
+    }
+
+    /// Estimates a suitable first step size (Hairer, Norsett & Wanner, II.4).
+    fn initial_step(
+        &self,
+        f: &mut Rhs,
+        t: f64,
+        y: &[f64],
+        span: f64,
+    ) -> Result<f64, SimulationError> {
+        let f0 = f(t, y)?;
+        let scale: Vec<f64> = y.iter().map(|v| self.atol + self.rtol * v.abs()).collect();
+        let norm = |v: &[f64]| -> f64 {
+            if v.is_empty() {
+                return 0.0;
+            }
+            let sum: f64 = v.iter().zip(&scale).map(|(x, s)| (x / s).powi(2)).sum();
+            (sum / v.len() as f64).sqrt()
+        };
+
+        let d0 = norm(y);
+        let d1 = norm(&f0);
+        let h0 = if d0 < 1e-5 || d1 < 1e-5 {
+            1e-6
+        } else {
+            0.01 * d0 / d1
+        }
+        .min(span);
+
+        let y1: Vec<f64> = y.iter().zip(&f0).map(|(y, f)| y + h0 * f).collect();
+        let f1 = f(t + h0, &y1)?;
+        let diff: Vec<f64> = f1.iter().zip(&f0).map(|(a, b)| a - b).collect();
+        let d2 = norm(&diff) / h0;
+
+        let h1 = if d1.max(d2) <= 1e-15 {
+            (h0 * 1e-3).max(1e-6)
+        } else {
+            (0.01 / d1.max(d2)).powf(1.0 / self.order())
+        };
+
+        Ok((100.0 * h0).min(h1).min(span).max(f64::MIN_POSITIVE))
+    }
+}
+
+/// A single Dormand-Prince 5(4) step returning the new state and the error estimate.
+fn dormand_prince(
+    f: &mut Rhs,
+    t: f64,
+    y: &[f64],
+    h: f64,
+) -> Result<(Vec<f64>, Vec<f64>), SimulationError> {
+    let n = y.len();
+    let mut k: Vec<Vec<f64>> = Vec::with_capacity(7);
+
+    for stage in 0..7 {
+        let y_stage: Vec<f64> = (0..n)
+            .map(|i| y[i] + h * (0..stage).map(|j| DP_A[stage][j] * k[j][i]).sum::<f64>())
+            .collect();
+        k.push(f(t + DP_C[stage] * h, &y_stage)?);
+    }
+
+    let y_new: Vec<f64> = (0..n)
+        .map(|i| y[i] + h * (0..7).map(|j| DP_B[j] * k[j][i]).sum::<f64>())
+        .collect();
+    let error: Vec<f64> = (0..n)
+        .map(|i| h * (0..7).map(|j| DP_E[j] * k[j][i]).sum::<f64>())
+        .collect();
+
+    Ok((y_new, error))
+}
+
+/// A single Rosenbrock 2(3) step (Shampine & Reichelt, 1997).
+fn rosenbrock(
+    f: &mut Rhs,
+    t: f64,
+    y: &[f64],
+    h: f64,
+) -> Result<(Vec<f64>, Vec<f64>), SimulationError> {
+    let n = y.len();
+    let d = 1.0 / (2.0 + std::f64::consts::SQRT_2);
+    let e32 = 6.0 + std::f64::consts::SQRT_2;
+
+    let f0 = f(t, y)?;
+    let (jacobian, dfdt) = jacobian(f, t, y, &f0)?;
+
+    // W = I - h d J
+    let mut w: Vec<f64> = jacobian.iter().map(|j| -h * d * j).collect();
+    for i in 0..n {
+        w[i * n + i] += 1.0;
+    }
+    let lu = Lu::factorize(&w, n).ok_or(SimulationError::SingularJacobian { time: t })?;
+
+    let rhs1: Vec<f64> = (0..n).map(|i| f0[i] + h * d * dfdt[i]).collect();
+    let k1 = lu.solve(&rhs1);
+
+    let y1: Vec<f64> = (0..n).map(|i| y[i] + 0.5 * h * k1[i]).collect();
+    let f1 = f(t + 0.5 * h, &y1)?;
+    let rhs2: Vec<f64> = (0..n).map(|i| f1[i] - k1[i]).collect();
+    let k2: Vec<f64> = lu
+        .solve(&rhs2)
+        .iter()
+        .zip(&k1)
+        .map(|(a, b)| a + b)
+        .collect();
+
+    let y_new: Vec<f64> = (0..n).map(|i| y[i] + h * k2[i]).collect();
+    let f2 = f(t + h, &y_new)?;
+    let rhs3: Vec<f64> = (0..n)
+        .map(|i| f2[i] - e32 * (k2[i] - f1[i]) - 2.0 * (k1[i] - f0[i]) + h * d * dfdt[i])
+        .collect();
+    let k3 = lu.solve(&rhs3);
+
+    let error: Vec<f64> = (0..n)
+        .map(|i| h / 6.0 * (k1[i] - 2.0 * k2[i] + k3[i]))
+        .collect();
+
+    Ok((y_new, error))
+}
+
+/// Approximates the Jacobian `df/dy` (row-major) and `df/dt` by forward differences.
+fn jacobian(
+    f: &mut Rhs,
+    t: f64,
+    y: &[f64],
+    f0: &[f64],
+) -> Result<(Vec<f64>, Vec<f64>), SimulationError> {
+    let n = y.len();
+    let sqrt_eps = f64::EPSILON.sqrt();
+    let mut jacobian = vec![0.0; n * n];
+    let mut y_perturbed = y.to_vec();
+
+    for j in 0..n {
+        let delta = sqrt_eps * y[j].abs().max(1e-8);
+        y_perturbed[j] = y[j] + delta;
+        let f_perturbed = f(t, &y_perturbed)?;
+        y_perturbed[j] = y[j];
+
+        for i in 0..n {
+            jacobian[i * n + j] = (f_perturbed[i] - f0[i]) / delta;
+        }
+    }
+
+    let dt = sqrt_eps * t.abs().max(1e-8);
+    let f_later = f(t + dt, y)?;
+    let dfdt = f_later.iter().zip(f0).map(|(a, b)| (a - b) / dt).collect();
+
+    Ok((jacobian, dfdt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Integrates `dy/dt = -k y` from 0 to 1 and compares against the exact solution.
+    fn decay(solver: Solver, k: f64) -> f64 {
+        let mut integrator = Integrator::new(solver, 1e-8, 1e-10, f64::INFINITY, None);
+        let mut f = |_t: f64, y: &[f64]| Ok(vec![-k * y[0]]);
+        let (mut t, mut y) = (0.0, vec![1.0]);
+        while t < 1.0 {
+            (t, y) = integrator.step(&mut f, t, &y, 1.0).unwrap();
+        }
+        (y[0] - (-k).exp()).abs()
+    }
+
+    #[test]
+    fn test_rk45_decay() {
+        assert!(decay(Solver::Rk45, 1.0) < 1e-7);
+    }
+
+    #[test]
+    fn test_rosenbrock_decay() {
+        assert!(decay(Solver::Rosenbrock, 1.0) < 1e-6);
+    }
+
+    #[test]
+    fn test_rosenbrock_stiff() {
+        // Stiff decay is handled without the step size collapsing
+        assert!(decay(Solver::Rosenbrock, 1e4) < 1e-6);
+    }
+
+    #[test]
+    fn test_step_hits_t_max() {
+        let mut integrator = Integrator::new(Solver::Rk45, 1e-6, 1e-9, f64::INFINITY, Some(1.0));
+        let mut f = |_t: f64, _y: &[f64]| Ok(vec![1.0]);
+        let (t, y) = integrator.step(&mut f, 0.0, &[0.0], 1e-3).unwrap();
+        assert_eq!(t, 1e-3);
+        assert!((y[0] - 1e-3).abs() < 1e-12);
+    }
+}
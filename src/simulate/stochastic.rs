@@ -0,0 +1,522 @@
+//! Stochastic time course simulation of an [`OdeSystem`] with events.
+//!
+//! Species amounts are treated as molecule counts and the rates of the kinetic laws
+//! as reaction propensities. Two methods are available:
+//!
+//! - The direct method of Gillespie, which simulates every single reaction event
+//! - Tau-leaping with the step size selection of Cao, Gillespie and Petzold (2006),
+//!   which fires many reactions at once. Reactions that are close to exhausting one
+//!   of their reactants are treated as critical and fire at most once per leap, such
+//!   that amounts cannot become negative.
+//!
+//! Between reaction events the species amounts are constant. Triggers that depend on
+//! time are located by bisection within this interval, after which the reaction event
+//! is sampled anew.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::{Exp1, Poisson};
+
+use super::{
+    events::{execute_pending, fire_events, next_pending, rising, Pending, MAX_BISECTIONS},
+    system::OdeSystem,
+    SimulationError, StochasticOptions, TimeCourse,
+};
+
+/// Reactions that can fire fewer times than this before exhausting a reactant are critical.
+const CRITICAL_FIRINGS: f64 = 10.0;
+
+/// Leaps shorter than this many expected reaction events fall back to the direct method.
+const MIN_LEAP_EVENTS: f64 = 10.0;
+
+/// Maximum number of times a leap is halved because amounts became negative.
+const MAX_HALVINGS: usize = 64;
+
+/// The method used to sample reaction events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StochasticMethod {
+    /// Gillespie's direct method, which is exact but fires one reaction per step
+    #[default]
+    Direct,
+    /// Tau-leaping, which fires many reactions per step at a small loss of accuracy
+    TauLeaping,
+}
+
+/// Reaction events sampled for the interval until the next step.
+struct Step {
+    /// Length of the interval
+    dt: f64,
+    /// Reactions and how often they fire at the end of the interval
+    firings: Vec<(usize, f64)>,
+}
+
+/// Simulates a system from time zero to `t_end`.
+///
+/// # Arguments
+/// * `system` - The system to simulate
+/// * `t_end` - The end time of the simulation
+/// * `options` - Sampling method, seed and output resolution
+///
+/// # Returns
+/// The values of all reported symbols at the output times
+pub(crate) fn run(
+    system: &OdeSystem,
+    t_end: f64,
+    options: &StochasticOptions,
+) -> Result<TimeCourse, SimulationError> {
+    options.validate(t_end)?;
+
+    if system.has_rate_rules() {
+        return Err(SimulationError::InvalidModel(
+            "Rate rules are not supported by stochastic simulation".to_string(),
+        ));
+    }
+
+    let mut rng = match options.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let n_points = options.n_points;
+    let times: Vec<f64> = (0..n_points)
+        .map(|i| t_end * i as f64 / (n_points - 1) as f64)
+        .collect();
+
+    let mut values = system.initial_values()?;
+    system.update(0.0, &mut values)?;
+
+    // Triggers that are true at the start only fire if their initial value is false
+    let mut triggers: Vec<bool> = system.events().iter().map(|e| e.initial_value).collect();
+    let mut pending: Vec<Pending> = Vec::new();
+    fire_events(system, 0.0, &mut values, &mut triggers, &mut pending)?;
+
+    let mut course = TimeCourse::new(system.output_names());
+    course.push(0.0, system.outputs(&values));
+
+    let mut t = 0.0;
+    let mut next_output = 1;
+    let mut steps = 0;
+
+    while next_output < times.len() {
+        steps += 1;
+        if steps > options.max_steps {
+            return Err(SimulationError::MaxStepsExceeded { time: t });
+        }
+
+        let t_max = next_pending(&pending, times[next_output]);
+
+        if t_max > t {
+            let horizon = t_max - t;
+            let propensities = system.propensities(t, &values)?;
+            let step = match options.method {
+                StochasticMethod::Direct => direct(&propensities, horizon, &mut rng),
+                StochasticMethod::TauLeaping => leap(
+                    system,
+                    &values,
+                    &propensities,
+                    options.epsilon,
+                    horizon,
+                    &mut rng,
+                ),
+            };
+
+            let t_new = if step.dt >= horizon {
+                t_max
+            } else {
+                t + step.dt
+            };
+
+            // The amounts are constant until the reactions fire, only time advances
+            let mut stationary = values.clone();
+            system.update(t_new, &mut stationary)?;
+
+            let now = system.triggers(t_new, &stationary)?;
+            let rising = rising(&now, &triggers);
+
+            if !rising.is_empty() {
+                // Move to the switch of the trigger and sample the reactions anew
+                let (mut lo, mut hi) = (t, t_new);
+                for _ in 0..MAX_BISECTIONS {
+                    if hi - lo <= 4.0 * f64::EPSILON * hi.abs().max(1.0) {
+                        break;
+                    }
+
+                    let mid = 0.5 * (lo + hi);
+                    let mut values_mid = values.clone();
+                    system.update(mid, &mut values_mid)?;
+
+                    let fired = system.triggers(mid, &values_mid)?;
+                    if rising.iter().any(|&i| fired[i]) {
+                        hi = mid;
+                        stationary = values_mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+                t = hi;
+                values = stationary;
+            } else {
+                for (jump, count) in step.firings {
+                    system.apply_jump(jump, count, &mut stationary);
+                }
+                system.update(t_new, &mut stationary)?;
+                t = t_new;
+                values = stationary;
+            }
+        }
+
+        execute_pending(system, t, &mut values, &mut pending)?;
+        fire_events(system, t, &mut values, &mut triggers, &mut pending)?;
+
+        if t >= times[next_output] {
+            course.push(times[next_output], system.outputs(&values));
+            next_output += 1;
+        }
+    }
+
+    Ok(course)
+}
+
+/// Samples the next reaction event with Gillespie's direct method.
+///
+/// If the sampled event lies beyond the horizon, no reaction fires within it.
+fn direct(propensities: &[f64], horizon: f64, rng: &mut StdRng) -> Step {
+    let total: f64 = propensities.iter().sum();
+    if total <= 0.0 {
+        return Step {
+            dt: horizon,
+            firings: Vec::new(),
+        };
+    }
+
+    let dt = rng.sample::<f64, _>(Exp1) / total;
+    if dt >= horizon {
+        return Step {
+            dt: horizon,
+            firings: Vec::new(),
+        };
+    }
+
+    Step {
+        dt,
+        firings: vec![(select(propensities, rng), 1.0)],
+    }
+}
+
+/// Selects a reaction with probability proportional to its propensity.
+fn select(propensities: &[f64], rng: &mut StdRng) -> usize {
+    let total: f64 = propensities.iter().sum();
+    let target = rng.gen::<f64>() * total;
+
+    let mut cumulative = 0.0;
+    let mut selected = 0;
+    for (j, &propensity) in propensities.iter().enumerate() {
+        if propensity <= 0.0 {
+            continue;
+        }
+        selected = j;
+        cumulative += propensity;
+        if cumulative > target {
+            break;
+        }
+    }
+    selected
+}
+
+/// Samples the reaction events of the next leap.
+///
+/// # Arguments
+/// * `system` - The simulated system
+/// * `values` - The current values, holding the species amounts
+/// * `propensities` - The current propensities of all reactions
+/// * `epsilon` - The bound of the relative change of propensities within a leap
+/// * `horizon` - The maximum length of the leap
+/// * `rng` - The random number generator
+fn leap(
+    system: &OdeSystem,
+    values: &[f64],
+    propensities: &[f64],
+    epsilon: f64,
+    horizon: f64,
+    rng: &mut StdRng,
+) -> Step {
+    let jumps = system.jumps();
+    let total: f64 = propensities.iter().sum();
+    if total <= 0.0 {
+        return direct(propensities, horizon, rng);
+    }
+
+    // Reactions close to exhausting a reactant must not fire more than once
+    let critical: Vec<bool> = jumps
+        .iter()
+        .zip(propensities)
+        .map(|(jump, &propensity)| {
+            propensity > 0.0
+                && jump.changes().iter().any(|&(i, coefficient)| {
+                    coefficient < 0.0 && (values[i] / -coefficient).floor() < CRITICAL_FIRINGS
+                })
+        })
+        .collect();
+
+    let mut tau = leap_size(system, values, propensities, &critical, epsilon);
+    if tau < MIN_LEAP_EVENTS / total {
+        return direct(propensities, horizon, rng);
+    }
+
+    let critical_total: f64 = propensities
+        .iter()
+        .zip(&critical)
+        .filter(|(_, &critical)| critical)
+        .map(|(propensity, _)| propensity)
+        .sum();
+
+    for _ in 0..MAX_HALVINGS {
+        let tau_critical = if critical_total > 0.0 {
+            rng.sample::<f64, _>(Exp1) / critical_total
+        } else {
+            f64::INFINITY
+        };
+
+        let (dt, fire_critical) = if tau_critical <= tau {
+            (tau_critical, true)
+        } else {
+            (tau, false)
+        };
+        let (dt, fire_critical) = if dt >= horizon {
+            (horizon, false)
+        } else {
+            (dt, fire_critical)
+        };
+
+        let mut firings = Vec::new();
+        for (j, &propensity) in propensities.iter().enumerate() {
+            if critical[j] || propensity <= 0.0 {
+                continue;
+            }
+            // The mean is positive and finite, so the distribution is valid
+            let count = rng.sample(Poisson::new(propensity * dt).unwrap());
+            if count > 0.0 {
+                firings.push((j, count));
+            }
+        }
+        if fire_critical {
+            let critical_propensities: Vec<f64> = propensities
+                .iter()
+                .zip(&critical)
+                .map(|(&propensity, &critical)| if critical { propensity } else { 0.0 })
+                .collect();
+            firings.push((select(&critical_propensities, rng), 1.0));
+        }
+
+        // Retry with a shorter leap if any amount would become negative
+        let mut amounts = values.to_vec();
+        for &(jump, count) in &firings {
+            system.apply_jump(jump, count, &mut amounts);
+        }
+        let negative = jumps
+            .iter()
+            .flat_map(|jump| jump.changes())
+            .any(|&(i, _)| amounts[i] < 0.0);
+        if !negative {
+            return Step { dt, firings };
+        }
+
+        tau /= 2.0;
+    }
+
+    direct(propensities, horizon, rng)
+}
+
+/// Computes the largest leap that keeps the relative change of all propensities
+/// below `epsilon`, following Cao, Gillespie and Petzold (2006).
+///
+/// Only non-critical reactions are considered. The highest order of the reactions
+/// consuming a species approximates how sensitive the propensities are to it.
+fn leap_size(
+    system: &OdeSystem,
+    values: &[f64],
+    propensities: &[f64],
+    critical: &[bool],
+    epsilon: f64,
+) -> f64 {
+    let jumps = system.jumps();
+
+    // Mean and variance of the change of each species per time, and its highest order
+    let mut moments: Vec<(usize, f64, f64, f64)> = Vec::new();
+    for (j, jump) in jumps.iter().enumerate() {
+        let order: f64 = jump
+            .changes()
+            .iter()
+            .filter(|(_, coefficient)| *coefficient < 0.0)
+            .map(|(_, coefficient)| -coefficient)
+            .sum();
+
+        for &(i, coefficient) in jump.changes() {
+            let position = match moments.iter().position(|m| m.0 == i) {
+                Some(position) => position,
+                None => {
+                    moments.push((i, 0.0, 0.0, 1.0));
+                    moments.len() - 1
+                }
+            };
+            let moment = &mut moments[position];
+            if coefficient < 0.0 {
+                moment.3 = moment.3.max(order);
+            }
+            if !critical[j] {
+                moment.1 += coefficient * propensities[j];
+                moment.2 += coefficient * coefficient * propensities[j];
+            }
+        }
+    }
+
+    moments
+        .iter()
+        .filter(|&&(i, _, _, _)| is_reactant(system, i))
+        .map(|&(i, mean, variance, order)| {
+            let bound = (epsilon * values[i] / order).max(1.0);
+            let by_mean = if mean != 0.0 {
+                bound / mean.abs()
+            } else {
+                f64::INFINITY
+            };
+            let by_variance = if variance > 0.0 {
+                bound * bound / variance
+            } else {
+                f64::INFINITY
+            };
+            by_mean.min(by_variance)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns whether a species is consumed by any reaction.
+fn is_reactant(system: &OdeSystem, species: usize) -> bool {
+    system.jumps().iter().any(|jump| {
+        jump.changes()
+            .iter()
+            .any(|&(i, coefficient)| i == species && coefficient < 0.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::Expr;
+    use crate::simulate::system::{
+        EventDefinition, InitialValue, OdeSystemBuilder, SpeciesDefinition, TIME_SYMBOL,
+    };
+
+    /// Builds the system A -> B with mass action rate k * A in a unit compartment.
+    fn decay(amount: f64) -> OdeSystemBuilder {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("c", 1.0);
+        builder.add_parameter("k", 1.0);
+        for (id, amount) in [("A", amount), ("B", 0.0)] {
+            builder.add_species(SpeciesDefinition {
+                id: id.to_string(),
+                compartment: "c".to_string(),
+                initial: InitialValue::Amount(amount),
+                has_only_substance_units: true,
+                boundary_condition: false,
+                constant: false,
+            });
+        }
+        builder.add_reaction(
+            "r",
+            Expr::parse("k * A").unwrap(),
+            vec![("A".to_string(), -1.0), ("B".to_string(), 1.0)],
+        );
+        builder
+    }
+
+    fn options(method: StochasticMethod, seed: u64) -> StochasticOptions {
+        StochasticOptions {
+            method,
+            seed: Some(seed),
+            n_points: 11,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_seed_is_reproducible() {
+        let system = decay(100.0).finish().unwrap();
+        for method in [StochasticMethod::Direct, StochasticMethod::TauLeaping] {
+            let first = run(&system, 1.0, &options(method, 42)).unwrap();
+            let second = run(&system, 1.0, &options(method, 42)).unwrap();
+            let other = run(&system, 1.0, &options(method, 7)).unwrap();
+
+            assert_eq!(first, second);
+            assert_ne!(first.rows, other.rows);
+        }
+    }
+
+    #[test]
+    fn test_mean_matches_deterministic() {
+        for method in [StochasticMethod::Direct, StochasticMethod::TauLeaping] {
+            let system = decay(1000.0).finish().unwrap();
+            let runs = 50;
+
+            let mut mean = 0.0;
+            for seed in 0..runs {
+                let course = run(&system, 1.0, &options(method, seed)).unwrap();
+                let a = course.get("A").unwrap();
+                let b = course.get("B").unwrap();
+
+                // Amounts stay whole numbers and are conserved
+                assert!(a.iter().all(|x| x.fract() == 0.0 && *x >= 0.0));
+                assert!(a.iter().zip(&b).all(|(a, b)| a + b == 1000.0));
+                mean += a[10] / runs as f64;
+            }
+
+            let expected = 1000.0 * (-1.0_f64).exp();
+            assert!((mean - expected).abs() < 10.0, "{:?}: {}", method, mean);
+        }
+    }
+
+    #[test]
+    fn test_leaping_keeps_amounts_positive() {
+        let system = decay(15.0).finish().unwrap();
+        for seed in 0..20 {
+            let options = options(StochasticMethod::TauLeaping, seed);
+            let course = run(&system, 5.0, &options).unwrap();
+            assert!(course.get("A").unwrap().iter().all(|a| *a >= 0.0));
+        }
+    }
+
+    #[test]
+    fn test_time_event_stops_reaction() {
+        let mut builder = decay(1000.0);
+        builder.add_event(EventDefinition {
+            id: "stop".to_string(),
+            trigger: Expr::parse("time >= 0.5")
+                .unwrap()
+                .substitute("time", &Expr::Symbol(TIME_SYMBOL.to_string())),
+            delay: None,
+            assignments: vec![("k".to_string(), Expr::parse("0").unwrap())],
+            initial_value: true,
+            use_values_from_trigger_time: true,
+        });
+        let system = builder.finish().unwrap();
+
+        let course = run(&system, 1.0, &options(StochasticMethod::Direct, 1)).unwrap();
+        let a = course.get("A").unwrap();
+        let k = course.get("k").unwrap();
+
+        assert_eq!(k[4], 1.0);
+        assert_eq!(k[5], 0.0);
+        assert!(a[5..].iter().all(|x| *x == a[5]));
+        assert!(a[5] < 1000.0);
+    }
+
+    #[test]
+    fn test_rate_rules_are_rejected() {
+        let mut builder = decay(10.0);
+        builder.add_parameter("x", 0.0);
+        builder.add_rate_rule("x", Expr::parse("1").unwrap());
+        let system = builder.finish().unwrap();
+
+        let result = run(&system, 1.0, &StochasticOptions::default());
+        assert!(matches!(result, Err(SimulationError::InvalidModel(_))));
+    }
+}
@@ -0,0 +1,854 @@
+//! Numerical representation of a model for the deterministic simulator.
+//!
+//! The [`OdeSystemBuilder`] collects the quantities, reactions, rules, initial
+//! assignments and events of a model by identifier. Calling `finish` resolves all
+//! identifiers, checks the formulas for unknown symbols and brings assignment rules
+//! and initial assignments into an order in which they can be evaluated.
+//!
+//! Species are always integrated as amounts. In formulas, a species evaluates to its
+//! concentration (amount divided by the size of its compartment) unless it has only
+//! substance units, which matches the semantics of SBML.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::formula::{Expr, FormulaError};
+
+use super::SimulationError;
+
+/// Symbol of the simulation time in formulas.
+///
+/// It is not a valid SBML identifier, so that it cannot clash with a quantity of the
+/// model. Time csymbols of the model are replaced by it when the math is parsed.
+pub(crate) const TIME_SYMBOL: &str = "<time>";
+
+/// Initial value of a species as given in the model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum InitialValue {
+    /// The initial amount of the species
+    Amount(f64),
+    /// The initial concentration of the species
+    Concentration(f64),
+}
+
+/// Definition of a species before its compartment is resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct SpeciesDefinition {
+    /// Identifier of the species
+    pub(crate) id: String,
+    /// Identifier of the compartment containing the species
+    pub(crate) compartment: String,
+    /// Initial amount or concentration
+    pub(crate) initial: InitialValue,
+    /// Whether the species evaluates to its amount rather than its concentration
+    pub(crate) has_only_substance_units: bool,
+    /// Whether reactions leave the species unchanged
+    pub(crate) boundary_condition: bool,
+    /// Whether the species is constant
+    pub(crate) constant: bool,
+}
+
+/// Definition of an event before its identifiers are resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct EventDefinition {
+    /// Identifier of the event, used in error messages
+    pub(crate) id: String,
+    /// Condition that fires the event when it switches from false to true
+    pub(crate) trigger: Expr,
+    /// Optional delay between triggering and executing the event
+    pub(crate) delay: Option<Expr>,
+    /// Variables and the formulas assigned to them
+    pub(crate) assignments: Vec<(String, Expr)>,
+    /// Value of the trigger just before the start of the simulation
+    pub(crate) initial_value: bool,
+    /// Whether assignments are computed when the event triggers or when it executes
+    pub(crate) use_values_from_trigger_time: bool,
+}
+
+/// A resolved event of an [`OdeSystem`].
+pub(crate) struct Event {
+    /// Identifier of the event
+    pub(crate) id: String,
+    /// Trigger condition
+    pub(crate) trigger: Expr,
+    /// Optional delay
+    pub(crate) delay: Option<Expr>,
+    /// Target symbol indices and the formulas assigned to them
+    pub(crate) assignments: Vec<(usize, Expr)>,
+    /// Value of the trigger just before the start of the simulation
+    pub(crate) initial_value: bool,
+    /// Whether assignments are computed when the event triggers
+    pub(crate) use_values_from_trigger_time: bool,
+}
+
+/// The effect of a single reaction event on the integrated species.
+pub(crate) struct Jump {
+    /// Symbol index of the reaction, which holds its rate
+    reaction: usize,
+    /// Species symbol indices and the change of their amounts
+    changes: Vec<(usize, f64)>,
+}
+
+impl Jump {
+    /// Returns the species and the change of their amounts.
+    pub(crate) fn changes(&self) -> &[(usize, f64)] {
+        &self.changes
+    }
+}
+
+/// How the time derivative of a state is computed.
+enum Derivative {
+    /// The state is governed by a rate rule
+    Rule(Expr),
+    /// The state is a species changed by reactions, given as (reaction, stoichiometry)
+    Reactions(Vec<(usize, f64)>),
+    /// The state only changes through events
+    Constant,
+}
+
+/// A reaction as (id, rate law, net stoichiometry per species).
+type ReactionDefinition = (String, Expr, Vec<(String, f64)>);
+
+/// Collects the parts of a model and resolves them into an [`OdeSystem`].
+#[derive(Default)]
+pub(crate) struct OdeSystemBuilder {
+    /// Identifiers and initial values of parameters and compartments
+    parameters: Vec<(String, f64)>,
+    /// Species definitions
+    species: Vec<SpeciesDefinition>,
+    /// Reaction definitions
+    reactions: Vec<ReactionDefinition>,
+    /// Assignment rules as (variable, formula)
+    assignment_rules: Vec<(String, Expr)>,
+    /// Rate rules as (variable, formula)
+    rate_rules: Vec<(String, Expr)>,
+    /// Initial assignments as (symbol, formula)
+    initial_assignments: Vec<(String, Expr)>,
+    /// Event definitions
+    events: Vec<EventDefinition>,
+}
+
+impl OdeSystemBuilder {
+    /// Declares a parameter or compartment with its initial value.
+    pub(crate) fn add_parameter(&mut self, id: &str, value: f64) {
+        self.parameters.push((id.to_string(), value));
+    }
+
+    /// Declares a species.
+    pub(crate) fn add_species(&mut self, species: SpeciesDefinition) {
+        self.species.push(species);
+    }
+
+    /// Declares a reaction with its rate law (in amount per time) and the net
+    /// stoichiometry of the species it changes.
+    pub(crate) fn add_reaction(&mut self, id: &str, rate: Expr, stoichiometry: Vec<(String, f64)>) {
+        self.reactions.push((id.to_string(), rate, stoichiometry));
+    }
+
+    /// Adds an assignment rule.
+    pub(crate) fn add_assignment_rule(&mut self, variable: &str, formula: Expr) {
+        self.assignment_rules.push((variable.to_string(), formula));
+    }
+
+    /// Adds a rate rule.
+    pub(crate) fn add_rate_rule(&mut self, variable: &str, formula: Expr) {
+        self.rate_rules.push((variable.to_string(), formula));
+    }
+
+    /// Adds an initial assignment.
+    pub(crate) fn add_initial_assignment(&mut self, symbol: &str, formula: Expr) {
+        self.initial_assignments.push((symbol.to_string(), formula));
+    }
+
+    /// Adds an event.
+    pub(crate) fn add_event(&mut self, event: EventDefinition) {
+        self.events.push(event);
+    }
+
+    /// Resolves all identifiers and orders the rules for evaluation.
+    ///
+    /// # Returns
+    /// The resolved system or an error if the model references unknown symbols or
+    /// contains an algebraic loop
+    pub(crate) fn finish(self) -> Result<OdeSystem, SimulationError> {
+        let mut names: Vec<String> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut declare = |name: &str| -> Result<usize, SimulationError> {
+            if index.contains_key(name) {
+                return Err(SimulationError::InvalidModel(format!(
+                    "Identifier '{}' is declared more than once",
+                    name
+                )));
+            }
+            index.insert(name.to_string(), names.len());
+            names.push(name.to_string());
+            Ok(names.len() - 1)
+        };
+
+        let mut initial = Vec::new();
+        for (id, value) in &self.parameters {
+            declare(id)?;
+            initial.push(*value);
+        }
+        for species in &self.species {
+            declare(&species.id)?;
+            initial.push(match species.initial {
+                InitialValue::Amount(amount) => amount,
+                InitialValue::Concentration(_) => f64::NAN,
+            });
+        }
+        for (id, _, _) in &self.reactions {
+            declare(id)?;
+            initial.push(0.0);
+        }
+
+        let n = names.len();
+        let resolve = |name: &str, element: &str| -> Result<usize, SimulationError> {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| SimulationError::Formula {
+                    element: element.to_string(),
+                    source: FormulaError::UnknownSymbol(name.to_string()),
+                })
+        };
+
+        // Resolve the compartments of species
+        let mut compartment = vec![None; n];
+        let mut scale = vec![None; n];
+        let mut initial_concentration = vec![None; n];
+        for species in &self.species {
+            let i = index[&species.id];
+            let c = index.get(&species.compartment).copied().ok_or_else(|| {
+                SimulationError::InvalidModel(format!(
+                    "Species '{}' refers to unknown compartment '{}'",
+                    species.id, species.compartment
+                ))
+            })?;
+            compartment[i] = Some(c);
+            if !species.has_only_substance_units {
+                scale[i] = Some(c);
+            }
+            if let InitialValue::Concentration(value) = species.initial {
+                initial_concentration[i] = Some(value);
+            }
+        }
+
+        // Check that every formula only references known symbols
+        let check = |expr: &Expr, element: &str| -> Result<(), SimulationError> {
+            for symbol in expr.symbols() {
+                let known = index.contains_key(&symbol)
+                    || symbol == TIME_SYMBOL
+                    || Expr::Symbol(symbol.clone()).eval(&|_| None).is_ok();
+                if !known {
+                    return Err(SimulationError::Formula {
+                        element: element.to_string(),
+                        source: FormulaError::UnknownSymbol(symbol),
+                    });
+                }
+            }
+            Ok(())
+        };
+
+        let mut assignment_rules = Vec::new();
+        for (variable, formula) in &self.assignment_rules {
+            check(formula, variable)?;
+            assignment_rules.push((resolve(variable, variable)?, formula.clone()));
+        }
+
+        let mut rates = Vec::new();
+        let mut reaction_terms: HashMap<usize, Vec<(usize, f64)>> = HashMap::new();
+        for (id, rate, stoichiometry) in &self.reactions {
+            check(rate, id)?;
+            let r = index[id];
+            rates.push((r, rate.clone()));
+            for (species, coefficient) in stoichiometry {
+                let s = resolve(species, id)?;
+                reaction_terms.entry(s).or_default().push((r, *coefficient));
+            }
+        }
+
+        let mut rate_rules = HashMap::new();
+        for (variable, formula) in &self.rate_rules {
+            check(formula, variable)?;
+            rate_rules.insert(resolve(variable, variable)?, formula.clone());
+        }
+
+        // Initial assignments are superseded by assignment rules for the same symbol
+        let assigned: HashSet<usize> = assignment_rules.iter().map(|(i, _)| *i).collect();
+        let mut initial_assignments = Vec::new();
+        for (symbol, formula) in &self.initial_assignments {
+            check(formula, symbol)?;
+            let i = resolve(symbol, symbol)?;
+            if !assigned.contains(&i) {
+                initial_assignments.push((i, formula.clone()));
+            }
+        }
+
+        let mut events = Vec::new();
+        for event in &self.events {
+            check(&event.trigger, &event.id)?;
+            if let Some(delay) = &event.delay {
+                check(delay, &event.id)?;
+            }
+            let mut assignments = Vec::new();
+            for (variable, formula) in &event.assignments {
+                check(formula, &event.id)?;
+                assignments.push((resolve(variable, &event.id)?, formula.clone()));
+            }
+            events.push(Event {
+                id: event.id.clone(),
+                trigger: event.trigger.clone(),
+                delay: event.delay.clone(),
+                assignments,
+                initial_value: event.initial_value,
+                use_values_from_trigger_time: event.use_values_from_trigger_time,
+            });
+        }
+
+        // Bring the derived quantities into evaluation order
+        let dependencies = |expr: &Expr| -> Vec<usize> {
+            let mut result = Vec::new();
+            for symbol in expr.symbols() {
+                if let Some(&i) = index.get(&symbol) {
+                    result.push(i);
+                    result.extend(scale[i]);
+                }
+            }
+            result
+        };
+
+        let derived: Vec<(usize, Expr)> = assignment_rules
+            .iter()
+            .cloned()
+            .chain(rates.iter().cloned())
+            .collect();
+        let derived = topological_order(derived, &dependencies, &scale, &names)?;
+
+        let initial_derived: Vec<(usize, Expr)> = initial_assignments
+            .into_iter()
+            .chain(assignment_rules.iter().cloned())
+            .chain(rates.iter().cloned())
+            .collect();
+        let initial_derived = topological_order(initial_derived, &dependencies, &scale, &names)?;
+
+        // Determine the integrated states and their derivatives
+        let mut states = Vec::new();
+        let mut derivatives = Vec::new();
+        for species in &self.species {
+            let i = index[&species.id];
+            if species.constant || assigned.contains(&i) {
+                continue;
+            }
+            states.push(i);
+            derivatives.push(match rate_rules.remove(&i) {
+                Some(rule) => Derivative::Rule(rule),
+                None if species.boundary_condition => Derivative::Constant,
+                None => Derivative::Reactions(reaction_terms.remove(&i).unwrap_or_default()),
+            });
+        }
+        for (variable, _) in &self.rate_rules {
+            let i = index[variable];
+            if let Some(rule) = rate_rules.remove(&i) {
+                states.push(i);
+                derivatives.push(Derivative::Rule(rule));
+            }
+        }
+
+        // Collect the effect of each reaction on the species it changes
+        let mut jumps: Vec<Jump> = self
+            .reactions
+            .iter()
+            .map(|(id, _, _)| Jump {
+                reaction: index[id],
+                changes: Vec::new(),
+            })
+            .collect();
+        for (&i, derivative) in states.iter().zip(&derivatives) {
+            if let Derivative::Reactions(terms) = derivative {
+                for &(r, coefficient) in terms {
+                    if let Some(jump) = jumps.iter_mut().find(|jump| jump.reaction == r) {
+                        jump.changes.push((i, coefficient));
+                    }
+                }
+            }
+        }
+
+        // Report species and everything else that may change over time
+        let mut outputs: Vec<usize> = self.species.iter().map(|s| index[&s.id]).collect();
+        let changing = self
+            .rate_rules
+            .iter()
+            .map(|(variable, _)| variable)
+            .chain(self.assignment_rules.iter().map(|(variable, _)| variable))
+            .chain(
+                self.events
+                    .iter()
+                    .flat_map(|event| event.assignments.iter().map(|(variable, _)| variable)),
+            );
+        for variable in changing {
+            let i = index[variable];
+            if !outputs.contains(&i) {
+                outputs.push(i);
+            }
+        }
+
+        Ok(OdeSystem {
+            names,
+            index,
+            initial,
+            compartment,
+            scale,
+            initial_concentration,
+            states,
+            derivatives,
+            derived,
+            initial_derived,
+            events,
+            jumps,
+            outputs,
+        })
+    }
+}
+
+/// Orders derived quantities such that each is evaluated after the quantities it depends on.
+///
+/// The original order is kept wherever the dependencies allow it.
+fn topological_order(
+    items: Vec<(usize, Expr)>,
+    dependencies: &dyn Fn(&Expr) -> Vec<usize>,
+    scale: &[Option<usize>],
+    names: &[String],
+) -> Result<Vec<(usize, Expr)>, SimulationError> {
+    let targets: HashMap<usize, usize> = items
+        .iter()
+        .enumerate()
+        .map(|(position, (target, _))| (*target, position))
+        .collect();
+
+    // Items that have to be evaluated before each item
+    let requires: Vec<HashSet<usize>> = items
+        .iter()
+        .enumerate()
+        .map(|(position, (target, expr))| {
+            dependencies(expr)
+                .into_iter()
+                .chain(scale[*target])
+                .filter_map(|symbol| targets.get(&symbol).copied())
+                .filter(|&other| other != position)
+                .collect()
+        })
+        .collect();
+
+    let mut done = vec![false; items.len()];
+    let mut order = Vec::with_capacity(items.len());
+    while order.len() < items.len() {
+        let next = (0..items.len())
+            .find(|&i| !done[i] && requires[i].iter().all(|&j| done[j]))
+            .ok_or_else(|| {
+                SimulationError::AlgebraicLoop(
+                    (0..items.len())
+                        .filter(|&i| !done[i])
+                        .map(|i| names[items[i].0].clone())
+                        .collect(),
+                )
+            })?;
+        done[next] = true;
+        order.push(next);
+    }
+
+    // Self references are loops as well
+    for (target, expr) in &items {
+        if expr.references(&names[*target]) {
+            return Err(SimulationError::AlgebraicLoop(vec![names[*target].clone()]));
+        }
+    }
+
+    let mut items: Vec<Option<(usize, Expr)>> = items.into_iter().map(Some).collect();
+    Ok(order
+        .into_iter()
+        .map(|i| items[i].take().expect("Item is ordered once"))
+        .collect())
+}
+
+/// A model resolved into a system of ordinary differential equations.
+///
+/// All quantities of the model are stored in a single value vector indexed by
+/// symbol. Species are stored as amounts.
+pub(crate) struct OdeSystem {
+    /// Identifiers of all symbols
+    names: Vec<String>,
+    /// Position of each identifier in `names`
+    index: HashMap<String, usize>,
+    /// Declared initial values
+    initial: Vec<f64>,
+    /// Compartment of each species
+    compartment: Vec<Option<usize>>,
+    /// Compartment dividing the amount of species that evaluate to concentrations
+    scale: Vec<Option<usize>>,
+    /// Initial concentration of species given as concentration
+    initial_concentration: Vec<Option<f64>>,
+    /// Symbols that are integrated
+    states: Vec<usize>,
+    /// Derivative of each state
+    derivatives: Vec<Derivative>,
+    /// Assignment rules and reaction rates in evaluation order
+    derived: Vec<(usize, Expr)>,
+    /// Initial assignments, assignment rules and reaction rates in evaluation order
+    initial_derived: Vec<(usize, Expr)>,
+    /// Events of the model
+    events: Vec<Event>,
+    /// Effect of each reaction on the integrated species
+    jumps: Vec<Jump>,
+    /// Symbols reported in the time course
+    outputs: Vec<usize>,
+}
+
+impl OdeSystem {
+    /// Returns the events of the system.
+    pub(crate) fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the reactions of the system as jumps of the species amounts.
+    pub(crate) fn jumps(&self) -> &[Jump] {
+        &self.jumps
+    }
+
+    /// Returns whether any state is governed by a rate rule.
+    pub(crate) fn has_rate_rules(&self) -> bool {
+        self.derivatives
+            .iter()
+            .any(|derivative| matches!(derivative, Derivative::Rule(_)))
+    }
+
+    /// Returns the identifiers of the reported symbols.
+    pub(crate) fn output_names(&self) -> Vec<String> {
+        self.outputs
+            .iter()
+            .map(|&i| self.names[i].clone())
+            .collect()
+    }
+
+    /// Returns the values of the reported symbols as seen in formulas.
+    pub(crate) fn outputs(&self, values: &[f64]) -> Vec<f64> {
+        self.outputs
+            .iter()
+            .map(|&i| self.value(values, i))
+            .collect()
+    }
+
+    /// Returns the value of a symbol as seen in formulas.
+    fn value(&self, values: &[f64], i: usize) -> f64 {
+        match self.scale[i] {
+            Some(c) => values[i] / values[c],
+            None => values[i],
+        }
+    }
+
+    /// Assigns a value, given as seen in formulas, to a symbol.
+    fn assign(&self, values: &mut [f64], i: usize, value: f64) {
+        values[i] = match self.scale[i] {
+            Some(c) => value * values[c],
+            None => value,
+        };
+    }
+
+    /// Evaluates a formula at the given time and values.
+    pub(crate) fn eval(
+        &self,
+        expr: &Expr,
+        element: &str,
+        t: f64,
+        values: &[f64],
+    ) -> Result<f64, SimulationError> {
+        let lookup = |name: &str| match self.index.get(name) {
+            Some(&i) => Some(self.value(values, i)),
+            None if name == TIME_SYMBOL => Some(t),
+            None => None,
+        };
+
+        expr.eval(&lookup)
+            .map_err(|source| SimulationError::Formula {
+                element: element.to_string(),
+                source,
+            })
+    }
+
+    /// Computes the values of all symbols at the start of the simulation.
+    pub(crate) fn initial_values(&self) -> Result<Vec<f64>, SimulationError> {
+        let mut values = self.initial.clone();
+        self.rescale_species(&mut values, None, &HashSet::new());
+
+        let mut assigned = HashSet::new();
+        for (target, expr) in &self.initial_derived {
+            let value = self.eval(expr, &self.names[*target], 0.0, &values)?;
+            self.assign(&mut values, *target, value);
+            assigned.insert(*target);
+
+            // Species given by concentration keep their concentration
+            self.rescale_species(&mut values, Some(*target), &assigned);
+        }
+
+        for &i in &self.states {
+            if !values[i].is_finite() {
+                return Err(SimulationError::NonFinite {
+                    symbol: self.names[i].clone(),
+                    time: 0.0,
+                });
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Converts initial concentrations into amounts.
+    ///
+    /// # Arguments
+    /// * `values` - The values to update
+    /// * `compartment` - Only rescale species in this compartment, all if None
+    /// * `skip` - Species that already received an initial assignment
+    fn rescale_species(
+        &self,
+        values: &mut [f64],
+        compartment: Option<usize>,
+        skip: &HashSet<usize>,
+    ) {
+        for (i, concentration) in self.initial_concentration.iter().enumerate() {
+            let (Some(concentration), Some(c)) = (concentration, self.compartment[i]) else {
+                continue;
+            };
+            if compartment.is_none_or(|target| target == c) && !skip.contains(&i) {
+                values[i] = concentration * values[c];
+            }
+        }
+    }
+
+    /// Extracts the integrated states from the values.
+    pub(crate) fn states(&self, values: &[f64]) -> Vec<f64> {
+        self.states.iter().map(|&i| values[i]).collect()
+    }
+
+    /// Writes the states into the values and updates rules and reaction rates.
+    pub(crate) fn load(
+        &self,
+        t: f64,
+        y: &[f64],
+        values: &mut [f64],
+    ) -> Result<(), SimulationError> {
+        for (&i, &value) in self.states.iter().zip(y) {
+            values[i] = value;
+        }
+        self.update(t, values)
+    }
+
+    /// Re-evaluates assignment rules and reaction rates.
+    pub(crate) fn update(&self, t: f64, values: &mut [f64]) -> Result<(), SimulationError> {
+        for (target, expr) in &self.derived {
+            let value = self.eval(expr, &self.names[*target], t, values)?;
+            self.assign(values, *target, value);
+        }
+        Ok(())
+    }
+
+    /// Computes the time derivatives of the states.
+    ///
+    /// # Arguments
+    /// * `t` - The current time
+    /// * `y` - The current states
+    /// * `base` - Values of the symbols that are not integrated
+    pub(crate) fn derivatives(
+        &self,
+        t: f64,
+        y: &[f64],
+        base: &[f64],
+    ) -> Result<Vec<f64>, SimulationError> {
+        let mut values = base.to_vec();
+        self.load(t, y, &mut values)?;
+
+        self.states
+            .iter()
+            .zip(&self.derivatives)
+            .map(|(&i, derivative)| match derivative {
+                Derivative::Rule(expr) => {
+                    let rate = self.eval(expr, &self.names[i], t, &values)?;
+                    Ok(match self.scale[i] {
+                        Some(c) => rate * values[c],
+                        None => rate,
+                    })
+                }
+                Derivative::Reactions(terms) => Ok(terms
+                    .iter()
+                    .map(|&(r, coefficient)| coefficient * values[r])
+                    .sum()),
+                Derivative::Constant => Ok(0.0),
+            })
+            .collect()
+    }
+
+    /// Returns the current rates of all reactions, as used for propensities.
+    ///
+    /// The values must be up to date, i.e. `update` must have been called after
+    /// the last change.
+    pub(crate) fn propensities(&self, t: f64, values: &[f64]) -> Result<Vec<f64>, SimulationError> {
+        self.jumps
+            .iter()
+            .map(|jump| {
+                let rate = values[jump.reaction];
+                let name = &self.names[jump.reaction];
+                if !rate.is_finite() {
+                    return Err(SimulationError::NonFinite {
+                        symbol: name.clone(),
+                        time: t,
+                    });
+                }
+                if rate < 0.0 {
+                    return Err(SimulationError::NegativePropensity {
+                        reaction: name.clone(),
+                        time: t,
+                    });
+                }
+                Ok(rate)
+            })
+            .collect()
+    }
+
+    /// Fires a reaction `count` times by changing the amounts of its species.
+    pub(crate) fn apply_jump(&self, jump: usize, count: f64, values: &mut [f64]) {
+        for &(i, coefficient) in &self.jumps[jump].changes {
+            values[i] += count * coefficient;
+        }
+    }
+
+    /// Evaluates the triggers of all events.
+    pub(crate) fn triggers(&self, t: f64, values: &[f64]) -> Result<Vec<bool>, SimulationError> {
+        self.events
+            .iter()
+            .map(|event| Ok(self.eval(&event.trigger, &event.id, t, values)? != 0.0))
+            .collect()
+    }
+
+    /// Computes the values assigned by an event.
+    pub(crate) fn event_values(
+        &self,
+        event: usize,
+        t: f64,
+        values: &[f64],
+    ) -> Result<Vec<f64>, SimulationError> {
+        let event = &self.events[event];
+        event
+            .assignments
+            .iter()
+            .map(|(_, expr)| self.eval(expr, &event.id, t, values))
+            .collect()
+    }
+
+    /// Applies previously computed event assignments.
+    pub(crate) fn apply_event(
+        &self,
+        event: usize,
+        assigned: &[f64],
+        t: f64,
+        values: &mut [f64],
+    ) -> Result<(), SimulationError> {
+        for ((target, _), &value) in self.events[event].assignments.iter().zip(assigned) {
+            if !value.is_finite() {
+                return Err(SimulationError::NonFinite {
+                    symbol: self.names[*target].clone(),
+                    time: t,
+                });
+            }
+            self.assign(values, *target, value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn species(id: &str, initial: InitialValue) -> SpeciesDefinition {
+        SpeciesDefinition {
+            id: id.to_string(),
+            compartment: "c".to_string(),
+            initial,
+            has_only_substance_units: false,
+            boundary_condition: false,
+            constant: false,
+        }
+    }
+
+    #[test]
+    fn test_concentrations_and_derivatives() {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("c", 2.0);
+        builder.add_parameter("k", 0.5);
+        builder.add_species(species("A", InitialValue::Concentration(3.0)));
+        builder.add_species(species("B", InitialValue::Amount(0.0)));
+        builder.add_reaction(
+            "r",
+            Expr::parse("k * A * c").unwrap(),
+            vec![("A".to_string(), -1.0), ("B".to_string(), 1.0)],
+        );
+        let system = builder.finish().unwrap();
+
+        let values = system.initial_values().unwrap();
+        let y = system.states(&values);
+        assert_eq!(y, vec![6.0, 0.0]);
+
+        // Rate is 0.5 * 3 * 2 = 3 amount per time
+        assert_eq!(
+            system.derivatives(0.0, &y, &values).unwrap(),
+            vec![-3.0, 3.0]
+        );
+        assert_eq!(system.outputs(&values), vec![3.0, 0.0]);
+    }
+
+    #[test]
+    fn test_rule_order_and_initial_assignments() {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("c", 1.0);
+        builder.add_parameter("x", 0.0);
+        builder.add_parameter("y", 0.0);
+        builder.add_parameter("k", 0.0);
+        builder.add_species(species("A", InitialValue::Amount(1.0)));
+        builder.add_assignment_rule("y", Expr::parse("2 * x").unwrap());
+        builder.add_assignment_rule("x", Expr::parse("k + 1").unwrap());
+        builder.add_initial_assignment("k", Expr::parse("A * 4").unwrap());
+        let system = builder.finish().unwrap();
+
+        let values = system.initial_values().unwrap();
+        assert_eq!(system.outputs(&values), vec![1.0, 10.0, 5.0]);
+    }
+
+    #[test]
+    fn test_algebraic_loop() {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("x", 0.0);
+        builder.add_parameter("y", 0.0);
+        builder.add_assignment_rule("x", Expr::parse("y + 1").unwrap());
+        builder.add_assignment_rule("y", Expr::parse("x + 1").unwrap());
+
+        assert!(matches!(
+            builder.finish(),
+            Err(SimulationError::AlgebraicLoop(_))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_symbol() {
+        let mut builder = OdeSystemBuilder::default();
+        builder.add_parameter("x", 0.0);
+        let rate = Expr::parse("k * time * pi").unwrap();
+        let rate = rate.substitute("time", &Expr::Symbol(TIME_SYMBOL.to_string()));
+        builder.add_rate_rule("x", rate);
+
+        match builder.finish() {
+            Err(SimulationError::Formula { element, source }) => {
+                assert_eq!(element, "x");
+                assert_eq!(source, FormulaError::UnknownSymbol("k".to_string()));
+            }
+            _ => panic!("Expected an unknown symbol error"),
+        }
+    }
+}
@@ -10,18 +10,26 @@
 
 use std::{cell::RefCell, pin::Pin, rc::Rc};
 
+#[cfg(feature = "fbc")]
+use autocxx::c_int;
 use cxx::let_cxx_string;
 
 use crate::{
-    inner,
+    comp_replacements, distrib_uncertainties, inner,
     model::Model,
-    pin_ptr,
+    pin_ptr, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
     upcast_annotation,
 };
 
+#[cfg(feature = "fbc")]
+use crate::{
+    packages::{get_plugin, Package},
+    upcast,
+};
+
 /// A safe wrapper around the libSBML Species class.
 ///
 /// This struct maintains a reference to the underlying C++ Species object
@@ -36,6 +44,9 @@ inner!(sbmlcxx::Species, Species<'a>);
 // Set the annotation trait for the Species struct
 upcast_annotation!(Species<'a>, sbmlcxx::Species, sbmlcxx::SBase);
 
+// Set the SBase trait for the Species struct
+sbase!(Species<'a>, sbmlcxx::Species);
+
 impl<'a> Species<'a> {
     /// Creates a new Species instance within the given Model.
     ///
@@ -245,8 +256,105 @@ impl<'a> Species<'a> {
             .setHasOnlySubstanceUnits(has_only_substance_units);
     }
 
+    /// Gets the conversion factor of this species.
+    ///
+    /// The conversion factor is a parameter converting the extent of reactions into
+    /// the substance units of the species, overriding the one of the model.
+    ///
+    /// # Returns
+    /// The identifier of the conversion factor parameter, empty if not set
+    pub fn conversion_factor(&self) -> String {
+        self.inner
+            .borrow()
+            .getConversionFactor()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Sets the conversion factor of this species.
+    ///
+    /// # Arguments
+    /// * `conversion_factor` - The identifier of the conversion factor parameter
+    pub fn set_conversion_factor(&self, conversion_factor: &str) {
+        let_cxx_string!(conversion_factor = conversion_factor);
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setConversionFactor(&conversion_factor);
+    }
+
+    /// Returns the fbc plugin of the species.
+    ///
+    /// # Arguments
+    /// * `enable` - Whether to enable the fbc package on the document if needed
+    #[cfg(feature = "fbc")]
+    fn fbc_plugin(&self, enable: bool) -> Option<Pin<&'a mut sbmlcxx::FbcSpeciesPlugin>> {
+        let base = upcast!(self, sbmlcxx::Species, sbmlcxx::SBase);
+        get_plugin(base, Package::Fbc, enable)
+    }
+
+    /// Gets the chemical formula of this species (fbc package).
+    ///
+    /// # Returns
+    /// The chemical formula in Hill notation, None if not set
+    #[cfg(feature = "fbc")]
+    pub fn chemical_formula(&self) -> Option<String> {
+        let plugin = self.fbc_plugin(false)?;
+        if !plugin.isSetChemicalFormula() {
+            return None;
+        }
+        Some(plugin.getChemicalFormula().to_str().unwrap().to_string())
+    }
+
+    /// Sets the chemical formula of this species (fbc package).
+    ///
+    /// The fbc package is enabled on the document if needed.
+    ///
+    /// # Arguments
+    /// * `formula` - The chemical formula in Hill notation, e.g. "C6H12O6"
+    #[cfg(feature = "fbc")]
+    pub fn set_chemical_formula(&self, formula: &str) {
+        if let Some(mut plugin) = self.fbc_plugin(true) {
+            let_cxx_string!(formula = formula);
+            plugin.as_mut().setChemicalFormula(&formula);
+        }
+    }
+
+    /// Gets the electrical charge of this species (fbc package).
+    ///
+    /// # Returns
+    /// The charge as a multiple of the elementary charge, None if not set
+    #[cfg(feature = "fbc")]
+    pub fn charge(&self) -> Option<i32> {
+        let plugin = self.fbc_plugin(false)?;
+        if !plugin.isSetCharge() {
+            return None;
+        }
+        Some(plugin.getCharge().into())
+    }
+
+    /// Sets the electrical charge of this species (fbc package).
+    ///
+    /// The fbc package is enabled on the document if needed.
+    ///
+    /// # Arguments
+    /// * `charge` - The charge as a multiple of the elementary charge
+    #[cfg(feature = "fbc")]
+    pub fn set_charge(&self, charge: i32) {
+        if let Some(mut plugin) = self.fbc_plugin(true) {
+            plugin.as_mut().setCharge(c_int::from(charge));
+        }
+    }
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Species, sbmlcxx::SBase);
+
+    // Replacement Methods generated by the `comp_replacements` macro
+    comp_replacements!(sbmlcxx::Species);
+
+    // Uncertainty Methods generated by the `distrib_uncertainties` macro
+    distrib_uncertainties!(sbmlcxx::Species);
 }
 
 impl FromPtr<sbmlcxx::Species> for Species<'_> {
@@ -370,6 +478,35 @@ impl<'a> SpeciesBuilder<'a> {
         self
     }
 
+    /// Sets the conversion factor of the species.
+    ///
+    /// # Arguments
+    /// * `conversion_factor` - The identifier of the conversion factor parameter
+    pub fn conversion_factor(self, conversion_factor: &str) -> Self {
+        self.species.set_conversion_factor(conversion_factor);
+        self
+    }
+
+    /// Sets the chemical formula of the species (fbc package).
+    ///
+    /// # Arguments
+    /// * `formula` - The chemical formula in Hill notation
+    #[cfg(feature = "fbc")]
+    pub fn chemical_formula(self, formula: &str) -> Self {
+        self.species.set_chemical_formula(formula);
+        self
+    }
+
+    /// Sets the electrical charge of the species (fbc package).
+    ///
+    /// # Arguments
+    /// * `charge` - The charge as a multiple of the elementary charge
+    #[cfg(feature = "fbc")]
+    pub fn charge(self, charge: i32) -> Self {
+        self.species.set_charge(charge);
+        self
+    }
+
     /// Sets the annotation for this species.
     ///
     /// # Arguments
@@ -13,6 +13,7 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use crate::{
     inner, pin_ptr,
     reaction::Reaction,
+    sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
@@ -37,6 +38,9 @@ upcast_annotation!(
     sbmlcxx::SBase
 );
 
+// Set the SBase trait for the SpeciesReference struct
+sbase!(SpeciesReference<'a>, sbmlcxx::SpeciesReference);
+
 impl<'a> SpeciesReference<'a> {
     /// Creates a new SimpleSpeciesReference instance within the given Reaction.
     ///
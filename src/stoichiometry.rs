@@ -0,0 +1,513 @@
+//! Stoichiometry matrix and structural analysis of reaction networks.
+//!
+//! The stoichiometry matrix `N` has one row per species and one column per reaction.
+//! Each entry is the net number of molecules of a species produced (positive) or
+//! consumed (negative) by one occurrence of a reaction.
+//!
+//! On top of the matrix, this module computes the structural properties that do not
+//! depend on kinetics:
+//!
+//! - Conservation laws, i.e. weighted sums of species that stay constant (the left
+//!   null space of `N`)
+//! - The split into independent and dependent species, where dependent species follow
+//!   from the independent ones and the conserved totals
+//! - The reduced system `N = L * N_R`, with the reduced matrix `N_R` of the independent
+//!   species and the link matrix `L`
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = Model::new(&doc, "atp");
+//! model.build_reaction("use").reactant("ATP", 1.0).product("ADP", 1.0).build();
+//! model.build_reaction("make").reactant("ADP", 1.0).product("ATP", 1.0).build();
+//!
+//! let matrix = model.stoichiometry_matrix(false);
+//! for law in matrix.conservation_laws() {
+//!     println!("{} = const", law);
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::{linalg::rref, model::Model};
+
+/// Entries with an absolute value below this threshold are considered zero.
+const TOLERANCE: f64 = 1e-9;
+
+/// A dense stoichiometry matrix with species and reaction labels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoichiometryMatrix {
+    /// Row labels
+    species: Vec<String>,
+    /// Column labels
+    reactions: Vec<String>,
+    /// Entries in row-major order
+    values: Vec<f64>,
+}
+
+impl StoichiometryMatrix {
+    /// Creates a stoichiometry matrix from labels and rows.
+    ///
+    /// # Arguments
+    /// * `species` - The row labels
+    /// * `reactions` - The column labels
+    /// * `rows` - The entries, one row per species with one value per reaction
+    ///
+    /// # Panics
+    /// If the number of rows or the length of a row does not match the labels
+    pub fn new(species: Vec<String>, reactions: Vec<String>, rows: Vec<Vec<f64>>) -> Self {
+        assert_eq!(rows.len(), species.len(), "One row per species is required");
+        assert!(
+            rows.iter().all(|row| row.len() == reactions.len()),
+            "One column per reaction is required"
+        );
+
+        Self {
+            species,
+            reactions,
+            values: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Builds the stoichiometry matrix of a model.
+    ///
+    /// Rows follow the order of the model's species and columns the order of its
+    /// reactions. Species referenced by reactions but not declared in the model are
+    /// ignored.
+    ///
+    /// # Arguments
+    /// * `model` - The model to build the matrix of
+    /// * `exclude_boundary` - Whether to leave out species with a boundary condition
+    ///
+    /// # Returns
+    /// The stoichiometry matrix
+    pub fn from_model(model: &Model, exclude_boundary: bool) -> Self {
+        let species: Vec<String> = model
+            .list_of_species()
+            .iter()
+            .filter(|species| !(exclude_boundary && species.boundary_condition()))
+            .map(|species| species.id())
+            .collect();
+
+        let reactions = model.list_of_reactions();
+        let n_reactions = reactions.len();
+        let mut values = vec![0.0; species.len() * n_reactions];
+
+        for (j, reaction) in reactions.iter().enumerate() {
+            let reactants = reaction.reactants().borrow();
+            let products = reaction.products().borrow();
+            let terms = reactants
+                .iter()
+                .map(|reactant| (reactant.species(), -reactant.stoichiometry()))
+                .chain(
+                    products
+                        .iter()
+                        .map(|product| (product.species(), product.stoichiometry())),
+                );
+
+            for (id, coefficient) in terms {
+                if let Some(i) = species.iter().position(|s| *s == id) {
+                    values[i * n_reactions + j] += coefficient;
+                }
+            }
+        }
+
+        Self {
+            species,
+            reactions: reactions.iter().map(|reaction| reaction.id()).collect(),
+            values,
+        }
+    }
+
+    /// Returns the species labelling the rows.
+    pub fn species(&self) -> &[String] {
+        &self.species
+    }
+
+    /// Returns the reactions labelling the columns.
+    pub fn reactions(&self) -> &[String] {
+        &self.reactions
+    }
+
+    /// Returns the number of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.species.len(), self.reactions.len())
+    }
+
+    /// Returns the entry at the given row and column.
+    ///
+    /// # Panics
+    /// If the row or column is out of bounds
+    pub fn value(&self, row: usize, col: usize) -> f64 {
+        let (rows, cols) = self.shape();
+        assert!(row < rows && col < cols, "Index out of bounds");
+        self.values[row * cols + col]
+    }
+
+    /// Returns the entry of a species and a reaction.
+    ///
+    /// # Arguments
+    /// * `species` - The identifier of the species
+    /// * `reaction` - The identifier of the reaction
+    ///
+    /// # Returns
+    /// Some(value) if both labels exist, None otherwise
+    pub fn get(&self, species: &str, reaction: &str) -> Option<f64> {
+        let row = self.species.iter().position(|s| s == species)?;
+        let col = self.reactions.iter().position(|r| r == reaction)?;
+        Some(self.value(row, col))
+    }
+
+    /// Returns the matrix as a vector of rows.
+    pub fn to_dense(&self) -> Vec<Vec<f64>> {
+        let cols = self.reactions.len();
+        (0..self.species.len())
+            .map(|i| self.values[i * cols..(i + 1) * cols].to_vec())
+            .collect()
+    }
+
+    /// Returns the non-zero entries as (row, column, value) triplets in row-major order.
+    pub fn to_sparse(&self) -> Vec<(usize, usize, f64)> {
+        let cols = self.reactions.len();
+        self.values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value != 0.0)
+            .map(|(k, &value)| (k / cols, k % cols, value))
+            .collect()
+    }
+
+    /// Returns the rank of the matrix, i.e. the number of independent species.
+    pub fn rank(&self) -> usize {
+        self.structure().0.len()
+    }
+
+    /// Computes a basis of the conservation laws of the network.
+    ///
+    /// There is one conservation law per dependent species. Each law has a coefficient
+    /// of one for its dependent species and expresses it through independent species
+    /// only. Species are chosen as independent in the order of the rows.
+    ///
+    /// # Returns
+    /// The conservation laws, in the order of their dependent species
+    pub fn conservation_laws(&self) -> Vec<ConservationLaw> {
+        self.reduce().conservation_laws
+    }
+
+    /// Returns the species that follow from the independent species and the conservation laws.
+    pub fn dependent_species(&self) -> Vec<String> {
+        self.reduce().dependent
+    }
+
+    /// Splits the network into independent and dependent species.
+    ///
+    /// # Returns
+    /// The reduced system, including the reduced matrix, the link matrix and the
+    /// conservation laws
+    pub fn reduce(&self) -> ReducedSystem {
+        let (pivots, echelon) = self.structure();
+        let n_species = self.species.len();
+
+        let independent: Vec<String> = pivots.iter().map(|&i| self.species[i].clone()).collect();
+        let dependent: Vec<usize> = (0..n_species).filter(|i| !pivots.contains(i)).collect();
+
+        // Each species is a combination of the independent ones, given by the echelon form
+        let link: Vec<Vec<f64>> = (0..n_species)
+            .map(|i| match pivots.iter().position(|&p| p == i) {
+                Some(k) => (0..pivots.len())
+                    .map(|l| if l == k { 1.0 } else { 0.0 })
+                    .collect(),
+                None => (0..pivots.len())
+                    .map(|k| snap(echelon[k * n_species + i]))
+                    .collect(),
+            })
+            .collect();
+
+        let conservation_laws = dependent
+            .iter()
+            .map(|&i| {
+                let mut coefficients = vec![(self.species[i].clone(), 1.0)];
+                for (k, &p) in pivots.iter().enumerate() {
+                    let coefficient = -link[i][k];
+                    if coefficient != 0.0 {
+                        coefficients.push((self.species[p].clone(), coefficient));
+                    }
+                }
+                ConservationLaw { coefficients }
+            })
+            .collect();
+
+        let cols = self.reactions.len();
+        let reduced = StoichiometryMatrix {
+            species: independent.clone(),
+            reactions: self.reactions.clone(),
+            values: pivots
+                .iter()
+                .flat_map(|&i| self.values[i * cols..(i + 1) * cols].iter().copied())
+                .collect(),
+        };
+
+        ReducedSystem {
+            independent,
+            dependent: dependent.iter().map(|&i| self.species[i].clone()).collect(),
+            reduced,
+            link,
+            conservation_laws,
+        }
+    }
+
+    /// Computes the reduced row echelon form of the transposed matrix.
+    ///
+    /// # Returns
+    /// The rows of the independent species and the echelon form, which has one row
+    /// per reaction and one column per species
+    fn structure(&self) -> (Vec<usize>, Vec<f64>) {
+        let (rows, cols) = self.shape();
+        let mut transposed = vec![0.0; rows * cols];
+        for i in 0..rows {
+            for j in 0..cols {
+                transposed[j * rows + i] = self.values[i * cols + j];
+            }
+        }
+
+        let pivots = rref(&mut transposed, cols, rows, TOLERANCE);
+        (pivots, transposed)
+    }
+}
+
+/// Rounds values that are within the tolerance of an integer.
+fn snap(value: f64) -> f64 {
+    let rounded = value.round();
+    if (value - rounded).abs() < TOLERANCE {
+        rounded + 0.0
+    } else {
+        value
+    }
+}
+
+/// A weighted sum of species amounts that no reaction changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConservationLaw {
+    /// Species and their weights, starting with the dependent species
+    coefficients: Vec<(String, f64)>,
+}
+
+impl ConservationLaw {
+    /// Returns the species and their weights, starting with the dependent species.
+    pub fn coefficients(&self) -> &[(String, f64)] {
+        &self.coefficients
+    }
+
+    /// Returns the species that is expressed through the others by this law.
+    pub fn dependent(&self) -> &str {
+        &self.coefficients[0].0
+    }
+
+    /// Computes the conserved total for the given species values.
+    ///
+    /// # Arguments
+    /// * `value` - Returns the value of a species by its identifier
+    ///
+    /// # Returns
+    /// Some(total) or None if a species has no value
+    pub fn total(&self, value: &dyn Fn(&str) -> Option<f64>) -> Option<f64> {
+        self.coefficients
+            .iter()
+            .map(|(species, weight)| Some(weight * value(species)?))
+            .sum()
+    }
+}
+
+impl fmt::Display for ConservationLaw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (k, (species, weight)) in self.coefficients.iter().enumerate() {
+            let sign = if *weight < 0.0 { "-" } else { "+" };
+            match k {
+                0 if *weight < 0.0 => write!(f, "-")?,
+                0 => {}
+                _ => write!(f, " {} ", sign)?,
+            }
+            if weight.abs() != 1.0 {
+                write!(f, "{} ", weight.abs())?;
+            }
+            write!(f, "{}", species)?;
+        }
+        Ok(())
+    }
+}
+
+/// A reaction network split into independent and dependent species.
+///
+/// The stoichiometry matrix factorizes as `N = L * N_R`, where `N_R` holds the rows
+/// of the independent species and the link matrix `L` expresses every species through
+/// the independent ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReducedSystem {
+    /// Identifiers of the independent species
+    independent: Vec<String>,
+    /// Identifiers of the dependent species
+    dependent: Vec<String>,
+    /// Rows of the independent species
+    reduced: StoichiometryMatrix,
+    /// Link matrix with one row per species and one column per independent species
+    link: Vec<Vec<f64>>,
+    /// One conservation law per dependent species
+    conservation_laws: Vec<ConservationLaw>,
+}
+
+impl ReducedSystem {
+    /// Returns the independent species.
+    pub fn independent_species(&self) -> &[String] {
+        &self.independent
+    }
+
+    /// Returns the dependent species.
+    pub fn dependent_species(&self) -> &[String] {
+        &self.dependent
+    }
+
+    /// Returns the reduced stoichiometry matrix of the independent species.
+    pub fn reduced_matrix(&self) -> &StoichiometryMatrix {
+        &self.reduced
+    }
+
+    /// Returns the link matrix, with rows in the order of the original species.
+    pub fn link_matrix(&self) -> &[Vec<f64>] {
+        &self.link
+    }
+
+    /// Returns the conservation laws, one per dependent species.
+    pub fn conservation_laws(&self) -> &[ConservationLaw] {
+        &self.conservation_laws
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    /// Enzyme kinetics E + S <-> ES -> E + P, with the conserved enzyme and substrate.
+    fn enzyme() -> StoichiometryMatrix {
+        StoichiometryMatrix::new(
+            labels(&["E", "S", "ES", "P"]),
+            labels(&["bind", "unbind", "cat"]),
+            vec![
+                vec![-1.0, 1.0, 1.0],
+                vec![-1.0, 1.0, 0.0],
+                vec![1.0, -1.0, -1.0],
+                vec![0.0, 0.0, 1.0],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_access() {
+        let matrix = enzyme();
+        assert_eq!(matrix.shape(), (4, 3));
+        assert_eq!(matrix.get("ES", "cat"), Some(-1.0));
+        assert_eq!(matrix.get("X", "cat"), None);
+        assert_eq!(matrix.to_dense()[3], vec![0.0, 0.0, 1.0]);
+        assert_eq!(matrix.to_sparse().len(), 9);
+        assert_eq!(matrix.to_sparse()[0], (0, 0, -1.0));
+    }
+
+    #[test]
+    fn test_conservation_laws() {
+        let matrix = enzyme();
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(matrix.dependent_species(), labels(&["ES", "P"]));
+
+        let laws = matrix.conservation_laws();
+        assert_eq!(laws.len(), 2);
+        assert_eq!(laws[0].to_string(), "ES + E");
+        assert_eq!(laws[1].to_string(), "P - E + S");
+
+        // Every law is orthogonal to all reaction columns
+        let dense = matrix.to_dense();
+        for law in &laws {
+            let mut sums = vec![0.0; 3];
+            for (species, weight) in law.coefficients() {
+                let i = matrix.species().iter().position(|s| s == species).unwrap();
+                for (sum, value) in sums.iter_mut().zip(&dense[i]) {
+                    *sum += weight * value;
+                }
+            }
+            assert_eq!(sums, vec![0.0; 3]);
+        }
+
+        let amounts = |species: &str| match species {
+            "E" => Some(1.0),
+            "S" => Some(10.0),
+            "ES" => Some(2.0),
+            "P" => Some(3.0),
+            _ => None,
+        };
+        assert_eq!(laws[0].total(&amounts), Some(3.0));
+        assert_eq!(laws[1].total(&amounts), Some(12.0));
+    }
+
+    #[test]
+    fn test_reduced_system() {
+        let matrix = enzyme();
+        let reduced = matrix.reduce();
+
+        assert_eq!(reduced.independent_species(), labels(&["E", "S"]));
+        assert_eq!(reduced.reduced_matrix().shape(), (2, 3));
+
+        // N = L * N_R
+        let link = reduced.link_matrix();
+        let n_r = reduced.reduced_matrix().to_dense();
+        for (i, row) in matrix.to_dense().iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                let product: f64 = (0..2).map(|k| link[i][k] * n_r[k][j]).sum();
+                assert!((product - value).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_model_stoichiometry_matrix() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_compartment("c").build();
+        model.build_species("glc").compartment("c").build();
+        model.build_species("atp").compartment("c").build();
+        model.build_species("adp").compartment("c").build();
+        model
+            .build_species("ext")
+            .compartment("c")
+            .boundary_condition(true)
+            .build();
+        model
+            .build_reaction("uptake")
+            .reactant("ext", 1.0)
+            .product("glc", 1.0)
+            .build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 1.0)
+            .reactant("atp", 1.0)
+            .product("adp", 1.0)
+            .build();
+
+        let matrix = model.stoichiometry_matrix(false);
+        assert_eq!(matrix.species(), labels(&["glc", "atp", "adp", "ext"]));
+        assert_eq!(matrix.get("ext", "uptake"), Some(-1.0));
+
+        let matrix = model.stoichiometry_matrix(true);
+        assert_eq!(matrix.species(), labels(&["glc", "atp", "adp"]));
+        assert_eq!(matrix.reactions(), labels(&["uptake", "hk"]));
+        assert_eq!(matrix.get("atp", "hk"), Some(-1.0));
+
+        let laws = matrix.conservation_laws();
+        assert_eq!(laws.len(), 1);
+        assert_eq!(laws[0].to_string(), "adp + atp");
+    }
+}
@@ -0,0 +1,321 @@
+//! Common interface of all SBML elements
+//!
+//! Every element of an SBML document derives from the libSBML class SBase, which holds
+//! the identifier, name, metaid, SBO term, notes and annotation of the element as well
+//! as its position in the document. The SBase trait exposes these attributes for all
+//! wrapper types, so that tooling can treat elements generically, e.g. when walking
+//! all elements of a model with [`Model::all_elements`].
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLReader::from_file("model.xml").unwrap();
+//! let model = doc.model().unwrap();
+//! for element in model.all_elements() {
+//!     if element.sbo_term_id().is_empty() {
+//!         println!(
+//!             "line {}: {} '{}' has no SBO term",
+//!             element.line(),
+//!             element.element_name(),
+//!             element.id()
+//!         );
+//!     }
+//! }
+//! ```
+//!
+//! [`Model::all_elements`]: crate::model::Model::all_elements
+
+use std::{cell::RefCell, pin::Pin};
+
+use crate::{inner, pin_ptr, sbase, sbmlcxx, traits::fromptr::FromPtr};
+
+/// Trait for the attributes shared by all SBML elements.
+pub trait SBase {
+    /// Gets the identifier of the element.
+    ///
+    /// # Returns
+    /// The identifier, empty if the element has none
+    fn id(&self) -> String;
+
+    /// Gets the name of the element.
+    ///
+    /// # Returns
+    /// The name, empty if the element has none
+    fn name(&self) -> String;
+
+    /// Gets the metaid of the element, used to refer to it from annotations.
+    ///
+    /// # Returns
+    /// The metaid, empty if the element has none
+    fn meta_id(&self) -> String;
+
+    /// Gets the SBO term of the element.
+    ///
+    /// # Returns
+    /// The SBO term ID (e.g. "SBO:0000001"), empty if the element has none
+    fn sbo_term_id(&self) -> String;
+
+    /// Gets the notes of the element.
+    ///
+    /// # Returns
+    /// The notes as an XHTML string, empty if the element has none
+    fn notes(&self) -> String;
+
+    /// Gets the annotation of the element.
+    ///
+    /// # Returns
+    /// The annotation as an XML string, empty if the element has none
+    fn annotation(&self) -> String;
+
+    /// Gets the SBML level of the document containing the element.
+    fn level(&self) -> u32;
+
+    /// Gets the SBML version of the document containing the element.
+    fn version(&self) -> u32;
+
+    /// Gets the line of the element in the document it was read from.
+    ///
+    /// # Returns
+    /// The line number, 0 if the element was not read from a file
+    fn line(&self) -> u32;
+
+    /// Gets the column of the element in the document it was read from.
+    ///
+    /// # Returns
+    /// The column number, 0 if the element was not read from a file
+    fn column(&self) -> u32;
+
+    /// Gets the XML element name of the element, e.g. "species".
+    fn element_name(&self) -> String;
+
+    /// Gets the element containing this element.
+    ///
+    /// Elements are contained in lists, so the parent of a species is the
+    /// "listOfSpecies" element, whose parent is the model.
+    ///
+    /// # Returns
+    /// The parent element, None if the element is not part of a document
+    fn parent(&self) -> Option<Element<'_>>;
+}
+
+/// An SBML element of any type, e.g. the parent of another element.
+pub struct Element<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::SBase>>,
+}
+
+// Set the inner trait for the Element struct
+inner!(sbmlcxx::SBase, Element<'a>);
+
+// Set the SBase trait for the Element struct
+sbase!(Element<'a>, sbmlcxx::SBase);
+
+impl FromPtr<sbmlcxx::SBase> for Element<'_> {
+    /// Creates a new Element instance from a pointer to a libSBML SBase.
+    ///
+    /// # Arguments
+    /// * `ptr` - A pointer to a libSBML SBase
+    ///
+    /// # Returns
+    /// A new Element instance
+    fn from_ptr(ptr: *mut sbmlcxx::SBase) -> Self {
+        let element = pin_ptr!(ptr, sbmlcxx::SBase);
+        Self {
+            inner: RefCell::new(element),
+        }
+    }
+}
+
+// The functions below implement the SBase trait on the upcast libSBML object and are
+// called by the implementations generated with the `sbase!` macro.
+
+pub(crate) fn id(base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.getId().to_str().unwrap().to_string()
+}
+
+pub(crate) fn name(base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.getName().to_str().unwrap().to_string()
+}
+
+pub(crate) fn meta_id(base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.getMetaId().to_str().unwrap().to_string()
+}
+
+pub(crate) fn sbo_term_id(base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.getSBOTermID().to_str().unwrap().to_string()
+}
+
+pub(crate) fn notes(mut base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.as_mut().getNotesString().to_str().unwrap().to_string()
+}
+
+pub(crate) fn annotation(base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.getAnnotationString().to_str().unwrap().to_string()
+}
+
+pub(crate) fn level(base: Pin<&mut sbmlcxx::SBase>) -> u32 {
+    base.getLevel().0
+}
+
+pub(crate) fn version(base: Pin<&mut sbmlcxx::SBase>) -> u32 {
+    base.getVersion().0
+}
+
+pub(crate) fn line(base: Pin<&mut sbmlcxx::SBase>) -> u32 {
+    base.getLine().0
+}
+
+pub(crate) fn column(base: Pin<&mut sbmlcxx::SBase>) -> u32 {
+    base.getColumn().0
+}
+
+pub(crate) fn element_name(base: Pin<&mut sbmlcxx::SBase>) -> String {
+    base.getElementName().to_str().unwrap().to_string()
+}
+
+pub(crate) fn parent<'b>(mut base: Pin<&mut sbmlcxx::SBase>) -> Option<Element<'b>> {
+    let parent = base.as_mut().getParentSBMLObject() as *mut sbmlcxx::SBase;
+    if parent.is_null() {
+        return None;
+    }
+    Some(Element::from_ptr(parent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sbase_attributes() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = doc.create_model("test");
+        let species = model
+            .build_species("glc")
+            .name("Glucose")
+            .compartment("cytosol")
+            .build();
+        species.set_sbo_term("SBO:0000247");
+
+        let element: &dyn SBase = species.as_ref();
+        assert_eq!(element.id(), "glc");
+        assert_eq!(element.name(), "Glucose");
+        assert_eq!(element.sbo_term_id(), "SBO:0000247");
+        assert_eq!(element.element_name(), "species");
+        assert_eq!((element.level(), element.version()), (3, 2));
+        assert_eq!(element.line(), 0);
+    }
+
+    #[test]
+    fn test_parent() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.build_species("glc").build();
+
+        let list = SBase::parent(species.as_ref()).unwrap();
+        assert_eq!(list.element_name(), "listOfSpecies");
+        let parent = list.parent().unwrap();
+        assert_eq!(parent.element_name(), "model");
+        assert_eq!(parent.id(), "test");
+    }
+
+    #[test]
+    fn test_position_and_metaid_from_file() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_compartment("cytosol").size(1.0).build();
+
+        let xml = doc
+            .to_xml_string()
+            .replace("<compartment ", "<compartment metaid=\"meta_cytosol\" ");
+        let read = SBMLReader::from_xml_string(&xml);
+        let compartment = read.model().unwrap().get_compartment("cytosol").unwrap();
+
+        let element: &dyn SBase = compartment.as_ref();
+        assert_eq!(element.meta_id(), "meta_cytosol");
+        assert!(element.line() > 1);
+        assert!(element.column() > 0);
+    }
+
+    #[test]
+    fn test_all_elements() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_compartment("cytosol").size(1.0).build();
+        model.build_species("glc").compartment("cytosol").build();
+        model.build_species("g6p").compartment("cytosol").build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .kinetic_law("glc")
+            .build();
+
+        let names: Vec<String> = model
+            .all_elements()
+            .map(|element| element.element_name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "compartment",
+                "species",
+                "species",
+                "reaction",
+                "speciesReference",
+                "speciesReference",
+                "kineticLaw"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_elements_rules_in_document_order() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_assignment_rule("x", "2 * y").build();
+        model.build_rate_rule("y", "-y").build();
+        model.build_assignment_rule("z", "x + y").build();
+
+        let names: Vec<String> = model
+            .all_elements()
+            .map(|element| element.element_name())
+            .collect();
+        assert_eq!(names, vec!["assignmentRule", "rateRule", "assignmentRule"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "groups", feature = "distrib"))]
+    fn test_all_elements_package_content() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let parameter = model.build_parameter("k").value(1.0).build();
+        parameter
+            .create_uncertainty()
+            .set_statistic(UncertType::Mean, 1.0);
+        model
+            .groups_plugin()
+            .build_group("pathway", GroupKind::Collection)
+            .member("k")
+            .build();
+
+        let names: Vec<String> = model
+            .all_elements()
+            .map(|element| element.element_name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "parameter",
+                "uncertainty",
+                "uncertParameter",
+                "group",
+                "member"
+            ]
+        );
+
+        // Walking the model does not enable further packages
+        assert!(!doc.is_package_enabled(Package::Layout));
+        assert!(!doc.is_package_enabled(Package::Qual));
+    }
+}
@@ -0,0 +1,146 @@
+//! This module provides a safe Rust interface to the libSBML Trigger class.
+//!
+//! The Trigger class holds the condition of an event. The event fires whenever the
+//! condition switches from false to true during a simulation.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Trigger class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use crate::{
+    astnode::{formula_to_string, parse_formula},
+    event::Event,
+    inner, pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation,
+};
+
+/// A safe wrapper around the libSBML Trigger class.
+///
+/// This struct maintains a reference to the underlying C++ Trigger object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Trigger<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Trigger>>,
+}
+
+// Set the inner trait for the Trigger struct
+inner!(sbmlcxx::Trigger, Trigger<'a>);
+
+// Set the annotation trait for the Trigger struct
+upcast_annotation!(Trigger<'a>, sbmlcxx::Trigger, sbmlcxx::SBase);
+
+// Set the SBase trait for the Trigger struct
+sbase!(Trigger<'a>, sbmlcxx::Trigger);
+
+impl<'a> Trigger<'a> {
+    /// Creates a new Trigger instance within the given Event.
+    ///
+    /// The trigger is initially true and persistent, which matches the behaviour of
+    /// events in SBML Level 2.
+    ///
+    /// # Arguments
+    /// * `event` - The parent Event that will contain this trigger
+    /// * `formula` - The condition of the trigger
+    ///
+    /// # Returns
+    /// A new Trigger instance
+    pub fn new(event: &Event<'a>, formula: &str) -> Self {
+        let trigger_ptr = event.inner().borrow_mut().as_mut().createTrigger();
+        let mut trigger = pin_ptr!(trigger_ptr, sbmlcxx::Trigger);
+
+        // Both attributes are required in Level 3 and ignored by earlier levels
+        trigger.as_mut().setInitialValue(true);
+        trigger.as_mut().setPersistent(true);
+
+        let trigger = Self {
+            inner: RefCell::new(trigger),
+        };
+        trigger.set_formula(formula);
+        trigger
+    }
+
+    /// Returns the condition of the trigger.
+    ///
+    /// # Returns
+    /// The formula in the SBML Level 3 infix syntax, empty if no math is set
+    pub fn formula(&self) -> String {
+        formula_to_string(self.inner.borrow().getMath())
+    }
+
+    /// Returns the math of the trigger, null if no math is set.
+    pub(crate) fn math(&self) -> *const sbmlcxx::ASTNode {
+        self.inner.borrow().getMath()
+    }
+
+    /// Sets the condition of the trigger.
+    ///
+    /// Formulas that cannot be parsed by libSBML leave the math unchanged.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula in the SBML Level 3 infix syntax
+    pub fn set_formula(&self, formula: &str) {
+        if let Some(math) = parse_formula(formula) {
+            unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        }
+    }
+
+    /// Returns the value of the trigger just before the start of a simulation.
+    ///
+    /// # Returns
+    /// true if the trigger is considered true before the start, false otherwise
+    pub fn initial_value(&self) -> bool {
+        self.inner.borrow().getInitialValue()
+    }
+
+    /// Sets the value of the trigger just before the start of a simulation.
+    ///
+    /// # Arguments
+    /// * `initial_value` - The initial value to set
+    pub fn set_initial_value(&self, initial_value: bool) {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .setInitialValue(initial_value);
+    }
+
+    /// Returns whether the event executes even if the trigger turns false during its delay.
+    ///
+    /// # Returns
+    /// true if the trigger is persistent, false otherwise
+    pub fn persistent(&self) -> bool {
+        self.inner.borrow().getPersistent()
+    }
+
+    /// Sets whether the event executes even if the trigger turns false during its delay.
+    ///
+    /// # Arguments
+    /// * `persistent` - Whether the trigger is persistent
+    pub fn set_persistent(&self, persistent: bool) {
+        self.inner.borrow_mut().as_mut().setPersistent(persistent);
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Trigger, sbmlcxx::SBase);
+}
+
+impl FromPtr<sbmlcxx::Trigger> for Trigger<'_> {
+    /// Creates a new Trigger instance from a unique pointer to a libSBML Trigger.
+    ///
+    /// This method is primarily used internally by the Event class to create
+    /// Trigger instances from libSBML Trigger pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Trigger
+    ///
+    /// # Returns
+    /// A new Trigger instance
+    fn from_ptr(ptr: *mut sbmlcxx::Trigger) -> Self {
+        let trigger = pin_ptr!(ptr, sbmlcxx::Trigger);
+        Self {
+            inner: RefCell::new(trigger),
+        }
+    }
+}
@@ -14,7 +14,7 @@ use autocxx::c_int;
 use cxx::let_cxx_string;
 
 use crate::{
-    inner, pin_ptr, sbmlcxx, sbo_term, traits::fromptr::FromPtr, unitdef::UnitDefinition,
+    inner, pin_ptr, sbase, sbmlcxx, sbo_term, traits::fromptr::FromPtr, unitdef::UnitDefinition,
     upcast_annotation,
 };
 
@@ -36,6 +36,9 @@ inner!(sbmlcxx::Unit, Unit<'a>);
 // Set the annotation trait for the Unit struct
 upcast_annotation!(Unit<'a>, sbmlcxx::Unit, sbmlcxx::SBase);
 
+// Set the SBase trait for the Unit struct
+sbase!(Unit<'a>, sbmlcxx::Unit);
+
 impl<'a> Unit<'a> {
     /// Creates a new Unit instance within the given Model.
     ///
@@ -390,6 +393,7 @@ impl FromStr for UnitKind {
             "kelvin" => Ok(UnitKind::Kelvin),
             "kilogram" => Ok(UnitKind::Kilogram),
             "liter" => Ok(UnitKind::Liter),
+            "litre" => Ok(UnitKind::Litre),
             "lumen" => Ok(UnitKind::Lumen),
             "lux" => Ok(UnitKind::Lux),
             "meter" => Ok(UnitKind::Meter),
@@ -543,6 +547,7 @@ mod tests {
         assert_eq!(UnitKind::from_str("kelvin").unwrap(), UnitKind::Kelvin);
         assert_eq!(UnitKind::from_str("kilogram").unwrap(), UnitKind::Kilogram);
         assert_eq!(UnitKind::from_str("liter").unwrap(), UnitKind::Liter);
+        assert_eq!(UnitKind::from_str("litre").unwrap(), UnitKind::Litre);
         assert_eq!(UnitKind::from_str("lumen").unwrap(), UnitKind::Lumen);
         assert_eq!(UnitKind::from_str("lux").unwrap(), UnitKind::Lux);
         assert_eq!(UnitKind::from_str("metre").unwrap(), UnitKind::Metre);
@@ -0,0 +1,367 @@
+//! Consistency of the units of kinetic laws and rules.
+//!
+//! [`Model::check_units`] derives the units of the math of every kinetic law and rule
+//! with libSBML and compares them to the units the element must have:
+//!
+//! - A kinetic law must be in extent units per time unit of the model.
+//! - A rate rule must be in the units of its variable per time unit.
+//! - An assignment rule must be in the units of its variable.
+//!
+//! Both sides are converted to SI base units first, so that e.g. "mmol / l" and
+//! "mol / m^3" are the same units. Elements whose units cannot be fully determined,
+//! e.g. because a parameter in the math has no units or the model does not declare
+//! its time units, are skipped.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLReader::from_file("model.xml").unwrap();
+//! let model = doc.model().unwrap();
+//! for mismatch in model.check_units() {
+//!     println!("{}", mismatch);
+//! }
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    compartment::Compartment,
+    derivedunit::DerivedUnit,
+    model::Model,
+    sbmlcxx,
+    species::Species,
+    traits::{fromptr::FromPtr, inner::Inner, sbase::SBase},
+    unit::UnitKind,
+    unitdef::UnitDefinition,
+    usage::ElementRef,
+};
+
+/// An element whose math does not have the units it must have.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitMismatch {
+    /// The kinetic law or rule with the mismatching units
+    pub element: ElementRef,
+    /// The units the element must have, in SI base units
+    pub expected: String,
+    /// The units derived from the math of the element, in SI base units
+    pub derived: String,
+}
+
+impl fmt::Display for UnitMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} has units '{}' instead of '{}'",
+            self.element, self.derived, self.expected
+        )
+    }
+}
+
+/// Derives the units of the math of a kinetic law or rule with libSBML.
+///
+/// Evaluates to None if the math uses elements with undeclared units.
+macro_rules! derived_units {
+    ($element:expr) => {{
+        let mut inner = $element.inner().borrow_mut();
+        if inner.as_mut().containsUndeclaredUnits() {
+            None
+        } else {
+            let ptr = inner.as_mut().getDerivedUnitDefinition() as *mut sbmlcxx::UnitDefinition;
+            (!ptr.is_null()).then(|| DerivedUnit::from(&UnitDefinition::from_ptr(ptr)))
+        }
+    }};
+}
+
+/// Checks the units of all kinetic laws and rules, see [`Model::check_units`].
+pub(crate) fn check_units(model: &Model) -> Vec<UnitMismatch> {
+    // libSBML caches the derived units, which may predate changes to the model
+    model
+        .inner()
+        .borrow_mut()
+        .as_mut()
+        .populateListFormulaUnitsData();
+
+    let time = model_units(
+        model,
+        &model.time_units(),
+        "time",
+        DerivedUnit::from(UnitKind::Second),
+    );
+    let extent = model_units(
+        model,
+        &model.extent_units(),
+        "substance",
+        DerivedUnit::from(UnitKind::Mole),
+    );
+
+    let mut mismatches = Vec::new();
+    let mut compare =
+        |element: ElementRef, expected: Option<DerivedUnit>, derived: Option<DerivedUnit>| {
+            if let (Some(expected), Some(derived)) = (expected, derived) {
+                if !derived.is_identical(&expected) {
+                    mismatches.push(UnitMismatch {
+                        element,
+                        expected: expected.to_si().to_string(),
+                        derived: derived.to_si().to_string(),
+                    });
+                }
+            }
+        };
+
+    for reaction in model.list_of_reactions() {
+        if let Some(kinetic_law) = reaction.kinetic_law() {
+            let expected = extent
+                .as_ref()
+                .zip(time.as_ref())
+                .map(|(extent, time)| extent.clone() / time.clone());
+            if expected.is_some() {
+                let element = ElementRef::KineticLaw {
+                    reaction: reaction.id(),
+                };
+                compare(element, expected, derived_units!(kinetic_law));
+            }
+        }
+    }
+
+    for rule in model.list_of_rate_rules() {
+        let variable = rule.variable();
+        let expected = variable_units(model, &variable)
+            .zip(time.as_ref())
+            .map(|(units, time)| units / time.clone());
+        if expected.is_some() {
+            compare(
+                ElementRef::RateRule { variable },
+                expected,
+                derived_units!(rule),
+            );
+        }
+    }
+
+    for rule in model.list_of_assignment_rules() {
+        let variable = rule.variable();
+        let expected = variable_units(model, &variable);
+        if expected.is_some() {
+            compare(
+                ElementRef::AssignmentRule { variable },
+                expected,
+                derived_units!(rule),
+            );
+        }
+    }
+
+    mismatches
+}
+
+/// Converts a units attribute to base units.
+///
+/// # Returns
+/// The units of the unit definition or base unit with the given identifier, None if
+/// there is neither
+fn resolve(model: &Model, units: &str) -> Option<DerivedUnit> {
+    if let Some(unit_definition) = model.get_unit_definition(units) {
+        return Some(DerivedUnit::from(unit_definition.as_ref()));
+    }
+    UnitKind::from_str(units).ok().map(DerivedUnit::from)
+}
+
+/// Resolves a default unit of the model.
+///
+/// SBML Level 3 declares the default units as attributes of the model, while Level 2
+/// predefines them under a fixed identifier, which the model may redefine.
+fn model_units(
+    model: &Model,
+    units: &str,
+    builtin: &str,
+    default: DerivedUnit,
+) -> Option<DerivedUnit> {
+    if model.level() < 3 {
+        return Some(resolve(model, builtin).unwrap_or(default));
+    }
+    if units.is_empty() {
+        return None;
+    }
+    resolve(model, units)
+}
+
+/// Resolves the units of the size of a compartment.
+fn compartment_units(model: &Model, compartment: &Compartment) -> Option<DerivedUnit> {
+    let units = compartment.unit();
+    if !units.is_empty() {
+        return resolve(model, &units);
+    }
+
+    let metre = DerivedUnit::from(UnitKind::Metre);
+    match compartment.spatial_dimensions() {
+        0 => Some(DerivedUnit::dimensionless()),
+        1 => model_units(model, &model.length_units(), "length", metre),
+        2 => model_units(model, &model.area_units(), "area", metre.pow(2)),
+        _ => model_units(
+            model,
+            &model.volume_units(),
+            "volume",
+            DerivedUnit::from(UnitKind::Litre),
+        ),
+    }
+}
+
+/// Resolves the units of the amount or concentration of a species.
+fn species_units(model: &Model, species: &Species) -> Option<DerivedUnit> {
+    let units = species.unit();
+    let substance = if units.is_empty() {
+        model_units(
+            model,
+            &model.substance_units(),
+            "substance",
+            DerivedUnit::from(UnitKind::Mole),
+        )?
+    } else {
+        resolve(model, &units)?
+    };
+    if species.has_only_substance_units() {
+        return Some(substance);
+    }
+
+    let compartment = model.get_compartment(&species.compartment())?;
+    Some(substance / compartment_units(model, &compartment)?)
+}
+
+/// Resolves the units of the variable of a rule.
+fn variable_units(model: &Model, variable: &str) -> Option<DerivedUnit> {
+    if let Some(species) = model.get_species(variable) {
+        return species_units(model, &species);
+    }
+    if let Some(compartment) = model.get_compartment(variable) {
+        return compartment_units(model, &compartment);
+    }
+
+    let units = model.get_parameter(variable)?.units();
+    if units.is_empty() {
+        return None;
+    }
+    resolve(model, &units)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::prelude::*;
+
+    /// Builds a Level 3 model with a concentration in mM and time in seconds
+    fn build_model<'a>(doc: &'a SBMLDocument<'a>) -> Rc<Model<'a>> {
+        let model = doc
+            .build_model("test")
+            .substance_units("mmol")
+            .time_units("second")
+            .volume_units("litre")
+            .extent_units("mmol")
+            .build();
+        model
+            .build_unit_definition("mmol", "mmol")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .build();
+        model
+            .build_unit_definition("per_second", "1 / s")
+            .unit(UnitKind::Second, Some(-1), Some(0), None, None)
+            .build();
+        model
+            .build_unit_definition("mmol_per_second", "mmol / s")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Second, Some(-1), Some(0), None, None)
+            .build();
+        model.build_compartment("cytosol").size(1.0).build();
+        model
+            .build_species("glc")
+            .compartment("cytosol")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_parameter("k1")
+            .value(0.1)
+            .units("per_second")
+            .build();
+        model
+            .build_parameter("v")
+            .value(0.1)
+            .units("mmol_per_second")
+            .build();
+        model
+    }
+
+    #[test]
+    fn test_consistent_units() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = build_model(&doc);
+        model
+            .build_reaction("uptake")
+            .reactant("glc", 1.0)
+            .kinetic_law("k1 * glc * cytosol")
+            .build();
+        model
+            .build_species("atp")
+            .compartment("cytosol")
+            .initial_concentration(1.0)
+            .build();
+        model.build_rate_rule("atp", "-k1 * atp").build();
+
+        assert!(model.check_units().is_empty());
+    }
+
+    #[test]
+    fn test_kinetic_law_mismatch() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = build_model(&doc);
+        model
+            .build_reaction("uptake")
+            .reactant("glc", 1.0)
+            .kinetic_law("k1 * glc")
+            .build();
+
+        let mismatches = model.check_units();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(
+            mismatches[0].element,
+            ElementRef::KineticLaw {
+                reaction: "uptake".to_string()
+            }
+        );
+        assert_eq!(mismatches[0].expected, "0.001 second^-1 * mole");
+    }
+
+    #[test]
+    fn test_rule_mismatch() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = build_model(&doc);
+        model.build_rate_rule("glc", "k1 * glc").build();
+        model.build_assignment_rule("v", "k1 * glc").build();
+
+        let mismatches = model.check_units();
+        assert_eq!(
+            mismatches
+                .iter()
+                .map(|mismatch| mismatch.element.clone())
+                .collect::<Vec<_>>(),
+            vec![ElementRef::AssignmentRule {
+                variable: "v".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_undeclared_units_are_skipped() {
+        let doc = SBMLDocument::new(3, 2);
+        let model = doc.create_model("test");
+        model.build_compartment("cytosol").size(1.0).build();
+        model.build_species("glc").compartment("cytosol").build();
+        model.build_parameter("k1").value(0.1).build();
+        model
+            .build_reaction("uptake")
+            .reactant("glc", 1.0)
+            .kinetic_law("k1 * glc")
+            .build();
+
+        assert!(model.check_units().is_empty());
+    }
+}
@@ -14,11 +14,12 @@ use cxx::let_cxx_string;
 use crate::{
     inner,
     model::Model,
-    pin_ptr,
+    pin_ptr, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
     unit::{Unit, UnitBuilder, UnitKind},
+    unitstr::{self, UnitParseError, UnitTerm},
     upcast_annotation,
 };
 
@@ -37,6 +38,9 @@ inner!(sbmlcxx::UnitDefinition, UnitDefinition<'a>);
 // Set the annotation trait for the UnitDefinition struct
 upcast_annotation!(UnitDefinition<'a>, sbmlcxx::UnitDefinition, sbmlcxx::SBase);
 
+// Set the SBase trait for the UnitDefinition struct
+sbase!(UnitDefinition<'a>, sbmlcxx::UnitDefinition);
+
 impl<'a> UnitDefinition<'a> {
     /// Creates a new UnitDefinition instance within the given Model.
     ///
@@ -63,6 +67,66 @@ impl<'a> UnitDefinition<'a> {
         }
     }
 
+    /// Creates a new UnitDefinition within the given Model from a unit string.
+    ///
+    /// The string is a product or quotient of unit symbols with optional SI prefixes
+    /// and exponents, e.g. "mmol / l", "1 / s" or "mol / (m^3 * min)". Each symbol
+    /// becomes a unit with the corresponding kind, scale, exponent and multiplier.
+    /// The string itself is used as the name of the unit definition.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this unit definition
+    /// * `id` - The identifier for this unit definition
+    /// * `units` - The unit string, see [`crate::unitstr`] for the syntax
+    ///
+    /// # Returns
+    /// The new UnitDefinition, or an error if the string cannot be parsed, in which
+    /// case no unit definition is created
+    pub fn from_unit_str(
+        model: &Model<'a>,
+        id: &str,
+        units: &str,
+    ) -> Result<Rc<UnitDefinition<'a>>, UnitParseError> {
+        let terms = unitstr::parse(units)?;
+        Ok(Self::from_terms(model, id, units.trim(), &terms))
+    }
+
+    /// Creates a new UnitDefinition within the given Model with a unit per term.
+    pub(crate) fn from_terms(
+        model: &Model<'a>,
+        id: &str,
+        name: &str,
+        terms: &[UnitTerm],
+    ) -> Rc<UnitDefinition<'a>> {
+        let unit_definition = model.create_unit_definition(id, name);
+        for term in terms {
+            unit_definition
+                .build_unit(term.kind)
+                .exponent(term.exponent)
+                .scale(term.scale)
+                .multiplier(term.multiplier)
+                .build();
+        }
+        unit_definition
+    }
+
+    /// Formats the units of the unit definition as a unit string, e.g. "mmol / l".
+    ///
+    /// The string can be parsed again with [`UnitDefinition::from_unit_str`].
+    pub fn to_unit_str(&self) -> String {
+        let terms: Vec<UnitTerm> = self
+            .units()
+            .iter()
+            .map(|unit| UnitTerm {
+                kind: unit.kind(),
+                exponent: unit.exponent(),
+                scale: unit.scale(),
+                multiplier: unit.multiplier(),
+            })
+            .collect();
+        unitstr::format(&terms)
+    }
+
     pub fn name(&self) -> String {
         self.inner.borrow().getName().to_str().unwrap().to_string()
     }
@@ -135,10 +199,20 @@ impl FromPtr<sbmlcxx::UnitDefinition> for UnitDefinition<'_> {
     /// # Returns
     /// A new UnitDefinition instance
     fn from_ptr(ptr: *mut sbmlcxx::UnitDefinition) -> Self {
-        let unit_definition = pin_ptr!(ptr, sbmlcxx::UnitDefinition);
+        let unit_definition = RefCell::new(pin_ptr!(ptr, sbmlcxx::UnitDefinition));
+
+        // Fetch all units
+        let n_units = unit_definition.borrow().getNumUnits().0;
+        let units: Vec<_> = (0..n_units)
+            .map(|i| {
+                let unit = unit_definition.borrow_mut().as_mut().getUnit1(i.into());
+                Rc::new(Unit::from_ptr(unit))
+            })
+            .collect();
+
         Self {
-            inner: RefCell::new(unit_definition),
-            units: RefCell::new(Vec::new()),
+            inner: unit_definition,
+            units: RefCell::new(units),
         }
     }
 }
@@ -324,4 +398,39 @@ mod tests {
             .expect("Failed to get annotation");
         assert_eq!(annotation.test, "test");
     }
+
+    #[test]
+    fn test_from_unit_str() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let unit_definition = UnitDefinition::from_unit_str(&model, "mM", " mmol / l ").unwrap();
+
+        assert_eq!(unit_definition.id(), "mM");
+        assert_eq!(unit_definition.name(), "mmol / l");
+        let units = unit_definition.units();
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].kind(), UnitKind::Mole);
+        assert_eq!((units[0].exponent(), units[0].scale()), (1, -3));
+        assert_eq!(units[1].kind(), UnitKind::Litre);
+        assert_eq!((units[1].exponent(), units[1].scale()), (-1, 0));
+        assert_eq!(units[1].multiplier(), 1.0);
+
+        let error = UnitDefinition::from_unit_str(&model, "bad", "mmol / furlong");
+        assert!(error.is_err());
+        assert!(model.get_unit_definition("bad").is_none());
+    }
+
+    #[test]
+    fn test_to_unit_str() {
+        let doc = SBMLReader::from_xml_string(include_str!("../tests/data/odes_example_test.xml"));
+        let model = doc.model().unwrap();
+
+        // The names of the unit definitions in the example are their unit strings
+        for unit_definition in model.list_of_unit_definitions() {
+            assert_eq!(unit_definition.to_unit_str(), unit_definition.name());
+        }
+
+        let per_minute = UnitDefinition::from_unit_str(&model, "per_minute", "1 / min").unwrap();
+        assert_eq!(per_minute.to_unit_str(), "1 / min");
+    }
 }
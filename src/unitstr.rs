@@ -0,0 +1,644 @@
+//! Human-readable unit strings such as "mmol / l" or "1 / s".
+//!
+//! Unit strings are products and quotients of unit symbols, e.g. "mol", "l" or "s",
+//! or SBML unit names such as "mole" or "litre". Symbols may carry an SI prefix, e.g.
+//! "mmol" or "µl", and an integer exponent, e.g. "m^3". Products are written with
+//! `*` or a space, quotients with `/`, and parentheses group parts of the string, e.g.
+//! "mmol / (l * s)". Besides the SBML unit kinds, "M" stands for mol / l and "min",
+//! "h" and "day" for multiples of the second.
+//!
+//! [`UnitDefinition::from_unit_str`] creates a unit definition from such a string and
+//! [`UnitDefinition::to_unit_str`] formats the units of a unit definition.
+//!
+//! [`UnitDefinition::from_unit_str`]: crate::unitdef::UnitDefinition::from_unit_str
+//! [`UnitDefinition::to_unit_str`]: crate::unitdef::UnitDefinition::to_unit_str
+
+use std::{error::Error, fmt, iter::Peekable, str::Chars, str::FromStr};
+
+use crate::{
+    derivedunit::{kind_name, DerivedUnit},
+    unit::UnitKind,
+};
+
+/// Error type for unit strings that cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitParseError {
+    /// The string contains no units
+    Empty,
+    /// A symbol is neither a unit nor a prefixed unit
+    UnknownUnit(String),
+    /// A character or symbol that is not allowed at its position
+    UnexpectedToken(String),
+    /// The string ends in the middle of an expression, e.g. after "/"
+    UnexpectedEnd,
+}
+
+impl fmt::Display for UnitParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitParseError::Empty => write!(f, "The unit string is empty"),
+            UnitParseError::UnknownUnit(symbol) => write!(f, "Unknown unit '{}'", symbol),
+            UnitParseError::UnexpectedToken(token) => {
+                write!(f, "Unexpected '{}' in unit string", token)
+            }
+            UnitParseError::UnexpectedEnd => write!(f, "Unexpected end of unit string"),
+        }
+    }
+}
+
+impl Error for UnitParseError {}
+
+/// A unit of a unit definition, i.e. (multiplier * 10^scale * kind)^exponent.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UnitTerm {
+    pub(crate) kind: UnitKind,
+    pub(crate) exponent: i32,
+    pub(crate) scale: i32,
+    pub(crate) multiplier: f64,
+}
+
+impl UnitTerm {
+    fn new(kind: UnitKind) -> Self {
+        Self {
+            kind,
+            exponent: 1,
+            scale: 0,
+            multiplier: 1.0,
+        }
+    }
+}
+
+/// SI prefixes as symbol, name and scale.
+const PREFIXES: [(&str, &str, i32); 22] = [
+    ("Y", "yotta", 24),
+    ("Z", "zetta", 21),
+    ("E", "exa", 18),
+    ("P", "peta", 15),
+    ("T", "tera", 12),
+    ("G", "giga", 9),
+    ("M", "mega", 6),
+    ("k", "kilo", 3),
+    ("h", "hecto", 2),
+    ("da", "deca", 1),
+    ("d", "deci", -1),
+    ("c", "centi", -2),
+    ("m", "milli", -3),
+    ("u", "micro", -6),
+    ("µ", "micro", -6),
+    ("μ", "micro", -6),
+    ("n", "nano", -9),
+    ("p", "pico", -12),
+    ("f", "femto", -15),
+    ("a", "atto", -18),
+    ("z", "zepto", -21),
+    ("y", "yocto", -24),
+];
+
+/// Symbols of the unit kinds, used when formatting units.
+fn symbol(kind: UnitKind) -> &'static str {
+    match kind {
+        UnitKind::Ampere => "A",
+        UnitKind::Becquerel => "Bq",
+        UnitKind::Candela => "cd",
+        UnitKind::Coulomb => "C",
+        UnitKind::Farad => "F",
+        UnitKind::Gram => "g",
+        UnitKind::Gray => "Gy",
+        UnitKind::Henry => "H",
+        UnitKind::Hertz => "Hz",
+        UnitKind::Joule => "J",
+        UnitKind::Katal => "kat",
+        UnitKind::Kelvin => "K",
+        UnitKind::Kilogram => "kg",
+        UnitKind::Liter | UnitKind::Litre => "l",
+        UnitKind::Lumen => "lm",
+        UnitKind::Lux => "lx",
+        UnitKind::Meter | UnitKind::Metre => "m",
+        UnitKind::Mole => "mol",
+        UnitKind::Newton => "N",
+        UnitKind::Pascal => "Pa",
+        UnitKind::Radian => "rad",
+        UnitKind::Second => "s",
+        UnitKind::Siemens => "S",
+        UnitKind::Sievert => "Sv",
+        UnitKind::Steradian => "sr",
+        UnitKind::Tesla => "T",
+        UnitKind::Volt => "V",
+        UnitKind::Watt => "W",
+        UnitKind::Weber => "Wb",
+        kind => kind_name(kind),
+    }
+}
+
+/// Multiples of the second, as name and multiplier.
+const TIME_UNITS: [(&str, f64); 3] = [("min", 60.0), ("h", 3600.0), ("day", 86400.0)];
+
+/// Looks up a unit symbol or name without prefix.
+fn lookup(symbol_or_name: &str) -> Option<Vec<UnitTerm>> {
+    if let Ok(kind) = UnitKind::from_str(symbol_or_name) {
+        return Some(vec![UnitTerm::new(kind)]);
+    }
+    if let Some((_, multiplier)) = TIME_UNITS.iter().find(|(name, _)| *name == symbol_or_name) {
+        let mut second = UnitTerm::new(UnitKind::Second);
+        second.multiplier = *multiplier;
+        return Some(vec![second]);
+    }
+
+    let kind = match symbol_or_name {
+        "M" | "molar" => {
+            let mut litre = UnitTerm::new(UnitKind::Litre);
+            litre.exponent = -1;
+            return Some(vec![UnitTerm::new(UnitKind::Mole), litre]);
+        }
+        "L" => UnitKind::Litre,
+        "sec" => UnitKind::Second,
+        "Ω" => UnitKind::Ohm,
+        "°C" => UnitKind::Celsius,
+        symbol_or_name => ALL_KINDS
+            .iter()
+            .copied()
+            .find(|kind| symbol(*kind) == symbol_or_name)?,
+    };
+    Some(vec![UnitTerm::new(kind)])
+}
+
+/// Unit kinds that have a symbol of their own.
+const ALL_KINDS: [UnitKind; 29] = [
+    UnitKind::Ampere,
+    UnitKind::Becquerel,
+    UnitKind::Candela,
+    UnitKind::Coulomb,
+    UnitKind::Farad,
+    UnitKind::Gram,
+    UnitKind::Gray,
+    UnitKind::Henry,
+    UnitKind::Hertz,
+    UnitKind::Joule,
+    UnitKind::Katal,
+    UnitKind::Kelvin,
+    UnitKind::Kilogram,
+    UnitKind::Litre,
+    UnitKind::Lumen,
+    UnitKind::Lux,
+    UnitKind::Metre,
+    UnitKind::Mole,
+    UnitKind::Newton,
+    UnitKind::Pascal,
+    UnitKind::Radian,
+    UnitKind::Second,
+    UnitKind::Siemens,
+    UnitKind::Sievert,
+    UnitKind::Steradian,
+    UnitKind::Tesla,
+    UnitKind::Volt,
+    UnitKind::Watt,
+    UnitKind::Weber,
+];
+
+/// Resolves a unit symbol or name, which may start with an SI prefix.
+fn resolve(word: &str) -> Result<Vec<UnitTerm>, UnitParseError> {
+    if let Some(terms) = lookup(word) {
+        return Ok(terms);
+    }
+
+    for (prefix_symbol, prefix_name, scale) in PREFIXES {
+        for prefix in [prefix_symbol, prefix_name] {
+            let rest = word.strip_prefix(prefix).filter(|rest| !rest.is_empty());
+            if let Some(mut terms) = rest.and_then(lookup) {
+                // The prefix applies to the first unit of a symbol, e.g. to the mole of mM
+                terms[0].scale = scale;
+                return Ok(terms);
+            }
+        }
+    }
+    Err(UnitParseError::UnknownUnit(word.to_string()))
+}
+
+/// A parsed part of a unit string: a numeric factor times a list of units.
+struct Product {
+    factor: f64,
+    terms: Vec<UnitTerm>,
+}
+
+impl Product {
+    fn mul(mut self, other: Product) -> Product {
+        self.factor *= other.factor;
+        self.terms.extend(other.terms);
+        self
+    }
+
+    fn pow(mut self, exponent: i32) -> Product {
+        self.factor = self.factor.powi(exponent);
+        for term in self.terms.iter_mut() {
+            term.exponent *= exponent;
+        }
+        self
+    }
+
+    /// Moves the numeric factor into the multiplier of the first unit.
+    fn absorb_factor(mut self) -> Product {
+        if self.factor != 1.0 {
+            if let Some(term) = self.terms.first_mut() {
+                term.multiplier *= self.factor.powf(1.0 / term.exponent as f64);
+                self.factor = 1.0;
+            }
+        }
+        self
+    }
+}
+
+/// Recursive descent parser for unit strings.
+struct Parser<'s> {
+    chars: Peekable<Chars<'s>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.peek().copied()
+    }
+
+    /// Parses a product or quotient of factors.
+    fn expression(&mut self) -> Result<Product, UnitParseError> {
+        let mut product = self.factor()?;
+        loop {
+            match self.peek() {
+                Some('*') | Some('·') => {
+                    self.chars.next();
+                    product = product.mul(self.factor()?);
+                }
+                Some('/') => {
+                    self.chars.next();
+                    product = product.mul(self.factor()?.pow(-1));
+                }
+                Some(c) if c != ')' => product = product.mul(self.factor()?),
+                _ => return Ok(product),
+            }
+        }
+    }
+
+    /// Parses a number, unit or parenthesized expression with an optional exponent.
+    fn factor(&mut self) -> Result<Product, UnitParseError> {
+        let base = match self.peek() {
+            None => return Err(UnitParseError::UnexpectedEnd),
+            Some('(') => {
+                self.chars.next();
+                let product = self.expression()?.absorb_factor();
+                match self.peek() {
+                    Some(')') => self.chars.next(),
+                    Some(c) => return Err(UnitParseError::UnexpectedToken(c.to_string())),
+                    None => return Err(UnitParseError::UnexpectedEnd),
+                };
+                product
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let number = self.take_while(|c| c.is_ascii_digit() || c == '.');
+                let factor = number
+                    .parse()
+                    .map_err(|_| UnitParseError::UnexpectedToken(number))?;
+                Product {
+                    factor,
+                    terms: Vec::new(),
+                }
+            }
+            Some(c) if is_symbol_char(c) => {
+                let word = self.take_while(is_symbol_char);
+                Product {
+                    factor: 1.0,
+                    terms: resolve(&word)?,
+                }
+            }
+            Some(c) => return Err(UnitParseError::UnexpectedToken(c.to_string())),
+        };
+
+        if self.peek() != Some('^') {
+            return Ok(base);
+        }
+        self.chars.next();
+        self.skip_whitespace();
+        let sign = self.take_while(|c| c == '-' || c == '+');
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let exponent: i32 =
+            format!("{}{}", sign, digits)
+                .parse()
+                .map_err(|_| match self.chars.peek() {
+                    Some(c) => UnitParseError::UnexpectedToken(c.to_string()),
+                    None => UnitParseError::UnexpectedEnd,
+                })?;
+        Ok(base.pow(exponent))
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+        while let Some(c) = self.chars.next_if(|c| predicate(*c)) {
+            taken.push(c);
+        }
+        taken
+    }
+}
+
+fn is_symbol_char(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '_' | '°' | 'Ω')
+}
+
+/// Parses a unit string into the units of a unit definition.
+///
+/// A numeric factor, e.g. the 60 of "60 s", becomes the multiplier of the first unit.
+/// A plain number, e.g. "1", is dimensionless.
+pub(crate) fn parse(units: &str) -> Result<Vec<UnitTerm>, UnitParseError> {
+    let mut parser = Parser {
+        chars: units.chars().peekable(),
+    };
+    if parser.peek().is_none() {
+        return Err(UnitParseError::Empty);
+    }
+
+    let product = parser.expression()?.absorb_factor();
+    if let Some(c) = parser.peek() {
+        return Err(UnitParseError::UnexpectedToken(c.to_string()));
+    }
+
+    let mut terms = product.terms;
+    terms.retain(|term| term.exponent != 0);
+    if terms.is_empty() {
+        let mut dimensionless = UnitTerm::new(UnitKind::Dimensionless);
+        dimensionless.multiplier = product.factor;
+        terms.push(dimensionless);
+    }
+    Ok(terms)
+}
+
+/// Formats a single unit without its exponent, e.g. "mmol".
+fn format_term(term: &UnitTerm) -> String {
+    if term.kind == UnitKind::Second && term.scale == 0 {
+        if let Some((name, _)) = TIME_UNITS
+            .iter()
+            .find(|(_, multiplier)| (multiplier - term.multiplier).abs() < 1e-9 * multiplier)
+        {
+            return name.to_string();
+        }
+    }
+
+    let prefix = PREFIXES
+        .iter()
+        .find(|(_, _, scale)| *scale == term.scale)
+        .map(|(prefix, _, _)| *prefix);
+    match (prefix, term.multiplier) {
+        (Some(prefix), multiplier) if multiplier == 1.0 => {
+            format!("{}{}", prefix, symbol(term.kind))
+        }
+        (None, multiplier) if multiplier == 1.0 && term.scale == 0 => symbol(term.kind).to_string(),
+        _ => {
+            let multiplier = term.multiplier * 10f64.powi(term.scale);
+            format!("({} {})", multiplier, symbol(term.kind))
+        }
+    }
+}
+
+/// Formats the units of a unit definition, e.g. "mmol / (l * s)".
+pub(crate) fn format(terms: &[UnitTerm]) -> String {
+    let format_all = |terms: Vec<&UnitTerm>, sign: i32| -> Vec<String> {
+        terms
+            .into_iter()
+            .map(|term| match term.exponent * sign {
+                1 => format_term(term),
+                exponent => format!("{}^{}", format_term(term), exponent),
+            })
+            .collect()
+    };
+
+    let (numerator, denominator): (Vec<&UnitTerm>, Vec<&UnitTerm>) = terms
+        .iter()
+        .filter(|term| term.exponent != 0)
+        .filter(|term| term.kind != UnitKind::Dimensionless || term.multiplier != 1.0)
+        .partition(|term| term.exponent > 0);
+    let numerator = format_all(numerator, 1);
+    let denominator = format_all(denominator, -1);
+
+    let numerator = if !numerator.is_empty() {
+        numerator.join(" * ")
+    } else if !denominator.is_empty() {
+        "1".to_string()
+    } else {
+        return "dimensionless".to_string();
+    };
+    match denominator.len() {
+        0 => numerator,
+        1 => format!("{} / {}", numerator, denominator[0]),
+        _ => format!("{} / ({})", numerator, denominator.join(" * ")),
+    }
+}
+
+/// Splits a derived unit into the units of a unit definition.
+///
+/// The factor of the derived unit becomes the scale of its first unit in the
+/// numerator if it is a power of ten, e.g. for mmol / l, and its multiplier otherwise.
+pub(crate) fn terms(unit: &DerivedUnit) -> Vec<UnitTerm> {
+    let mut terms: Vec<UnitTerm> = unit
+        .kinds()
+        .iter()
+        .map(|(kind, exponent)| {
+            let mut term = UnitTerm::new(*kind);
+            term.exponent = *exponent;
+            term
+        })
+        .collect();
+
+    if terms.is_empty() {
+        let mut dimensionless = UnitTerm::new(UnitKind::Dimensionless);
+        dimensionless.multiplier = unit.factor();
+        return vec![dimensionless];
+    }
+    if (unit.factor() - 1.0).abs() <= 1e-12 {
+        return terms;
+    }
+
+    let index = terms.iter().position(|term| term.exponent > 0).unwrap_or(0);
+    let term = &mut terms[index];
+    let mut multiplier = unit.factor().powf(1.0 / term.exponent as f64);
+    if (multiplier - multiplier.round()).abs() < 1e-9 * multiplier {
+        multiplier = multiplier.round();
+    }
+    let scale = multiplier.log10();
+    if (scale - scale.round()).abs() < 1e-9 {
+        term.scale = scale.round() as i32;
+    } else {
+        term.multiplier = multiplier;
+    }
+    terms
+}
+
+/// Derives an identifier for a unit definition from its units, e.g. "mmol_per_l".
+pub(crate) fn unit_id(terms: &[UnitTerm]) -> String {
+    let name = |term: &UnitTerm| {
+        let mut name: String = format_term(term)
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert(0, '_');
+        }
+        match term.exponent.abs() {
+            1 => name,
+            exponent => format!("{}{}", name, exponent),
+        }
+    };
+
+    let (numerator, denominator): (Vec<&UnitTerm>, Vec<&UnitTerm>) = terms
+        .iter()
+        .filter(|term| term.exponent != 0)
+        .filter(|term| term.kind != UnitKind::Dimensionless || term.multiplier != 1.0)
+        .partition(|term| term.exponent > 0);
+    let numerator: Vec<String> = numerator.into_iter().map(name).collect();
+    let denominator: Vec<String> = denominator.into_iter().map(name).collect();
+
+    match (numerator.is_empty(), denominator.is_empty()) {
+        (true, true) => "dimensionless".to_string(),
+        (false, true) => numerator.join("_"),
+        (true, false) => format!("per_{}", denominator.join("_")),
+        (false, false) => format!("{}_per_{}", numerator.join("_"), denominator.join("_")),
+    }
+}
+
+impl FromStr for DerivedUnit {
+    type Err = UnitParseError;
+
+    /// Parses a unit string such as "mmol / l", see the module documentation.
+    fn from_str(units: &str) -> Result<Self, Self::Err> {
+        Ok(parse(units)?
+            .iter()
+            .fold(DerivedUnit::dimensionless(), |product, term| {
+                product
+                    * DerivedUnit::from(term.kind)
+                        .multiplied(term.multiplier)
+                        .scaled(term.scale)
+                        .pow(term.exponent)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(kind: UnitKind, exponent: i32, scale: i32, multiplier: f64) -> UnitTerm {
+        UnitTerm {
+            kind,
+            exponent,
+            scale,
+            multiplier,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("mmol / l").unwrap(),
+            vec![
+                term(UnitKind::Mole, 1, -3, 1.0),
+                term(UnitKind::Litre, -1, 0, 1.0)
+            ]
+        );
+        assert_eq!(
+            parse("1 / s").unwrap(),
+            vec![term(UnitKind::Second, -1, 0, 1.0)]
+        );
+        assert_eq!(
+            parse("mol m^-3").unwrap(),
+            vec![
+                term(UnitKind::Mole, 1, 0, 1.0),
+                term(UnitKind::Metre, -3, 0, 1.0)
+            ]
+        );
+        assert_eq!(
+            parse("µM / (mmol * min)").unwrap(),
+            vec![
+                term(UnitKind::Mole, 1, -6, 1.0),
+                term(UnitKind::Litre, -1, 0, 1.0),
+                term(UnitKind::Mole, -1, -3, 1.0),
+                term(UnitKind::Second, -1, 0, 60.0)
+            ]
+        );
+        assert_eq!(
+            parse("millilitre").unwrap(),
+            vec![term(UnitKind::Litre, 1, -3, 1.0)]
+        );
+        assert_eq!(
+            parse("1").unwrap(),
+            vec![term(UnitKind::Dimensionless, 1, 0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("  "), Err(UnitParseError::Empty));
+        assert_eq!(
+            parse("mmol / furlong"),
+            Err(UnitParseError::UnknownUnit("furlong".to_string()))
+        );
+        assert_eq!(parse("mol /"), Err(UnitParseError::UnexpectedEnd));
+        assert_eq!(parse("mol / (l"), Err(UnitParseError::UnexpectedEnd));
+        assert_eq!(
+            parse("mol) / l"),
+            Err(UnitParseError::UnexpectedToken(")".to_string()))
+        );
+        assert_eq!(
+            parse("m^x"),
+            Err(UnitParseError::UnexpectedToken("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_format_roundtrip() {
+        for units in [
+            "mmol / l",
+            "1 / s",
+            "mol / (l * s)",
+            "m^2",
+            "ul",
+            "mmol / (g * min)",
+            "dimensionless",
+        ] {
+            assert_eq!(format(&parse(units).unwrap()), units);
+        }
+        assert_eq!(format(&parse("(2.5 mol) / l").unwrap()), "(2.5 mol) / l");
+    }
+
+    #[test]
+    fn test_derived_unit_from_str() {
+        let millimolar: DerivedUnit = "mM".parse().unwrap();
+        let si: DerivedUnit = "mol / m^3".parse().unwrap();
+        assert!(millimolar.is_identical(&si));
+
+        let per_minute: DerivedUnit = "1 / min".parse().unwrap();
+        let per_second: DerivedUnit = "Hz".parse().unwrap();
+        let factor = per_minute.conversion_factor(&per_second).unwrap();
+        assert!((factor - 1.0 / 60.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_terms_and_ids() {
+        let cases = [
+            ("mM", "mmol / l", "mmol_per_l"),
+            ("1 / min", "1 / min", "per_min"),
+            ("µl", "ul", "ul"),
+            ("mol / (l * s)", "mol / (l * s)", "mol_per_l_s"),
+            ("m^2", "m^2", "m2"),
+            ("1", "dimensionless", "dimensionless"),
+        ];
+        for (units, formatted, id) in cases {
+            let unit: DerivedUnit = units.parse().unwrap();
+            let terms = terms(&unit);
+            assert_eq!(format(&terms), formatted);
+            assert_eq!(unit_id(&terms), id);
+        }
+    }
+}
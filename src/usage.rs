@@ -0,0 +1,483 @@
+//! Index of the references between the elements of a model.
+//!
+//! [`Model::usages`] lists every element that mentions an identifier, either in an
+//! attribute such as the species of a species reference, as a symbol in its math or
+//! as the units of a number in its math.
+//! [`Model::unused_elements`] builds on it to find parameters, unit definitions and
+//! species that nothing refers to, e.g. to prune models imported from other sources.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLReader::from_file("model.xml").unwrap();
+//! let model = doc.model().unwrap();
+//! for usage in model.usages("k1") {
+//!     println!("k1 is used by {}", usage);
+//! }
+//!
+//! let unused = model.unused_elements();
+//! println!("Unused parameters: {:?}", unused.parameters);
+//! ```
+
+use std::fmt;
+
+use crate::{astnode::uses_units, formula::Expr, model::Model, sbmlcxx};
+
+/// An element of a model that refers to an identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ElementRef {
+    /// A compartment nested in the compartment
+    Compartment { id: String },
+    /// A species located in the compartment or converted by the parameter
+    Species { id: String },
+    /// A reaction referring to the identifier in an attribute, e.g. a flux bound
+    Reaction { id: String },
+    /// The kinetic law of a reaction
+    KineticLaw { reaction: String },
+    /// A reactant or product of a reaction
+    SpeciesReference { reaction: String, species: String },
+    /// A modifier of a reaction
+    ModifierSpeciesReference { reaction: String, species: String },
+    /// A rate rule, identified by its variable
+    RateRule { variable: String },
+    /// An assignment rule, identified by its variable
+    AssignmentRule { variable: String },
+    /// An initial assignment, identified by its symbol
+    InitialAssignment { symbol: String },
+    /// An event, through its trigger, delay or event assignments
+    Event { id: String },
+    /// An objective, through its flux objectives (fbc package)
+    #[cfg(feature = "fbc")]
+    Objective { id: String },
+}
+
+impl fmt::Display for ElementRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElementRef::Compartment { id } => write!(f, "compartment '{}'", id),
+            ElementRef::Species { id } => write!(f, "species '{}'", id),
+            ElementRef::Reaction { id } => write!(f, "reaction '{}'", id),
+            ElementRef::KineticLaw { reaction } => {
+                write!(f, "kinetic law of reaction '{}'", reaction)
+            }
+            ElementRef::SpeciesReference { reaction, species } => write!(
+                f,
+                "species reference '{}' of reaction '{}'",
+                species, reaction
+            ),
+            ElementRef::ModifierSpeciesReference { reaction, species } => {
+                write!(f, "modifier '{}' of reaction '{}'", species, reaction)
+            }
+            ElementRef::RateRule { variable } => write!(f, "rate rule for '{}'", variable),
+            ElementRef::AssignmentRule { variable } => {
+                write!(f, "assignment rule for '{}'", variable)
+            }
+            ElementRef::InitialAssignment { symbol } => {
+                write!(f, "initial assignment for '{}'", symbol)
+            }
+            ElementRef::Event { id } => write!(f, "event '{}'", id),
+            #[cfg(feature = "fbc")]
+            ElementRef::Objective { id } => write!(f, "objective '{}'", id),
+        }
+    }
+}
+
+/// Elements of a model that are not referred to by any other element.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnusedElements {
+    /// Global parameters that are not used in any math or attribute
+    pub parameters: Vec<String>,
+    /// Unit definitions that are not used as units of any element
+    pub unit_definitions: Vec<String>,
+    /// Species that take part in no reaction and are not used in any math
+    pub species: Vec<String>,
+}
+
+impl UnusedElements {
+    /// Returns whether all elements are in use.
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty() && self.unit_definitions.is_empty() && self.species.is_empty()
+    }
+}
+
+/// Unit identifiers that SBML Level 2 uses as default units of the model, so that
+/// redefining them counts as a use.
+const DEFAULT_UNITS: [&str; 5] = ["substance", "volume", "area", "length", "time"];
+
+/// Lists the elements referring to an identifier, see [`Model::usages`].
+pub(crate) fn usages(model: &Model, id: &str) -> Vec<ElementRef> {
+    let mut usages = Vec::new();
+
+    for compartment in model.list_of_compartments() {
+        if compartment.outside() == id {
+            usages.push(ElementRef::Compartment {
+                id: compartment.id(),
+            });
+        }
+    }
+    for species in model.list_of_species() {
+        if species.compartment() == id || species.conversion_factor() == id {
+            usages.push(ElementRef::Species { id: species.id() });
+        }
+    }
+
+    for initial_assignment in model.list_of_initial_assignments() {
+        let symbol = initial_assignment.symbol();
+        if symbol == id || mentions(&initial_assignment.formula(), initial_assignment.math(), id) {
+            usages.push(ElementRef::InitialAssignment { symbol });
+        }
+    }
+    for rule in model.list_of_rate_rules() {
+        let variable = rule.variable();
+        if variable == id || mentions(&rule.formula(), rule.math(), id) {
+            usages.push(ElementRef::RateRule { variable });
+        }
+    }
+    for rule in model.list_of_assignment_rules() {
+        let variable = rule.variable();
+        if variable == id || mentions(&rule.formula(), rule.math(), id) {
+            usages.push(ElementRef::AssignmentRule { variable });
+        }
+    }
+
+    for reaction in model.list_of_reactions() {
+        #[cfg(feature = "fbc")]
+        {
+            let bounds = [reaction.lower_flux_bound(), reaction.upper_flux_bound()];
+            if bounds.iter().flatten().any(|bound| bound == id) {
+                usages.push(ElementRef::Reaction { id: reaction.id() });
+            }
+        }
+
+        for reference in reaction
+            .reactants()
+            .borrow()
+            .iter()
+            .chain(reaction.products().borrow().iter())
+        {
+            if reference.species() == id {
+                usages.push(ElementRef::SpeciesReference {
+                    reaction: reaction.id(),
+                    species: reference.species(),
+                });
+            }
+        }
+        for modifier in reaction.modifiers().borrow().iter() {
+            if modifier.species() == id {
+                usages.push(ElementRef::ModifierSpeciesReference {
+                    reaction: reaction.id(),
+                    species: modifier.species(),
+                });
+            }
+        }
+
+        if let Some(kinetic_law) = reaction.kinetic_law() {
+            // A local parameter with the same identifier shadows the global one
+            let shadowed = kinetic_law
+                .list_of_local_parameters()
+                .iter()
+                .any(|parameter| parameter.id() == id);
+            if !shadowed && mentions(&kinetic_law.formula(), kinetic_law.math(), id) {
+                usages.push(ElementRef::KineticLaw {
+                    reaction: reaction.id(),
+                });
+            }
+        }
+    }
+
+    for event in model.list_of_events() {
+        let in_trigger = event
+            .trigger()
+            .is_some_and(|trigger| mentions(&trigger.formula(), trigger.math(), id));
+        let in_delay = event
+            .delay()
+            .is_some_and(|delay| mentions(&delay.formula(), delay.math(), id));
+        let in_assignments = event.list_of_event_assignments().iter().any(|assignment| {
+            assignment.variable() == id || mentions(&assignment.formula(), assignment.math(), id)
+        });
+        if in_trigger || in_delay || in_assignments {
+            usages.push(ElementRef::Event { id: event.id() });
+        }
+    }
+
+    #[cfg(feature = "fbc")]
+    if let Some(plugin) = model.fbc_plugin_if_enabled() {
+        for objective in plugin.list_of_objectives() {
+            let in_objective = objective
+                .list_of_flux_objectives()
+                .iter()
+                .any(|flux_objective| flux_objective.reaction() == id);
+            if in_objective {
+                usages.push(ElementRef::Objective { id: objective.id() });
+            }
+        }
+    }
+
+    usages
+}
+
+/// Finds the elements no other element refers to, see [`Model::unused_elements`].
+pub(crate) fn unused_elements(model: &Model) -> UnusedElements {
+    let parameters = model
+        .list_of_parameters()
+        .iter()
+        .map(|parameter| parameter.id())
+        .filter(|id| *id != model.conversion_factor() && usages(model, id).is_empty())
+        .collect();
+
+    let species = model
+        .list_of_species()
+        .iter()
+        .map(|species| species.id())
+        .filter(|id| usages(model, id).is_empty())
+        .collect();
+
+    let mut used_units: Vec<String> = [
+        model.substance_units(),
+        model.time_units(),
+        model.volume_units(),
+        model.area_units(),
+        model.length_units(),
+        model.extent_units(),
+    ]
+    .into_iter()
+    .chain(
+        model
+            .list_of_compartments()
+            .iter()
+            .map(|compartment| compartment.unit()),
+    )
+    .chain(model.list_of_species().iter().map(|species| species.unit()))
+    .chain(
+        model
+            .list_of_parameters()
+            .iter()
+            .map(|parameter| parameter.units()),
+    )
+    .collect();
+    for reaction in model.list_of_reactions() {
+        if let Some(kinetic_law) = reaction.kinetic_law() {
+            used_units.extend(
+                kinetic_law
+                    .list_of_local_parameters()
+                    .iter()
+                    .map(|parameter| parameter.units()),
+            );
+        }
+    }
+
+    let unit_definitions = model
+        .list_of_unit_definitions()
+        .iter()
+        .map(|unit_definition| unit_definition.id())
+        .filter(|id| {
+            !used_units.contains(id)
+                && !DEFAULT_UNITS.contains(&id.as_str())
+                && usages(model, id).is_empty()
+        })
+        .collect();
+
+    UnusedElements {
+        parameters,
+        unit_definitions,
+        species,
+    }
+}
+
+/// Returns whether the math of an element refers to an identifier, either as a symbol
+/// or as the units of a number.
+///
+/// Formulas that cannot be parsed are searched for the identifier as a word.
+fn mentions(formula: &str, math: *const sbmlcxx::ASTNode, id: &str) -> bool {
+    if uses_units(math, id) {
+        return true;
+    }
+    match Expr::parse(formula) {
+        Ok(expr) => expr.references(id),
+        Err(_) => formula
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .any(|word| word == id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// Builds a model with unused parameters, species and unit definitions
+    fn build_model<'a>(doc: &'a SBMLDocument<'a>) {
+        let model = doc.create_model("test");
+        model
+            .build_unit_definition("per_second", "1 / s")
+            .unit(UnitKind::Second, Some(-1), Some(0), None, None)
+            .build();
+        model
+            .build_unit_definition("mM", "mmol / l")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Litre, Some(-1), Some(0), None, None)
+            .build();
+        model.build_compartment("cytosol").size(1.0).build();
+        model
+            .build_species("glc")
+            .compartment("cytosol")
+            .initial_concentration(5.0)
+            .build();
+        model
+            .build_species("g6p")
+            .compartment("cytosol")
+            .initial_concentration(0.0)
+            .build();
+        model
+            .build_species("atp")
+            .compartment("cytosol")
+            .initial_concentration(1.0)
+            .build();
+        model
+            .build_species("orphan")
+            .compartment("cytosol")
+            .initial_concentration(1.0)
+            .build();
+        model
+            .build_parameter("k1")
+            .value(0.1)
+            .units("per_second")
+            .build();
+        model.build_parameter("dead").value(1.0).build();
+        model.build_parameter("total").value(0.0).build();
+        model.build_assignment_rule("total", "glc + g6p").build();
+        model
+            .build_reaction("hk")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .modifier("atp")
+            .kinetic_law("k1 * glc")
+            .build();
+        model
+            .build_event("pulse")
+            .trigger("time > 10")
+            .assignment("glc", "glc + 1")
+            .build();
+    }
+
+    #[test]
+    fn test_usages_of_species() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+
+        let usages = model.usages("glc");
+        assert_eq!(
+            usages,
+            vec![
+                ElementRef::AssignmentRule {
+                    variable: "total".to_string()
+                },
+                ElementRef::SpeciesReference {
+                    reaction: "hk".to_string(),
+                    species: "glc".to_string()
+                },
+                ElementRef::KineticLaw {
+                    reaction: "hk".to_string()
+                },
+                ElementRef::Event {
+                    id: "pulse".to_string()
+                },
+            ]
+        );
+        assert!(model
+            .usages("atp")
+            .contains(&ElementRef::ModifierSpeciesReference {
+                reaction: "hk".to_string(),
+                species: "atp".to_string()
+            }));
+    }
+
+    #[test]
+    fn test_usages_of_compartment() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+
+        let usages = model.usages("cytosol");
+        assert_eq!(usages.len(), 4);
+        assert_eq!(
+            usages[0].to_string(),
+            "species 'glc'",
+            "Species must be listed in document order"
+        );
+    }
+
+    #[test]
+    fn test_local_parameter_shadows_global() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+        let kinetic_law = model.get_reaction("hk").unwrap().kinetic_law().unwrap();
+        kinetic_law.build_local_parameter("k1").value(1.0).build();
+
+        assert!(model.usages("k1").is_empty());
+    }
+
+    #[test]
+    fn test_unused_elements() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+
+        let unused = model.unused_elements();
+        assert_eq!(unused.parameters, vec!["dead"]);
+        assert_eq!(unused.species, vec!["orphan"]);
+        assert_eq!(unused.unit_definitions, vec!["mM"]);
+        assert!(!unused.is_empty());
+    }
+
+    #[test]
+    fn test_model_attributes_are_uses() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+        model.set_substance_units("mM");
+        model.set_conversion_factor("dead");
+
+        let unused = model.unused_elements();
+        assert!(unused.parameters.is_empty());
+        assert!(unused.unit_definitions.is_empty());
+    }
+
+    #[test]
+    fn test_species_conversion_factor_is_use() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+        model
+            .get_species("glc")
+            .unwrap()
+            .set_conversion_factor("dead");
+
+        assert_eq!(
+            model.usages("dead"),
+            vec![ElementRef::Species {
+                id: "glc".to_string()
+            }]
+        );
+        assert!(model.unused_elements().parameters.is_empty());
+    }
+
+    #[test]
+    fn test_units_of_numbers_are_uses() {
+        let doc = SBMLDocument::default();
+        build_model(&doc);
+        let model = doc.model().unwrap();
+        model.build_initial_assignment("g6p", "0.5 mM").build();
+
+        assert_eq!(
+            model.usages("mM"),
+            vec![ElementRef::InitialAssignment {
+                symbol: "g6p".to_string()
+            }]
+        );
+        assert!(model.unused_elements().unit_definitions.is_empty());
+    }
+}
@@ -0,0 +1,413 @@
+//! Traversal of all elements of a document.
+//!
+//! The [`Visitor`] trait has a callback for each type of element, all of which do
+//! nothing by default. [`SBMLDocument::walk`] traverses the document in document
+//! order and calls the callback of every element it passes, including nested elements
+//! such as the units of a unit definition or the participants of a reaction. Container
+//! elements additionally have a `leave_*` callback, which is called after their nested
+//! elements have been visited.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! /// Reports reactions without a kinetic law
+//! #[derive(Default)]
+//! struct MissingKineticLaws {
+//!     current: Option<String>,
+//!     has_kinetic_law: bool,
+//!     missing: Vec<String>,
+//! }
+//!
+//! impl Visitor for MissingKineticLaws {
+//!     fn visit_reaction(&mut self, reaction: &Reaction) {
+//!         self.current = Some(reaction.id());
+//!         self.has_kinetic_law = false;
+//!     }
+//!
+//!     fn visit_kinetic_law(&mut self, _kinetic_law: &KineticLaw) {
+//!         self.has_kinetic_law = true;
+//!     }
+//!
+//!     fn leave_reaction(&mut self, _reaction: &Reaction) {
+//!         if !self.has_kinetic_law {
+//!             self.missing.extend(self.current.take());
+//!         }
+//!     }
+//! }
+//!
+//! let doc = SBMLReader::from_file("model.xml").unwrap();
+//! let mut visitor = MissingKineticLaws::default();
+//! doc.walk(&mut visitor);
+//! println!("Reactions without kinetic law: {:?}", visitor.missing);
+//! ```
+
+use crate::{
+    compartment::Compartment, delay::Delay, diff::SpeciesRole, event::Event,
+    eventassign::EventAssignment, initassign::InitialAssignment, kineticlaw::KineticLaw,
+    localparam::LocalParameter, model::Model, modref::ModifierSpeciesReference,
+    parameter::Parameter, reaction::Reaction, rule::Rule, species::Species,
+    speciesref::SpeciesReference, traits::fromptr::FromPtr, trigger::Trigger, unit::Unit,
+    unitdef::UnitDefinition,
+};
+
+#[cfg(feature = "fbc")]
+use crate::fbc::{FluxObjective, GeneProduct, Objective};
+
+/// Callbacks for the elements of a document, called by [`SBMLDocument::walk`].
+///
+/// All callbacks do nothing by default, so that implementations only override the
+/// callbacks of the elements they are interested in.
+///
+/// [`SBMLDocument::walk`]: crate::sbmldoc::SBMLDocument::walk
+#[allow(unused_variables)]
+pub trait Visitor {
+    /// Called for the model, before any of its elements
+    fn visit_model(&mut self, model: &Model) {}
+
+    /// Called for the model, after all of its elements
+    fn leave_model(&mut self, model: &Model) {}
+
+    /// Called for each unit definition, before its units
+    fn visit_unit_definition(&mut self, unit_definition: &UnitDefinition) {}
+
+    /// Called for each unit definition, after its units
+    fn leave_unit_definition(&mut self, unit_definition: &UnitDefinition) {}
+
+    /// Called for each unit of a unit definition
+    fn visit_unit(&mut self, unit: &Unit) {}
+
+    /// Called for each compartment
+    fn visit_compartment(&mut self, compartment: &Compartment) {}
+
+    /// Called for each species
+    fn visit_species(&mut self, species: &Species) {}
+
+    /// Called for each global parameter
+    fn visit_parameter(&mut self, parameter: &Parameter) {}
+
+    /// Called for each initial assignment
+    fn visit_initial_assignment(&mut self, initial_assignment: &InitialAssignment) {}
+
+    /// Called for each rule, regardless of its type
+    fn visit_rule(&mut self, rule: &Rule) {}
+
+    /// Called for each reaction, before its participants and kinetic law
+    fn visit_reaction(&mut self, reaction: &Reaction) {}
+
+    /// Called for each reaction, after its participants and kinetic law
+    fn leave_reaction(&mut self, reaction: &Reaction) {}
+
+    /// Called for each reactant and product of a reaction
+    fn visit_species_reference(&mut self, species_reference: &SpeciesReference, role: SpeciesRole) {
+    }
+
+    /// Called for each modifier of a reaction
+    fn visit_modifier(&mut self, modifier: &ModifierSpeciesReference) {}
+
+    /// Called for the kinetic law of a reaction, before its local parameters
+    fn visit_kinetic_law(&mut self, kinetic_law: &KineticLaw) {}
+
+    /// Called for the kinetic law of a reaction, after its local parameters
+    fn leave_kinetic_law(&mut self, kinetic_law: &KineticLaw) {}
+
+    /// Called for each local parameter of a kinetic law
+    fn visit_local_parameter(&mut self, local_parameter: &LocalParameter) {}
+
+    /// Called for each event, before its trigger, delay and event assignments
+    fn visit_event(&mut self, event: &Event) {}
+
+    /// Called for each event, after its trigger, delay and event assignments
+    fn leave_event(&mut self, event: &Event) {}
+
+    /// Called for the trigger of an event
+    fn visit_trigger(&mut self, trigger: &Trigger) {}
+
+    /// Called for the delay of an event
+    fn visit_delay(&mut self, delay: &Delay) {}
+
+    /// Called for each event assignment of an event
+    fn visit_event_assignment(&mut self, event_assignment: &EventAssignment) {}
+
+    /// Called for each objective (fbc package), before its flux objectives
+    #[cfg(feature = "fbc")]
+    fn visit_objective(&mut self, objective: &Objective) {}
+
+    /// Called for each flux objective of an objective (fbc package)
+    #[cfg(feature = "fbc")]
+    fn visit_flux_objective(&mut self, flux_objective: &FluxObjective) {}
+
+    /// Called for each gene product (fbc package)
+    #[cfg(feature = "fbc")]
+    fn visit_gene_product(&mut self, gene_product: &GeneProduct) {}
+}
+
+/// Traverses a model in document order, see [`SBMLDocument::walk`].
+///
+/// [`SBMLDocument::walk`]: crate::sbmldoc::SBMLDocument::walk
+pub(crate) fn walk_model<V: Visitor + ?Sized>(model: &Model, visitor: &mut V) {
+    visitor.visit_model(model);
+
+    for unit_definition in model.list_of_unit_definitions() {
+        visitor.visit_unit_definition(&unit_definition);
+        for unit in unit_definition.units() {
+            visitor.visit_unit(&unit);
+        }
+        visitor.leave_unit_definition(&unit_definition);
+    }
+    for compartment in model.list_of_compartments() {
+        visitor.visit_compartment(&compartment);
+    }
+    for species in model.list_of_species() {
+        visitor.visit_species(&species);
+    }
+    for parameter in model.list_of_parameters() {
+        visitor.visit_parameter(&parameter);
+    }
+    for initial_assignment in model.list_of_initial_assignments() {
+        visitor.visit_initial_assignment(&initial_assignment);
+    }
+
+    // The model keeps rate and assignment rules apart, so the rules are taken from
+    // libSBML to preserve their order
+    let n_rules = model.inner().borrow().getNumRules().0;
+    for i in 0..n_rules {
+        let rule = model.inner().borrow_mut().as_mut().getRule1(i.into());
+        visitor.visit_rule(&Rule::from_ptr(rule));
+    }
+
+    for reaction in model.list_of_reactions() {
+        visitor.visit_reaction(&reaction);
+        for reactant in reaction.reactants().borrow().iter() {
+            visitor.visit_species_reference(reactant, SpeciesRole::Reactant);
+        }
+        for product in reaction.products().borrow().iter() {
+            visitor.visit_species_reference(product, SpeciesRole::Product);
+        }
+        for modifier in reaction.modifiers().borrow().iter() {
+            visitor.visit_modifier(modifier);
+        }
+        if let Some(kinetic_law) = reaction.kinetic_law() {
+            visitor.visit_kinetic_law(&kinetic_law);
+            for local_parameter in kinetic_law.list_of_local_parameters() {
+                visitor.visit_local_parameter(&local_parameter);
+            }
+            visitor.leave_kinetic_law(&kinetic_law);
+        }
+        visitor.leave_reaction(&reaction);
+    }
+
+    for event in model.list_of_events() {
+        visitor.visit_event(&event);
+        if let Some(trigger) = event.trigger() {
+            visitor.visit_trigger(&trigger);
+        }
+        if let Some(delay) = event.delay() {
+            visitor.visit_delay(&delay);
+        }
+        for event_assignment in event.list_of_event_assignments() {
+            visitor.visit_event_assignment(&event_assignment);
+        }
+        visitor.leave_event(&event);
+    }
+
+    #[cfg(feature = "fbc")]
+    if let Some(plugin) = model.fbc_plugin_if_enabled() {
+        for objective in plugin.list_of_objectives() {
+            visitor.visit_objective(&objective);
+            for flux_objective in objective.list_of_flux_objectives() {
+                visitor.visit_flux_objective(&flux_objective);
+            }
+        }
+        for gene_product in plugin.list_of_gene_products() {
+            visitor.visit_gene_product(&gene_product);
+        }
+    }
+
+    visitor.leave_model(model);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// Records the visited elements as "<type> <id>" lines
+    #[derive(Default)]
+    struct Recorder {
+        visited: Vec<String>,
+    }
+
+    impl Visitor for Recorder {
+        fn visit_model(&mut self, model: &Model) {
+            self.visited.push(format!("model {}", model.id()));
+        }
+
+        fn leave_model(&mut self, model: &Model) {
+            self.visited.push(format!("/model {}", model.id()));
+        }
+
+        fn visit_unit_definition(&mut self, unit_definition: &UnitDefinition) {
+            self.visited
+                .push(format!("unitDefinition {}", unit_definition.id()));
+        }
+
+        fn visit_unit(&mut self, unit: &Unit) {
+            self.visited.push(format!("unit {:?}", unit.kind()));
+        }
+
+        fn visit_compartment(&mut self, compartment: &Compartment) {
+            self.visited
+                .push(format!("compartment {}", compartment.id()));
+        }
+
+        fn visit_species(&mut self, species: &Species) {
+            self.visited.push(format!("species {}", species.id()));
+        }
+
+        fn visit_rule(&mut self, rule: &Rule) {
+            self.visited.push(format!("rule {}", rule.variable()));
+        }
+
+        fn visit_reaction(&mut self, reaction: &Reaction) {
+            self.visited.push(format!("reaction {}", reaction.id()));
+        }
+
+        fn leave_reaction(&mut self, reaction: &Reaction) {
+            self.visited.push(format!("/reaction {}", reaction.id()));
+        }
+
+        fn visit_species_reference(&mut self, reference: &SpeciesReference, role: SpeciesRole) {
+            self.visited
+                .push(format!("{:?} {}", role, reference.species()));
+        }
+
+        fn visit_modifier(&mut self, modifier: &ModifierSpeciesReference) {
+            self.visited
+                .push(format!("modifier {}", modifier.species()));
+        }
+
+        fn visit_kinetic_law(&mut self, kinetic_law: &KineticLaw) {
+            self.visited
+                .push(format!("kineticLaw {}", kinetic_law.formula()));
+        }
+
+        fn visit_event(&mut self, event: &Event) {
+            self.visited.push(format!("event {}", event.id()));
+        }
+
+        fn visit_trigger(&mut self, _trigger: &Trigger) {
+            self.visited.push("trigger".to_string());
+        }
+
+        fn visit_event_assignment(&mut self, event_assignment: &EventAssignment) {
+            self.visited
+                .push(format!("eventAssignment {}", event_assignment.variable()));
+        }
+    }
+
+    #[test]
+    fn test_walk_in_document_order() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model
+            .build_unit_definition("mM", "mmol / l")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Litre, Some(-1), Some(0), None, None)
+            .build();
+        model.build_compartment("cytosol").size(1.0).build();
+        model.build_species("glc").compartment("cytosol").build();
+        model.build_species("atp").compartment("cytosol").build();
+        model
+            .build_reaction("uptake")
+            .reactant("glc", 1.0)
+            .product("glc", 1.0)
+            .modifier("atp")
+            .kinetic_law("atp")
+            .build();
+        model
+            .build_event("pulse")
+            .trigger("time > 10")
+            .assignment("glc", "1")
+            .build();
+
+        let mut recorder = Recorder::default();
+        doc.walk(&mut recorder);
+
+        assert_eq!(
+            recorder.visited,
+            vec![
+                "model test",
+                "unitDefinition mM",
+                "unit Mole",
+                "unit Litre",
+                "compartment cytosol",
+                "species glc",
+                "species atp",
+                "reaction uptake",
+                "Reactant glc",
+                "Product glc",
+                "modifier atp",
+                "kineticLaw atp",
+                "/reaction uptake",
+                "event pulse",
+                "trigger",
+                "eventAssignment glc",
+                "/model test",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_keeps_rule_order() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_assignment_rule("a", "1").build();
+        model.build_rate_rule("b", "2").build();
+        model.build_assignment_rule("c", "3").build();
+
+        let mut recorder = Recorder::default();
+        doc.walk(&mut recorder);
+
+        let rules: Vec<&str> = recorder
+            .visited
+            .iter()
+            .filter(|line| line.starts_with("rule"))
+            .map(String::as_str)
+            .collect();
+        assert_eq!(rules, vec!["rule a", "rule b", "rule c"]);
+    }
+
+    #[test]
+    fn test_walk_without_model() {
+        let doc = SBMLDocument::default();
+
+        let mut recorder = Recorder::default();
+        doc.walk(&mut recorder);
+
+        assert!(recorder.visited.is_empty());
+    }
+
+    #[test]
+    fn test_default_callbacks() {
+        /// Counts the local parameters only
+        struct LocalParameters(usize);
+
+        impl Visitor for LocalParameters {
+            fn visit_local_parameter(&mut self, _local_parameter: &LocalParameter) {
+                self.0 += 1;
+            }
+        }
+
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model.build_reaction("r1").kinetic_law("k1 * k2").build();
+        let kinetic_law = reaction.kinetic_law().unwrap();
+        kinetic_law.build_local_parameter("k1").value(1.0).build();
+        kinetic_law.build_local_parameter("k2").value(2.0).build();
+
+        let mut visitor = LocalParameters(0);
+        doc.walk(&mut visitor);
+        assert_eq!(visitor.0, 2);
+    }
+}
@@ -0,0 +1,97 @@
+//! Integration tests of the `sbml` command-line tool.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+use sbml::prelude::*;
+
+/// Path of the example model shipped with the tests
+const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/example.xml");
+
+/// Returns a command running the `sbml` binary
+fn sbml() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_sbml"))
+}
+
+#[test]
+fn test_info() {
+    let output = sbml().args(["info", EXAMPLE]).output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Level 3 Version 2"));
+    assert!(stdout.contains("Species:       2"));
+    assert!(stdout.contains("Reactions:     1"));
+}
+
+#[test]
+fn test_validate_invalid_document() {
+    let path = std::env::temp_dir().join("sbml_cli_invalid.xml");
+    let doc = SBMLDocument::new(3, 2);
+    let model = doc.create_model("invalid");
+    model
+        .build_species("glucose")
+        .compartment("missing")
+        .build();
+    std::fs::write(&path, doc.to_xml_string()).unwrap();
+
+    let output = sbml()
+        .args(["validate", "--json"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["valid"], false);
+    assert!(!report["errors"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_validate_missing_file() {
+    let output = sbml()
+        .args(["validate", "does_not_exist.xml"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_convert() {
+    // The example model redefines base units, which Level 2 does not permit
+    let doc = SBMLDocument::default();
+    let model = doc.create_model("convert");
+    model
+        .build_compartment("cytosol")
+        .size(1.0)
+        .constant(true)
+        .build();
+    model
+        .build_species("glc")
+        .compartment("cytosol")
+        .initial_concentration(1.0)
+        .build();
+    let input = std::env::temp_dir().join("sbml_cli_convert_input.xml");
+    std::fs::write(&input, doc.to_xml_string()).unwrap();
+
+    let path = std::env::temp_dir().join("sbml_cli_converted.xml");
+    let output = sbml()
+        .args(["convert", "--level", "2", "--version", "4"])
+        .arg(&input)
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let converted = SBMLReader::from_file(&path).unwrap();
+    assert_eq!((converted.level(), converted.version()), (2, 4));
+    assert_eq!(converted.model().unwrap().list_of_species().len(), 1);
+}
+
+#[test]
+fn test_diff_identical_files() {
+    let output = sbml().args(["diff", EXAMPLE, EXAMPLE]).output().unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,59 @@
+//! Integration tests of the libSBML build on Linux.
+//!
+//! libSBML and its XML parser are linked statically into the test binary, unless an
+//! installed libSBML is used.
+
+#![cfg(target_os = "linux")]
+
+use sbml::prelude::*;
+
+/// Path of the example model shipped with the tests
+const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/example.xml");
+
+#[test]
+fn test_read_example_from_file() {
+    let doc = SBMLReader::from_file(EXAMPLE).expect("Failed to read example.xml");
+    assert_eq!((doc.level(), doc.version()), (3, 2));
+
+    let model = doc.model().expect("Model not found");
+    assert_eq!(model.id(), "example");
+    assert_eq!(model.list_of_unit_definitions().len(), 2);
+    assert_eq!(model.list_of_compartments().len(), 1);
+    assert_eq!(model.list_of_species().len(), 2);
+    assert_eq!(model.list_of_reactions().len(), 1);
+
+    let ethanol = model.get_species("ethanol").expect("Species not found");
+    assert_eq!(ethanol.compartment(), "cytosol");
+    assert_eq!(ethanol.initial_concentration(), 0.5);
+}
+
+#[test]
+fn test_roundtrip_example() {
+    let doc = SBMLReader::from_file(EXAMPLE).expect("Failed to read example.xml");
+    let xml = doc.to_xml_string();
+
+    let read = SBMLReader::from_xml_string(&xml);
+    let model = read.model().expect("Model not found");
+    assert_eq!(model.id(), "example");
+    assert_eq!(model.list_of_species().len(), 2);
+}
+
+#[test]
+fn test_libsbml_linked_statically() {
+    if cfg!(feature = "system-libsbml") || option_env!("SBML_LIB_DIR").is_some() {
+        return;
+    }
+
+    // Touch libSBML, so that a shared library would be loaded by now
+    let doc = SBMLDocument::default();
+    doc.create_model("static");
+
+    let maps = std::fs::read_to_string("/proc/self/maps").expect("Failed to read maps");
+    for library in ["libsbml", "libexpat"] {
+        assert!(
+            !maps.contains(&format!("{}.so", library)),
+            "{} is linked dynamically",
+            library
+        );
+    }
+}